@@ -1,7 +1,8 @@
-use crate::line_editor::configure_ctrl_c;
+use crate::line_editor::{configure_ctrl_c, configure_termination};
 use nu_command::commands::default_context::create_default_context;
 #[allow(unused_imports)]
 use nu_command::maybe_print_errors;
+use nu_engine::plugin::build_plugin::{plugin_dirs, search_paths};
 use nu_engine::run_block;
 use nu_engine::EvaluationContext;
 
@@ -21,10 +22,14 @@ use nu_stream::InputStream;
 #[allow(unused_imports)]
 use std::sync::atomic::Ordering;
 
-use nu_command::script::{print_err, run_script_standalone};
+use nu_command::script::{print_err, print_file_err, run_exit_hook, run_script_standalone};
 
 #[cfg(feature = "rustyline-support")]
-use rustyline::{self, error::ReadlineError};
+use rustyline::{
+    self,
+    config::{Configurer, EditMode},
+    error::ReadlineError,
+};
 
 use crate::EnvironmentSyncer;
 use nu_errors::ShellError;
@@ -33,43 +38,67 @@ use nu_protocol::{UntaggedValue, Value};
 
 use std::error::Error;
 use std::iter::Iterator;
-use std::path::PathBuf;
+#[cfg(feature = "rustyline-support")]
+use std::path::Path;
+#[cfg(feature = "rustyline-support")]
+use std::time::SystemTime;
 
-pub fn search_paths() -> Vec<std::path::PathBuf> {
-    use std::env;
+#[cfg(feature = "rustyline-support")]
+use crate::shell::helper::continue_input;
+
+/// Runs a script, then its `main` definition, if it has one, with the given arguments. Login
+/// shells source the login scripts first.
+pub async fn run_script_file(
+    file_contents: String,
+    redirect_stdin: bool,
+    script_args: Vec<String>,
+    login: bool,
+) -> Result<(), Box<dyn Error>> {
+    let context = script_context(login).await?;
 
-    let mut search_paths = Vec::new();
+    run_script_standalone(file_contents, redirect_stdin, &context, true).await?;
 
-    // Automatically add path `nu` is in as a search path
-    if let Ok(exe_path) = env::current_exe() {
-        if let Some(exe_dir) = exe_path.parent() {
-            search_paths.push(exe_dir.to_path_buf());
-        }
+    if context.scope.has_command("main") {
+        run_script_standalone(main_call(&script_args), false, &context, true).await?;
     }
 
-    if let Ok(config) = nu_data::config::config(Tag::unknown()) {
-        if let Some(plugin_dirs) = config.get("plugin_dirs") {
-            if let Value {
-                value: UntaggedValue::Table(pipelines),
-                ..
-            } = plugin_dirs
-            {
-                for pipeline in pipelines {
-                    if let Ok(plugin_dir) = pipeline.as_string() {
-                        search_paths.push(PathBuf::from(plugin_dir));
-                    }
-                }
-            }
+    run_exit_hook(&context).await;
+
+    Ok(())
+}
+
+/// Builds the call to a script's `main`, leaving flags bare so they are parsed against its
+/// signature and quoting everything else.
+fn main_call(args: &[String]) -> String {
+    let mut call = String::from("main");
+
+    for arg in args {
+        let is_flag = arg.len() > 1
+            && arg.starts_with('-')
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        call.push(' ');
+        if is_flag {
+            call.push_str(arg);
+        } else if !arg.contains('\'') {
+            call.push_str(&format!("'{}'", arg));
+        } else if !arg.contains('"') {
+            call.push_str(&format!("\"{}\"", arg));
+        } else {
+            call.push_str(&format!("`{}`", arg));
         }
     }
 
-    search_paths
+    call
 }
 
-pub async fn run_script_file(
-    file_contents: String,
-    redirect_stdin: bool,
-) -> Result<(), Box<dyn Error>> {
+/// A context for running scripts non-interactively, with the environment, plugins, and startup
+/// commands loaded, and the login scripts sourced for a login shell.
+pub(crate) async fn script_context(login: bool) -> Result<EvaluationContext, Box<dyn Error>> {
+    report_config_problems();
+
     let mut syncer = EnvironmentSyncer::new();
     let mut context = create_default_context(false)?;
     let config = syncer.get_config();
@@ -85,18 +114,25 @@ pub async fn run_script_file(
 
         let _ = register_plugins(ctx);
         let _ = configure_ctrl_c(ctx);
+        let _ = configure_termination(ctx);
     });
 
-    let _ = run_startup_commands(&mut context, &config).await;
+    if let Err(err) = run_startup_commands(&mut context, &config).await {
+        print_config_err(err);
+    }
 
-    run_script_standalone(file_contents, redirect_stdin, &context, true).await?;
+    if login {
+        crate::login::run_login_scripts(&context).await;
+    }
 
-    Ok(())
+    Ok(context)
 }
 
-/// The entry point for the CLI. Will register all known internal commands, load experimental commands, load plugins, then prepare the prompt and line reader for input.
+/// The entry point for the CLI. Will register all known internal commands, load experimental commands, load plugins, then prepare the prompt and line reader for input. Login shells also source the login scripts.
 #[cfg(feature = "rustyline-support")]
-pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
+pub async fn cli(mut context: EvaluationContext, login: bool) -> Result<(), Box<dyn Error>> {
+    report_config_problems();
+
     let mut syncer = EnvironmentSyncer::new();
     let configuration = syncer.get_config();
 
@@ -112,19 +148,28 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
         }
 
         let _ = configure_ctrl_c(ctx);
+        let _ = configure_termination(ctx);
         let _ = configure_rustyline_editor(&mut rl, config);
 
         let helper = Some(nu_line_editor_helper(ctx, config));
         rl.set_helper(helper);
     });
 
-    let _ = run_startup_commands(&mut context, &configuration).await;
+    if let Err(err) = run_startup_commands(&mut context, &configuration).await {
+        print_config_err(err);
+    }
+
+    if login {
+        crate::login::run_login_scripts(&context).await;
+    }
 
     // Give ourselves a scope to work in
     context.scope.enter_scope();
 
     let history_path = nu_engine::history_path(&configuration);
     let _ = rl.load_history(&history_path);
+    let mut history_saved = history_file_state(&history_path);
+    let _ = nu_engine::trim_history_timings(&configuration);
 
     let mut session_text = String::new();
     let mut line_start: usize = 0;
@@ -146,6 +191,7 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
     }
 
     let mut ctrlcbreak = false;
+    let mut last_command = crate::prompt::LastCommand::default();
 
     loop {
         if context.ctrl_c.load(Ordering::SeqCst) {
@@ -153,67 +199,23 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
             continue;
         }
 
-        let cwd = context.shell_manager.path();
-
-        let colored_prompt = {
-            if let Some(prompt) = configuration.var("prompt") {
-                let prompt_line = prompt.as_string()?;
-
-                context.scope.enter_scope();
-                let (prompt_block, err) = nu_parser::parse(&prompt_line, 0, &context.scope);
+        let integration = crate::shell_integration::ShellIntegration::from_config(&configuration);
+        integration.prompt_shown(&context.shell_manager.path());
 
-                if err.is_some() {
-                    context.scope.exit_scope();
-
-                    format!("\x1b[32m{}{}\x1b[m> ", cwd, current_branch())
-                } else {
-                    // let env = context.get_env();
-
-                    let run_result = run_block(&prompt_block, &context, InputStream::empty()).await;
-                    context.scope.exit_scope();
-
-                    match run_result {
-                        Ok(result) => match result.collect_string(Tag::unknown()).await {
-                            Ok(string_result) => {
-                                let errors = context.get_errors();
-                                maybe_print_errors(&context, Text::from(prompt_line));
-                                context.clear_errors();
-
-                                if !errors.is_empty() {
-                                    "> ".to_string()
-                                } else {
-                                    string_result.item
-                                }
-                            }
-                            Err(e) => {
-                                crate::cli::print_err(e, &Text::from(prompt_line));
-                                context.clear_errors();
-
-                                "> ".to_string()
-                            }
-                        },
-                        Err(e) => {
-                            crate::cli::print_err(e, &Text::from(prompt_line));
-                            context.clear_errors();
-
-                            "> ".to_string()
-                        }
-                    }
-                }
-            } else {
-                format!("\x1b[32m{}{}\x1b[m> ", cwd, current_branch())
-            }
-        };
+        // The prompt goes by the mode the line editor is in, rather than the one configured
+        let vi_mode = rl.config_mut().edit_mode() == EditMode::Vi;
+        let colored_prompt =
+            crate::prompt::render(&configuration, &context, &last_command, vi_mode)
+                .await
+                .unwrap_or_else(|err| {
+                    print_config_err(err);
+                    "> ".to_string()
+                });
 
-        let prompt = {
-            if let Ok(bytes) = strip_ansi_escapes::strip(&colored_prompt) {
-                String::from_utf8_lossy(&bytes).to_string()
-            } else {
-                "> ".to_string()
-            }
-        };
+        let prompt = strip_ansi(&colored_prompt);
 
-        rl.helper_mut().expect("No helper").colored_prompt = colored_prompt;
+        rl.helper_mut().expect("No helper").colored_prompt =
+            integration.wrap_prompt(&colored_prompt);
         let mut initial_command = Some(String::new());
         let mut readline = Err(ReadlineError::Eof);
         while let Some(ref cmd) = initial_command {
@@ -221,14 +223,26 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
             initial_command = None;
         }
 
+        let continuation = crate::prompt::continuation(&configuration);
+        let readline = readline.and_then(|line| {
+            continue_input(line, || {
+                rl.helper_mut().expect("No helper").colored_prompt = continuation.clone();
+                rl.readline(&strip_ansi(&continuation))
+            })
+        });
+
         if let Ok(line) = &readline {
             line_start = session_text.len();
             session_text.push_str(line);
             session_text.push('\n');
         }
 
+        let started = std::time::SystemTime::now();
+        let timer = std::time::Instant::now();
+
         let line = match convert_rustyline_result_to_string(readline) {
             LineResult::Success(_) => {
+                integration.command_started(&session_text[line_start..]);
                 process_script(
                     &session_text[line_start..],
                     &context,
@@ -259,27 +273,52 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
             let _ = configure_rustyline_editor(&mut rl, config);
         });
 
+        let elapsed = timer.elapsed();
+
+        if let LineResult::Success(line) | LineResult::Error(line, _) = &line {
+            let timing = nu_engine::HistoryTiming {
+                command: line.trim().to_string(),
+                start: started
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|since| since.as_secs() as i64)
+                    .unwrap_or_default(),
+                duration: elapsed.as_nanos() as u64,
+            };
+            let _ = nu_engine::record_history_timings(&configuration, &[timing]);
+        }
+
         match line {
             LineResult::Success(line) => {
-                rl.add_history_entry(&line);
-                let _ = rl.save_history(&history_path);
-                maybe_print_errors(&context, Text::from(session_text.clone()));
+                add_history_entry(&mut rl, &history_path, &line, &mut history_saved);
+                let failed = maybe_print_errors(&context, Text::from(session_text.clone()));
+                integration.command_finished(failed);
+
+                last_command = crate::prompt::LastCommand {
+                    failed,
+                    duration: Some(elapsed),
+                };
             }
 
             LineResult::ClearHistory => {
                 rl.clear_history();
                 let _ = rl.save_history(&history_path);
+                let _ = std::fs::remove_file(nu_engine::history_timing_path(&configuration));
             }
 
             LineResult::Error(line, err) => {
-                rl.add_history_entry(&line);
-                let _ = rl.save_history(&history_path);
+                add_history_entry(&mut rl, &history_path, &line, &mut history_saved);
 
                 context.with_host(|_host| {
                     print_err(err, &Text::from(session_text.clone()));
                 });
 
                 maybe_print_errors(&context, Text::from(session_text.clone()));
+                integration.command_finished(true);
+
+                last_command = crate::prompt::LastCommand {
+                    failed: true,
+                    duration: Some(elapsed),
+                };
             }
 
             LineResult::CtrlC => {
@@ -294,6 +333,8 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
 
                 if ctrlcbreak {
                     let _ = rl.save_history(&history_path);
+                    let _ = nu_engine::trim_history_timings(&configuration);
+                    run_exit_hook(&context).await;
                     std::process::exit(0);
                 } else {
                     context.with_host(|host| host.stdout("CTRL-C pressed (again to quit)"));
@@ -318,12 +359,53 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
 
     // we are ok if we can not save history
     let _ = rl.save_history(&history_path);
+    let _ = nu_engine::trim_history_timings(&configuration);
+
+    run_exit_hook(&context).await;
 
     Ok(())
 }
 
+/// Adds the line to the history of the line editor and saves it. Commands like `history import`
+/// add to the history file behind the line editor's back, so the file is read again first when it
+/// changed since the line editor last saved it.
+#[cfg(feature = "rustyline-support")]
+fn add_history_entry<H: rustyline::Helper>(
+    rl: &mut rustyline::Editor<H>,
+    history_path: &Path,
+    line: &str,
+    saved: &mut Option<(u64, SystemTime)>,
+) {
+    if history_file_state(history_path) != *saved {
+        rl.clear_history();
+        let _ = rl.load_history(history_path);
+    }
+
+    rl.add_history_entry(line);
+    let _ = rl.save_history(history_path);
+    *saved = history_file_state(history_path);
+}
+
+/// The size of the history file and when it last changed.
+#[cfg(feature = "rustyline-support")]
+fn history_file_state(history_path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = std::fs::metadata(history_path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// The text of a prompt, which the line editor measures, without its colors.
+#[cfg(feature = "rustyline-support")]
+fn strip_ansi(prompt: &str) -> String {
+    match strip_ansi_escapes::strip(prompt) {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).to_string(),
+        Err(_) => "> ".to_string(),
+    }
+}
+
 pub fn register_plugins(context: &mut EvaluationContext) -> Result<(), ShellError> {
-    if let Ok(plugins) = nu_engine::plugin::build_plugin::scan(search_paths()) {
+    if let Ok(plugins) =
+        nu_engine::plugin::build_plugin::scan(search_paths(), &plugin_dirs(), false)
+    {
         context.add_commands(
             plugins
                 .into_iter()
@@ -335,6 +417,39 @@ pub fn register_plugins(context: &mut EvaluationContext) -> Result<(), ShellErro
     Ok(())
 }
 
+/// Reports the settings in the config file that nu doesn't know of, or that hold the wrong type of
+/// value. Nu runs with the defaults of those.
+fn report_config_problems() {
+    let path = match config::default_path() {
+        Ok(path) if path.exists() => path,
+        _ => return,
+    };
+
+    if let Ok((contents, problems)) = config::schema::check(&path) {
+        let file = path.to_string_lossy();
+        let source = Text::from(contents);
+
+        for problem in problems {
+            print_file_err(problem, &file, &source);
+        }
+    }
+}
+
+/// Reports an error in a setting of the config file against the text of the file.
+fn print_config_err(err: ShellError) {
+    let path = match config::default_path() {
+        Ok(path) => path,
+        Err(_) => return print_err(err, &Text::from("")),
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => print_file_err(err, &path.to_string_lossy(), &Text::from(contents)),
+        Err(_) => print_err(err, &Text::from("")),
+    }
+}
+
+/// Runs the `startup` commands from the config one at a time, so that an error in one of them is
+/// reported against its own text and doesn't keep the rest from running.
 async fn run_startup_commands(
     context: &mut EvaluationContext,
     config: &dyn nu_data::config::Conf,
@@ -345,12 +460,31 @@ async fn run_startup_commands(
                 value: UntaggedValue::Table(pipelines),
                 ..
             } => {
-                let mut script_file = String::new();
+                let mut script = String::new();
+
                 for pipeline in pipelines {
-                    script_file.push_str(&pipeline.as_string()?);
-                    script_file.push('\n');
+                    match pipeline.as_string() {
+                        Ok(line) => {
+                            script.push_str(&line);
+                            script.push('\n');
+                        }
+                        Err(err) => {
+                            print_config_err(err);
+                            continue;
+                        }
+                    }
+
+                    // A command can continue on the next entries, like a `def` with its body
+                    // split across lines, so only run what has all its brackets closed
+                    if nu_parser::lex(&script, 0).1.is_none() {
+                        let script = std::mem::take(&mut script);
+                        let _ = run_script_standalone(script, false, context, false).await;
+                    }
+                }
+
+                if !script.is_empty() {
+                    let _ = run_script_standalone(script, false, context, false).await;
                 }
-                let _ = run_script_standalone(script_file, false, context, false).await;
             }
             _ => {
                 return Err(ShellError::untagged_runtime_error(
@@ -389,22 +523,6 @@ pub async fn parse_and_eval(line: &str, ctx: &EvaluationContext) -> Result<Strin
     result?.collect_string(Tag::unknown()).await.map(|x| x.item)
 }
 
-#[allow(dead_code)]
-fn current_branch() -> String {
-    #[cfg(feature = "shadow-rs")]
-    {
-        Some(shadow_rs::branch())
-            .map(|x| x.trim().to_string())
-            .filter(|x| !x.is_empty())
-            .map(|x| format!("({})", x))
-            .unwrap_or_default()
-    }
-    #[cfg(not(feature = "shadow-rs"))]
-    {
-        "".to_string()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use nu_engine::basic_evaluation_context;
@@ -17,7 +17,9 @@ impl PathCompleter {
         let expanded = nu_parser::expand_ndots(partial);
         let expanded = expanded.as_ref();
 
-        let (base_dir_name, partial) = match expanded.rfind(SEP) {
+        // Windows takes forward slashes as separators too
+        let is_separator = |c: char| c == SEP || (cfg!(windows) && c == '/');
+        let (base_dir_name, partial) = match expanded.rfind(is_separator) {
             Some(pos) => expanded.split_at(pos + SEP.len_utf8()),
             None => ("", expanded),
         };
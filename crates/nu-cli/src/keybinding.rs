@@ -1,4 +1,6 @@
-use serde::{Deserialize, Serialize};
+use nu_data::keybinding::{
+    Anchor, At, CharSearch, Cmd, KeyPress, Keybinding, Keybindings, Movement, Word,
+};
 
 fn convert_keypress(keypress: KeyPress) -> rustyline::KeyPress {
     match keypress {
@@ -147,264 +149,6 @@ fn convert_keybinding(keybinding: Keybinding) -> (rustyline::KeyPress, rustyline
     )
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum KeyPress {
-    /// Unsupported escape sequence (on unix platform)
-    UnknownEscSeq,
-    /// ⌫ or `KeyPress::Ctrl('H')`
-    Backspace,
-    /// ⇤ (usually Shift-Tab)
-    BackTab,
-    /// Paste (on unix platform)
-    BracketedPasteStart,
-    /// Paste (on unix platform)
-    BracketedPasteEnd,
-    /// Single char
-    Char(char),
-    /// Ctrl-↓
-    ControlDown,
-    /// Ctrl-←
-    ControlLeft,
-    /// Ctrl-→
-    ControlRight,
-    /// Ctrl-↑
-    ControlUp,
-    /// Ctrl-char
-    Ctrl(char),
-    /// ⌦
-    Delete,
-    /// ↓ arrow key
-    Down,
-    /// ⇲
-    End,
-    /// ↵ or `KeyPress::Ctrl('M')`
-    Enter,
-    /// Escape or `KeyPress::Ctrl('[')`
-    Esc,
-    /// Function key
-    F(u8),
-    /// ⇱
-    Home,
-    /// Insert key
-    Insert,
-    /// ← arrow key
-    Left,
-    /// Escape-char or Alt-char
-    Meta(char),
-    /// `KeyPress::Char('\0')`
-    Null,
-    /// ⇟
-    PageDown,
-    /// ⇞
-    PageUp,
-    /// → arrow key
-    Right,
-    /// Shift-↓
-    ShiftDown,
-    /// Shift-←
-    ShiftLeft,
-    /// Shift-→
-    ShiftRight,
-    /// Shift-↑
-    ShiftUp,
-    /// ⇥ or `KeyPress::Ctrl('I')`
-    Tab,
-    /// ↑ arrow key
-    Up,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Cmd {
-    /// abort
-    Abort, // Miscellaneous Command
-    /// accept-line
-    AcceptLine,
-    /// beginning-of-history
-    BeginningOfHistory,
-    /// capitalize-word
-    CapitalizeWord,
-    /// clear-screen
-    ClearScreen,
-    /// complete
-    Complete,
-    /// complete-backward
-    CompleteBackward,
-    /// complete-hint
-    CompleteHint,
-    /// downcase-word
-    DowncaseWord,
-    /// vi-eof-maybe
-    EndOfFile,
-    /// end-of-history
-    EndOfHistory,
-    /// forward-search-history
-    ForwardSearchHistory,
-    /// history-search-backward
-    HistorySearchBackward,
-    /// history-search-forward
-    HistorySearchForward,
-    /// Insert text
-    Insert { repeat: RepeatCount, string: String },
-    /// Interrupt signal (Ctrl-C)
-    Interrupt,
-    /// backward-delete-char, backward-kill-line, backward-kill-word
-    /// delete-char, kill-line, kill-word, unix-line-discard, unix-word-rubout,
-    /// vi-delete, vi-delete-to, vi-rubout
-    Kill(Movement),
-    /// backward-char, backward-word, beginning-of-line, end-of-line,
-    /// forward-char, forward-word, vi-char-search, vi-end-word, vi-next-word,
-    /// vi-prev-word
-    Move(Movement),
-    /// next-history
-    NextHistory,
-    /// No action
-    Noop,
-    /// vi-replace
-    Overwrite(char),
-    /// previous-history
-    PreviousHistory,
-    /// quoted-insert
-    QuotedInsert,
-    /// vi-change-char
-    ReplaceChar { repeat: RepeatCount, ch: char },
-    /// vi-change-to, vi-substitute
-    Replace {
-        movement: Movement,
-        replacement: Option<String>,
-    },
-    /// reverse-search-history
-    ReverseSearchHistory,
-    /// self-insert
-    SelfInsert { repeat: RepeatCount, ch: char },
-    /// Suspend signal (Ctrl-Z on unix platform)
-    Suspend,
-    /// transpose-chars
-    TransposeChars,
-    /// transpose-words
-    TransposeWords(RepeatCount),
-    /// undo
-    Undo(RepeatCount),
-    /// Unsupported / unexpected
-    Unknown,
-    /// upcase-word
-    UpcaseWord,
-    /// vi-yank-to
-    ViYankTo(Movement),
-    /// yank, vi-put
-    Yank { repeat: RepeatCount, anchor: Anchor },
-    /// yank-pop
-    YankPop,
-    /// moves cursor to the line above or switches to prev history entry if
-    /// the cursor is already on the first line
-    LineUpOrPreviousHistory(RepeatCount),
-    /// moves cursor to the line below or switches to next history entry if
-    /// the cursor is already on the last line
-    LineDownOrNextHistory(RepeatCount),
-    /// accepts the line when cursor is at the end of the text (non including
-    /// trailing whitespace), inserts newline character otherwise
-    AcceptOrInsertLine,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Movement {
-    /// Whole current line (not really a movement but a range)
-    WholeLine,
-    /// beginning-of-line
-    BeginningOfLine,
-    /// end-of-line
-    EndOfLine,
-    /// backward-word, vi-prev-word
-    BackwardWord { repeat: RepeatCount, word: Word }, // Backward until start of word
-    /// forward-word, vi-end-word, vi-next-word
-    ForwardWord {
-        repeat: RepeatCount,
-        at: At,
-        word: Word,
-    }, // Forward until start/end of word
-    /// vi-char-search
-    ViCharSearch {
-        repeat: RepeatCount,
-        search: CharSearch,
-    },
-    /// vi-first-print
-    ViFirstPrint,
-    /// backward-char
-    BackwardChar(RepeatCount),
-    /// forward-char
-    ForwardChar(RepeatCount),
-    /// move to the same column on the previous line
-    LineUp(RepeatCount),
-    /// move to the same column on the next line
-    LineDown(RepeatCount),
-    /// Whole user input (not really a movement but a range)
-    WholeBuffer,
-    /// beginning-of-buffer
-    BeginningOfBuffer,
-    /// end-of-buffer
-    EndOfBuffer,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-enum InputMode {
-    /// Vi Command/Alternate
-    Command,
-    /// Insert/Input mode
-    Insert,
-    /// Overwrite mode
-    Replace,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Word {
-    /// non-blanks characters
-    Big,
-    /// alphanumeric characters
-    Emacs,
-    /// alphanumeric (and '_') characters
-    Vi,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum At {
-    /// Start of word.
-    Start,
-    /// Before end of word.
-    BeforeEnd,
-    /// After end of word.
-    AfterEnd,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Anchor {
-    /// After cursor
-    After,
-    /// Before cursor
-    Before,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum CharSearch {
-    /// Forward search
-    Forward(char),
-    /// Forward search until
-    ForwardBefore(char),
-    /// Backward search
-    Backward(char),
-    /// Backward search until
-    BackwardAfter(char),
-}
-
-/// The number of times one command should be repeated.
-pub type RepeatCount = usize;
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Keybinding {
-    key: KeyPress,
-    binding: Cmd,
-}
-
-type Keybindings = Vec<Keybinding>;
-
 pub(crate) fn load_keybindings(
     rl: &mut rustyline::Editor<crate::shell::Helper>,
 ) -> Result<(), nu_errors::ShellError> {
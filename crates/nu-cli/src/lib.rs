@@ -21,7 +21,14 @@ mod format;
 #[cfg(feature = "rustyline-support")]
 mod keybinding;
 mod line_editor;
+mod login;
+#[cfg(feature = "rustyline-support")]
+mod prompt;
+mod sh_compat;
 mod shell;
+#[cfg(feature = "rustyline-support")]
+mod shell_integration;
+mod test_runner;
 pub mod types;
 
 #[cfg(feature = "rustyline-support")]
@@ -30,6 +37,9 @@ pub use crate::cli::cli;
 pub use crate::cli::{parse_and_eval, register_plugins, run_script_file};
 
 pub use crate::env::environment_syncer::EnvironmentSyncer;
+pub use crate::login::{is_login_shell, setup_login_environment};
+pub use crate::sh_compat::run_sh_compat;
+pub use crate::test_runner::run_tests;
 pub use nu_command::commands::default_context::create_default_context;
 pub use nu_data::config;
 pub use nu_data::dict::TaggedListBuilder;
@@ -53,6 +53,9 @@ pub fn default_rustyline_editor_configuration() -> Editor<Helper> {
         Cmd::Move(Movement::ForwardWord(1, At::AfterEnd, Word::Vi)),
     );
 
+    // undo every change made to the line, like revert-line in readline
+    rl.bind_sequence(KeyPress::Meta('r'), Cmd::Undo(usize::MAX));
+
     // workaround for multiline-paste hang in rustyline (see https://github.com/kkawakam/rustyline/issues/202)
     rl.bind_sequence(KeyPress::BracketedPasteStart, rustyline::Cmd::Noop);
 
@@ -67,7 +70,11 @@ pub fn default_rustyline_editor_configuration() -> Editor<Helper> {
     rl.set_edit_mode(rustyline::config::EditMode::Emacs);
     rl.set_auto_add_history(false);
     rl.set_bell_style(rustyline::config::BellStyle::default());
-    rl.set_color_mode(rustyline::ColorMode::Enabled);
+    rl.set_color_mode(if nu_table::colors_enabled() {
+        rustyline::ColorMode::Enabled
+    } else {
+        rustyline::ColorMode::Disabled
+    });
     rl.set_tab_stop(8);
 
     if let Err(e) = crate::keybinding::load_keybindings(&mut rl) {
@@ -189,6 +196,11 @@ pub fn configure_rustyline_editor(
         }
     }
 
+    // Turning colors off wins over the line editor's own color mode
+    if !nu_table::colors_enabled() {
+        rl.set_color_mode(rustyline::ColorMode::Disabled);
+    }
+
     Ok(())
 }
 
@@ -232,3 +244,28 @@ pub fn configure_ctrl_c(_context: &mut EvaluationContext) -> Result<(), Box<dyn
 
     Ok(())
 }
+
+/// Ends the session when nu is told to terminate or its terminal is closed, running the `on-exit`
+/// block first. This happens on its own thread, as the prompt may be waiting for a line.
+#[cfg(unix)]
+pub fn configure_termination(context: &EvaluationContext) -> Result<(), Box<dyn Error>> {
+    use signal_hook::{iterator::Signals, SIGHUP, SIGTERM};
+
+    let signals = Signals::new(&[SIGTERM, SIGHUP])?;
+    let context = context.clone();
+
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            futures::executor::block_on(nu_command::script::run_exit_hook(&context));
+            nu_command::remove_temp_files();
+            std::process::exit(128 + signal);
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn configure_termination(_context: &EvaluationContext) -> Result<(), Box<dyn Error>> {
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use nu_command::script::run_script_standalone;
+use nu_engine::EvaluationContext;
+use std::path::PathBuf;
+
+/// Whether nu was started as a login shell, either with `--login` or by a program like `login`
+/// that prefixes the shell's name with a dash.
+pub fn is_login_shell(login_flag: bool) -> bool {
+    login_flag
+        || std::env::args_os()
+            .next()
+            .map(|arg0| arg0.to_string_lossy().starts_with('-'))
+            .unwrap_or(false)
+}
+
+/// Prepares the process environment the way a login shell is expected to. Terminals launched
+/// from the macOS GUI don't get the PATH from `/etc/paths` and `/etc/paths.d`, so it is rebuilt
+/// with `path_helper`, the same as `/etc/profile` does for other shells.
+pub fn setup_login_environment() {
+    #[cfg(target_os = "macos")]
+    {
+        if let Some(path) = path_helper_path() {
+            std::env::set_var("PATH", path);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn path_helper_path() -> Option<String> {
+    let output = std::process::Command::new("/usr/libexec/path_helper")
+        .arg("-s")
+        .output()
+        .ok()?;
+
+    // The output looks like `PATH="/usr/local/bin:/usr/bin"; export PATH;`
+    String::from_utf8(output.stdout)
+        .ok()?
+        .split(';')
+        .find_map(|statement| {
+            statement
+                .trim()
+                .strip_prefix("PATH=\"")?
+                .strip_suffix('"')
+                .map(String::from)
+        })
+}
+
+/// The login scripts, in the order they are sourced: the system-wide one first, so that the
+/// user's own `login.nu` can override it.
+fn login_scripts() -> Vec<PathBuf> {
+    let mut scripts = vec![];
+
+    #[cfg(windows)]
+    {
+        if let Some(dir) = std::env::var_os("ProgramData") {
+            scripts.push(PathBuf::from(dir).join("nu").join("login.nu"));
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        scripts.push(PathBuf::from("/etc/nu/login.nu"));
+    }
+
+    if let Ok(path) = nu_data::config::default_path_for(&Some(PathBuf::from("login.nu"))) {
+        scripts.push(path);
+    }
+
+    scripts
+}
+
+/// Sources the login scripts that exist. Errors are reported but don't stop the shell from
+/// starting.
+pub(crate) async fn run_login_scripts(context: &EvaluationContext) {
+    for script in login_scripts() {
+        if let Ok(contents) = std::fs::read_to_string(&script) {
+            let _ = run_script_standalone(contents, false, context, false).await;
+        }
+    }
+}
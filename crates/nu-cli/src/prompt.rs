@@ -0,0 +1,306 @@
+//! Renders the prompt of the interactive shell.
+//!
+//! Unless the config has a `prompt` pipeline, the prompt is made of the segments listed in
+//! `prompt_segments`. The built in ones are `cwd`, `git_branch`, `exit_status` and `duration`.
+//! Any other segment `<name>` is the output of a command called `prompt <name>`, which is how
+//! plugins and scripts add segments of their own.
+//!
+//! The prompt ends with an indicator: `prompt_indicator`, or `prompt_indicator_vi` when the line
+//! editor is in vi mode. The line editor doesn't tell insert mode from normal mode, nor draw the
+//! prompt again on switching between them, so both modes share that one indicator.
+//!
+//! Lines that continue an unfinished one start with `prompt_continuation` instead.
+
+use nu_command::script::print_err;
+use nu_data::config::Conf;
+use nu_engine::{run_block, EvaluationContext};
+use nu_errors::ShellError;
+use nu_parser::ParserScope;
+use nu_protocol::format_duration;
+use nu_source::{Tag, Text};
+use nu_stream::InputStream;
+
+use ansi_term::Color;
+use num_bigint::BigInt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const DEFAULT_SEGMENTS: &[&str] = &["cwd", "git_branch"];
+const DEFAULT_INDICATOR: &str = "> ";
+const DEFAULT_CONTINUATION: &str = "::: ";
+
+/// Commands that finish quicker than this don't show their duration.
+const MIN_DURATION_SHOWN: Duration = Duration::from_secs(2);
+
+/// What the prompt knows about the line that ran last.
+#[derive(Debug, Default)]
+pub struct LastCommand {
+    pub failed: bool,
+    pub duration: Option<Duration>,
+}
+
+/// Renders the prompt for a line, `vi_mode` telling whether the line editor is in vi mode.
+pub async fn render(
+    config: &dyn Conf,
+    context: &EvaluationContext,
+    last: &LastCommand,
+    vi_mode: bool,
+) -> Result<String, ShellError> {
+    if let Some(prompt) = config.var("prompt") {
+        let prompt_line = prompt.as_string()?;
+
+        match run_pipeline(&prompt_line, context).await {
+            Ok(prompt) => return Ok(prompt),
+            Err(err) => print_err(err, &Text::from(prompt_line)),
+        }
+    }
+
+    let segments = match config.var("prompt_segments") {
+        Some(segments) => segments
+            .table_entries()
+            .map(|segment| segment.as_string())
+            .collect::<Result<Vec<_>, _>>()?,
+        None => DEFAULT_SEGMENTS.iter().map(|s| s.to_string()).collect(),
+    };
+
+    let separator = match config.var("prompt_separator") {
+        Some(separator) => separator.as_string()?,
+        None => " ".to_string(),
+    };
+
+    let mut rendered = vec![];
+    for segment in segments {
+        if let Some(text) = render_segment(&segment, context, last).await {
+            rendered.push(text);
+        }
+    }
+
+    Ok(format!(
+        "{}{}",
+        rendered.join(&separator),
+        indicator(config, last, vi_mode)
+    ))
+}
+
+/// The marker ending the prompt. With `prompt_indicator_failure` set, it turns red after a line
+/// that failed.
+pub fn indicator(config: &dyn Conf, last: &LastCommand, vi_mode: bool) -> String {
+    let configured = |name: &str| config.var(name).and_then(|value| value.as_string().ok());
+
+    let indicator = (if vi_mode {
+        configured("prompt_indicator_vi")
+    } else {
+        None
+    })
+    .or_else(|| configured("prompt_indicator"))
+    .unwrap_or_else(|| DEFAULT_INDICATOR.to_string());
+
+    let show_failure = config
+        .var("prompt_indicator_failure")
+        .map(|value| value.is_true())
+        .unwrap_or(false);
+
+    if show_failure && last.failed {
+        Color::Red.paint(indicator).to_string()
+    } else {
+        indicator
+    }
+}
+
+/// The prompt of the lines that continue an unfinished block, string or pipeline.
+pub fn continuation(config: &dyn Conf) -> String {
+    config
+        .var("prompt_continuation")
+        .and_then(|value| value.as_string().ok())
+        .unwrap_or_else(|| DEFAULT_CONTINUATION.to_string())
+}
+
+async fn render_segment(
+    segment: &str,
+    context: &EvaluationContext,
+    last: &LastCommand,
+) -> Option<String> {
+    match segment {
+        "cwd" => Some(Color::Green.paint(context.shell_manager.path()).to_string()),
+        "git_branch" => git_branch(Path::new(&context.shell_manager.path()))
+            .map(|branch| Color::Green.paint(format!("({})", branch)).to_string()),
+        "exit_status" if last.failed => Some(Color::Red.paint("✘").to_string()),
+        "exit_status" => None,
+        "duration" => last
+            .duration
+            .filter(|duration| *duration >= MIN_DURATION_SHOWN)
+            .map(|duration| {
+                let nanos = BigInt::from(duration.as_nanos() as u64);
+                Color::Yellow
+                    .paint(format!("took {}", format_duration(&nanos)))
+                    .to_string()
+            }),
+        _ => {
+            let command = format!("prompt {}", segment);
+
+            if !context.is_command_registered(&command) {
+                return None;
+            }
+
+            match run_pipeline(&command, context).await {
+                Ok(text) if text.is_empty() => None,
+                Ok(text) => Some(text),
+                Err(err) => {
+                    print_err(err, &Text::from(command));
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Runs a pipeline in a scope of its own and collects its output as a string.
+async fn run_pipeline(line: &str, context: &EvaluationContext) -> Result<String, ShellError> {
+    context.scope.enter_scope();
+
+    let (block, err) = nu_parser::parse(line, 0, &context.scope);
+    let result = match err {
+        Some(err) => Err(err.into()),
+        None => run_block(&block, context, InputStream::empty()).await,
+    };
+
+    context.scope.exit_scope();
+
+    let output = result?.collect_string(Tag::unknown()).await?;
+
+    if let Some(err) = context.get_errors().into_iter().next() {
+        context.clear_errors();
+        return Err(err);
+    }
+
+    Ok(output.item)
+}
+
+/// The branch checked out in the git repository containing `cwd`, or the abbreviated commit if
+/// the HEAD is detached.
+fn git_branch(cwd: &Path) -> Option<String> {
+    let git = cwd
+        .ancestors()
+        .map(|dir| dir.join(".git"))
+        .find(|git| git.exists())?;
+
+    // Worktrees and submodules have a `.git` file pointing at the actual git directory
+    let git_dir = if git.is_file() {
+        let contents = std::fs::read_to_string(&git).ok()?;
+        let dir = PathBuf::from(contents.trim().strip_prefix("gitdir:")?.trim());
+
+        if dir.is_relative() {
+            git.parent()?.join(dir)
+        } else {
+            dir
+        }
+    } else {
+        git
+    };
+
+    let head = std::fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+
+    match head.strip_prefix("ref: refs/heads/") {
+        Some(branch) => Some(branch.to_string()),
+        None => Some(head.chars().take(7).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{continuation, git_branch, indicator, LastCommand};
+    use ansi_term::Color;
+    use nu_data::config::tests::FakeConfig;
+    use nu_test_support::fs::Stub::{FileWithContent, FileWithContentToBeTrimmed};
+    use nu_test_support::playground::Playground;
+
+    #[test]
+    fn finds_the_branch_from_a_subdirectory() {
+        Playground::setup("prompt_git_branch_test", |dirs, sandbox| {
+            sandbox
+                .mkdir(".git")
+                .with_files(vec![FileWithContent(
+                    ".git/HEAD",
+                    "ref: refs/heads/feature/prompt\n",
+                )])
+                .mkdir("src");
+
+            assert_eq!(
+                git_branch(&dirs.test.join("src")),
+                Some("feature/prompt".to_string())
+            );
+        })
+    }
+
+    #[test]
+    fn shows_the_commit_when_detached() {
+        Playground::setup("prompt_git_detached_test", |dirs, sandbox| {
+            sandbox.mkdir(".git").with_files(vec![FileWithContent(
+                ".git/HEAD",
+                "8fd1c0a2e4b7d9f1a3c5e7b9d1f3a5c7e9b1d3f5\n",
+            )]);
+
+            assert_eq!(git_branch(&dirs.test), Some("8fd1c0a".to_string()));
+        })
+    }
+
+    #[test]
+    fn indicator_turns_red_after_a_failure() {
+        Playground::setup("prompt_indicator_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContentToBeTrimmed(
+                "config.toml",
+                r#"
+                    prompt_indicator = "❯ "
+                    prompt_indicator_failure = true
+                "#,
+            )]);
+
+            let config = FakeConfig::new(&dirs.test().join("config.toml"));
+            let failed = LastCommand {
+                failed: true,
+                duration: None,
+            };
+
+            assert_eq!(indicator(&config, &LastCommand::default(), false), "❯ ");
+            assert_eq!(
+                indicator(&config, &failed, false),
+                Color::Red.paint("❯ ").to_string()
+            );
+        })
+    }
+
+    #[test]
+    fn indicator_of_vi_mode() {
+        Playground::setup("prompt_indicator_test_2", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContentToBeTrimmed(
+                "config.toml",
+                r#"
+                    prompt_indicator = "❯ "
+                    prompt_indicator_vi = ": "
+                "#,
+            )]);
+
+            let config = FakeConfig::new(&dirs.test().join("config.toml"));
+
+            assert_eq!(indicator(&config, &LastCommand::default(), true), ": ");
+            assert_eq!(indicator(&config, &LastCommand::default(), false), "❯ ");
+        })
+    }
+
+    #[test]
+    fn continuation_is_configurable() {
+        Playground::setup("prompt_continuation_test", |dirs, sandbox| {
+            sandbox.with_files(vec![
+                FileWithContentToBeTrimmed("config.toml", r#"prompt_continuation = "… ""#),
+                FileWithContentToBeTrimmed("default.toml", r#"prompt_indicator = "> ""#),
+            ]);
+
+            let config = FakeConfig::new(&dirs.test().join("config.toml"));
+            let default = FakeConfig::new(&dirs.test().join("default.toml"));
+
+            assert_eq!(continuation(&config), "… ");
+            assert_eq!(continuation(&default), "::: ");
+        })
+    }
+}
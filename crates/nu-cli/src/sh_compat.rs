@@ -0,0 +1,526 @@
+//! Runs `-c` snippets written for a POSIX `sh`, for tools that assume `$SHELL -c` takes one.
+//!
+//! Only the subset those tools use is supported: simple commands with quoting and variable
+//! expansion, `NAME=value` prefixes, pipes, and lists joined with `;`, `&&` and `||`. Each
+//! command is translated to a nu pipeline and the lists are evaluated here, since nu has no
+//! `&&` or `||` of its own.
+
+use crate::cli::script_context;
+use nu_command::maybe_print_errors;
+use nu_command::script::{print_err, process_script, run_exit_hook, LineResult};
+use nu_engine::EvaluationContext;
+use nu_errors::ShellError;
+use nu_source::Text;
+use std::error::Error;
+
+/// How a command is joined to the one before it.
+#[derive(Debug, PartialEq)]
+pub enum Connector {
+    /// `;` or a newline: always runs.
+    Always,
+    /// `&&`: runs if the previous command succeeded.
+    And,
+    /// `||`: runs if the previous command failed.
+    Or,
+}
+
+/// A translated command.
+#[derive(Debug, PartialEq)]
+pub enum Step {
+    /// A nu pipeline.
+    Nu(String),
+    /// `true`, `false` or `:`, which only set the status.
+    Status(bool),
+    /// `exit`, with an optional exit code.
+    Exit(Option<i32>),
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(Word),
+    Pipe,
+    Connector(Connector),
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Word {
+    text: String,
+    /// Whether any part of the word was quoted, escaped, or expanded from a variable.
+    literal: bool,
+    /// How many characters were read before the first quote, escape, or expansion.
+    plain_prefix: usize,
+}
+
+impl Word {
+    fn push_plain(&mut self, c: char) {
+        self.text.push(c);
+        if !self.literal {
+            self.plain_prefix += 1;
+        }
+    }
+
+    fn push_literal(&mut self, s: &str) {
+        self.text.push_str(s);
+        self.literal = true;
+    }
+
+    /// The name and value of a `NAME=value` assignment.
+    fn assignment(&self) -> Option<(&str, &str)> {
+        let eq = self.text.find('=')?;
+        let name = &self.text[..eq];
+
+        let valid = eq < self.plain_prefix
+            && name
+                .chars()
+                .next()
+                .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if valid {
+            Some((name, &self.text[eq + 1..]))
+        } else {
+            None
+        }
+    }
+}
+
+fn unsupported(what: &str) -> String {
+    format!("{} is not supported", what)
+}
+
+fn tokenize(script: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut word: Option<Word> = None;
+    let mut chars = script.chars().peekable();
+
+    macro_rules! finish_word {
+        () => {
+            if let Some(word) = word.take() {
+                tokens.push(Token::Word(word));
+            }
+        };
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' => finish_word!(),
+            '\n' => {
+                finish_word!();
+                tokens.push(Token::Connector(Connector::Always));
+            }
+            ';' => {
+                finish_word!();
+                tokens.push(Token::Connector(Connector::Always));
+            }
+            '#' if word.is_none() => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '&' => {
+                finish_word!();
+                if chars.peek() != Some(&'&') {
+                    return Err(unsupported("running commands in the background with `&`"));
+                }
+                chars.next();
+                tokens.push(Token::Connector(Connector::And));
+            }
+            '|' => {
+                finish_word!();
+                if chars.peek() == Some(&'|') {
+                    chars.next();
+                    tokens.push(Token::Connector(Connector::Or));
+                } else {
+                    tokens.push(Token::Pipe);
+                }
+            }
+            '<' | '>' => return Err(unsupported("redirection")),
+            '(' | ')' | '{' | '}' if word.is_none() => {
+                return Err(unsupported("grouping commands"))
+            }
+            '`' => return Err(unsupported("command substitution")),
+            '\'' => {
+                let word = word.get_or_insert_with(Word::default);
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => quoted.push(c),
+                        None => return Err("unterminated single quote".to_string()),
+                    }
+                }
+                word.push_literal(&quoted);
+            }
+            '"' => {
+                let word = word.get_or_insert_with(Word::default);
+                word.literal = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ '"') | Some(c @ '\\') | Some(c @ '$') | Some(c @ '`') => {
+                                word.text.push(c)
+                            }
+                            Some('\n') => {}
+                            Some(c) => {
+                                word.text.push('\\');
+                                word.text.push(c);
+                            }
+                            None => return Err("unterminated double quote".to_string()),
+                        },
+                        Some('$') => word.text.push_str(&expand_variable(&mut chars)?),
+                        Some('`') => return Err(unsupported("command substitution")),
+                        Some(c) => word.text.push(c),
+                        None => return Err("unterminated double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(c) => word
+                    .get_or_insert_with(Word::default)
+                    .push_literal(&c.to_string()),
+                None => {}
+            },
+            '$' => {
+                let value = expand_variable(&mut chars)?;
+                word.get_or_insert_with(Word::default).push_literal(&value);
+            }
+            c => word.get_or_insert_with(Word::default).push_plain(c),
+        }
+    }
+
+    finish_word!();
+
+    Ok(tokens)
+}
+
+/// Expands `$NAME` or `${NAME}` from the environment, with the `$` already read.
+fn expand_variable(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    let mut name = String::new();
+
+    match chars.peek() {
+        Some('{') => {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                    Some(_) => return Err(unsupported("parameter expansion")),
+                    None => return Err("unterminated `${`".to_string()),
+                }
+            }
+        }
+        Some('(') => return Err(unsupported("command substitution")),
+        Some(c) if c.is_ascii_alphabetic() || *c == '_' => {
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '_') {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+        }
+        Some(c) if c.is_ascii_digit() || "?#@*!$-".contains(*c) => {
+            return Err(unsupported("special parameters like `$?`"))
+        }
+        _ => return Ok("$".to_string()),
+    }
+
+    Ok(std::env::var(name).unwrap_or_default())
+}
+
+/// Quotes an argument so nu passes it through unchanged.
+fn quote(arg: &str) -> Result<String, String> {
+    if !arg.contains('\'') {
+        Ok(format!("'{}'", arg))
+    } else if !arg.contains('"') {
+        Ok(format!("\"{}\"", arg))
+    } else if !arg.contains('`') {
+        Ok(format!("`{}`", arg))
+    } else {
+        Err(format!("cannot quote the argument {}", arg))
+    }
+}
+
+/// Formats an argument for nu, leaving unquoted globs and `~` bare so they are still expanded.
+fn argument(word: &Word) -> Result<String, String> {
+    let expands = word.text.contains('*') || word.text.contains('?') || word.text.starts_with('~');
+    let bare = word
+        .text
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_-./*?~+,@%=".contains(c));
+
+    if expands && bare && !word.literal {
+        Ok(word.text.clone())
+    } else {
+        quote(&word.text)
+    }
+}
+
+fn translate_command(words: &[Word]) -> Result<String, String> {
+    let assignments = words
+        .iter()
+        .map(Word::assignment)
+        .take_while(Option::is_some)
+        .flatten()
+        .collect::<Vec<_>>();
+    let words = &words[assignments.len()..];
+
+    let command = match words.first() {
+        Some(command) if command.text.is_empty() => return Err("empty command name".to_string()),
+        Some(command) if command.text.chars().any(|c| " '\"`$;|".contains(c)) => {
+            return Err(format!("cannot run the command {}", command.text))
+        }
+        Some(command) => command,
+        None => {
+            return assignments
+                .iter()
+                .map(|(name, value)| Ok(format!("let-env {} = {}", name, quote(value)?)))
+                .collect::<Result<Vec<_>, String>>()
+                .map(|lets| lets.join("; "));
+        }
+    };
+
+    let mut pipeline = match command.text.as_str() {
+        "cd" if assignments.is_empty() => "cd".to_string(),
+        "export" if assignments.is_empty() => {
+            return words[1..]
+                .iter()
+                .filter_map(|word| word.assignment())
+                .map(|(name, value)| Ok(format!("let-env {} = {}", name, quote(value)?)))
+                .collect::<Result<Vec<_>, String>>()
+                .map(|lets| lets.join("; "));
+        }
+        name => format!("^{}", name),
+    };
+
+    for word in &words[1..] {
+        pipeline.push(' ');
+        pipeline.push_str(&argument(word)?);
+    }
+
+    if assignments.is_empty() {
+        Ok(pipeline)
+    } else {
+        let mut env = vec![];
+        for (name, value) in assignments {
+            env.push(name.to_string());
+            env.push(quote(value)?);
+        }
+
+        Ok(format!("with-env [{}] {{ {} }}", env.join(" "), pipeline))
+    }
+}
+
+fn translate_step(commands: &[Vec<Word>]) -> Result<Step, String> {
+    if let [command] = commands {
+        match command
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<Vec<_>>()[..]
+        {
+            ["true"] | [":"] => return Ok(Step::Status(true)),
+            ["false"] => return Ok(Step::Status(false)),
+            ["exit"] => return Ok(Step::Exit(None)),
+            ["exit", code] => {
+                return code
+                    .parse()
+                    .map(|code| Step::Exit(Some(code)))
+                    .map_err(|_| format!("invalid exit code {}", code))
+            }
+            _ => {}
+        }
+    }
+
+    let pipeline = commands
+        .iter()
+        .map(|command| translate_command(command))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Step::Nu(pipeline.join(" | ")))
+}
+
+/// Translates a `sh` snippet into the commands to run and how each is joined to the previous one.
+pub fn translate(script: &str) -> Result<Vec<(Connector, Step)>, String> {
+    let mut steps = vec![];
+    let mut connector = Connector::Always;
+    let mut commands: Vec<Vec<Word>> = vec![vec![]];
+
+    for token in tokenize(script)?
+        .into_iter()
+        .chain(std::iter::once(Token::Connector(Connector::Always)))
+    {
+        match token {
+            Token::Word(word) => {
+                if let Some(command) = commands.last_mut() {
+                    command.push(word);
+                }
+            }
+            Token::Pipe => {
+                if commands.last().map_or(true, Vec::is_empty) {
+                    return Err("expected a command before `|`".to_string());
+                }
+                commands.push(vec![]);
+            }
+            Token::Connector(next) => {
+                let empty = commands.last().map_or(true, Vec::is_empty);
+
+                if empty && (commands.len() > 1 || next != Connector::Always) {
+                    return Err("expected a command".to_string());
+                }
+
+                if !empty {
+                    steps.push((connector, translate_step(&commands)?));
+                    connector = next;
+                } else if connector != Connector::Always {
+                    return Err("expected a command".to_string());
+                }
+
+                commands = vec![vec![]];
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+/// Runs a nu pipeline, returning its exit status: the exit code of the external command that
+/// failed, or 1 when nu gave an error.
+async fn run_step(pipeline: &str, context: &EvaluationContext, redirect_stdin: bool) -> i32 {
+    context.scope.take_last_exit_code();
+
+    let failed = match process_script(pipeline, context, redirect_stdin, 0, false).await {
+        LineResult::Success(line) => maybe_print_errors(context, Text::from(line)),
+        LineResult::Error(line, err) => {
+            print_err(err, &Text::from(line.clone()));
+            maybe_print_errors(context, Text::from(line));
+            true
+        }
+        _ => false,
+    };
+
+    match context.scope.take_last_exit_code() {
+        Some(code) if failed && code != 0 => code,
+        _ if failed => 1,
+        _ => 0,
+    }
+}
+
+/// Runs a `sh` snippet, returning the status of the last command run for the shell to exit with,
+/// as `sh` does. Login shells source the login scripts first.
+pub async fn run_sh_compat(
+    script: String,
+    redirect_stdin: bool,
+    login: bool,
+) -> Result<i32, Box<dyn Error>> {
+    let steps = match translate(&script) {
+        Ok(steps) => steps,
+        Err(reason) => {
+            print_err(
+                ShellError::untagged_runtime_error(format!("sh compatibility: {}", reason)),
+                &Text::from(script),
+            );
+            return Ok(2);
+        }
+    };
+
+    let context = script_context(login).await?;
+    let mut status = 0;
+
+    for (connector, step) in steps {
+        let run = match connector {
+            Connector::Always => true,
+            Connector::And => status == 0,
+            Connector::Or => status != 0,
+        };
+
+        if !run {
+            continue;
+        }
+
+        status = match step {
+            Step::Nu(pipeline) => run_step(&pipeline, &context, redirect_stdin).await,
+            Step::Status(true) => 0,
+            Step::Status(false) => 1,
+            Step::Exit(code) => {
+                status = code.unwrap_or(status);
+                break;
+            }
+        };
+    }
+
+    run_exit_hook(&context).await;
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{translate, Connector, Step};
+
+    fn pipelines(script: &str) -> Vec<(Connector, Step)> {
+        translate(script).expect("translation failed")
+    }
+
+    #[test]
+    fn translates_lists() {
+        assert_eq!(
+            pipelines("make && make install || echo 'build failed'; ls"),
+            vec![
+                (Connector::Always, Step::Nu("^make".to_string())),
+                (Connector::And, Step::Nu("^make 'install'".to_string())),
+                (Connector::Or, Step::Nu("^echo 'build failed'".to_string())),
+                (Connector::Always, Step::Nu("^ls".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn translates_pipes_and_environment_prefixes() {
+        assert_eq!(
+            pipelines("RUST_LOG=debug cargo run | grep \"it's\""),
+            vec![(
+                Connector::Always,
+                Step::Nu(
+                    "with-env [RUST_LOG 'debug'] { ^cargo 'run' } | ^grep \"it's\"".to_string()
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn translates_builtins() {
+        assert_eq!(
+            pipelines("cd /tmp\nexport EDITOR=vi\nfalse || exit 3"),
+            vec![
+                (Connector::Always, Step::Nu("cd '/tmp'".to_string())),
+                (
+                    Connector::Always,
+                    Step::Nu("let-env EDITOR = 'vi'".to_string())
+                ),
+                (Connector::Always, Step::Status(false)),
+                (Connector::Or, Step::Exit(Some(3))),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_unquoted_globs_bare() {
+        assert_eq!(
+            pipelines("ls *.rs '*.md'"),
+            vec![(Connector::Always, Step::Nu("^ls *.rs '*.md'".to_string()))]
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_syntax() {
+        assert!(translate("echo $(date)").is_err());
+        assert!(translate("make > log").is_err());
+        assert!(translate("sleep 1 &").is_err());
+        assert!(translate("make &&").is_err());
+    }
+}
@@ -41,9 +41,12 @@ impl NuCompleter {
             })
             .unwrap_or_else(String::new);
 
+        // Windows paths and commands aren't case sensitive, so neither is completing them there
         let matcher = matcher.as_str();
         let matcher: &dyn Matcher = match matcher {
             "case-insensitive" => &matchers::case_insensitive::Matcher,
+            "case-sensitive" => &matchers::case_sensitive::Matcher,
+            _ if cfg!(windows) => &matchers::case_insensitive::Matcher,
             _ => &matchers::case_sensitive::Matcher,
         };
 
@@ -66,7 +69,7 @@ impl NuCompleter {
                             flag_completer.complete(context, partial, matcher.to_owned())
                         }
 
-                        LocationType::Argument(cmd, _arg_name) => {
+                        LocationType::Argument(cmd, arg_name) => {
                             let path_completer = PathCompleter;
 
                             const QUOTE_CHARS: &[char] = &['\'', '"', '`'];
@@ -92,8 +95,25 @@ impl NuCompleter {
                                 partial
                             };
 
+                            let custom_completions = cmd
+                                .as_deref()
+                                .and_then(|cmd| nu_context.scope.get_command(cmd))
+                                .map(|command| {
+                                    command.argument_completions(arg_name.as_deref(), partial)
+                                })
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|v| matcher.matches(partial, v))
+                                .map(|v| Suggestion {
+                                    replacement: requote(v.clone()),
+                                    display: v,
+                                });
+
                             let completed_paths = path_completer.path_suggestions(partial, matcher);
-                            match cmd.as_deref().unwrap_or("") {
+                            let completed_paths = match cmd.as_deref().unwrap_or("") {
+                                "cd" if completed_paths.is_empty() => select_directory_suggestions(
+                                    abbreviated_path_suggestions(partial, matcher),
+                                ),
                                 "cd" => select_directory_suggestions(completed_paths),
                                 _ => completed_paths,
                             }
@@ -101,8 +121,9 @@ impl NuCompleter {
                             .map(|s| Suggestion {
                                 replacement: requote(s.suggestion.replacement),
                                 display: s.suggestion.display,
-                            })
-                            .collect()
+                            });
+
+                            custom_completions.chain(completed_paths).collect()
                         }
 
                         LocationType::Variable => Vec::new(),
@@ -128,6 +149,30 @@ fn select_directory_suggestions(completed_paths: Vec<PathSuggestion>) -> Vec<Pat
         .collect()
 }
 
+/// Completes a path abbreviated to the start of each of its directories, like `s/p/r` for
+/// `src/parser/registry`, when they're the start of only one path.
+fn abbreviated_path_suggestions(partial: &str, matcher: &dyn Matcher) -> Vec<PathSuggestion> {
+    let is_separator = |c: char| c == std::path::MAIN_SEPARATOR || (cfg!(windows) && c == '/');
+    let (dirs, rest) = match partial.rfind(is_separator) {
+        Some(pos) => partial.split_at(pos + 1),
+        None => return vec![],
+    };
+
+    match nu_engine::path::expand_abbreviation(".", dirs).as_slice() {
+        [dir] => {
+            let dir = dir.strip_prefix(".").unwrap_or(dir.as_path());
+            let partial = if dir.as_os_str().is_empty() {
+                rest.to_string()
+            } else {
+                format!("{}{}{}", dir.display(), std::path::MAIN_SEPARATOR, rest)
+            };
+
+            PathCompleter.path_suggestions(&partial, matcher)
+        }
+        _ => vec![],
+    }
+}
+
 fn requote(orig_value: String) -> String {
     let value: Cow<str> = rustyline::completion::unescape(&orig_value, Some('\\'));
 
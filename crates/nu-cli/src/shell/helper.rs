@@ -1,6 +1,7 @@
 use crate::completion;
 use crate::shell::completer::NuCompleter;
 use nu_engine::{DefaultPalette, EvaluationContext, Painter};
+use nu_parser::TokenContents;
 use nu_source::{Tag, Tagged};
 use std::borrow::Cow::{self, Owned};
 
@@ -9,7 +10,6 @@ pub struct Helper {
     hinter: Option<rustyline::hint::HistoryHinter>,
     context: EvaluationContext,
     pub colored_prompt: String,
-    validator: NuValidator,
 }
 
 impl Helper {
@@ -22,7 +22,6 @@ impl Helper {
             hinter,
             context,
             colored_prompt: String::new(),
-            validator: NuValidator {},
         }
     }
 }
@@ -90,45 +89,61 @@ impl rustyline::highlight::Highlighter for Helper {
     }
 }
 
-impl rustyline::validate::Validator for Helper {
-    fn validate(
-        &self,
-        ctx: &mut rustyline::validate::ValidationContext,
-    ) -> rustyline::Result<rustyline::validate::ValidationResult> {
-        self.validator.validate(ctx)
+impl rustyline::validate::Validator for Helper {}
+
+/// Reads more lines with `read_line` for as long as the input is unfinished, and joins them to
+/// it. Ending the input early leaves it as it is, so that it runs into a parse error.
+pub(crate) fn continue_input(
+    mut input: String,
+    mut read_line: impl FnMut() -> rustyline::Result<String>,
+) -> rustyline::Result<String> {
+    while is_incomplete(&input) {
+        let line = match read_line() {
+            Ok(line) => line,
+            Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(err) => return Err(err),
+        };
+
+        input.push('\n');
+        input.push_str(&line);
     }
 
-    fn validate_while_typing(&self) -> bool {
-        self.validator.validate_while_typing()
-    }
+    Ok(input)
 }
 
-struct NuValidator {}
-
-impl rustyline::validate::Validator for NuValidator {
-    fn validate(
-        &self,
-        ctx: &mut rustyline::validate::ValidationContext,
-    ) -> rustyline::Result<rustyline::validate::ValidationResult> {
-        let src = ctx.input();
-
-        let (tokens, err) = nu_parser::lex(src, 0);
-        if let Some(err) = err {
-            if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
-                return Ok(rustyline::validate::ValidationResult::Incomplete);
-            }
+/// Whether the input stops in the middle of a block, a string or a pipeline, and so continues on
+/// the next line.
+fn is_incomplete(src: &str) -> bool {
+    let (tokens, err) = nu_parser::lex(src, 0);
+    if let Some(err) = err {
+        if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
+            return true;
         }
+    }
 
-        let (_, err) = nu_parser::block(tokens);
+    let ends_with_pipe = matches!(
+        tokens
+            .iter()
+            .rev()
+            .find(|token| !matches!(
+                token.contents,
+                TokenContents::EOL | TokenContents::Comment(_)
+            ))
+            .map(|token| &token.contents),
+        Some(TokenContents::Pipe)
+    );
+    if ends_with_pipe {
+        return true;
+    }
 
-        if let Some(err) = err {
-            if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
-                return Ok(rustyline::validate::ValidationResult::Incomplete);
-            }
+    let (_, err) = nu_parser::block(tokens);
+    if let Some(err) = err {
+        if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
+            return true;
         }
-
-        Ok(rustyline::validate::ValidationResult::Valid(None))
     }
+
+    false
 }
 
 #[allow(unused)]
@@ -191,4 +206,55 @@ mod tests {
             "cd \"folder with spaces\\subdirectory\\subsubdirectory\\\""
         );
     }
+
+    #[test]
+    fn unterminated_input_is_incomplete() {
+        assert!(is_incomplete("ls | where {"));
+        assert!(is_incomplete("echo \"hello"));
+        assert!(is_incomplete("ls |"));
+        assert!(is_incomplete("ls | # sizes next\n"));
+    }
+
+    #[test]
+    fn reads_lines_until_the_input_is_complete() {
+        let mut lines = vec!["  = $it.size > 10kb", "}", "ls"].into_iter();
+
+        let input = continue_input("ls | where {".to_string(), || {
+            Ok(lines.next().expect("read past the end").to_string())
+        });
+
+        assert_eq!(
+            input.expect("input was read"),
+            "ls | where {\n  = $it.size > 10kb\n}"
+        );
+        assert_eq!(lines.next(), Some("ls"));
+    }
+
+    #[test]
+    fn ending_unfinished_input_keeps_what_was_read() {
+        let input = continue_input("echo \"hello".to_string(), || {
+            Err(rustyline::error::ReadlineError::Eof)
+        });
+
+        assert_eq!(input.expect("input was read"), "echo \"hello");
+    }
+
+    #[test]
+    fn interrupting_unfinished_input_drops_it() {
+        let input = continue_input("ls |".to_string(), || {
+            Err(rustyline::error::ReadlineError::Interrupted)
+        });
+
+        assert!(matches!(
+            input,
+            Err(rustyline::error::ReadlineError::Interrupted)
+        ));
+    }
+
+    #[test]
+    fn finished_input_is_complete() {
+        assert!(!is_incomplete("ls | where { = $it.size > 10kb }"));
+        assert!(!is_incomplete("echo \"a | b\""));
+        assert!(!is_incomplete(""));
+    }
 }
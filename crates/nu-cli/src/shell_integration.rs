@@ -0,0 +1,161 @@
+//! Operating system commands (OSC) telling the terminal what the shell is doing.
+//!
+//! None is sent unless turned on in the `shell_integration` table of the config, as terminals
+//! that don't know them print them:
+//!
+//! * `title` sets the window title to the running command, and to the directory in between.
+//! * `cwd` reports the current directory (OSC 7), so new tabs and panes can open in it.
+//! * `marks` marks where the prompt, the input and the output of each command start (OSC 133),
+//!   so the terminal can jump between commands and tell which of them failed.
+
+use nu_data::config::Conf;
+use std::io::Write;
+use std::path::Path;
+
+/// String terminator of an OSC
+const ST: &str = "\x07";
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ShellIntegration {
+    pub title: bool,
+    pub cwd: bool,
+    pub marks: bool,
+}
+
+impl ShellIntegration {
+    pub fn from_config(config: &dyn Conf) -> ShellIntegration {
+        let mut integration = ShellIntegration::default();
+
+        if let Some(settings) = config.var("shell_integration") {
+            for (name, value) in settings.row_entries() {
+                let enabled = value.is_true();
+
+                match name.as_str() {
+                    "title" => integration.title = enabled,
+                    "cwd" => integration.cwd = enabled,
+                    "marks" => integration.marks = enabled,
+                    _ => {}
+                }
+            }
+        }
+
+        integration
+    }
+
+    /// Surrounds the prompt with the marks of where the prompt and the input start.
+    pub fn wrap_prompt(&self, prompt: &str) -> String {
+        if self.marks {
+            format!("\x1b]133;A{}{}\x1b]133;B{}", ST, prompt, ST)
+        } else {
+            prompt.to_string()
+        }
+    }
+
+    /// Shown before the prompt: the directory the shell is in.
+    pub fn prompt_shown(&self, cwd: &str) {
+        let mut sequences = String::new();
+
+        if self.cwd {
+            sequences.push_str(&format!("\x1b]7;{}{}", file_url(Path::new(cwd)), ST));
+        }
+        if self.title {
+            sequences.push_str(&title(cwd));
+        }
+
+        emit(&sequences);
+    }
+
+    /// Shown when a line starts running: its output starts here.
+    pub fn command_started(&self, line: &str) {
+        let mut sequences = String::new();
+
+        if self.title {
+            sequences.push_str(&title(line.trim()));
+        }
+        if self.marks {
+            sequences.push_str(&format!("\x1b]133;C{}", ST));
+        }
+
+        emit(&sequences);
+    }
+
+    /// Shown when a line finishes, with whether it failed.
+    pub fn command_finished(&self, failed: bool) {
+        if self.marks {
+            emit(&format!("\x1b]133;D;{}{}", if failed { 1 } else { 0 }, ST));
+        }
+    }
+}
+
+fn title(text: &str) -> String {
+    // Control characters would end the sequence early
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+
+    format!("\x1b]0;{}{}", text, ST)
+}
+
+fn file_url(cwd: &Path) -> String {
+    let path = match url::Url::from_directory_path(cwd) {
+        Ok(url) => url.path().to_string(),
+        Err(_) => cwd.to_string_lossy().to_string(),
+    };
+
+    format!("file://{}{}", hostname(), path)
+}
+
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .unwrap_or_default()
+}
+
+fn emit(sequences: &str) {
+    if sequences.is_empty() {
+        return;
+    }
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(sequences.as_bytes());
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{file_url, title, ShellIntegration};
+    use std::path::Path;
+
+    #[test]
+    fn marks_surround_the_prompt() {
+        let integration = ShellIntegration {
+            title: false,
+            cwd: false,
+            marks: true,
+        };
+
+        assert_eq!(
+            integration.wrap_prompt("> "),
+            "\x1b]133;A\x07> \x1b]133;B\x07"
+        );
+    }
+
+    #[test]
+    fn sends_nothing_unless_turned_on() {
+        let integration = ShellIntegration::default();
+
+        assert!(!integration.title && !integration.cwd && !integration.marks);
+        assert_eq!(integration.wrap_prompt("> "), "> ");
+    }
+
+    #[test]
+    fn titles_leave_out_control_characters() {
+        assert_eq!(title("ls\n| first"), "\x1b]0;ls| first\x07");
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn directories_are_percent_encoded() {
+        assert!(file_url(Path::new("/tmp/with space")).ends_with("/tmp/with%20space/"));
+    }
+}
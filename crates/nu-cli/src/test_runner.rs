@@ -0,0 +1,119 @@
+//! Runs the tests written in nu scripts, for `nu --test`.
+//!
+//! A test is a custom command whose name starts with `test_`. It passes unless it raises an
+//! error, usually from one of the `assert` commands. Each file gets a context of its own, and
+//! each test runs in a fresh scope so that nothing it defines leaks into the next one.
+
+use crate::cli::script_context;
+use nu_command::maybe_print_errors;
+use nu_command::script::{print_err, print_values, process_script, LineResult};
+use nu_engine::EvaluationContext;
+use nu_parser::ParserScope;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Tag, Text};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// The `.nu` files under `path`, or `path` itself if it isn't a directory.
+fn test_files(path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = vec![];
+
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?.path();
+
+        if entry.is_dir() {
+            files.extend(test_files(&entry)?);
+        } else if entry.extension().map_or(false, |ext| ext == "nu") {
+            files.push(entry);
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+/// Runs a line of nu, reporting errors against the source of the test file since that is where
+/// the failing code lives. Returns whether it succeeded.
+async fn run_line(line: &str, source: &Text, context: &EvaluationContext) -> bool {
+    match process_script(line, context, false, 0, false).await {
+        LineResult::Success(_) => !maybe_print_errors(context, source.clone()),
+        LineResult::Error(_, err) => {
+            print_err(err, source);
+            maybe_print_errors(context, source.clone());
+            false
+        }
+        _ => true,
+    }
+}
+
+fn result_row(file: &str, test: &str, passed: bool, duration: Duration) -> Value {
+    let mut row = TaggedDictBuilder::new(Tag::unknown());
+
+    row.insert_untagged("file", UntaggedValue::string(file));
+    row.insert_untagged("test", UntaggedValue::string(test));
+    row.insert_untagged(
+        "result",
+        UntaggedValue::string(if passed { "passed" } else { "failed" }),
+    );
+    row.insert_untagged(
+        "duration",
+        UntaggedValue::duration(duration.as_nanos() as u64),
+    );
+
+    row.into_value()
+}
+
+/// Runs every test in the scripts found at `path` and prints a table of the results. Returns
+/// whether all of them passed.
+pub async fn run_tests(path: &Path) -> Result<bool, Box<dyn Error>> {
+    let mut results = vec![];
+    let mut all_passed = true;
+
+    for file in test_files(path)? {
+        let contents = std::fs::read_to_string(&file)?;
+        let source = Text::from(contents.clone());
+        let file = file.display().to_string();
+        let context = script_context(false).await?;
+
+        // Sourcing the script defines its tests, so a script that fails here has none to run
+        let start = Instant::now();
+        if !run_line(&contents, &source, &context).await {
+            all_passed = false;
+            results.push(result_row(&file, "(script)", false, start.elapsed()));
+            continue;
+        }
+
+        let tests = context
+            .scope
+            .get_command_names()
+            .into_iter()
+            .filter(|name| name.starts_with("test_"));
+
+        for test in tests {
+            context.scope.enter_scope();
+            let start = Instant::now();
+            let passed = run_line(&test, &source, &context).await;
+            let duration = start.elapsed();
+            context.scope.exit_scope();
+
+            all_passed &= passed;
+            results.push(result_row(&file, &test, passed, duration));
+        }
+    }
+
+    if results.is_empty() {
+        eprintln!("nu: no tests found in {}", path.display());
+        return Ok(all_passed);
+    }
+
+    let context = script_context(false).await?;
+    print_values(results, &context).await?;
+
+    Ok(all_passed)
+}
@@ -4,15 +4,19 @@ pub(crate) mod macros;
 mod from_delimited_data;
 mod to_delimited_data;
 
+pub(crate) mod alias_import;
 pub(crate) mod ansi;
 pub(crate) mod append;
 pub(crate) mod args;
+pub(crate) mod assert;
+pub(crate) mod ast;
 pub mod autoenv;
 pub(crate) mod autoenv_trust;
 pub(crate) mod autoenv_untrust;
 pub(crate) mod autoview;
 pub(crate) mod benchmark;
 pub(crate) mod build_string;
+pub(crate) mod cache;
 pub(crate) mod cal;
 pub(crate) mod cd;
 pub(crate) mod char_;
@@ -28,30 +32,38 @@ pub(crate) mod count;
 pub(crate) mod cp;
 pub(crate) mod date;
 pub(crate) mod debug;
+pub(crate) mod debug_break;
+pub(crate) mod debug_parse;
 pub(crate) mod def;
 pub(crate) mod default;
 pub mod default_context;
 pub(crate) mod describe;
+pub(crate) mod detect;
+pub(crate) mod dirs;
 pub(crate) mod do_;
 pub(crate) mod drop;
 pub(crate) mod du;
 pub(crate) mod each;
 pub(crate) mod echo;
 pub(crate) mod empty;
+pub(crate) mod encode_;
 pub(crate) mod enter;
 pub(crate) mod every;
 pub(crate) mod exec;
 pub(crate) mod exit;
+pub(crate) mod fill;
 pub(crate) mod first;
 pub(crate) mod flatten;
 pub(crate) mod format;
 pub(crate) mod from;
 pub(crate) mod from_csv;
+pub(crate) mod from_dotenv;
 pub(crate) mod from_eml;
 pub(crate) mod from_ics;
 pub(crate) mod from_ini;
 pub(crate) mod from_json;
 pub(crate) mod from_ods;
+pub(crate) mod from_semver;
 pub(crate) mod from_ssv;
 pub(crate) mod from_toml;
 pub(crate) mod from_tsv;
@@ -68,28 +80,38 @@ pub(crate) mod headers;
 pub(crate) mod help;
 pub(crate) mod histogram;
 pub(crate) mod history;
+pub(crate) mod history_import;
 pub(crate) mod if_;
+pub(crate) mod ignore;
 pub(crate) mod insert;
+pub(crate) mod into;
 pub(crate) mod into_int;
 pub(crate) mod keep;
+pub(crate) mod keybindings;
 pub(crate) mod last;
 pub(crate) mod let_;
 pub(crate) mod let_env;
 pub(crate) mod lines;
+pub(crate) mod load_env;
 pub(crate) mod ls;
 pub(crate) mod math;
 pub(crate) mod merge;
 pub(crate) mod mkdir;
+pub(crate) mod mock;
 pub(crate) mod move_;
 pub(crate) mod next;
 pub(crate) mod nth;
 pub(crate) mod nu;
+pub(crate) mod on_exit;
+pub(crate) mod on_signal;
 pub(crate) mod open;
 pub(crate) mod parse;
 pub(crate) mod path;
 pub(crate) mod pivot;
+pub(crate) mod popd;
 pub(crate) mod prepend;
 pub(crate) mod prev;
+pub(crate) mod pushd;
 pub(crate) mod pwd;
 pub(crate) mod random;
 pub(crate) mod range;
@@ -98,8 +120,10 @@ pub(crate) mod reject;
 pub(crate) mod rename;
 pub(crate) mod reverse;
 pub(crate) mod rm;
+pub(crate) mod run_elevated;
 pub(crate) mod run_external;
 pub(crate) mod save;
+pub(crate) mod schema;
 pub(crate) mod select;
 pub(crate) mod seq;
 pub(crate) mod seq_dates;
@@ -110,25 +134,32 @@ pub(crate) mod skip;
 pub(crate) mod sleep;
 pub(crate) mod sort_by;
 pub(crate) mod source;
+pub(crate) mod source_env;
 pub(crate) mod split;
 pub(crate) mod split_by;
 pub(crate) mod str_;
 pub(crate) mod table;
 pub(crate) mod tags;
+pub(crate) mod tmpfile;
 pub(crate) mod to;
 pub(crate) mod to_csv;
 pub(crate) mod to_html;
 pub(crate) mod to_json;
 pub(crate) mod to_md;
+pub(crate) mod to_text;
 pub(crate) mod to_toml;
 pub(crate) mod to_tsv;
 pub(crate) mod to_url;
 pub(crate) mod to_xml;
 pub(crate) mod to_yaml;
+pub(crate) mod try_;
 pub(crate) mod uniq;
 pub(crate) mod update;
+pub(crate) mod upsert;
 pub(crate) mod url_;
+pub(crate) mod validate;
 pub(crate) mod version;
+pub(crate) mod view_source;
 pub(crate) mod where_;
 pub(crate) mod which_;
 pub(crate) mod with_env;
@@ -137,13 +168,17 @@ pub(crate) mod wrap;
 pub(crate) use autoview::Autoview;
 pub(crate) use cd::Cd;
 
+pub(crate) use alias_import::AliasImport;
 pub(crate) use ansi::Ansi;
 pub(crate) use append::Command as Append;
+pub(crate) use assert::{Assert, AssertEqual, AssertError};
+pub(crate) use ast::Ast;
 pub(crate) use autoenv::Autoenv;
 pub(crate) use autoenv_trust::AutoenvTrust;
 pub(crate) use autoenv_untrust::AutoenvUnTrust;
 pub(crate) use benchmark::Benchmark;
 pub(crate) use build_string::BuildString;
+pub(crate) use cache::Cache;
 pub(crate) use cal::Cal;
 pub(crate) use char_::Char;
 pub(crate) use chart::Chart;
@@ -155,9 +190,13 @@ pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
 pub(crate) use date::{Date, DateFormat, DateListTimeZone, DateNow, DateToTable, DateToTimeZone};
 pub(crate) use debug::Debug;
+pub(crate) use debug_break::DebugBreak;
+pub(crate) use debug_parse::DebugParse;
 pub(crate) use def::Def;
 pub(crate) use default::Default;
 pub(crate) use describe::Describe;
+pub(crate) use detect::{Detect, DetectColumns};
+pub(crate) use dirs::Dirs;
 pub(crate) use do_::Do;
 pub(crate) use drop::Drop;
 pub(crate) use du::Du;
@@ -166,9 +205,13 @@ pub(crate) use each::EachGroup;
 pub(crate) use each::EachWindow;
 pub(crate) use echo::Echo;
 pub(crate) use empty::Command as Empty;
+pub(crate) use encode_::{Decode, DecodeBase64, DecodeHex, Encode, EncodeBase64, EncodeHex};
 pub(crate) use if_::If;
+pub(crate) use ignore::Ignore;
 pub(crate) use nu::NuPlugin;
+pub(crate) use schema::Schema;
 pub(crate) use update::Command as Update;
+pub(crate) use upsert::Command as Upsert;
 pub(crate) mod kill;
 pub(crate) use kill::Kill;
 pub(crate) mod clear;
@@ -178,16 +221,19 @@ pub(crate) use enter::Enter;
 pub(crate) use every::Every;
 pub(crate) use exec::Exec;
 pub(crate) use exit::Exit;
+pub(crate) use fill::Fill;
 pub(crate) use first::First;
 pub(crate) use flatten::Command as Flatten;
-pub(crate) use format::{FileSize, Format};
+pub(crate) use format::{FileSize, Format, FormatDuration, FormatNumber};
 pub(crate) use from::From;
 pub(crate) use from_csv::FromCSV;
+pub(crate) use from_dotenv::FromDotenv;
 pub(crate) use from_eml::FromEML;
 pub(crate) use from_ics::FromIcs;
 pub(crate) use from_ini::FromINI;
 pub(crate) use from_json::FromJSON;
 pub(crate) use from_ods::FromODS;
+pub(crate) use from_semver::FromSemver;
 pub(crate) use from_ssv::FromSSV;
 pub(crate) use from_toml::FromTOML;
 pub(crate) use from_tsv::FromTSV;
@@ -205,13 +251,19 @@ pub(crate) use headers::Headers;
 pub(crate) use help::Help;
 pub(crate) use histogram::Histogram;
 pub(crate) use history::History;
+pub(crate) use history_import::HistoryImport;
 pub(crate) use insert::Command as Insert;
+pub(crate) use into::{IntoCommand, IntoFilesize};
 pub(crate) use into_int::IntoInt;
 pub(crate) use keep::{Keep, KeepUntil, KeepWhile};
+#[cfg(feature = "crossterm")]
+pub(crate) use keybindings::KeybindingsListen;
+pub(crate) use keybindings::{Keybindings, KeybindingsList};
 pub(crate) use last::Last;
 pub(crate) use let_::Let;
 pub(crate) use let_env::LetEnv;
 pub(crate) use lines::Lines;
+pub(crate) use load_env::LoadEnv;
 pub(crate) use ls::Ls;
 pub(crate) use math::{
     Math, MathAbs, MathAverage, MathCeil, MathEval, MathFloor, MathMaximum, MathMedian,
@@ -219,9 +271,12 @@ pub(crate) use math::{
 };
 pub(crate) use merge::Merge;
 pub(crate) use mkdir::Mkdir;
+pub(crate) use mock::Mock;
 pub(crate) use move_::{Move, Mv};
 pub(crate) use next::Next;
 pub(crate) use nth::Nth;
+pub(crate) use on_exit::OnExit;
+pub(crate) use on_signal::OnSignal;
 pub(crate) use open::Open;
 pub(crate) use parse::Parse;
 pub(crate) use path::{
@@ -229,8 +284,10 @@ pub(crate) use path::{
     PathType,
 };
 pub(crate) use pivot::Pivot;
+pub(crate) use popd::Popd;
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
+pub(crate) use pushd::Pushd;
 pub(crate) use pwd::Pwd;
 #[cfg(feature = "uuid_crate")]
 pub(crate) use random::RandomUUID;
@@ -243,6 +300,7 @@ pub(crate) use reject::Reject;
 pub(crate) use rename::Rename;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
+pub(crate) use run_elevated::RunElevated;
 pub(crate) use run_external::RunExternalCommand;
 pub(crate) use save::Save;
 pub(crate) use select::Command as Select;
@@ -255,30 +313,37 @@ pub(crate) use skip::{Skip, SkipUntil, SkipWhile};
 pub(crate) use sleep::Sleep;
 pub(crate) use sort_by::SortBy;
 pub(crate) use source::Source;
+pub(crate) use source_env::SourceEnv;
 pub(crate) use split::{Split, SplitChars, SplitColumn, SplitRow};
 pub(crate) use split_by::SplitBy;
 pub(crate) use str_::{
-    Str, StrCamelCase, StrCapitalize, StrCollect, StrContains, StrDowncase, StrEndsWith,
-    StrFindReplace, StrFrom, StrIndexOf, StrKebabCase, StrLPad, StrLength, StrPascalCase, StrRPad,
-    StrReverse, StrScreamingSnakeCase, StrSnakeCase, StrStartsWith, StrSubstring, StrToDatetime,
-    StrToDecimal, StrToInteger, StrTrim, StrTrimLeft, StrTrimRight, StrUpcase,
+    Str, StrCamelCase, StrCapitalize, StrCollect, StrContains, StrDistance, StrDowncase,
+    StrEndsWith, StrFindReplace, StrFrom, StrIndexOf, StrKebabCase, StrLPad, StrLength,
+    StrNormalize, StrPascalCase, StrRPad, StrReverse, StrScreamingSnakeCase, StrSimilarity,
+    StrSnakeCase, StrStartsWith, StrSubstring, StrTitleCase, StrToDatetime, StrToDecimal,
+    StrToInteger, StrTrim, StrTrimLeft, StrTrimRight, StrUpcase,
 };
 pub(crate) use table::Table;
 pub(crate) use tags::Tags;
+pub(crate) use tmpfile::TmpFile;
 pub(crate) use to::To;
 pub(crate) use to_csv::ToCSV;
 pub(crate) use to_html::ToHTML;
 pub(crate) use to_json::ToJSON;
 pub(crate) use to_md::Command as ToMarkdown;
+pub(crate) use to_text::ToText;
 pub(crate) use to_toml::ToTOML;
 pub(crate) use to_tsv::ToTSV;
 pub(crate) use to_url::ToURL;
 pub(crate) use to_xml::ToXML;
 pub(crate) use to_yaml::ToYAML;
 pub(crate) use touch::Touch;
+pub(crate) use try_::Try;
 pub(crate) use uniq::Uniq;
-pub(crate) use url_::{UrlCommand, UrlHost, UrlPath, UrlQuery, UrlScheme};
+pub(crate) use url_::{UrlCommand, UrlDecode, UrlEncode, UrlHost, UrlPath, UrlQuery, UrlScheme};
+pub(crate) use validate::Validate;
 pub(crate) use version::Version;
+pub(crate) use view_source::ViewSource;
 pub(crate) use where_::Where;
 pub(crate) use which_::Which;
 pub(crate) use with_env::WithEnv;
@@ -313,6 +378,8 @@ mod tests {
             whole_stream_command(StrSubstring),
             whole_stream_command(StrToDatetime),
             whole_stream_command(StrContains),
+            whole_stream_command(StrDistance),
+            whole_stream_command(StrSimilarity),
             whole_stream_command(StrIndexOf),
             whole_stream_command(StrTrim),
             whole_stream_command(StrTrimLeft),
@@ -322,6 +389,7 @@ mod tests {
             //whole_stream_command(StrCollect),
             whole_stream_command(StrLength),
             whole_stream_command(StrLPad),
+            whole_stream_command(StrNormalize),
             whole_stream_command(StrReverse),
             whole_stream_command(StrRPad),
             whole_stream_command(StrCamelCase),
@@ -329,6 +397,7 @@ mod tests {
             whole_stream_command(StrKebabCase),
             whole_stream_command(StrSnakeCase),
             whole_stream_command(StrScreamingSnakeCase),
+            whole_stream_command(StrTitleCase),
             whole_stream_command(ToMarkdown),
         ]
     }
@@ -0,0 +1,220 @@
+use crate::commands::history_import::{home_file, Shell};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct Arguments {
+    from: Tagged<String>,
+    path: Option<Tagged<PathBuf>>,
+}
+
+pub struct AliasImport;
+
+#[async_trait]
+impl WholeStreamCommand for AliasImport {
+    fn name(&self) -> &str {
+        "alias import"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("alias import")
+            .required_named(
+                "from",
+                SyntaxShape::String,
+                "the shell the aliases come from: bash, zsh or fish",
+                Some('f'),
+            )
+            .optional(
+                "path",
+                SyntaxShape::FilePath,
+                "the file defining the aliases, if not the shell's startup file",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Reads the aliases of another shell as nu alias definitions.
+
+Only simple aliases are read: ones that run a single command. Aliases chaining commands, redirecting output or substituting commands are left out, since they need rewriting for nu."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        alias_import(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the bash aliases as nu aliases",
+                example: "alias import --from bash",
+                result: None,
+            },
+            Example {
+                description: "Run the zsh aliases at startup",
+                example:
+                    "config set startup $(config get startup | append $(alias import --from zsh))",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn alias_import(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (Arguments { from, path }, _) = args.process().await?;
+
+    let shell = Shell::from_name(&from)?;
+    let (path, path_tag) = match path {
+        Some(path) => (path.item, path.tag),
+        None => match startup_file(shell) {
+            Some(path) => (path, tag.clone()),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Could not find the startup file",
+                    "give the path of the file defining the aliases",
+                    &tag,
+                ))
+            }
+        },
+    };
+
+    let contents = std::fs::read_to_string(&path).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not open the aliases",
+            format!("could not read {}", path.display()),
+            &path_tag,
+        )
+    })?;
+
+    let definitions: Vec<_> = contents
+        .lines()
+        .filter_map(|line| parse_alias(line, shell))
+        .map(|(name, body)| {
+            ReturnSuccess::value(
+                UntaggedValue::string(format!("alias {} = {}", name, body)).into_value(&tag),
+            )
+        })
+        .collect();
+
+    Ok(futures::stream::iter(definitions).to_output_stream())
+}
+
+fn startup_file(shell: Shell) -> Option<PathBuf> {
+    match shell {
+        Shell::Bash => home_file(".bashrc"),
+        Shell::Zsh => std::env::var_os("ZDOTDIR")
+            .map(|dir| PathBuf::from(dir).join(".zshrc"))
+            .or_else(|| home_file(".zshrc")),
+        Shell::Fish => std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home_file(".config"))
+            .map(|config| config.join("fish").join("config.fish")),
+    }
+}
+
+/// The name and body of a line like `alias ll='ls -l'`, or fish's `alias ll 'ls -l'`, if the body
+/// is a single command nu can run as it is.
+fn parse_alias(line: &str, shell: Shell) -> Option<(String, String)> {
+    let definition = line.trim().strip_prefix("alias ")?.trim_start();
+
+    // Flags like zsh's `alias -g` define other kinds of aliases
+    if definition.starts_with('-') {
+        return None;
+    }
+
+    let (name, body) = match definition.find('=') {
+        Some(index) if !definition[..index].contains(char::is_whitespace) => {
+            (&definition[..index], &definition[index + 1..])
+        }
+        _ if shell == Shell::Fish => {
+            let index = definition.find(char::is_whitespace)?;
+            (&definition[..index], definition[index..].trim_start())
+        }
+        _ => return None,
+    };
+
+    let body = unquote(body.trim())?;
+    let is_simple = !body.is_empty()
+        && !["&&", "||", ";", "|", ">", "<", "$(", "`", "&"]
+            .iter()
+            .any(|construct| body.contains(construct));
+
+    if name.is_empty() || !is_simple {
+        None
+    } else {
+        Some((name.to_string(), body))
+    }
+}
+
+/// Takes off the quotes around an alias body, which may have quoted parts of its own.
+fn unquote(body: &str) -> Option<String> {
+    let mut unquoted = String::new();
+    let mut chars = body.chars();
+    let mut quote = None;
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, '\'') | (None, '"') => quote = Some(c),
+            (Some(open), c) if c == open => quote = None,
+            (Some('\''), c) => unquoted.push(c),
+            (_, '\\') => unquoted.push(chars.next()?),
+            // A comment after the definition
+            (None, '#') => break,
+            (_, c) => unquoted.push(c),
+        }
+    }
+
+    if quote.is_some() {
+        None
+    } else {
+        Some(unquoted.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_alias, Shell};
+
+    fn alias(name: &str, body: &str) -> Option<(String, String)> {
+        Some((name.to_string(), body.to_string()))
+    }
+
+    #[test]
+    fn reads_simple_aliases() {
+        assert_eq!(
+            parse_alias("alias ll='ls -l'", Shell::Bash),
+            alias("ll", "ls -l")
+        );
+        assert_eq!(
+            parse_alias("  alias gs=\"git status\" # short", Shell::Zsh),
+            alias("gs", "git status")
+        );
+        assert_eq!(
+            parse_alias("alias vi=nvim", Shell::Bash),
+            alias("vi", "nvim")
+        );
+        assert_eq!(
+            parse_alias("alias gco 'git checkout'", Shell::Fish),
+            alias("gco", "git checkout")
+        );
+        assert_eq!(
+            parse_alias("alias gco='git checkout'", Shell::Fish),
+            alias("gco", "git checkout")
+        );
+    }
+
+    #[test]
+    fn leaves_out_what_needs_rewriting() {
+        assert_eq!(parse_alias("alias up='cd .. && ls'", Shell::Bash), None);
+        assert_eq!(
+            parse_alias("alias today='date | cut -c1-10'", Shell::Bash),
+            None
+        );
+        assert_eq!(parse_alias("alias -g G='| grep'", Shell::Zsh), None);
+        assert_eq!(parse_alias("alias broken='ls", Shell::Bash), None);
+        assert_eq!(parse_alias("export EDITOR=vim", Shell::Bash), None);
+    }
+}
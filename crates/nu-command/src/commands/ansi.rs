@@ -130,7 +130,7 @@ Format: #
                     e.tag(),
                 ));
             }
-            let output = format!("\x1b[{}", e.item);
+            let output = without_colors(format!("\x1b[{}", e.item));
             return Ok(OutputStream::one(ReturnSuccess::value(
                 UntaggedValue::string(output).into_value(e.tag()),
             )));
@@ -155,7 +155,7 @@ Format: #
         }
 
         let code_string = code.as_string()?;
-        let ansi_code = str_to_ansi(code_string);
+        let ansi_code = str_to_ansi(code_string).map(without_colors);
 
         if let Some(output) = ansi_code {
             Ok(OutputStream::one(ReturnSuccess::value(
@@ -171,6 +171,16 @@ Format: #
     }
 }
 
+/// Colors and styles become empty when they're turned off, while other codes, like those moving
+/// the cursor, are kept.
+fn without_colors(code: String) -> String {
+    if code.ends_with('m') && !nu_table::colors_enabled() {
+        String::new()
+    } else {
+        code
+    }
+}
+
 pub fn str_to_ansi(s: String) -> Option<String> {
     match s.as_str() {
         "g" | "green" => Some(Color::Green.prefix().to_string()),
@@ -0,0 +1,118 @@
+use crate::prelude::*;
+use nu_engine::evaluate_baseline_expr;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, hir::ClassifiedCommand, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct Command;
+
+#[derive(Deserialize)]
+struct Arguments {
+    condition: CapturedBlock,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "assert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert")
+            .required(
+                "condition",
+                SyntaxShape::MathExpression,
+                "the condition that must be true",
+            )
+            .named(
+                "message",
+                SyntaxShape::String,
+                "the message to show if the assertion fails",
+                Some('m'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error if the condition is not true."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        assert(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check that a condition holds",
+                example: "assert 1 + 1 == 2",
+                result: Some(vec![]),
+            },
+            Example {
+                description: "Explain what went wrong when the condition doesn't hold",
+                example: "let files = $(ls | count); assert -m 'expected some files' $files > 0",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn assert(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let context = EvaluationContext::from_args(&args);
+    let (Arguments { condition, message }, _) = args.process().await?;
+
+    let expr = match condition
+        .block
+        .block
+        .get(0)
+        .and_then(|group| group.pipelines.get(0))
+        .and_then(|pipeline| pipeline.list.get(0))
+    {
+        Some(ClassifiedCommand::Expr(expr)) if condition.block.block.len() == 1 => expr.clone(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a condition",
+                "expected a condition",
+                tag,
+            ))
+        }
+    };
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&condition.captured.entries);
+    let result = evaluate_baseline_expr(&expr, &context).await;
+    context.scope.exit_scope();
+
+    if result?.as_bool()? {
+        Ok(OutputStream::empty())
+    } else {
+        Err(ShellError::labeled_error(
+            failure_message(&message),
+            "this condition is false",
+            expr.span,
+        ))
+    }
+}
+
+/// The headline of an assertion failure, which is the user's message if they gave one.
+pub(crate) fn failure_message(message: &Option<Tagged<String>>) -> String {
+    match message {
+        Some(message) => format!("Assertion failed: {}", message.item),
+        None => "Assertion failed".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Command {})?)
+    }
+}
@@ -0,0 +1,101 @@
+use super::command::failure_message;
+use crate::prelude::*;
+use nu_data::value::{compare_values, format_leaf};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Operator, ShellTypeName, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    left: Value,
+    right: Value,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "assert equal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert equal")
+            .required("left", SyntaxShape::Any, "the value to check")
+            .required("right", SyntaxShape::Any, "the value it should equal")
+            .named(
+                "message",
+                SyntaxShape::String,
+                "the message to show if the assertion fails",
+                Some('m'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error showing both values if they are not equal."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (
+            Arguments {
+                left,
+                right,
+                message,
+            },
+            _,
+        ) = args.process().await?;
+
+        let equal = compare_values(Operator::Equal, &left.value, &right.value)
+            .unwrap_or_else(|_| left.value == right.value);
+
+        if equal {
+            Ok(OutputStream::empty())
+        } else {
+            Err(ShellError::labeled_error_with_secondary(
+                failure_message(&message),
+                format!("left: {}", describe(&left)),
+                &left.tag,
+                format!("right: {}", describe(&right)),
+                &right.tag,
+            ))
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check that two values are equal",
+                example: "assert equal $(echo [1 2 3] | last) 3",
+                result: Some(vec![]),
+            },
+            Example {
+                description: "Tables are compared row by row",
+                example: "assert equal $(echo [[a]; [1]]) $(echo [[a]; [1]])",
+                result: Some(vec![]),
+            },
+        ]
+    }
+}
+
+fn describe(value: &Value) -> String {
+    format!(
+        "{} ({})",
+        format_leaf(&value.value).plain_string(100),
+        value.type_name()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+}
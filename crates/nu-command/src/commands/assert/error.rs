@@ -0,0 +1,111 @@
+use super::command::failure_message;
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, hir::ExternalRedirection, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+struct Arguments {
+    block: CapturedBlock,
+    message: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "assert error"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert error")
+            .required("block", SyntaxShape::Block, "the block that should fail")
+            .named(
+                "message",
+                SyntaxShape::String,
+                "the message to show if the assertion fails",
+                Some('m'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fails with an error if running the block doesn't produce one."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        assert_error(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check that a block fails",
+            example: "assert error { echo [1 2] | get name }",
+            result: Some(vec![]),
+        }]
+    }
+}
+
+async fn assert_error(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&args);
+    let (Arguments { mut block, message }, input) = args.process().await?;
+
+    // The block is expected to fail, so its errors are kept off the terminal
+    block
+        .block
+        .set_redirect(ExternalRedirection::StdoutAndStderr);
+
+    // Errors from before the block are left as they are
+    let errors_before = context.current_errors.lock().len();
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    let result = run_block(&block.block, &context, input).await;
+    context.scope.exit_scope();
+
+    let failed = match result {
+        Ok(mut stream) => {
+            let output = stream.drain_vec().await;
+
+            output.iter().any(|value| {
+                matches!(
+                    value,
+                    Value {
+                        value: UntaggedValue::Error(_),
+                        ..
+                    }
+                )
+            }) || context.current_errors.lock().len() > errors_before
+        }
+        Err(_) => true,
+    };
+
+    context.current_errors.lock().truncate(errors_before);
+
+    if failed {
+        Ok(OutputStream::empty())
+    } else {
+        Err(ShellError::labeled_error(
+            failure_message(&message),
+            "this block ran without an error",
+            block.block.span,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod command;
+pub mod equal;
+pub mod error;
+
+pub use command::Command as Assert;
+pub use equal::SubCommand as AssertEqual;
+pub use error::SubCommand as AssertError;
@@ -0,0 +1,176 @@
+use crate::commands::from_json::from_json_string_to_value;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_parser::{LiteBlock, ParserScope, Token, TokenContents};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+use serde::Serialize;
+
+pub struct Ast;
+
+#[derive(Deserialize)]
+pub struct AstArgs {
+    pipeline: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Ast {
+    fn name(&self) -> &str {
+        "ast"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ast").required(
+            "pipeline",
+            SyntaxShape::String,
+            "the pipeline to parse, as a string",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Shows how a pipeline is parsed, without running it.
+
+The `tokens` are what the lexer splits the text into, `lite` groups them into pipelines and commands, and `hir` is the tree the pipeline would run as. If the pipeline doesn't parse, `error` says why."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        ast(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the tokens of a pipeline",
+                example: "ast 'ls | where size > 10kb' | get tokens",
+                result: None,
+            },
+            Example {
+                description: "Show the commands of each pipeline",
+                example: "ast 'echo 1; echo 2 | str length' | get lite",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn ast(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (AstArgs { pipeline }, _) = args.process().await?;
+
+    let (tokens, lex_err) = nu_parser::lex(&pipeline.item, 0);
+    let (lite_block, block_err) = nu_parser::block(tokens.clone());
+
+    // Parsing registers the definitions it finds, which shouldn't outlive the command
+    scope.enter_scope();
+    let (block, parse_err) = nu_parser::parse(&pipeline.item, 0, &scope);
+    scope.exit_scope();
+
+    let describe_failed = || {
+        ShellError::labeled_error(
+            "Could not describe the parsed pipeline",
+            "could not describe",
+            &pipeline.tag,
+        )
+    };
+
+    let mut row = TaggedDictBuilder::new(&tag);
+    row.insert_value("tokens", tokens_table(&tokens, &tag));
+    row.insert_value("lite", lite_table(&lite_block, &tag));
+    row.insert_value("hir", to_value(&block, &tag).ok_or_else(describe_failed)?);
+    row.insert_value(
+        "error",
+        match lex_err.or(block_err).or(parse_err) {
+            Some(err) => to_value(&err, &tag).ok_or_else(describe_failed)?,
+            None => UntaggedValue::nothing().into_value(&tag),
+        },
+    );
+
+    Ok(OutputStream::one(ReturnSuccess::value(row.into_value())))
+}
+
+/// Turns parser structures into nested values by way of their serialized form.
+fn to_value(item: &impl Serialize, tag: &Tag) -> Option<Value> {
+    let json = serde_json::to_string(item).ok()?;
+    from_json_string_to_value(json, tag).ok()
+}
+
+fn tokens_table(tokens: &[Token], tag: &Tag) -> Value {
+    let rows = tokens
+        .iter()
+        .map(|token| {
+            let kind = match &token.contents {
+                TokenContents::Baseline(_) => "baseline",
+                TokenContents::Comment(_) => "comment",
+                TokenContents::Pipe => "pipe",
+                TokenContents::Semicolon => "semicolon",
+                TokenContents::EOL => "eol",
+            };
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("kind", UntaggedValue::string(kind));
+            row.insert_untagged("text", UntaggedValue::string(token.contents.to_string()));
+            row.insert_untagged("start", UntaggedValue::int(token.span.start()));
+            row.insert_untagged("end", UntaggedValue::int(token.span.end()));
+            row.into_value()
+        })
+        .collect();
+
+    UntaggedValue::table(&rows).into_value(tag)
+}
+
+/// The lite block as a table of groups, each a table of pipelines, each a table of commands
+/// with their parts.
+fn lite_table(lite_block: &LiteBlock, tag: &Tag) -> Value {
+    let groups = lite_block
+        .block
+        .iter()
+        .map(|group| {
+            let pipelines = group
+                .pipelines
+                .iter()
+                .map(|pipeline| {
+                    let commands = pipeline
+                        .commands
+                        .iter()
+                        .map(|command| {
+                            let parts: Vec<Value> = command
+                                .parts
+                                .iter()
+                                .map(|part| UntaggedValue::string(&part.item).into_value(tag))
+                                .collect();
+
+                            let mut row = TaggedDictBuilder::new(tag);
+                            row.insert_value("parts", UntaggedValue::table(&parts).into_value(tag));
+                            row.insert_untagged(
+                                "comments",
+                                UntaggedValue::string(command.comments_joined()),
+                            );
+                            row.into_value()
+                        })
+                        .collect::<Vec<_>>();
+
+                    UntaggedValue::table(&commands).into_value(tag)
+                })
+                .collect::<Vec<_>>();
+
+            UntaggedValue::table(&pipelines).into_value(tag)
+        })
+        .collect::<Vec<_>>();
+
+    UntaggedValue::table(&groups).into_value(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ast;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Ast {})?)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::commands::autoview::options::{ConfigExtensions, NuConfig as AutoViewConfiguration};
 use crate::prelude::*;
 use crate::primitive::get_color_config;
-use nu_data::value::format_leaf;
+use nu_data::value::{format_leaf, format_leaf_with_precision};
 use nu_engine::{UnevaluatedCallInfo, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::hir::{self, Expression, ExternalRedirection, Literal, SpannedExpression};
@@ -86,6 +86,7 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
     let table = context.get_command("table");
 
     let pivot_mode = configuration.pivot_mode();
+    let float_precision = configuration.float_precision();
 
     let (mut input_stream, context) = RunnableContextWithoutInput::convert(context);
     let term_width = context.host.lock().width();
@@ -238,13 +239,16 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
                             entries.push(vec![
                                 nu_table::StyledString::new(
                                     key.to_string(),
-                                    TextStyle::new()
-                                        .alignment(nu_table::Alignment::Left)
-                                        .fg(ansi_term::Color::Green)
-                                        .bold(Some(true)),
+                                    TextStyle::new().alignment(nu_table::Alignment::Left).style(
+                                        color_hm
+                                            .get("header_color")
+                                            .copied()
+                                            .unwrap_or_else(|| ansi_term::Color::Green.bold()),
+                                    ),
                                 ),
                                 nu_table::StyledString::new(
-                                    format_leaf(value).plain_string(100_000),
+                                    format_leaf_with_precision(value, float_precision)
+                                        .plain_string(100_000),
                                     nu_table::TextStyle::basic_left(),
                                 ),
                             ]);
@@ -25,6 +25,7 @@ impl AutoPivotMode {
 
 pub trait ConfigExtensions: Debug + Send {
     fn pivot_mode(&self) -> AutoPivotMode;
+    fn float_precision(&self) -> usize;
 }
 
 pub fn pivot_mode(config: &NuConfig) -> AutoPivotMode {
@@ -48,4 +49,8 @@ impl ConfigExtensions for NuConfig {
     fn pivot_mode(&self) -> AutoPivotMode {
         pivot_mode(self)
     }
+
+    fn float_precision(&self) -> usize {
+        crate::commands::table::options::float_precision(self)
+    }
 }
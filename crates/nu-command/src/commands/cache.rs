@@ -0,0 +1,238 @@
+use crate::commands::to_json::value_to_json_value;
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+pub struct Cache;
+
+#[derive(Deserialize)]
+struct CacheArgs {
+    ttl: Tagged<u64>,
+    block: CapturedBlock,
+}
+
+/// What a block returned, and when.
+#[derive(Serialize, Deserialize)]
+struct CachedOutput {
+    created: SystemTime,
+    expires: SystemTime,
+    values: Vec<Value>,
+}
+
+/// Only when a cached output expires, read without the values when evicting old outputs.
+#[derive(Deserialize)]
+struct Expiry {
+    expires: SystemTime,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Cache {
+    fn name(&self) -> &str {
+        "cache"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cache")
+            .required(
+                "ttl",
+                SyntaxShape::Unit,
+                "how long the output of the block is kept",
+            )
+            .required("block", SyntaxShape::Block, "the block to run")
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block, or returns what it returned for the same input if that was recent enough"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        cache(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fetch a page at most once every ten minutes",
+                example: "cache 10min { fetch https://www.nushell.sh/ }",
+                result: None,
+            },
+            Example {
+                description: "Keep the result of a block for each input it's given",
+                example: "echo [1 2 3] | cache 1hr { math sum }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn cache(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = EvaluationContext::from_args(&raw_args);
+    let (CacheArgs { ttl, block }, input) = raw_args.process().await?;
+
+    let input: Vec<Value> = input.collect().await;
+    let cwd = context.shell_manager.path();
+    let env = context.scope.get_env_vars();
+    let dir = cache_dir()?;
+    let path = dir.join(format!("{}.json", key(&block, &input, &cwd, &env)?));
+    let ttl = Duration::from_nanos(ttl.item);
+
+    if let Some(cached) = read(&path) {
+        let fresh = match cached.created.elapsed() {
+            Ok(age) => age < ttl,
+            Err(_) => false,
+        };
+
+        if fresh {
+            return Ok(futures::stream::iter(cached.values).to_output_stream());
+        }
+    }
+
+    // Errors from before the block are not its own
+    let errors_before = context.get_errors().len();
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    let result = run_block(&block.block, &context, InputStream::from(input)).await;
+    context.scope.exit_scope();
+
+    let values = result?.into_vec().await;
+
+    // Failures aren't kept, so that the next call tries again
+    let failed = context.get_errors().len() > errors_before
+        || values.iter().any(|value| value.value.is_error());
+    if !failed {
+        let created = SystemTime::now();
+        let output = CachedOutput {
+            created,
+            expires: created + ttl,
+            values,
+        };
+        // Not being able to keep the output only means running the block again next time
+        evict(&dir);
+        let _ = write(&path, &output);
+
+        return Ok(futures::stream::iter(output.values).to_output_stream());
+    }
+
+    Ok(futures::stream::iter(values).to_output_stream())
+}
+
+/// The same block, with the same variables and input, run in the same directory and environment,
+/// has the same key.
+fn key(
+    block: &CapturedBlock,
+    input: &[Value],
+    cwd: &str,
+    env: &IndexMap<String, String>,
+) -> Result<String, ShellError> {
+    let mut hasher = Sha256::new();
+
+    match &block.block.source {
+        Some(source) => hasher.update(source.as_bytes()),
+        None => hasher.update(format!("{:?}", block.block).as_bytes()),
+    }
+
+    for (name, value) in block.captured.entries.iter() {
+        hasher.update(name.as_bytes());
+        hasher.update(key_part(value)?.as_bytes());
+    }
+
+    for value in input {
+        hasher.update(key_part(value)?.as_bytes());
+    }
+
+    hasher.update(format!("{}\n", cwd).as_bytes());
+
+    let mut env: Vec<_> = env.iter().collect();
+    env.sort();
+    for (name, value) in env {
+        hasher.update(format!("{}={}\n", name, value).as_bytes());
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn key_part(value: &Value) -> Result<String, ShellError> {
+    // Values are compared as JSON, since their tags differ from one run to the next
+    let json = value_to_json_value(value).map_err(|_| {
+        ShellError::labeled_error(
+            "Can't cache the output of a block for this value",
+            "the value can't be compared with the cached ones",
+            &value.tag,
+        )
+    })?;
+
+    Ok(format!("{}\n", json))
+}
+
+fn cache_dir() -> Result<PathBuf, ShellError> {
+    let path = nu_data::config::user_cache_path_for("blocks", "cache")?;
+    std::fs::create_dir_all(&path)?;
+
+    Ok(path)
+}
+
+/// Removes the outputs that expired, and those that can't be read anymore.
+fn evict(dir: &Path) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    let now = SystemTime::now();
+    for path in entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+    {
+        if path
+            .extension()
+            .map_or(true, |extension| extension != "json")
+        {
+            continue;
+        }
+
+        let expired = match std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Expiry>(&contents).ok())
+        {
+            Some(expiry) => expiry.expires <= now,
+            None => true,
+        };
+
+        if expired {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+fn read(path: &Path) -> Option<CachedOutput> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write(path: &Path, output: &CachedOutput) -> Result<(), ShellError> {
+    let contents = serde_json::to_string(output)?;
+    std::fs::write(path, contents)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Cache {})?)
+    }
+}
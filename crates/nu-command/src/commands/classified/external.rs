@@ -1,12 +1,13 @@
-use crate::futures::ThreadedReceiver;
+use crate::futures::{ByteLimit, ThreadedReceiver};
 use crate::prelude::*;
-use nu_engine::evaluate_baseline_expr;
+use nu_engine::{evaluate_baseline_expr, PipelineBuffer};
 use nu_engine::{MaybeTextCodec, StringOrBinary};
 
 use std::borrow::Cow;
 use std::io::Write;
 use std::ops::Deref;
-use std::process::{Command, Stdio};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
 use std::sync::mpsc;
 
 use futures::executor::block_on_stream;
@@ -16,7 +17,7 @@ use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::hir::Expression;
 use nu_protocol::hir::{ExternalCommand, ExternalRedirection};
-use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_protocol::{closest_names, Primitive, ShellTypeName, UntaggedValue, Value};
 use nu_source::Tag;
 use nu_stream::trace_stream;
 
@@ -25,18 +26,33 @@ pub(crate) async fn run_external_command(
     context: &mut EvaluationContext,
     input: InputStream,
     external_redirection: ExternalRedirection,
+    stderr_append: Option<PathBuf>,
 ) -> Result<InputStream, ShellError> {
     trace!(target: "nu::run::external", "-> {}", command.name);
 
     if !did_find_command(&command.name) {
+        let commands = context
+            .scope
+            .get_command_names()
+            .into_iter()
+            .filter(|name| !name.contains(' '));
+
+        let label = match closest_names(&command.name, commands).first() {
+            Some(suggestion) => format!(
+                "unknown command `{}`, did you mean `{}`?",
+                command.name, suggestion
+            ),
+            None => "command not found".to_string(),
+        };
+
         return Err(ShellError::labeled_error(
             "Command not found",
-            "command not found",
+            label,
             &command.name_tag,
         ));
     }
 
-    run_with_stdin(command, context, input, external_redirection).await
+    run_with_stdin(command, context, input, external_redirection, stderr_append).await
 }
 
 async fn run_with_stdin(
@@ -44,6 +60,7 @@ async fn run_with_stdin(
     context: &mut EvaluationContext,
     input: InputStream,
     external_redirection: ExternalRedirection,
+    stderr_append: Option<PathBuf>,
 ) -> Result<InputStream, ShellError> {
     let path = context.shell_manager.path();
 
@@ -131,6 +148,7 @@ async fn run_with_stdin(
         &process_args[..],
         input,
         external_redirection,
+        stderr_append,
         &context.scope,
     )
 }
@@ -141,22 +159,83 @@ fn spawn(
     args: &[String],
     input: InputStream,
     external_redirection: ExternalRedirection,
+    stderr_append: Option<PathBuf>,
     scope: &Scope,
 ) -> Result<InputStream, ShellError> {
     let command = command.clone();
 
+    // stderr going to a file can't be read back into the pipeline as well
+    let external_redirection = match (external_redirection, &stderr_append) {
+        (ExternalRedirection::Stderr, Some(_)) => ExternalRedirection::None,
+        (ExternalRedirection::StdoutAndStderr, Some(_)) => ExternalRedirection::Stdout,
+        (external_redirection, _) => external_redirection,
+    };
+
+    // sudo asks for the password while nu reads from its pipes, where the prompt gets lost
+    // among the output, so it's asked for beforehand on the terminal instead. With -k, sudo
+    // would ask again after that, so the cached credentials are reset before asking and the
+    // command runs without it.
+    let redirected = !input.is_empty() || external_redirection != ExternalRedirection::None;
+    let prompt = if redirected {
+        sudo_prompt(&command.name, args)
+    } else {
+        SudoPrompt::None
+    };
+    let fresh_args;
+    let args = if prompt == SudoPrompt::Fresh {
+        fresh_args = without_timestamp_reset(args);
+        &fresh_args[..]
+    } else {
+        args
+    };
+
     let mut process = {
         #[cfg(windows)]
         {
-            let mut process = Command::new("cmd");
-            process.arg("/c");
-            process.arg(&command.name);
-            for arg in args {
-                // Clean the args before we use them:
-                let arg = arg.replace("|", "\\|");
-                process.arg(&arg);
+            let env_vars = scope.get_env_vars();
+            let paths = env_vars
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case("path"))
+                .map(|(_, paths)| paths.clone());
+
+            let resolved = find_windows_command(&command.name, paths, path);
+            match windows_host(&command.name, resolved.as_deref()) {
+                WindowsHost::Program => {
+                    let mut process =
+                        Command::new(resolved.unwrap_or_else(|| PathBuf::from(&command.name)));
+                    process.args(args);
+                    process
+                }
+                WindowsHost::Cmd => {
+                    let mut process = Command::new("cmd");
+                    process.arg("/d").arg("/c");
+                    process.arg(escape_for_cmd(&command.name));
+                    for arg in args {
+                        process.arg(escape_for_cmd(arg));
+                    }
+                    process
+                }
+                WindowsHost::PowerShell => {
+                    let shell = if find_windows_command("pwsh", None, path).is_some() {
+                        "pwsh"
+                    } else {
+                        "powershell"
+                    };
+
+                    let mut process = Command::new(shell);
+                    process.args(&[
+                        "-NoLogo",
+                        "-NoProfile",
+                        "-NonInteractive",
+                        "-ExecutionPolicy",
+                        "Bypass",
+                        "-File",
+                    ]);
+                    process.arg(resolved.unwrap_or_else(|| PathBuf::from(&command.name)));
+                    process.args(args);
+                    process
+                }
             }
-            process
         }
 
         #[cfg(not(windows))]
@@ -194,17 +273,66 @@ fn spawn(
         _ => {}
     }
 
+    if let Some(stderr_path) = stderr_append {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&stderr_path)
+            .map_err(|e| {
+                ShellError::labeled_error(
+                    format!("Could not open {} ({})", stderr_path.display(), e),
+                    "could not redirect stderr",
+                    &command.name_tag,
+                )
+            })?;
+        process.stderr(Stdio::from(file));
+        trace!(target: "nu::run::external", "set up stderr redirection");
+    }
+
     // open since we have some contents for stdin
     if !input.is_empty() {
         process.stdin(Stdio::piped());
         trace!(target: "nu::run::external", "set up stdin pipe");
     }
 
+    if prompt != SudoPrompt::None {
+        if prompt == SudoPrompt::Fresh {
+            trace!(target: "nu::run::external", "resetting sudo credentials");
+            let _ = Command::new(&command.name)
+                .arg("-k")
+                .current_dir(path)
+                .env_clear()
+                .envs(scope.get_env_vars())
+                .status();
+        }
+
+        trace!(target: "nu::run::external", "validating sudo credentials");
+        let validated = Command::new(&command.name)
+            .arg("-v")
+            .current_dir(path)
+            .env_clear()
+            .envs(scope.get_env_vars())
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false);
+
+        if !validated {
+            return Err(ShellError::labeled_error(
+                "Could not run the command with sudo",
+                "sudo could not authenticate",
+                &command.name_tag,
+            ));
+        }
+    }
+
     trace!(target: "nu::run::external", "built command {:?}", process);
 
     // TODO Switch to async_std::process once it's stabilized
     if let Ok(mut child) = process.spawn() {
-        let (tx, rx) = mpsc::sync_channel(0);
+        let buffer = pipeline_buffer(scope);
+        let (tx, rx) = mpsc::sync_channel(buffer.values);
+        let bytes_in_flight = ByteLimit::new(buffer.bytes);
+        let stdout_bytes = bytes_in_flight.clone();
 
         let mut stdin = child.stdin.take();
 
@@ -212,6 +340,7 @@ fn spawn(
         let stdout_read_tx = tx;
         let stdin_name_tag = command.name_tag.clone();
         let stdout_name_tag = command.name_tag;
+        let exit_scope = scope.clone();
 
         std::thread::spawn(move || {
             if !input.is_empty() {
@@ -281,6 +410,10 @@ fn spawn(
                     match line {
                         Ok(line) => match line {
                             StringOrBinary::String(s) => {
+                                if !stdout_bytes.reserve(s.len()) {
+                                    break;
+                                }
+
                                 let result = stdout_read_tx.send(Ok(Value {
                                     value: UntaggedValue::Primitive(Primitive::String(s.clone())),
                                     tag: stdout_name_tag.clone(),
@@ -291,6 +424,10 @@ fn spawn(
                                 }
                             }
                             StringOrBinary::Binary(b) => {
+                                if !stdout_bytes.reserve(b.len()) {
+                                    break;
+                                }
+
                                 let result = stdout_read_tx.send(Ok(Value {
                                     value: UntaggedValue::Primitive(Primitive::Binary(
                                         b.into_iter().collect(),
@@ -406,7 +543,10 @@ fn spawn(
             // than what other shells will do.
             let external_failed = match child.wait() {
                 Err(_) => true,
-                Ok(exit_status) => !exit_status.success(),
+                Ok(exit_status) => {
+                    exit_scope.set_last_exit_code(exit_code(&exit_status));
+                    !exit_status.success()
+                }
             };
 
             if external_failed {
@@ -432,7 +572,11 @@ fn spawn(
             Ok(())
         });
 
-        let stream = ThreadedReceiver::new(rx);
+        let releaser = bytes_in_flight.releaser();
+        let stream = ThreadedReceiver::new(rx).map(move |value| {
+            releaser.release(value_bytes(&value));
+            value
+        });
         Ok(stream.to_input_stream())
     } else {
         Err(ShellError::labeled_error(
@@ -443,6 +587,64 @@ fn spawn(
     }
 }
 
+/// The exit code a shell gives for the status: 128 and the number of the signal for commands
+/// killed by one.
+fn exit_code(status: &ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    status.code().unwrap_or(1)
+}
+
+/// The number of values a stage may get ahead of the next one without a config.
+const DEFAULT_PIPELINE_BUFFER_SIZE: usize = 1024;
+/// The bytes of text and binary data those values may hold without a config.
+const DEFAULT_PIPELINE_BUFFER_BYTES: usize = 1024 * 1024;
+
+/// How far a stage running on a thread of its own, like an external command, may get ahead of the
+/// stage consuming its output, configured with `pipeline_buffer_size` for the number of values
+/// and `pipeline_buffer_bytes` for their bytes. Once either limit is reached, the stage waits for
+/// the next one to catch up, so a fast producer can't fill the memory while a slow consumer
+/// drains it. A size of 0 makes the stage wait on every value.
+pub(crate) fn pipeline_buffer(scope: &Scope) -> PipelineBuffer {
+    scope.pipeline_buffer(|| {
+        let config = nu_data::config::config(Tag::unknown()).ok();
+        let configured = |name: &str| {
+            config
+                .as_ref()
+                .and_then(|cfg| cfg.get(name).map(|value| value.as_u64()))
+                .and_then(|value| value.ok())
+                .map(|value| value as usize)
+        };
+
+        PipelineBuffer {
+            values: configured("pipeline_buffer_size").unwrap_or(DEFAULT_PIPELINE_BUFFER_SIZE),
+            bytes: configured("pipeline_buffer_bytes").unwrap_or(DEFAULT_PIPELINE_BUFFER_BYTES),
+        }
+    })
+}
+
+/// The bytes a value from an external command holds, for the pipeline buffer.
+fn value_bytes<E>(value: &Result<Value, E>) -> usize {
+    match value {
+        Ok(Value {
+            value: UntaggedValue::Primitive(Primitive::String(s)),
+            ..
+        }) => s.len(),
+        Ok(Value {
+            value: UntaggedValue::Primitive(Primitive::Binary(b)),
+            ..
+        }) => b.len(),
+        _ => 0,
+    }
+}
+
 pub fn did_find_command(#[allow(unused)] name: &str) -> bool {
     #[cfg(not(feature = "which"))]
     {
@@ -472,6 +674,226 @@ pub fn did_find_command(#[allow(unused)] name: &str) -> bool {
     }
 }
 
+/// Whether sudo is about to ask for a password on the terminal.
+#[derive(Debug, PartialEq)]
+enum SudoPrompt {
+    /// It doesn't ask, when it isn't sudo, when told to read the password from elsewhere or not
+    /// to ask at all, or for the commands that only change or inspect the credentials themselves
+    None,
+    /// It asks, unless the credentials it has cached are still valid
+    Cached,
+    /// It asks even with valid credentials cached, as `sudo -k` with a command ignores them
+    Fresh,
+}
+
+/// The short options of sudo taking a value, like the user of `-u root`.
+const SUDO_SHORT_WITH_VALUE: &str = "CDgprRtTUu";
+
+/// The long options of sudo taking a value, when it isn't given after `=`.
+const SUDO_LONG_WITH_VALUE: &[&str] = &[
+    "--close-from",
+    "--chdir",
+    "--group",
+    "--prompt",
+    "--role",
+    "--chroot",
+    "--type",
+    "--command-timeout",
+    "--other-user",
+    "--user",
+];
+
+/// How sudo asks for a password when the command is sudo, going by the options it's given.
+fn sudo_prompt(name: &str, args: &[String]) -> SudoPrompt {
+    let is_sudo = std::path::Path::new(name)
+        .file_stem()
+        .map(|stem| stem == "sudo")
+        .unwrap_or(false);
+    if !is_sudo || cfg!(windows) {
+        return SudoPrompt::None;
+    }
+
+    let command_start = sudo_command_start(args);
+    let mut resets_timestamp = false;
+
+    for option in args[..command_start]
+        .iter()
+        .map(|arg| arg.trim_matches('"'))
+    {
+        let flags = if option.starts_with("--") {
+            vec![option.split('=').next().unwrap_or(option).to_string()]
+        } else if option.starts_with('-') {
+            short_flags(option)
+                .iter()
+                .map(|flag| format!("-{}", flag))
+                .collect()
+        } else {
+            // The value of the option before
+            continue;
+        };
+
+        for flag in flags {
+            match flag.as_str() {
+                "-k" | "--reset-timestamp" => resets_timestamp = true,
+                "-n" | "-S" | "-A" | "-v" | "-K" | "-h" | "-V" | "-l" | "--non-interactive"
+                | "--stdin" | "--askpass" | "--validate" | "--remove-timestamp" | "--help"
+                | "--version" | "--list" => return SudoPrompt::None,
+                _ => {}
+            }
+        }
+    }
+
+    match (resets_timestamp, command_start < args.len()) {
+        // Without a command, -k only throws the cached credentials away
+        (true, false) => SudoPrompt::None,
+        (true, true) => SudoPrompt::Fresh,
+        (false, _) => SudoPrompt::Cached,
+    }
+}
+
+/// Where the command sudo runs starts in its arguments, past its options and their values.
+fn sudo_command_start(args: &[String]) -> usize {
+    let mut index = 0;
+
+    while let Some(arg) = args.get(index).map(|arg| arg.trim_matches('"')) {
+        if arg == "--" {
+            return index + 1;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            return index;
+        }
+
+        let takes_next = if arg.starts_with("--") {
+            SUDO_LONG_WITH_VALUE.contains(&arg)
+        } else {
+            let flags = short_flags(arg);
+            flags.len() == arg.chars().count() - 1
+                && flags
+                    .last()
+                    .map(|flag| SUDO_SHORT_WITH_VALUE.contains(*flag))
+                    .unwrap_or(false)
+        };
+
+        index += if takes_next { 2 } else { 1 };
+    }
+
+    index
+}
+
+/// The flags of a group of short options like `-Eu`, up to the first taking a value, as the rest
+/// of the group is that value.
+fn short_flags(group: &str) -> Vec<char> {
+    let mut flags = vec![];
+
+    for flag in group.chars().skip(1) {
+        flags.push(flag);
+        if SUDO_SHORT_WITH_VALUE.contains(flag) {
+            break;
+        }
+    }
+
+    flags
+}
+
+/// The arguments of sudo without the `-k` that would make it ignore the credentials just asked
+/// for.
+fn without_timestamp_reset(args: &[String]) -> Vec<String> {
+    let command_start = sudo_command_start(args);
+
+    args.iter()
+        .enumerate()
+        .filter_map(|(index, arg)| {
+            let option = arg.trim_matches('"');
+            if index >= command_start || !option.starts_with('-') || option == "--" {
+                return Some(arg.clone());
+            }
+
+            if option == "-k" || option == "--reset-timestamp" {
+                None
+            } else if option.starts_with("--") {
+                Some(arg.clone())
+            } else {
+                let flags = short_flags(option).len();
+                let group: String = option
+                    .chars()
+                    .enumerate()
+                    .filter(|(position, flag)| {
+                        !(*position >= 1 && *position <= flags && *flag == 'k')
+                    })
+                    .map(|(_, flag)| flag)
+                    .collect();
+
+                Some(group)
+            }
+        })
+        .collect()
+}
+
+/// What runs a command on Windows.
+#[cfg(windows)]
+#[derive(Debug, PartialEq)]
+enum WindowsHost {
+    /// Programs are started on their own, so that their arguments reach them as they were given
+    Program,
+    /// cmd.exe runs its built-ins, like `dir`, and batch files
+    Cmd,
+    /// PowerShell runs its scripts
+    PowerShell,
+}
+
+/// Picks what runs the command from the file it was found in, if it was found at all. Commands
+/// that weren't are left to cmd.exe, which has built-ins of its own and reports those it can't find.
+#[cfg(windows)]
+fn windows_host(name: &str, resolved: Option<&std::path::Path>) -> WindowsHost {
+    let extension = resolved
+        .unwrap_or_else(|| std::path::Path::new(name))
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some("ps1") => WindowsHost::PowerShell,
+        Some("bat") | Some("cmd") => WindowsHost::Cmd,
+        _ if resolved.is_some() => WindowsHost::Program,
+        _ => WindowsHost::Cmd,
+    }
+}
+
+/// Finds the command in the PATH given to it, the way Windows does, trying the extensions of
+/// PATHEXT for names without one.
+#[cfg(windows)]
+fn find_windows_command(name: &str, paths: Option<String>, cwd: &str) -> Option<PathBuf> {
+    #[cfg(feature = "which")]
+    {
+        which::which_in(name, paths, cwd).ok()
+    }
+
+    #[cfg(not(feature = "which"))]
+    {
+        None
+    }
+}
+
+/// cmd.exe reads `&`, `|`, `<`, `>`, `^` and parentheses as its own unless they're escaped with
+/// `^`, except within quotes. Arguments with spaces are quoted when the process starts, so only
+/// the others are escaped.
+#[cfg(windows)]
+fn escape_for_cmd(argument: &str) -> Cow<'_, str> {
+    let quoted = argument.is_empty() || argument.contains(' ') || argument.contains('\t');
+    if quoted || !argument.contains(|c: char| "&|<>^()".contains(c)) {
+        return Cow::Borrowed(argument);
+    }
+
+    let mut escaped = String::with_capacity(argument.len() * 2);
+    for c in argument.chars() {
+        if "&|<>^()".contains(c) {
+            escaped.push('^');
+        }
+        escaped.push(c);
+    }
+
+    Cow::Owned(escaped)
+}
+
 fn expand_tilde<SI: ?Sized, P, HD>(input: &SI, home_dir: HD) -> std::borrow::Cow<str>
 where
     SI: AsRef<str>,
@@ -532,8 +954,12 @@ mod tests {
     use super::{
         add_double_quotes, argument_is_quoted, escape_double_quotes, expand_tilde, remove_quotes,
     };
+    #[cfg(windows)]
+    use super::{escape_for_cmd, windows_host, WindowsHost};
     #[cfg(feature = "which")]
     use super::{run_external_command, InputStream};
+    #[cfg(not(windows))]
+    use super::{sudo_prompt, without_timestamp_reset, SudoPrompt};
 
     #[cfg(feature = "which")]
     use futures::executor::block_on;
@@ -566,7 +992,7 @@ mod tests {
             basic_evaluation_context().expect("There was a problem creating a basic context.");
 
         assert!(
-            run_external_command(cmd, &mut ctx, input, ExternalRedirection::Stdout)
+            run_external_command(cmd, &mut ctx, input, ExternalRedirection::Stdout, None)
                 .await
                 .is_err()
         );
@@ -635,6 +1061,96 @@ mod tests {
         assert_eq!(argument_is_quoted(r#""andrés""#), true);
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn escapes_the_operators_of_cmd() {
+        assert_eq!(escape_for_cmd("a&b|c"), "a^&b^|c");
+        assert_eq!(escape_for_cmd("(x)>y"), "^(x^)^>y");
+        assert_eq!(escape_for_cmd("a & b"), "a & b");
+        assert_eq!(escape_for_cmd("plain"), "plain");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn picks_what_runs_commands_on_windows() {
+        use std::path::Path;
+
+        assert_eq!(windows_host("dir", None), WindowsHost::Cmd);
+        assert_eq!(
+            windows_host("build", Some(Path::new("C:\\tools\\build.BAT"))),
+            WindowsHost::Cmd
+        );
+        assert_eq!(windows_host("deploy.ps1", None), WindowsHost::PowerShell);
+        assert_eq!(
+            windows_host("git", Some(Path::new("C:\\git\\git.exe"))),
+            WindowsHost::Program
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn asks_for_the_sudo_password_beforehand() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["\"tee\"", "\"/etc/hosts\""])),
+            SudoPrompt::Cached
+        );
+        assert_eq!(
+            sudo_prompt("/usr/bin/sudo", &args(&["-u", "root", "cat"])),
+            SudoPrompt::Cached
+        );
+        assert_eq!(sudo_prompt("sudo", &args(&["-n", "cat"])), SudoPrompt::None);
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["--stdin", "cat"])),
+            SudoPrompt::None
+        );
+        assert_eq!(sudo_prompt("sudo", &args(&["-v"])), SudoPrompt::None);
+        assert_eq!(sudo_prompt("doas", &args(&["cat"])), SudoPrompt::None);
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["cat", "-n"])),
+            SudoPrompt::Cached
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn asks_for_the_sudo_password_again_with_a_reset_timestamp() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(sudo_prompt("sudo", &args(&["-k"])), SudoPrompt::None);
+        assert_eq!(sudo_prompt("sudo", &args(&["-K"])), SudoPrompt::None);
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["-k", "-u", "root"])),
+            SudoPrompt::None
+        );
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["-k", "cat"])),
+            SudoPrompt::Fresh
+        );
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["-Ek", "-u", "root", "cat"])),
+            SudoPrompt::Fresh
+        );
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["-uk", "cat"])),
+            SudoPrompt::Cached
+        );
+        assert_eq!(
+            sudo_prompt("sudo", &args(&["-kn", "cat"])),
+            SudoPrompt::None
+        );
+
+        assert_eq!(
+            without_timestamp_reset(&args(&["-k", "cat", "-k"])),
+            args(&["cat", "-k"])
+        );
+        assert_eq!(
+            without_timestamp_reset(&args(&["-Ek", "-u", "k", "cat"])),
+            args(&["-E", "-u", "k", "cat"])
+        );
+    }
+
     #[test]
     fn adds_double_quotes_to_argument_to_be_passed_in() {
         assert_eq!(add_double_quotes("andrés"), "\"andrés\"");
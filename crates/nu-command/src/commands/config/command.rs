@@ -2,11 +2,20 @@ use crate::prelude::*;
 use nu_engine::CommandArgs;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 use nu_stream::OutputStream;
 
 pub struct Command;
 
+#[derive(Deserialize)]
+pub struct ConfigArgs {
+    get: Option<ColumnPath>,
+    set: Option<Value>,
+    remove: Option<Tagged<String>>,
+    path: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Command {
     fn name(&self) -> &str {
@@ -15,6 +24,25 @@ impl WholeStreamCommand for Command {
 
     fn signature(&self) -> Signature {
         Signature::build("config")
+            .named(
+                "get",
+                SyntaxShape::ColumnPath,
+                "value to get from the config",
+                Some('g'),
+            )
+            .named(
+                "set",
+                SyntaxShape::Any,
+                "sets a value in the config, eg) --set [key value]",
+                Some('s'),
+            )
+            .named(
+                "remove",
+                SyntaxShape::String,
+                "remove a value from the config",
+                Some('r'),
+            )
+            .switch("path", "return the path to the config file", Some('p'))
     }
 
     fn usage(&self) -> &str {
@@ -22,13 +50,85 @@ impl WholeStreamCommand for Command {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        let name_span = args.call_info.name_tag.clone();
-        let name = args.call_info.name_tag;
-        let result = nu_data::config::read(name_span, &None)?;
+        let name = args.call_info.name_tag.clone();
+        let (
+            ConfigArgs {
+                get,
+                set,
+                remove,
+                path,
+            },
+            _,
+        ) = args.process().await?;
+
+        if let Some(column_path) = get {
+            return super::get::get_path(&column_path, name);
+        }
+
+        if let Some(pair) = set {
+            let (column_path, value) = key_and_value(pair)?;
+            return super::set::set_path(&column_path, value, name);
+        }
+
+        if let Some(key) = remove {
+            return super::remove::remove_key(&key, name);
+        }
+
+        if path {
+            return super::path::config_path(name);
+        }
+
+        let result = nu_data::config::read(&name, &None)?;
 
         Ok(futures::stream::iter(vec![ReturnSuccess::value(
             UntaggedValue::Row(result.into()).into_value(name),
         )])
         .to_output_stream())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "See the whole config",
+                example: "config",
+                result: None,
+            },
+            Example {
+                description: "Get the table mode",
+                example: "config --get table_mode",
+                result: None,
+            },
+            Example {
+                description: "Set the startup commands",
+                example: "config --set [startup [\"alias la [path] {ls -a $path}\"]]",
+                result: None,
+            },
+            Example {
+                description: "Remove the custom prompt",
+                example: "config --remove prompt",
+                result: None,
+            },
+            Example {
+                description: "Show where the config file is",
+                example: "config --path",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Splits the `[key value]` pair given to `--set`.
+fn key_and_value(pair: Value) -> Result<(ColumnPath, Value), ShellError> {
+    match &pair.value {
+        UntaggedValue::Table(items) if items.len() == 2 => {
+            let key = items[0].as_string()?.spanned(items[0].tag.span);
+
+            Ok((ColumnPath::build(&key), items[1].clone()))
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a key and a value",
+            "expected [key value]",
+            pair.tag,
+        )),
+    }
 }
@@ -45,11 +45,15 @@ pub async fn get(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
     let (GetArgs { path }, _) = args.process().await?;
 
+    get_path(&path, name_tag)
+}
+
+pub(crate) fn get_path(path: &ColumnPath, name_tag: Tag) -> Result<OutputStream, ShellError> {
     // NOTE: None because we are not loading a new config file, we just want to read from the
     // existing config
     let result = UntaggedValue::row(nu_data::config::read(&name_tag, &None)?).into_value(&name_tag);
 
-    let value = crate::commands::get::get_column_path(&path, &result)?;
+    let value = crate::commands::get::get_column_path(path, &result)?;
 
     Ok(match value {
         Value {
@@ -33,9 +33,13 @@ impl WholeStreamCommand for SubCommand {
 }
 
 pub async fn path(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    config_path(args.call_info.name_tag)
+}
+
+pub(crate) fn config_path(name_tag: Tag) -> Result<OutputStream, ShellError> {
     let path = config::default_path()?;
 
     Ok(OutputStream::one(ReturnSuccess::value(
-        UntaggedValue::Primitive(Primitive::FilePath(path)).into_value(args.call_info.name_tag),
+        UntaggedValue::Primitive(Primitive::FilePath(path)).into_value(name_tag),
     )))
 }
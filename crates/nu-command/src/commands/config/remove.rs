@@ -20,7 +20,7 @@ impl WholeStreamCommand for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("config remove").required(
             "remove",
-            SyntaxShape::Any,
+            SyntaxShape::String,
             "remove a value from the config",
         )
     }
@@ -46,6 +46,13 @@ pub async fn remove(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_span = args.call_info.name_tag.clone();
     let (RemoveArgs { remove }, _) = args.process().await?;
 
+    remove_key(&remove, name_span)
+}
+
+pub(crate) fn remove_key(
+    remove: &Tagged<String>,
+    name_span: Tag,
+) -> Result<OutputStream, ShellError> {
     let mut result = nu_data::config::read(name_span, &None)?;
 
     let key = remove.to_string();
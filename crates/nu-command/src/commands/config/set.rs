@@ -59,8 +59,16 @@ impl WholeStreamCommand for SubCommand {
 
 pub async fn set(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
-    let (SetArgs { path, mut value }, _) = args.process().await?;
+    let (SetArgs { path, value }, _) = args.process().await?;
 
+    set_path(&path, value, name_tag)
+}
+
+pub(crate) fn set_path(
+    path: &ColumnPath,
+    mut value: Value,
+    name_tag: Tag,
+) -> Result<OutputStream, ShellError> {
     // NOTE: None because we are not loading a new config file, we just want to read from the
     // existing config
     let raw_entries = nu_data::config::read(&name_tag, &None)?;
@@ -72,7 +80,7 @@ pub async fn set(args: CommandArgs) -> Result<OutputStream, ShellError> {
         }
     }
 
-    match configuration.forgiving_insert_data_at_column_path(&path, value) {
+    match configuration.forgiving_insert_data_at_column_path(path, value) {
         Ok(Value {
             value: UntaggedValue::Row(changes),
             ..
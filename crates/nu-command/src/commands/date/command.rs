@@ -1,10 +1,22 @@
+use crate::commands::date::to_table::date_to_table;
+use crate::commands::str_::parse_date;
 use crate::prelude::*;
+use chrono::{DateTime, FixedOffset, Local};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::fmt::{self, write};
 
 pub struct Command;
 
+#[derive(Deserialize)]
+struct DateArgs {
+    date: Option<Tagged<String>>,
+    format: Option<Tagged<String>>,
+    utc: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Command {
     fn name(&self) -> &str {
@@ -13,19 +25,104 @@ impl WholeStreamCommand for Command {
 
     fn signature(&self) -> Signature {
         Signature::build("date")
+            .optional(
+                "date",
+                SyntaxShape::String,
+                "a date to parse instead of the current time",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "format the date with a strftime format",
+                Some('f'),
+            )
+            .switch("utc", "give the date in UTC", Some('u'))
     }
 
     fn usage(&self) -> &str {
-        "Apply date function"
+        "Get the current date as a row of its parts, or parse a date, optionally formatting it."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        Ok(OutputStream::one(ReturnSuccess::value(
-            UntaggedValue::string(get_help(&Command, &args.scope)).into_value(Tag::unknown()),
-        )))
+        date(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the parts of the current date",
+                example: "date | get year",
+                result: None,
+            },
+            Example {
+                description: "Format the current date in UTC",
+                example: "date --utc --format '%Y-%m-%d %H:%M'",
+                result: None,
+            },
+            Example {
+                description: "Parse a date",
+                example: "date '2021-02-27 14:30:00 +01:00' --format '%d/%m/%Y'",
+                result: Some(vec![UntaggedValue::string("27/02/2021").into()]),
+            },
+        ]
     }
 }
 
+async fn date(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let (DateArgs { date, format, utc }, _) = args.process().await?;
+
+    let dt: DateTime<FixedOffset> = match &date {
+        Some(date) => {
+            let parsed = parse_date(
+                &UntaggedValue::string(&date.item).into_value(&date.tag),
+                &date.tag,
+            )?;
+            match parsed.value {
+                UntaggedValue::Primitive(Primitive::Date(dt)) => dt,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Could not parse the date",
+                        "could not parse as datetime",
+                        &date.tag,
+                    ))
+                }
+            }
+        }
+        None => {
+            let now = Local::now();
+            now.with_timezone(now.offset())
+        }
+    };
+
+    let dt = if utc {
+        dt.with_timezone(&FixedOffset::east(0))
+    } else {
+        dt
+    };
+
+    let value = match format {
+        Some(format) => {
+            let mut output = String::new();
+            if let Err(fmt::Error) = write(&mut output, format_args!("{}", dt.format(&format.item)))
+            {
+                return Err(ShellError::labeled_error(
+                    "The date format is invalid",
+                    "invalid strftime format",
+                    &format.tag,
+                ));
+            }
+
+            UntaggedValue::string(output).into_value(&tag)
+        }
+        // A given date is kept a date, so that it can be compared and piped into other commands
+        None if date.is_some() => UntaggedValue::date(dt).into_value(&tag),
+        None => date_to_table(&dt, &tag),
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(value)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::Command;
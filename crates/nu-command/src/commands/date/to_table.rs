@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use chrono::{Datelike, Timelike};
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
 use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -44,44 +44,7 @@ async fn to_table(args: CommandArgs) -> Result<OutputStream, ShellError> {
             Value {
                 value: UntaggedValue::Primitive(Primitive::Date(dt)),
                 ..
-            } => {
-                let mut indexmap = IndexMap::new();
-
-                indexmap.insert(
-                    "year".to_string(),
-                    UntaggedValue::int(dt.year()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "month".to_string(),
-                    UntaggedValue::int(dt.month()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "day".to_string(),
-                    UntaggedValue::int(dt.day()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "hour".to_string(),
-                    UntaggedValue::int(dt.hour()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "minute".to_string(),
-                    UntaggedValue::int(dt.minute()).into_value(&tag),
-                );
-                indexmap.insert(
-                    "second".to_string(),
-                    UntaggedValue::int(dt.second()).into_value(&tag),
-                );
-
-                let tz = dt.offset();
-                indexmap.insert(
-                    "timezone".to_string(),
-                    UntaggedValue::string(format!("{}", tz)).into_value(&tag),
-                );
-
-                let value = UntaggedValue::Row(Dictionary::from(indexmap)).into_value(&tag);
-
-                ReturnSuccess::value(value)
-            }
+            } => ReturnSuccess::value(date_to_table(&dt, &tag)),
             _ => Err(ShellError::labeled_error(
                 "Expected a date from pipeline",
                 "requires date input",
@@ -91,6 +54,44 @@ async fn to_table(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
+/// The parts of the date as a row, from the year down to the second and the timezone.
+pub fn date_to_table(dt: &DateTime<FixedOffset>, tag: &Tag) -> Value {
+    let mut indexmap = IndexMap::new();
+
+    indexmap.insert(
+        "year".to_string(),
+        UntaggedValue::int(dt.year()).into_value(tag),
+    );
+    indexmap.insert(
+        "month".to_string(),
+        UntaggedValue::int(dt.month()).into_value(tag),
+    );
+    indexmap.insert(
+        "day".to_string(),
+        UntaggedValue::int(dt.day()).into_value(tag),
+    );
+    indexmap.insert(
+        "hour".to_string(),
+        UntaggedValue::int(dt.hour()).into_value(tag),
+    );
+    indexmap.insert(
+        "minute".to_string(),
+        UntaggedValue::int(dt.minute()).into_value(tag),
+    );
+    indexmap.insert(
+        "second".to_string(),
+        UntaggedValue::int(dt.second()).into_value(tag),
+    );
+
+    let tz = dt.offset();
+    indexmap.insert(
+        "timezone".to_string(),
+        UntaggedValue::string(format!("{}", tz)).into_value(tag),
+    );
+
+    UntaggedValue::Row(Dictionary::from(indexmap)).into_value(tag)
+}
+
 #[cfg(test)]
 mod tests {
     use super::Date;
@@ -1,5 +1,6 @@
 use crate::commands::date::parser::{datetime_in_timezone, ParseErrorKind};
 use crate::prelude::*;
+use chrono_tz::TZ_VARIANTS;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
@@ -37,6 +38,10 @@ Use `date list-timezone` to list all supported time zones.
         to_timezone(args).await
     }
 
+    fn argument_completions(&self, _flag: Option<&str>, _partial: &str) -> Vec<String> {
+        TZ_VARIANTS.iter().map(|tz| tz.name().to_string()).collect()
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue, Value};
+
+pub struct DebugBreak;
+
+#[async_trait]
+impl WholeStreamCommand for DebugBreak {
+    fn name(&self) -> &str {
+        "debug break"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug break")
+    }
+
+    fn usage(&self) -> &str {
+        r#"Stops the script here when it is run with `nu --debugger`.
+
+At the debugger prompt, `vars` prints the variables in scope, `input` prints the values coming into the breakpoint, `step` runs the next pipeline and stops again, and `continue` lets the script carry on. Without the debugger, values pass through unchanged."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        debug_break(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Look at the files before they are filtered",
+                example: "ls | debug break | where size > 10kb",
+                result: None,
+            },
+            Example {
+                description: "Pass the values through unchanged",
+                example: "echo [1 2] | debug break",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+async fn debug_break(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    if !nu_engine::is_debugger_enabled() {
+        return Ok(args.input.to_output_stream());
+    }
+
+    let context = EvaluationContext::from_args(&args);
+    let span = args.call_info.name_tag.span;
+    let input: Vec<Value> = args.input.collect().await;
+
+    nu_engine::break_into_debugger(&context, span, &input)?;
+
+    Ok(futures::stream::iter(input).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugBreak;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(DebugBreak {})?)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_parser::ParserScope;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct DebugParse;
+
+#[derive(Deserialize)]
+pub struct DebugParseArgs {
+    pipeline: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DebugParse {
+    fn name(&self) -> &str {
+        "debug parse"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug parse").required(
+            "pipeline",
+            SyntaxShape::String,
+            "the pipeline to parse, as a string",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Prints the parsed form of a pipeline as the parser sees it, without running it. See `ast` for the same as structured data."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        debug_parse(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Print the parsed form of a pipeline",
+            example: "debug parse 'ls | where size > 10kb'",
+            result: None,
+        }]
+    }
+}
+
+async fn debug_parse(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (DebugParseArgs { pipeline }, _) = args.process().await?;
+
+    scope.enter_scope();
+    let (block, err) = nu_parser::parse(&pipeline.item, 0, &scope);
+    scope.exit_scope();
+
+    let mut text = format!("{:#?}", block);
+    if let Some(err) = err {
+        text.push_str(&format!("\n{:#?}", err));
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(text).into_value(tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugParse;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(DebugParse {})?)
+    }
+}
@@ -29,6 +29,11 @@ impl WholeStreamCommand for Def {
                 "the parameters of the command",
             )
             .required("block", SyntaxShape::Block, "the body of the command")
+            .switch(
+                "env",
+                "keep the command's changes to the environment and the directory for its caller",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -16,12 +16,20 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(LetEnv),
             whole_stream_command(Def),
             whole_stream_command(Source),
+            whole_stream_command(SourceEnv),
+            whole_stream_command(LoadEnv),
+            whole_stream_command(OnSignal),
+            whole_stream_command(OnExit),
             // System/file operations
             whole_stream_command(Exec),
+            whole_stream_command(RunElevated),
             whole_stream_command(Pwd),
             whole_stream_command(Ls),
             whole_stream_command(Du),
             whole_stream_command(Cd),
+            whole_stream_command(Pushd),
+            whole_stream_command(Popd),
+            whole_stream_command(Dirs),
             whole_stream_command(Remove),
             whole_stream_command(Open),
             whole_stream_command(Config),
@@ -34,7 +42,14 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(ConfigPath),
             whole_stream_command(Help),
             whole_stream_command(History),
+            whole_stream_command(HistoryImport),
+            whole_stream_command(Keybindings),
+            whole_stream_command(KeybindingsList),
+            #[cfg(feature = "crossterm")]
+            whole_stream_command(KeybindingsListen),
+            whole_stream_command(AliasImport),
             whole_stream_command(Save),
+            whole_stream_command(TmpFile),
             whole_stream_command(Touch),
             whole_stream_command(Cpy),
             whole_stream_command(Date),
@@ -50,11 +65,25 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Version),
             whole_stream_command(Clear),
             whole_stream_command(Describe),
+            whole_stream_command(Schema),
+            whole_stream_command(Validate),
             whole_stream_command(Which),
+            whole_stream_command(ViewSource),
             whole_stream_command(Debug),
+            whole_stream_command(DebugBreak),
+            whole_stream_command(DebugParse),
+            whole_stream_command(Ast),
             whole_stream_command(WithEnv),
             whole_stream_command(Do),
+            whole_stream_command(Try),
+            whole_stream_command(Ignore),
+            whole_stream_command(Cache),
             whole_stream_command(Sleep),
+            // Testing
+            whole_stream_command(Assert),
+            whole_stream_command(AssertEqual),
+            whole_stream_command(AssertError),
+            whole_stream_command(Mock),
             // Statistics
             whole_stream_command(Size),
             whole_stream_command(Count),
@@ -75,10 +104,18 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             // Text manipulation
             whole_stream_command(Hash),
             whole_stream_command(HashBase64),
+            whole_stream_command(Encode),
+            whole_stream_command(EncodeBase64),
+            whole_stream_command(EncodeHex),
+            whole_stream_command(Decode),
+            whole_stream_command(DecodeBase64),
+            whole_stream_command(DecodeHex),
             whole_stream_command(Split),
             whole_stream_command(SplitColumn),
             whole_stream_command(SplitRow),
             whole_stream_command(SplitChars),
+            whole_stream_command(Detect),
+            whole_stream_command(DetectColumns),
             whole_stream_command(Lines),
             whole_stream_command(Echo),
             whole_stream_command(Parse),
@@ -93,6 +130,8 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(StrSubstring),
             whole_stream_command(StrToDatetime),
             whole_stream_command(StrContains),
+            whole_stream_command(StrDistance),
+            whole_stream_command(StrSimilarity),
             whole_stream_command(StrIndexOf),
             whole_stream_command(StrTrim),
             whole_stream_command(StrTrimLeft),
@@ -102,6 +141,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(StrCollect),
             whole_stream_command(StrLength),
             whole_stream_command(StrLPad),
+            whole_stream_command(StrNormalize),
             whole_stream_command(StrReverse),
             whole_stream_command(StrRPad),
             whole_stream_command(StrCamelCase),
@@ -109,6 +149,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(StrKebabCase),
             whole_stream_command(StrSnakeCase),
             whole_stream_command(StrScreamingSnakeCase),
+            whole_stream_command(StrTitleCase),
             whole_stream_command(BuildString),
             whole_stream_command(Ansi),
             whole_stream_command(Char),
@@ -119,7 +160,10 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Get),
             whole_stream_command(Update),
             whole_stream_command(Insert),
+            whole_stream_command(Upsert),
             whole_stream_command(IntoInt),
+            whole_stream_command(IntoCommand),
+            whole_stream_command(IntoFilesize),
             whole_stream_command(SplitBy),
             // Row manipulation
             whole_stream_command(Reverse),
@@ -135,6 +179,9 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Drop),
             whole_stream_command(Format),
             whole_stream_command(FileSize),
+            whole_stream_command(FormatDuration),
+            whole_stream_command(FormatNumber),
+            whole_stream_command(Fill),
             whole_stream_command(Where),
             whole_stream_command(If),
             whole_stream_command(Compact),
@@ -187,6 +234,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(ToHTML),
             whole_stream_command(ToJSON),
             whole_stream_command(ToMarkdown),
+            whole_stream_command(ToText),
             whole_stream_command(ToTOML),
             whole_stream_command(ToTSV),
             whole_stream_command(ToURL),
@@ -195,12 +243,14 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             // File format input
             whole_stream_command(From),
             whole_stream_command(FromCSV),
+            whole_stream_command(FromDotenv),
             whole_stream_command(FromEML),
             whole_stream_command(FromTSV),
             whole_stream_command(FromSSV),
             whole_stream_command(FromINI),
             whole_stream_command(FromJSON),
             whole_stream_command(FromODS),
+            whole_stream_command(FromSemver),
             whole_stream_command(FromTOML),
             whole_stream_command(FromURL),
             whole_stream_command(FromXLSX),
@@ -235,6 +285,8 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(UrlPath),
             whole_stream_command(UrlHost),
             whole_stream_command(UrlQuery),
+            whole_stream_command(UrlEncode),
+            whole_stream_command(UrlDecode),
             whole_stream_command(Seq),
             whole_stream_command(SeqDates),
         ]);
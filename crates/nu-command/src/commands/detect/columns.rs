@@ -0,0 +1,261 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+
+/// How many lines are looked at to find the columns, unless told otherwise.
+const DEFAULT_SAMPLE: usize = 100;
+
+#[derive(Deserialize)]
+struct Arguments {
+    skip: Option<Tagged<usize>>,
+    lines: Option<Tagged<usize>>,
+    #[serde(rename(deserialize = "no-headers"))]
+    no_headers: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "detect columns"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("detect columns")
+            .named(
+                "skip",
+                SyntaxShape::Int,
+                "number of lines to skip before the headers",
+                Some('s'),
+            )
+            .named(
+                "lines",
+                SyntaxShape::Int,
+                "number of lines to look at to find the columns (100 by default)",
+                Some('l'),
+            )
+            .switch(
+                "no-headers",
+                "don't treat the first line as the names of the columns",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits text into a table, finding the columns from how its lines are aligned."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        detect_columns(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Turn the containers listed by docker into a table",
+                example: "^docker ps | detect columns",
+                result: None,
+            },
+            Example {
+                description: "Turn a listing without headers into a table",
+                example: "^ls -l | detect columns --skip 1 --no-headers",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn detect_columns(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (
+        Arguments {
+            skip,
+            lines: sample,
+            no_headers,
+        },
+        input,
+    ) = args.process().await?;
+
+    let text = input.collect_string(name_tag).await?;
+    let tag = text.tag;
+
+    let lines: Vec<Vec<char>> = text
+        .item
+        .lines()
+        .skip(skip.map(|skip| skip.item).unwrap_or(0))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    if lines.is_empty() {
+        return Ok(OutputStream::empty());
+    }
+
+    let sample = lines
+        .len()
+        .min(sample.map_or(DEFAULT_SAMPLE, |sample| sample.item));
+    let headers = lines.first().filter(|_| !no_headers);
+    let columns = find_columns(&lines[..sample], headers.map(|line| &line[..]));
+
+    let mut rows = lines.iter().map(|line| split_line(line, &columns));
+    let headers = name_columns(if no_headers {
+        vec![String::new(); columns.len()]
+    } else {
+        rows.next().unwrap_or_default()
+    });
+
+    let rows: Vec<_> = rows
+        .map(|cells| {
+            let mut dict = TaggedDictBuilder::new(&tag);
+            for (header, cell) in headers.iter().zip(cells) {
+                dict.insert_untagged(header, UntaggedValue::string(cell));
+            }
+
+            ReturnSuccess::value(dict.into_value())
+        })
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// Finds where each column starts and ends, as the runs of characters that aren't whitespace in
+/// every line. With headers, a column without one is part of the column before it, as happens
+/// with the spaces in the last column of a line.
+fn find_columns(lines: &[Vec<char>], headers: Option<&[char]>) -> Vec<(usize, usize)> {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut columns = vec![];
+    let mut start = None;
+
+    for at in 0..width {
+        let blank = lines
+            .iter()
+            .all(|line| line.get(at).map_or(true, |c| c.is_whitespace()));
+
+        match (blank, start) {
+            (false, None) => start = Some(at),
+            (true, Some(from)) => {
+                columns.push((from, at));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(from) = start {
+        columns.push((from, width));
+    }
+
+    let headers = match headers {
+        Some(headers) => headers,
+        None => return columns,
+    };
+
+    let mut merged: Vec<(usize, usize)> = vec![];
+    for (start, end) in columns {
+        let named = headers
+            .iter()
+            .take(end)
+            .skip(start)
+            .any(|c| !c.is_whitespace());
+
+        match merged.last_mut() {
+            Some(last) if !named => last.1 = end,
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Cuts a line where its columns end. A word running past the end of its column, in a line that
+/// wasn't looked at to find them, stays whole in that column.
+fn split_line(line: &[char], columns: &[(usize, usize)]) -> Vec<String> {
+    let mut cells = vec![];
+    let mut from = 0;
+
+    for (index, (_, end)) in columns.iter().enumerate() {
+        let mut to = if index + 1 == columns.len() {
+            line.len()
+        } else {
+            (*end).max(from).min(line.len())
+        };
+
+        while to > 0
+            && to < line.len()
+            && !line[to - 1].is_whitespace()
+            && !line[to].is_whitespace()
+        {
+            to += 1;
+        }
+
+        cells.push(line[from..to].iter().collect::<String>().trim().to_string());
+        from = to;
+    }
+
+    cells
+}
+
+/// Names the columns after their headers, making up names for those without one.
+fn name_columns(headers: Vec<String>) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+
+    for (index, header) in headers.into_iter().enumerate() {
+        if header.is_empty() || names.contains(&header) {
+            names.push(format!("Column{}", index + 1));
+        } else {
+            names.push(header);
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_columns, split_line, ShellError, SubCommand};
+
+    fn lines(text: &str) -> Vec<Vec<char>> {
+        text.lines().map(|line| line.chars().collect()).collect()
+    }
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn finds_columns_with_spaces_in_their_headers() {
+        let lines = lines(
+            "CONTAINER ID   IMAGE    STATUS\n\
+             4b2c1e0f9a7d   ubuntu   Up 2 hours\n\
+             9f8e7d6c5b4a   nginx    Exited (0) 3 days ago",
+        );
+
+        let columns = find_columns(&lines, Some(&lines[0]));
+        let rows: Vec<_> = lines
+            .iter()
+            .map(|line| split_line(line, &columns))
+            .collect();
+
+        assert_eq!(rows[0], vec!["CONTAINER ID", "IMAGE", "STATUS"]);
+        assert_eq!(rows[1], vec!["4b2c1e0f9a7d", "ubuntu", "Up 2 hours"]);
+        assert_eq!(
+            rows[2],
+            vec!["9f8e7d6c5b4a", "nginx", "Exited (0) 3 days ago"]
+        );
+    }
+
+    #[test]
+    fn keeps_words_running_past_their_column_whole() {
+        let columns = find_columns(&lines("name  size\nnu    12"), None);
+
+        let cells = split_line(&"nushell 1024".chars().collect::<Vec<_>>(), &columns);
+
+        assert_eq!(cells, vec!["nushell", "1024"]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod columns;
+pub mod command;
+
+pub use columns::SubCommand as DetectColumns;
+pub use command::Command as Detect;
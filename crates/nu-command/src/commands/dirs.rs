@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, TaggedDictBuilder};
+
+pub struct Dirs;
+
+#[async_trait]
+impl WholeStreamCommand for Dirs {
+    fn name(&self) -> &str {
+        "dirs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dirs")
+    }
+
+    fn usage(&self) -> &str {
+        "Display the directory stack, from the current path to the first one left with pushd."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        dirs(args)
+    }
+}
+
+fn dirs(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag;
+    let current = args.shell_manager.path();
+
+    let mut dirs_out = VecDeque::new();
+    let stack = args.shell_manager.directory_stack.lock();
+
+    for path in std::iter::once(&current).chain(stack.iter().rev()) {
+        let mut dict = TaggedDictBuilder::new(&tag);
+        dict.insert_untagged("path", path.clone());
+
+        dirs_out.push_back(dict.into_value());
+    }
+
+    Ok(dirs_out.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dirs;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Dirs {})?)
+    }
+}
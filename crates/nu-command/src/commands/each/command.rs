@@ -5,7 +5,8 @@ use nu_engine::WholeStreamCommand;
 use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::{
-    hir::CapturedBlock, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+    hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
 };
 use nu_source::Tagged;
 
@@ -15,6 +16,7 @@ pub struct Each;
 pub struct EachArgs {
     block: CapturedBlock,
     numbered: Tagged<bool>,
+    keep_empty: Tagged<bool>,
 }
 
 #[async_trait]
@@ -31,6 +33,11 @@ impl WholeStreamCommand for Each {
                 "returned a numbered item ($it.index and $it.item)",
                 Some('n'),
             )
+            .switch(
+                "keep-empty",
+                "emit $nothing for rows where the block returns no value",
+                Some('k'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -63,6 +70,11 @@ impl WholeStreamCommand for Each {
                     "echo ['bob' 'fred'] | each --numbered { echo `{{$it.index}} is {{$it.item}}` }",
                 result: Some(vec![Value::from("0 is bob"), Value::from("1 is fred")]),
             },
+            Example {
+                description: "Keep a row for every input, even when the block returns nothing",
+                example: "echo [1 2 3] | each --keep-empty { if $it == 2 { echo $it } { } }",
+                result: None,
+            },
         ]
     }
 }
@@ -87,7 +99,7 @@ pub async fn process_row(
     context.scope.add_vars(&captured_block.captured.entries);
 
     if !captured_block.block.params.positional.is_empty() {
-        // FIXME: add check for more than parameter, once that's supported
+        // run_block refuses any parameters past the first, which are given no value
         context
             .scope
             .add_var(captured_block.block.params.positional[0].0.name(), input);
@@ -115,40 +127,41 @@ async fn each(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let (each_args, input): (EachArgs, _) = raw_args.process().await?;
     let block = Arc::new(Box::new(each_args.block));
-
-    if each_args.numbered.item {
-        Ok(input
-            .enumerate()
-            .then(move |input| {
-                let block = block.clone();
-                let context = context.clone();
-                let row = make_indexed_item(input.0, input.1);
-
-                async {
-                    match process_row(block, context, row).await {
-                        Ok(s) => s,
-                        Err(e) => OutputStream::one(Err(e)),
+    let numbered = each_args.numbered.item;
+    let keep_empty = each_args.keep_empty.item;
+
+    Ok(input
+        .enumerate()
+        .then(move |(index, input)| {
+            let block = block.clone();
+            let context = context.clone();
+            let tag = input.tag();
+            let row = if numbered {
+                make_indexed_item(index, input)
+            } else {
+                input
+            };
+
+            async move {
+                match process_row(block, context, row).await {
+                    Ok(mut s) if keep_empty => {
+                        let values = s.drain_vec().await;
+
+                        if values.is_empty() {
+                            OutputStream::one(ReturnSuccess::value(
+                                UntaggedValue::nothing().into_value(tag),
+                            ))
+                        } else {
+                            values.into()
+                        }
                     }
+                    Ok(s) => s,
+                    Err(e) => OutputStream::one(Err(e)),
                 }
-            })
-            .flatten()
-            .to_output_stream())
-    } else {
-        Ok(input
-            .then(move |input| {
-                let block = block.clone();
-                let context = context.clone();
-
-                async {
-                    match process_row(block, context, input).await {
-                        Ok(s) => s,
-                        Err(e) => OutputStream::one(Err(e)),
-                    }
-                }
-            })
-            .flatten()
-            .to_output_stream())
-    }
+            }
+        })
+        .flatten()
+        .to_output_stream())
 }
 
 #[cfg(test)]
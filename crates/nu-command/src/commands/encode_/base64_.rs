@@ -0,0 +1,180 @@
+use super::{bytes_of, decoded_value, operate};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    character_set: Option<Tagged<String>>,
+}
+
+pub struct EncodeBase64;
+
+#[async_trait]
+impl WholeStreamCommand for EncodeBase64 {
+    fn name(&self) -> &str {
+        "encode base64"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("encode base64")
+    }
+
+    fn usage(&self) -> &str {
+        "base64 encode a string or binary value"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (
+            Arguments {
+                rest,
+                character_set,
+            },
+            input,
+        ) = args.process().await?;
+        let config = character_set_config(character_set)?;
+
+        operate(input, rest, move |value| {
+            let encoded = base64::encode_config(bytes_of(value)?, config);
+            Ok(UntaggedValue::string(encoded).into_value(value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Base64 encode a string",
+                example: "echo 'username:password' | encode base64",
+                result: Some(vec![Value::from("dXNlcm5hbWU6cGFzc3dvcmQ=")]),
+            },
+            Example {
+                description: "Base64 encode a string so it can be used in a url",
+                example: "echo 'is this ok?' | encode base64 --character_set url-safe-no-padding",
+                result: Some(vec![Value::from("aXMgdGhpcyBvaz8")]),
+            },
+            Example {
+                description: "Base64 encode the contents of a file",
+                example: "open --raw logo.png | encode base64",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub struct DecodeBase64;
+
+#[async_trait]
+impl WholeStreamCommand for DecodeBase64 {
+    fn name(&self) -> &str {
+        "decode base64"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("decode base64")
+    }
+
+    fn usage(&self) -> &str {
+        "base64 decode a string, producing binary if the result is not valid UTF-8"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (
+            Arguments {
+                rest,
+                character_set,
+            },
+            input,
+        ) = args.process().await?;
+        let config = character_set_config(character_set)?;
+
+        operate(input, rest, move |value| {
+            let decoded = base64::decode_config(bytes_of(value)?, config).map_err(|e| {
+                ShellError::labeled_error(
+                    "value could not be base64 decoded",
+                    format!("{}", e),
+                    value.tag.span,
+                )
+            })?;
+
+            Ok(decoded_value(decoded, value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Base64 decode a string",
+                example: "echo 'dXNlcm5hbWU6cGFzc3dvcmQ=' | decode base64",
+                result: Some(vec![Value::from("username:password")]),
+            },
+            Example {
+                description: "Base64 decode a column",
+                example: "echo [[token]; ['bnU=']] | decode base64 token | get token",
+                result: Some(vec![Value::from("nu")]),
+            },
+        ]
+    }
+}
+
+fn signature(name: &str) -> Signature {
+    Signature::build(name)
+        .named(
+            "character_set",
+            SyntaxShape::String,
+            "the character set to use: 'standard' (default), 'standard-no-padding', 'url-safe', \
+             'url-safe-no-padding', 'binhex', 'bcrypt' or 'crypt'",
+            Some('c'),
+        )
+        .rest(SyntaxShape::ColumnPath, "optionally operate by column path")
+}
+
+fn character_set_config(
+    character_set: Option<Tagged<String>>,
+) -> Result<base64::Config, ShellError> {
+    let character_set = match character_set {
+        Some(character_set) => character_set,
+        None => return Ok(base64::STANDARD),
+    };
+
+    match character_set.item.as_str() {
+        "standard" => Ok(base64::STANDARD),
+        "standard-no-padding" => Ok(base64::STANDARD_NO_PAD),
+        "url-safe" => Ok(base64::URL_SAFE),
+        "url-safe-no-padding" => Ok(base64::URL_SAFE_NO_PAD),
+        "binhex" => Ok(base64::BINHEX),
+        "bcrypt" => Ok(base64::BCRYPT),
+        "crypt" => Ok(base64::CRYPT),
+        other => Err(ShellError::labeled_error(
+            "value is not an accepted character set",
+            format!("{} is not a valid character set", other),
+            character_set.tag.span,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{DecodeBase64, EncodeBase64};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(EncodeBase64 {})?;
+        Ok(test_examples(DecodeBase64 {})?)
+    }
+
+    #[test]
+    fn rejects_unknown_character_sets() {
+        use nu_source::TaggedItem;
+
+        let character_set = "base32".to_string().tagged(Tag::unknown());
+
+        assert!(super::character_set_config(Some(character_set)).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Decode;
+
+#[async_trait]
+impl WholeStreamCommand for Decode {
+    fn name(&self) -> &str {
+        "decode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode")
+    }
+
+    fn usage(&self) -> &str {
+        "Decode strings back into their original data (based on subcommand, like base64 or hex)."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_help(&Decode, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decode;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Decode {})?)
+    }
+}
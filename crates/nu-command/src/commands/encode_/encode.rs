@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+
+pub struct Encode;
+
+#[async_trait]
+impl WholeStreamCommand for Encode {
+    fn name(&self) -> &str {
+        "encode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode")
+    }
+
+    fn usage(&self) -> &str {
+        "Encode strings or binary data (based on subcommand, like base64 or hex)."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_help(&Encode, &args.scope)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encode;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Encode {})?)
+    }
+}
@@ -0,0 +1,130 @@
+use super::{bytes_of, decoded_value, operate, DefaultArguments};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct EncodeHex;
+
+#[async_trait]
+impl WholeStreamCommand for EncodeHex {
+    fn name(&self) -> &str {
+        "encode hex"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("encode hex")
+            .rest(SyntaxShape::ColumnPath, "optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "hex encode a string or binary value"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (DefaultArguments { rest }, input) = args.process().await?;
+
+        operate(input, rest, |value| {
+            let encoded = encode(bytes_of(value)?);
+            Ok(UntaggedValue::string(encoded).into_value(value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Hex encode a string",
+            example: "echo 'nu!' | encode hex",
+            result: Some(vec![Value::from("6e7521")]),
+        }]
+    }
+}
+
+pub struct DecodeHex;
+
+#[async_trait]
+impl WholeStreamCommand for DecodeHex {
+    fn name(&self) -> &str {
+        "decode hex"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("decode hex")
+            .rest(SyntaxShape::ColumnPath, "optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "hex decode a string, producing binary if the result is not valid UTF-8"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (DefaultArguments { rest }, input) = args.process().await?;
+
+        operate(input, rest, |value| {
+            let decoded = decode(bytes_of(value)?).ok_or_else(|| {
+                ShellError::labeled_error(
+                    "value could not be hex decoded",
+                    "expected an even number of hex digits",
+                    value.tag.span,
+                )
+            })?;
+
+            Ok(decoded_value(decoded, value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Hex decode a string",
+            example: "echo '6E7521' | decode hex",
+            result: Some(vec![Value::from("nu!")]),
+        }]
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode(digits: &[u8]) -> Option<Vec<u8>> {
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{decode, encode, DecodeHex, EncodeHex};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(EncodeHex {})?;
+        Ok(test_examples(DecodeHex {})?)
+    }
+
+    #[test]
+    fn round_trips_binary_data() {
+        let bytes = vec![0, 15, 16, 255];
+
+        assert_eq!(encode(&bytes), "000f10ff");
+        assert_eq!(decode(b"000F10ff"), Some(bytes));
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        assert_eq!(decode(b"abc"), None);
+        assert_eq!(decode(b"zz"), None);
+    }
+}
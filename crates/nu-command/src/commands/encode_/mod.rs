@@ -0,0 +1,66 @@
+mod base64_;
+mod decode;
+mod encode;
+mod hex;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, ShellTypeName, UntaggedValue, Value};
+use nu_source::Tag;
+
+pub use base64_::{DecodeBase64, EncodeBase64};
+pub use decode::Decode;
+pub use encode::Encode;
+pub use hex::{DecodeHex, EncodeHex};
+
+#[derive(Deserialize)]
+struct DefaultArguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub(crate) fn operate<F>(
+    input: crate::InputStream,
+    paths: Vec<ColumnPath>,
+    action: F,
+) -> Result<OutputStream, ShellError>
+where
+    F: Fn(&Value) -> Result<Value, ShellError> + Clone + Send + 'static,
+{
+    Ok(input
+        .map(move |v| {
+            if paths.is_empty() {
+                ReturnSuccess::value(action(&v)?)
+            } else {
+                let mut ret = v;
+
+                for path in &paths {
+                    let action = action.clone();
+                    ret = ret.swap_data_by_column_path(path, Box::new(move |old| action(old)))?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+/// The raw bytes of a string or binary value
+pub(crate) fn bytes_of(value: &Value) -> Result<&[u8], ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(s.as_bytes()),
+        UntaggedValue::Primitive(Primitive::Binary(b)) => Ok(b),
+        other => Err(ShellError::labeled_error(
+            "value is not a string or binary",
+            format!("got {}", other.type_name()),
+            value.tag.span,
+        )),
+    }
+}
+
+/// Decoded bytes come back as a string when they are valid UTF-8, and as binary otherwise
+pub(crate) fn decoded_value(bytes: Vec<u8>, tag: impl Into<Tag>) -> Value {
+    match String::from_utf8(bytes) {
+        Ok(s) => UntaggedValue::string(s).into_value(tag),
+        Err(e) => UntaggedValue::binary(e.into_bytes()).into_value(tag),
+    }
+}
@@ -1,9 +1,16 @@
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{CommandAction, ReturnSuccess, Signature};
+use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
 
 pub struct Exit;
 
+#[derive(Deserialize)]
+pub struct ExitArgs {
+    pub code: Option<Tagged<i32>>,
+    pub now: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Exit {
     fn name(&self) -> &str {
@@ -11,7 +18,9 @@ impl WholeStreamCommand for Exit {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("exit").switch("now", "exit out of the shell immediately", Some('n'))
+        Signature::build("exit")
+            .optional("code", SyntaxShape::Int, "the exit code nu exits with")
+            .switch("now", "exit out of the shell immediately", Some('n'))
     }
 
     fn usage(&self) -> &str {
@@ -34,17 +43,24 @@ impl WholeStreamCommand for Exit {
                 example: "exit --now",
                 result: None,
             },
+            Example {
+                description: "Exit with an exit code, e.g. from a script",
+                example: "exit 42",
+                result: None,
+            },
         ]
     }
 }
 
 pub async fn exit(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once().await?;
+    let (ExitArgs { code, now }, _) = args.process().await?;
+
+    let code = code.map(|code| code.item).unwrap_or(0);
 
-    let command_action = if args.call_info.args.has("now") {
-        CommandAction::Exit
+    let command_action = if now {
+        CommandAction::Exit(code)
     } else {
-        CommandAction::LeaveShell
+        CommandAction::LeaveShell(code)
     };
 
     Ok(OutputStream::one(ReturnSuccess::action(command_action)))
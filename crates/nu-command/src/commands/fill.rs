@@ -0,0 +1,226 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Deserialize)]
+struct Arguments {
+    width: Tagged<usize>,
+    alignment: Option<Tagged<String>>,
+    character: Option<Tagged<String>>,
+    rest: Vec<ColumnPath>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+pub struct Fill;
+
+#[async_trait]
+impl WholeStreamCommand for Fill {
+    fn name(&self) -> &str {
+        "fill"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("fill")
+            .required_named(
+                "width",
+                SyntaxShape::Int,
+                "the width to fill the text to",
+                Some('w'),
+            )
+            .named(
+                "alignment",
+                SyntaxShape::String,
+                "where the text goes: left (the default), right or center",
+                Some('a'),
+            )
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to fill with (a space by default)",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally fill the values of these columns",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Fills strings and numbers with a character up to a width, aligning them in it."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        fill(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fill a string on the right",
+                example: "echo nu | fill --width 5 --character '.'",
+                result: Some(vec![UntaggedValue::string("nu...").into_untagged_value()]),
+            },
+            Example {
+                description: "Right-align a number with zeros",
+                example: "echo 42 | fill -w 5 -a right -c '0'",
+                result: Some(vec![UntaggedValue::string("00042").into_untagged_value()]),
+            },
+            Example {
+                description: "Make a banner",
+                example: "echo ' nushell ' | fill -w 15 -a center -c '='",
+                result: Some(vec![
+                    UntaggedValue::string("=== nushell ===").into_untagged_value()
+                ]),
+            },
+            Example {
+                description: "Right-align the sizes of the files in a directory",
+                example: "ls | fill -w 10 -a right size",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn fill(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            width,
+            alignment,
+            character,
+            rest: column_paths,
+        },
+        input,
+    ) = args.process().await?;
+
+    let width = width.item;
+    let alignment = match alignment {
+        Some(alignment) => match alignment.item.as_str() {
+            "left" | "l" => Alignment::Left,
+            "right" | "r" => Alignment::Right,
+            "center" | "c" | "middle" | "m" => Alignment::Center,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Unknown alignment",
+                    "expected left, right or center",
+                    alignment.tag,
+                ))
+            }
+        },
+        None => Alignment::Left,
+    };
+    let character = match character {
+        Some(character) if character.item.graphemes(true).count() == 1 => character.item,
+        Some(character) => {
+            return Err(ShellError::labeled_error(
+                "Can't fill with more than one character",
+                "expected a single character",
+                character.tag,
+            ))
+        }
+        None => " ".to_string(),
+    };
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, width, alignment, &character)?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let character = character.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, width, alignment, &character)),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(
+    input: &Value,
+    width: usize,
+    alignment: Alignment,
+    character: &str,
+) -> Result<Value, ShellError> {
+    let text = match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_))
+        | UntaggedValue::Primitive(Primitive::Filesize(_)) => input.convert_to_string(),
+        other => {
+            return Err(ShellError::labeled_error(
+                "Can only fill strings and numbers",
+                format!("got {}", other.type_name()),
+                input.tag.span,
+            ))
+        }
+    };
+
+    Ok(UntaggedValue::string(fill_text(&text, width, alignment, character)).into_value(&input.tag))
+}
+
+/// Text wider than the width is left as it is, rather than cut. When the text can't be exactly in
+/// the center, it goes one character to the left.
+fn fill_text(text: &str, width: usize, alignment: Alignment, character: &str) -> String {
+    let missing = width.saturating_sub(text.graphemes(true).count());
+    let (before, after) = match alignment {
+        Alignment::Left => (0, missing),
+        Alignment::Right => (missing, 0),
+        Alignment::Center => (missing / 2, missing - missing / 2),
+    };
+
+    format!(
+        "{}{}{}",
+        character.repeat(before),
+        text,
+        character.repeat(after)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fill_text, Alignment, Fill, ShellError};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Fill {})?)
+    }
+
+    #[test]
+    fn puts_the_odd_character_after_centered_text() {
+        assert_eq!(fill_text("nu", 5, Alignment::Center, "*"), "*nu**");
+    }
+
+    #[test]
+    fn leaves_wider_text_whole() {
+        assert_eq!(fill_text("nushell", 3, Alignment::Right, "*"), "nushell");
+    }
+
+    #[test]
+    fn counts_graphemes() {
+        assert_eq!(
+            fill_text("ne\u{303}e", 4, Alignment::Left, "."),
+            "ne\u{303}e."
+        );
+    }
+}
@@ -11,6 +11,7 @@ pub struct Command;
 #[derive(Deserialize)]
 pub struct Arguments {
     rest: Vec<Tagged<String>>,
+    all: bool,
 }
 
 #[async_trait]
@@ -20,7 +21,13 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("flatten").rest(SyntaxShape::String, "optionally flatten data by column")
+        Signature::build("flatten")
+            .rest(SyntaxShape::String, "optionally flatten data by column")
+            .switch(
+                "all",
+                "keep flattening until no nested tables or rows are left",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -47,6 +54,11 @@ impl WholeStreamCommand for Command {
                 description: "restrict the flattening by passing column names",
                 example: "echo [[origin, crate, versions]; [World, $(echo [[name]; ['nu-cli']]), ['0.21', '0.22']]] | flatten versions | last | get versions",
                 result: Some(vec![Value::from("0.22")]),
+            },
+            Example {
+                description: "flatten nested tables all the way down",
+                example: "echo [[1 [2 [3 4]]]] | flatten --all | last",
+                result: Some(vec![UntaggedValue::int(4).into()]),
             }
         ]
     }
@@ -54,16 +66,49 @@ impl WholeStreamCommand for Command {
 
 async fn flatten(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
-    let (Arguments { rest: columns }, input) = args.process().await?;
+    let (Arguments { rest: columns, all }, input) = args.process().await?;
 
     Ok(input
         .map(move |item| {
-            futures::stream::iter(flat_value(&columns, &item, &tag).into_iter().flatten())
+            let flattened = if all {
+                let mut out = vec![];
+                flat_value_all(&columns, &item, &tag, &mut out).map(|_| out)
+            } else {
+                flat_value(&columns, &item, &tag)
+            };
+
+            futures::stream::iter(flattened.into_iter().flatten())
         })
         .flatten()
         .to_output_stream())
 }
 
+fn flat_value_all(
+    columns: &[Tagged<String>],
+    item: &Value,
+    name_tag: &Tag,
+    out: &mut Vec<Result<ReturnSuccess, ShellError>>,
+) -> Result<(), ShellError> {
+    let flattened = flat_value(columns, item, name_tag)?;
+
+    // Nothing left to flatten once a pass hands back the value it was given
+    if let [Ok(ReturnSuccess::Value(value))] = &flattened[..] {
+        if value == item {
+            out.push(ReturnSuccess::value(value.clone()));
+            return Ok(());
+        }
+    }
+
+    for result in flattened {
+        match result {
+            Ok(ReturnSuccess::Value(value)) => flat_value_all(columns, &value, name_tag, out)?,
+            other => out.push(other),
+        }
+    }
+
+    Ok(())
+}
+
 enum TableInside<'a> {
     Entries(&'a str, &'a Tag, Vec<&'a Value>),
 }
@@ -2,8 +2,9 @@ use crate::prelude::*;
 use nu_engine::evaluate_baseline_expr;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
-use nu_source::Tagged;
+use nu_protocol::hir::SpannedExpression;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{Spanned, Tagged};
 use std::borrow::Borrow;
 
 pub struct Format;
@@ -28,7 +29,9 @@ impl WholeStreamCommand for Format {
     }
 
     fn usage(&self) -> &str {
-        "Format columns into a string using a simple pattern."
+        r#"Format columns into a string using a simple pattern.
+
+Each row becomes one string, with the columns named in curly brackets replaced by their values. Column paths such as `{package.name}` work too. Write `{{` and `}}` for literal brackets."#
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -36,11 +39,18 @@ impl WholeStreamCommand for Format {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Print filenames with their sizes",
-            example: "ls | format '{name}: {size}'",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Print filenames with their sizes",
+                example: "ls | format '{name}: {size}'",
+                result: None,
+            },
+            Example {
+                description: "Write a line of JSON for each row",
+                example: "echo [[name]; [nu]] | format '{{\"name\": \"{name}\"}}'",
+                result: Some(vec![Value::from(r#"{"name": "nu"}"#)]),
+            },
+        ]
     }
 }
 
@@ -48,8 +58,19 @@ async fn format_command(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let ctx = Arc::new(EvaluationContext::from_args(&args));
     let (FormatArgs { pattern }, input) = args.process().await?;
 
-    let format_pattern = format(&pattern);
-    let commands = Arc::new(format_pattern);
+    let format_pattern = format(&pattern)?;
+    let commands = Arc::new(
+        format_pattern
+            .into_iter()
+            .map(|command| match command {
+                FormatCommand::Text(s) => FormatPart::Text(s),
+                FormatCommand::Column(c) => {
+                    let (full_column_path, _) = nu_parser::parse_full_column_path(&c, &ctx.scope);
+                    FormatPart::Column(full_column_path)
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
 
     Ok(input
         .then(move |value| {
@@ -58,21 +79,17 @@ async fn format_command(args: CommandArgs) -> Result<OutputStream, ShellError> {
             let ctx = ctx.clone();
 
             async move {
+                let tag = value.tag.clone();
+
                 for command in &*commands {
                     match command {
-                        FormatCommand::Text(s) => {
+                        FormatPart::Text(s) => {
                             output.push_str(&s);
                         }
-                        FormatCommand::Column(c) => {
-                            // FIXME: use the correct spans
-                            let full_column_path = nu_parser::parse_full_column_path(
-                                &(c.to_string()).spanned(Span::unknown()),
-                                &ctx.scope,
-                            );
-
+                        FormatPart::Column(full_column_path) => {
                             ctx.scope.enter_scope();
                             ctx.scope.add_var("$it", value.clone());
-                            let result = evaluate_baseline_expr(&full_column_path.0, &*ctx).await;
+                            let result = evaluate_baseline_expr(full_column_path, &*ctx).await;
                             ctx.scope.exit_scope();
 
                             if let Ok(c) = result {
@@ -85,61 +102,97 @@ async fn format_command(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     }
                 }
 
-                ReturnSuccess::value(UntaggedValue::string(output).into_untagged_value())
+                ReturnSuccess::value(UntaggedValue::string(output).into_value(tag))
             }
         })
         .to_output_stream())
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum FormatCommand {
     Text(String),
-    Column(String),
+    Column(Spanned<String>),
 }
 
-fn format(input: &str) -> Vec<FormatCommand> {
-    let mut output = vec![];
-
-    let mut loop_input = input.chars();
-    loop {
-        let mut before = String::new();
+/// A pattern piece with its column path parsed, so each row only needs evaluating.
+enum FormatPart {
+    Text(String),
+    Column(SpannedExpression),
+}
 
-        while let Some(c) = loop_input.next() {
-            if c == '{' {
-                break;
+/// Splits a pattern into text and the columns in curly brackets, with where each column is in the
+/// source. `{{` and `}}` stand for literal brackets.
+fn format(pattern: &Tagged<String>) -> Result<Vec<FormatCommand>, ShellError> {
+    let mut output = vec![];
+    let mut text = String::new();
+
+    // The text of a quoted pattern starts past its opening quote
+    let span = pattern.tag.span;
+    let start = if span.end() - span.start() > pattern.item.len() {
+        span.start() + 1
+    } else {
+        span.start()
+    };
+
+    let mut chars = pattern.item.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|(_, c)| *c) == Some('{') => {
+                chars.next();
+                text.push('{');
             }
-            before.push(c);
-        }
+            '}' if chars.peek().map(|(_, c)| *c) == Some('}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut column = String::new();
+                let mut closed = false;
+
+                for (_, c) in &mut chars {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    column.push(c);
+                }
 
-        if !before.is_empty() {
-            output.push(FormatCommand::Text(before.to_string()));
-        }
-        // Look for column as we're now at one
-        let mut column = String::new();
+                if !closed {
+                    return Err(ShellError::labeled_error(
+                        "Unclosed column in the pattern",
+                        "a '{' is missing its '}' (use '{{' for a literal bracket)",
+                        &pattern.tag,
+                    ));
+                }
 
-        while let Some(c) = loop_input.next() {
-            if c == '}' {
-                break;
-            }
-            column.push(c);
-        }
+                if !text.is_empty() {
+                    output.push(FormatCommand::Text(std::mem::take(&mut text)));
+                }
+                if !column.trim().is_empty() {
+                    let from = start + index + 1 + column.len() - column.trim_start().len();
+                    let name = column.trim();
 
-        if !column.is_empty() {
-            output.push(FormatCommand::Column(column.to_string()));
+                    output.push(FormatCommand::Column(
+                        name.to_string().spanned(Span::new(from, from + name.len())),
+                    ));
+                }
+            }
+            c => text.push(c),
         }
+    }
 
-        if before.is_empty() && column.is_empty() {
-            break;
-        }
+    if !text.is_empty() {
+        output.push(FormatCommand::Text(text));
     }
 
-    output
+    Ok(output)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Format;
     use super::ShellError;
+    use super::{format, Format, FormatCommand};
+    use nu_source::{Span, SpannedItem, TaggedItem};
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -147,4 +200,41 @@ mod tests {
 
         Ok(test_examples(Format {})?)
     }
+
+    #[test]
+    fn splits_text_and_columns() -> Result<(), ShellError> {
+        let pattern = "{name}: {size}".to_string().tagged_unknown();
+
+        assert_eq!(
+            format(&pattern)?,
+            vec![
+                FormatCommand::Column("name".to_string().spanned(Span::new(1, 5))),
+                FormatCommand::Text(": ".into()),
+                FormatCommand::Column("size".to_string().spanned(Span::new(9, 13))),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn doubled_brackets_are_literal() -> Result<(), ShellError> {
+        let pattern = "{{\"n\": {n}}}".to_string().tagged_unknown();
+
+        assert_eq!(
+            format(&pattern)?,
+            vec![
+                FormatCommand::Text("{\"n\": ".into()),
+                FormatCommand::Column("n".to_string().spanned(Span::new(8, 9))),
+                FormatCommand::Text("}".into()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unclosed_columns_are_an_error() {
+        let pattern = "{name".to_string().tagged_unknown();
+
+        assert!(format(&pattern).is_err());
+    }
 }
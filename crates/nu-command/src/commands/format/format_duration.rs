@@ -0,0 +1,208 @@
+use crate::commands::format::format_number::{format_number, NumberFormat};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    unit: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    decimals: Option<Tagged<u64>>,
+}
+
+/// Decimals shown when `--decimals` isn't given; trailing zeros are dropped.
+const DEFAULT_DECIMALS: u64 = 2;
+
+pub struct FormatDuration;
+
+#[async_trait]
+impl WholeStreamCommand for FormatDuration {
+    fn name(&self) -> &str {
+        "format duration"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format duration")
+            .required(
+                "unit",
+                SyntaxShape::String,
+                "the unit to show the durations in: ns, us, ms, sec, min, hr, day or wk",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format durations by column paths",
+            )
+            .named(
+                "decimals",
+                SyntaxShape::Int,
+                "digits to show after the decimal point",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Formats durations into strings in a single unit"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show a duration in minutes",
+                example: "echo 90sec | format duration min",
+                result: Some(vec![Value::from("1.5 min")]),
+            },
+            Example {
+                description: "Show a duration in hours with a fixed number of decimals",
+                example: "echo 20min | format duration hr -d 3",
+                result: Some(vec![Value::from("0.333 hr")]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            unit,
+            rest: column_paths,
+            decimals,
+        },
+        input,
+    ) = args.process().await?;
+
+    let nanos = unit_nanos(&unit.item).ok_or_else(|| {
+        ShellError::labeled_error(
+            "Unknown duration unit",
+            "expected ns, us, ms, sec, min, hr, day or wk",
+            &unit.tag,
+        )
+    })?;
+    let unit = unit.item;
+    let format = NumberFormat {
+        decimals: decimals.map(|d| d.item),
+        ..NumberFormat::default()
+    };
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &unit, nanos, &format, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let unit = unit.clone();
+                    let format = format.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &unit, nanos, &format, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn unit_nanos(unit: &str) -> Option<i64> {
+    Some(match unit {
+        "ns" => 1,
+        "us" => 1_000,
+        "ms" => 1_000_000,
+        "sec" => 1_000_000_000,
+        "min" => 60 * 1_000_000_000,
+        "hr" => 60 * 60 * 1_000_000_000,
+        "day" => 24 * 60 * 60 * 1_000_000_000,
+        "wk" => 7 * 24 * 60 * 60 * 1_000_000_000,
+        _ => return None,
+    })
+}
+
+fn action(
+    input: &Value,
+    unit: &str,
+    unit_nanos: i64,
+    format: &NumberFormat,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Duration(duration)) => {
+            let amount = BigDecimal::new(duration.clone(), 0) / BigDecimal::from(unit_nanos);
+
+            let formatted = match format.decimals {
+                Some(_) => format_number(&amount, format),
+                None => {
+                    let rounded = format_number(
+                        &amount,
+                        &NumberFormat {
+                            decimals: Some(DEFAULT_DECIMALS),
+                            ..format.clone()
+                        },
+                    );
+                    rounded
+                        .trim_end_matches('0')
+                        .trim_end_matches(&format.decimal_separator[..])
+                        .to_string()
+                }
+            };
+
+            Ok(UntaggedValue::string(format!("{} {}", formatted, unit)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a duration",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, unit_nanos, FormatDuration, ShellError};
+    use crate::commands::format::format_number::NumberFormat;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(FormatDuration {})?)
+    }
+
+    fn in_unit(nanos: i64, unit: &str) -> Value {
+        let duration = UntaggedValue::duration(nanos).into_untagged_value();
+        action(
+            &duration,
+            unit,
+            unit_nanos(unit).expect("a known unit"),
+            &NumberFormat::default(),
+            Tag::unknown(),
+        )
+        .expect("a duration")
+    }
+
+    #[test]
+    fn drops_trailing_zeros_by_default() {
+        assert_eq!(
+            in_unit(2 * 60 * 60 * 1_000_000_000, "hr"),
+            Value::from("2 hr")
+        );
+        assert_eq!(in_unit(1_500_000, "ms"), Value::from("1.5 ms"));
+        assert_eq!(in_unit(-90_000_000_000, "min"), Value::from("-1.5 min"));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    decimals: Option<Tagged<u64>>,
+    thousands: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "decimal-separator"))]
+    decimal_separator: Option<Tagged<String>>,
+}
+
+/// How `format number` and `format duration` lay numbers out.
+#[derive(Debug, Clone)]
+pub(crate) struct NumberFormat {
+    pub decimals: Option<u64>,
+    pub thousands: String,
+    pub decimal_separator: String,
+}
+
+impl Default for NumberFormat {
+    fn default() -> NumberFormat {
+        NumberFormat {
+            decimals: None,
+            thousands: String::new(),
+            decimal_separator: ".".to_string(),
+        }
+    }
+}
+
+pub struct FormatNumber;
+
+#[async_trait]
+impl WholeStreamCommand for FormatNumber {
+    fn name(&self) -> &str {
+        "format number"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format number")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format numbers by column paths",
+            )
+            .named(
+                "decimals",
+                SyntaxShape::Int,
+                "digits to show after the decimal separator, rounding half away from zero",
+                Some('d'),
+            )
+            .named(
+                "thousands",
+                SyntaxShape::String,
+                "separator to group the digits by thousands with",
+                Some('t'),
+            )
+            .named(
+                "decimal-separator",
+                SyntaxShape::String,
+                "separator between the whole and fractional digits (default: '.')",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Formats numbers into strings with a fixed number of decimals and grouped digits"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show an amount of money",
+                example: "echo 1234567.891 | format number --decimals 2 --thousands ','",
+                result: Some(vec![Value::from("1,234,567.89")]),
+            },
+            Example {
+                description: "Pad whole numbers with decimals",
+                example: "echo 42 | format number -d 2",
+                result: Some(vec![Value::from("42.00")]),
+            },
+            Example {
+                description: "Use European separators",
+                example: "echo 1234.5 | format number -d 2 -t '.' --decimal-separator ','",
+                result: Some(vec![Value::from("1.234,50")]),
+            },
+            Example {
+                description: "Format a column of a table",
+                example: "echo [[item price]; [tea 3.5]] | format number price -d 2 | get price",
+                result: Some(vec![Value::from("3.50")]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            rest: column_paths,
+            decimals,
+            thousands,
+            decimal_separator,
+        },
+        input,
+    ) = args.process().await?;
+
+    let format = NumberFormat {
+        decimals: decimals.map(|d| d.item),
+        thousands: thousands.map(|t| t.item).unwrap_or_default(),
+        decimal_separator: decimal_separator
+            .map(|s| s.item)
+            .unwrap_or_else(|| ".".to_string()),
+    };
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &format, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let format = format.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &format, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, format: &NumberFormat, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let number = match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(int)) => BigDecimal::new(int.clone(), 0),
+        UntaggedValue::Primitive(Primitive::Decimal(decimal)) => decimal.clone(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                tag.into().span,
+            ));
+        }
+    };
+
+    Ok(UntaggedValue::string(format_number(&number, format)).into_value(tag))
+}
+
+/// Renders a number with the given decimals (or all of its own), grouping the whole digits.
+pub(crate) fn format_number(number: &BigDecimal, format: &NumberFormat) -> String {
+    let number = match format.decimals {
+        Some(decimals) => round(number, decimals as i64),
+        None => {
+            let normalized = number.normalized();
+            if normalized.as_bigint_and_exponent().1 < 0 {
+                normalized.with_scale(0)
+            } else {
+                normalized
+            }
+        }
+    };
+
+    let (digits, scale) = number.as_bigint_and_exponent();
+    let scale = scale as usize;
+    let mut digits = digits.abs().to_string();
+    if digits.len() <= scale {
+        digits = format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits);
+    }
+    let (whole, fraction) = digits.split_at(digits.len() - scale);
+
+    let mut output = String::new();
+    if number.is_negative() {
+        output.push('-');
+    }
+    output.push_str(&group_thousands(whole, &format.thousands));
+    if !fraction.is_empty() {
+        output.push_str(&format.decimal_separator);
+        output.push_str(fraction);
+    }
+
+    output
+}
+
+/// Rounds half away from zero to the given number of decimals.
+fn round(number: &BigDecimal, decimals: i64) -> BigDecimal {
+    let half = BigDecimal::new(BigInt::from(5), decimals + 1);
+    let nudged = if number.is_negative() {
+        number - half
+    } else {
+        number + half
+    };
+
+    // `with_scale` truncates towards zero, which after the nudge rounds
+    let rounded = nudged.with_scale(decimals);
+    if rounded.is_zero() {
+        BigDecimal::new(BigInt::zero(), decimals)
+    } else {
+        rounded
+    }
+}
+
+fn group_thousands(whole: &str, separator: &str) -> String {
+    if separator.is_empty() {
+        return whole.to_string();
+    }
+
+    let mut grouped = String::new();
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, FormatNumber, NumberFormat, ShellError};
+    use bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(FormatNumber {})?)
+    }
+
+    fn formatted(number: &str, decimals: Option<u64>, thousands: &str) -> String {
+        let number = BigDecimal::from_str(number).expect("a valid number");
+        format_number(
+            &number,
+            &NumberFormat {
+                decimals,
+                thousands: thousands.to_string(),
+                ..NumberFormat::default()
+            },
+        )
+    }
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        assert_eq!(formatted("2.345", Some(2), ""), "2.35");
+        assert_eq!(formatted("-2.345", Some(2), ""), "-2.35");
+        assert_eq!(formatted("0.004", Some(2), ""), "0.00");
+        assert_eq!(formatted("-0.004", Some(2), ""), "0.00");
+        assert_eq!(formatted("9.999", Some(0), ""), "10");
+    }
+
+    #[test]
+    fn groups_whole_digits() {
+        assert_eq!(formatted("1234567", None, ","), "1,234,567");
+        assert_eq!(formatted("-123456.5", None, " "), "-123 456.5");
+        assert_eq!(formatted("999", Some(1), ","), "999.0");
+    }
+
+    #[test]
+    fn keeps_significant_decimals_without_rounding() {
+        assert_eq!(formatted("1.2500", None, ""), "1.25");
+        assert_eq!(formatted("1500", None, ""), "1500");
+        assert_eq!(formatted("0.05", None, ""), "0.05");
+    }
+}
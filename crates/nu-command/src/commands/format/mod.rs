@@ -1,5 +1,9 @@
 pub mod command;
+pub mod format_duration;
 pub mod format_filesize;
+pub mod format_number;
 
 pub use command::Format;
+pub use format_duration::FormatDuration;
 pub use format_filesize::FileSize;
+pub use format_number::FormatNumber;
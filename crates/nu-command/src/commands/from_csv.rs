@@ -3,6 +3,7 @@ use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 
 pub struct FromCSV;
 
@@ -10,6 +11,7 @@ pub struct FromCSV;
 pub struct FromCSVArgs {
     headerless: bool,
     separator: Option<Value>,
+    preview: Option<Tagged<usize>>,
 }
 
 #[async_trait]
@@ -31,6 +33,12 @@ impl WholeStreamCommand for FromCSV {
                 "don't treat the first row as column names",
                 None,
             )
+            .named(
+                "preview",
+                SyntaxShape::Int,
+                "only read and parse the input for this many rows, and give them with their inferred columns",
+                Some('p'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -58,6 +66,11 @@ impl WholeStreamCommand for FromCSV {
                 example: "open data.txt | from csv --separator ';'",
                 result: None,
             },
+            Example {
+                description: "Preview the first rows of a large file and their column types",
+                example: "open --raw big.csv | from csv --preview 5",
+                result: None,
+            },
         ]
     }
 }
@@ -69,6 +82,7 @@ async fn from_csv(args: CommandArgs) -> Result<OutputStream, ShellError> {
         FromCSVArgs {
             headerless,
             separator,
+            preview,
         },
         input,
     ) = args.process().await?;
@@ -95,7 +109,9 @@ async fn from_csv(args: CommandArgs) -> Result<OutputStream, ShellError> {
         _ => ',',
     };
 
-    from_delimited_data(headerless, sep, "CSV", input, name).await
+    let preview = preview.map(|rows| rows.item);
+
+    from_delimited_data(headerless, sep, preview, "CSV", input, name).await
 }
 
 #[cfg(test)]
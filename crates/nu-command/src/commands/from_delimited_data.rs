@@ -1,112 +1,423 @@
 use crate::prelude::*;
-use csv::{ErrorKind, ReaderBuilder};
+use csv::{ErrorKind, ReaderBuilder, StringRecord};
 use nu_errors::ShellError;
-use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, TaggedDictBuilder, Type, UntaggedValue, Value};
 
-fn from_delimited_string_to_value(
-    s: String,
+/// The type of a field, or of a whole column, going from the narrowest to the widest, so that a
+/// column takes the widest type of its fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FieldType {
+    Int,
+    Decimal,
+    String,
+}
+
+impl FieldType {
+    fn of(field: &str) -> FieldType {
+        if field.parse::<i64>().is_ok() {
+            FieldType::Int
+        } else if field.parse::<f64>().is_ok() {
+            FieldType::Decimal
+        } else {
+            FieldType::String
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FieldType::Int => "integer",
+            FieldType::Decimal => "decimal",
+            FieldType::String => "string",
+        }
+    }
+
+    fn value(self, field: &str, tag: &Tag) -> Value {
+        match self {
+            FieldType::Int => match field.parse::<i64>() {
+                Ok(i) => UntaggedValue::int(i).into_value(tag),
+                Err(_) => UntaggedValue::string(field).into_value(tag),
+            },
+            FieldType::Decimal => match field.parse::<f64>() {
+                Ok(f) => UntaggedValue::decimal_from_float(f, tag.span).into_value(tag),
+                Err(_) => UntaggedValue::string(field).into_value(tag),
+            },
+            FieldType::String => UntaggedValue::string(field).into_value(tag),
+        }
+    }
+}
+
+/// A row as it's read, with its fields not typed yet.
+type Row = Vec<(String, String)>;
+
+fn from_row_to_value(row: &[(String, String)], types: Option<&[FieldType]>, tag: &Tag) -> Value {
+    let mut tagged_row = TaggedDictBuilder::new(tag);
+    for (i, (header, field)) in row.iter().enumerate() {
+        let field_type = match types.and_then(|types| types.get(i)) {
+            Some(field_type) => *field_type,
+            None => FieldType::of(field),
+        };
+
+        tagged_row.insert_value(header, field_type.value(field, tag));
+    }
+    tagged_row.into_value()
+}
+
+/// Splits text, as it comes in pieces, into whole records, which end at a line ending outside of
+/// quotes. A line ending inside quotes is part of a value.
+#[derive(Default)]
+struct RecordSplitter {
+    pending: Vec<u8>,
+    scanned: usize,
+    quoted: bool,
+}
+
+impl RecordSplitter {
+    /// Adds the next piece of the text, giving back the records it completes.
+    fn push(&mut self, bytes: &[u8]) -> Option<Vec<u8>> {
+        self.pending.extend_from_slice(bytes);
+
+        let mut end = None;
+        for (i, byte) in self.pending[self.scanned..].iter().enumerate() {
+            match byte {
+                b'"' => self.quoted = !self.quoted,
+                b'\n' if !self.quoted => end = Some(self.scanned + i + 1),
+                _ => {}
+            }
+        }
+        self.scanned = self.pending.len();
+
+        let end = end?;
+        let rest = self.pending.split_off(end);
+        self.scanned -= end;
+        Some(std::mem::replace(&mut self.pending, rest))
+    }
+
+    /// The last record, when the text doesn't end with a line ending.
+    fn finish(&mut self) -> Option<Vec<u8>> {
+        if self.pending.is_empty() {
+            None
+        } else {
+            self.scanned = 0;
+            Some(std::mem::take(&mut self.pending))
+        }
+    }
+}
+
+/// Parses the input into rows as it's read, record by record, so that `first` stops the reading
+/// and the parsing of the rest of it.
+struct DelimitedParser {
     headerless: bool,
-    separator: char,
-    tag: impl Into<Tag>,
-) -> Result<Value, csv::Error> {
-    let mut reader = ReaderBuilder::new()
-        .has_headers(!headerless)
-        .delimiter(separator as u8)
-        .from_reader(s.as_bytes());
-    let tag = tag.into();
-    let span = tag.span;
-
-    let headers = if headerless {
-        (1..=reader.headers()?.len())
-            .map(|i| format!("Column{}", i))
-            .collect::<Vec<String>>()
-    } else {
-        reader.headers()?.iter().map(String::from).collect()
-    };
+    sep: char,
+    format_name: &'static str,
+    name: Tag,
+    source: Tag,
+    splitter: RecordSplitter,
+    headers: Option<Vec<String>>,
+    /// The lines of the records parsed so far.
+    lines: u64,
+    sample: String,
+    failed: bool,
+}
+
+impl DelimitedParser {
+    fn new(headerless: bool, sep: char, format_name: &'static str, name: Tag) -> DelimitedParser {
+        DelimitedParser {
+            headerless,
+            sep,
+            format_name,
+            source: name.clone(),
+            name,
+            splitter: RecordSplitter::default(),
+            headers: None,
+            lines: 0,
+            sample: String::new(),
+            failed: false,
+        }
+    }
+
+    /// The rows of the records completed by the next value of the input, or of the last record
+    /// once there's no more input. Nothing more is read after an error.
+    fn next_rows(&mut self, value: Option<Value>) -> Option<Vec<Result<Row, ShellError>>> {
+        if self.failed {
+            return None;
+        }
+
+        let records = match value {
+            Some(Value {
+                value: UntaggedValue::Primitive(Primitive::String(s)),
+                tag,
+            }) => {
+                self.source = tag;
+                self.keep_sample(s.as_bytes());
+                self.splitter.push(s.as_bytes())
+            }
+            Some(Value {
+                value: UntaggedValue::Primitive(Primitive::Binary(b)),
+                tag,
+            }) => {
+                self.source = tag;
+                self.keep_sample(&b);
+                self.splitter.push(&b)
+            }
+            Some(Value {
+                value: UntaggedValue::Primitive(Primitive::Nothing),
+                ..
+            }) => None,
+            Some(Value { value, tag }) => {
+                self.failed = true;
+                return Some(vec![Err(ShellError::labeled_error_with_secondary(
+                    "Expected a string from pipeline",
+                    "requires string input",
+                    &self.name,
+                    format!(
+                        "{} originates from here",
+                        Type::from_value(&value).plain_string(100000)
+                    ),
+                    tag,
+                ))]);
+            }
+            None => self.splitter.finish(),
+        };
+
+        Some(match records {
+            Some(records) => self.parse(&records),
+            None => vec![],
+        })
+    }
+
+    fn parse(&mut self, records: &[u8]) -> Vec<Result<Row, ShellError>> {
+        // Each piece of the input has a reader of its own, so the lengths of the records are
+        // checked here, against the headers
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.sep as u8)
+            .from_reader(records);
 
-    let mut rows = vec![];
-    for row in reader.records() {
-        let mut tagged_row = TaggedDictBuilder::new(&tag);
-        for (value, header) in row?.iter().zip(headers.iter()) {
-            if let Ok(i) = value.parse::<i64>() {
-                tagged_row.insert_value(header, UntaggedValue::int(i).into_value(&tag))
-            } else if let Ok(f) = value.parse::<f64>() {
-                tagged_row.insert_value(
-                    header,
-                    UntaggedValue::decimal_from_float(f, span).into_value(&tag),
-                )
-            } else {
-                tagged_row.insert_value(header, UntaggedValue::string(value).into_value(&tag))
+        let mut rows = vec![];
+        let mut record = StringRecord::new();
+        loop {
+            match reader.read_record(&mut record) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(err) => {
+                    rows.push(Err(self.error(pretty_csv_error(err))));
+                    break;
+                }
             }
+
+            if self.headers.is_none() {
+                self.headers = Some(if self.headerless {
+                    (1..=record.len()).map(|i| format!("Column{}", i)).collect()
+                } else {
+                    record.iter().map(String::from).collect()
+                });
+
+                if !self.headerless {
+                    continue;
+                }
+            }
+            let headers = match &self.headers {
+                Some(headers) => headers,
+                None => continue,
+            };
+
+            if record.len() != headers.len() {
+                let line = self.lines + record.position().map_or(1, |pos| pos.line());
+                let pretty = format!(
+                    "Line {}: expected {} fields, found {}",
+                    line,
+                    headers.len(),
+                    record.len()
+                );
+                rows.push(Err(self.error(Some(pretty))));
+                break;
+            }
+
+            rows.push(Ok(headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(String::from))
+                .collect()));
         }
-        rows.push(tagged_row.into_value());
+
+        self.lines += records.iter().filter(|byte| **byte == b'\n').count() as u64;
+        self.failed = rows.last().map_or(false, |row| row.is_err());
+
+        rows
     }
 
-    Ok(UntaggedValue::Table(rows).into_value(&tag))
+    fn error(&self, pretty: Option<String>) -> ShellError {
+        let line_one = match pretty {
+            Some(pretty) => format!(
+                "Could not parse as {} split by '{}' ({})",
+                self.format_name, self.sep, pretty
+            ),
+            None => format!(
+                "Could not parse as {} split by '{}'",
+                self.format_name, self.sep
+            ),
+        };
+        let line_two = format!(
+            "input cannot be parsed as {} split by '{}'. Input's first lines:\n{}",
+            self.format_name,
+            self.sep,
+            self.sample.lines().take(3).collect_vec().join("\n")
+        );
+
+        ShellError::labeled_error_with_secondary(
+            line_one,
+            line_two,
+            &self.name,
+            "value originates from here",
+            &self.source,
+        )
+    }
+
+    /// Keeps the first lines of the input, for the errors.
+    fn keep_sample(&mut self, bytes: &[u8]) {
+        let mut lines = self.sample.matches('\n').count();
+        for c in String::from_utf8_lossy(bytes).chars() {
+            if lines >= 3 {
+                break;
+            }
+            if c == '\n' {
+                lines += 1;
+            }
+            self.sample.push(c);
+        }
+    }
 }
 
+/// The columns of the rows, each with the widest type of its fields.
+fn infer_schema(rows: &[Row]) -> Vec<(String, FieldType)> {
+    let mut schema: Vec<(String, FieldType)> = vec![];
+    for row in rows {
+        for (i, (header, field)) in row.iter().enumerate() {
+            let field_type = FieldType::of(field);
+            match schema.get_mut(i) {
+                Some((_, column_type)) => *column_type = (*column_type).max(field_type),
+                None => schema.push((header.clone(), field_type)),
+            }
+        }
+    }
+    schema
+}
+
+/// Rows are parsed as the input is read, so that `first` stops the parsing and the reading of
+/// the rest of the input. A preview reads only the input holding its first rows, and gives them
+/// along with the columns and types inferred from them.
 pub async fn from_delimited_data(
     headerless: bool,
     sep: char,
+    preview: Option<usize>,
     format_name: &'static str,
     input: InputStream,
     name: Tag,
 ) -> Result<OutputStream, ShellError> {
-    let name_tag = name;
-    let concat_string = input.collect_string(name_tag.clone()).await?;
-    let sample_lines = concat_string.item.lines().take(3).collect_vec().join("\n");
-
-    match from_delimited_string_to_value(concat_string.item, headerless, sep, name_tag.clone()) {
-        Ok(x) => match x {
-            Value {
-                value: UntaggedValue::Table(list),
-                ..
-            } => Ok(futures::stream::iter(list).to_output_stream()),
-            x => Ok(OutputStream::one(x)),
-        },
-        Err(err) => {
-            let line_one = match pretty_csv_error(err) {
-                Some(pretty) => format!(
-                    "Could not parse as {} split by '{}' ({})",
-                    format_name, sep, pretty
-                ),
-                None => format!("Could not parse as {} split by '{}'", format_name, sep),
-            };
-            let line_two = format!(
-                "input cannot be parsed as {} split by '{}'. Input's first lines:\n{}",
-                format_name, sep, sample_lines
-            );
-
-            Err(ShellError::labeled_error_with_secondary(
-                line_one,
-                line_two,
-                name_tag.clone(),
-                "value originates from here",
-                concat_string.tag,
-            ))
+    let name_tag = name.clone();
+    let parser = DelimitedParser::new(headerless, sep, format_name, name);
+
+    let rows = input
+        .map(Some)
+        .chain(futures::stream::once(async { None }))
+        .scan(parser, |parser, value| {
+            futures::future::ready(parser.next_rows(value))
+        })
+        .flat_map(futures::stream::iter);
+
+    let preview = match preview {
+        Some(preview) => preview,
+        None => {
+            return Ok(rows
+                .map(move |row| {
+                    row.and_then(|row| {
+                        ReturnSuccess::value(from_row_to_value(&row, None, &name_tag))
+                    })
+                })
+                .to_output_stream())
         }
-    }
+    };
+
+    let rows = rows
+        .take(preview)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<Row>, ShellError>>()?;
+
+    let schema = infer_schema(&rows);
+    let types = schema
+        .iter()
+        .map(|(_, column_type)| *column_type)
+        .collect::<Vec<_>>();
+
+    let columns: Vec<Value> = schema
+        .iter()
+        .map(|(column, column_type)| {
+            let mut dict = TaggedDictBuilder::new(&name_tag);
+            dict.insert_untagged("name", UntaggedValue::string(column));
+            dict.insert_untagged("type", UntaggedValue::string(column_type.name()));
+            dict.into_value()
+        })
+        .collect();
+    let rows: Vec<Value> = rows
+        .iter()
+        .map(|row| from_row_to_value(row, Some(&types), &name_tag))
+        .collect();
+
+    let mut preview = TaggedDictBuilder::new(&name_tag);
+    preview.insert_untagged("columns", UntaggedValue::table(&columns));
+    preview.insert_untagged("rows", UntaggedValue::table(&rows));
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        preview.into_value(),
+    )))
 }
 
 fn pretty_csv_error(err: csv::Error) -> Option<String> {
     match err.kind() {
-        ErrorKind::UnequalLengths {
-            pos,
-            expected_len,
-            len,
-        } => {
-            if let Some(pos) = pos {
-                Some(format!(
-                    "Line {}: expected {} fields, found {}",
-                    pos.line(),
-                    expected_len,
-                    len
-                ))
-            } else {
-                Some(format!("Expected {} fields, found {}", expected_len, len))
-            }
-        }
         ErrorKind::Seek => Some("Internal error while parsing csv".to_string()),
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{infer_schema, FieldType, RecordSplitter, Row};
+
+    fn row(fields: &[(&str, &str)]) -> Row {
+        fields
+            .iter()
+            .map(|(header, field)| (header.to_string(), field.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn splits_records_at_line_endings_outside_of_quotes() {
+        let mut splitter = RecordSplitter::default();
+
+        assert_eq!(splitter.push(b"a,b\n1,\"x"), Some(b"a,b\n".to_vec()));
+        assert_eq!(splitter.push(b"\ny\"\n2,"), Some(b"1,\"x\ny\"\n".to_vec()));
+        assert_eq!(splitter.push(b"z"), None);
+        assert_eq!(splitter.finish(), Some(b"2,z".to_vec()));
+        assert_eq!(splitter.finish(), None);
+    }
+
+    #[test]
+    fn infers_the_widest_type_of_each_column() {
+        let rows = vec![
+            row(&[("id", "1"), ("price", "2"), ("name", "3")]),
+            row(&[("id", "2"), ("price", "2.5"), ("name", "tea")]),
+        ];
+
+        assert_eq!(
+            infer_schema(&rows),
+            vec![
+                ("id".to_string(), FieldType::Int),
+                ("price".to_string(), FieldType::Decimal),
+                ("name".to_string(), FieldType::String),
+            ]
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+
+pub struct FromDotenv;
+
+#[async_trait]
+impl WholeStreamCommand for FromDotenv {
+    fn name(&self) -> &str {
+        "from dotenv"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from dotenv")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as a .env file of KEY=value lines and create a row."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        from_dotenv(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Set the environment variables of a .env file",
+            example: "open .env | load-env",
+            result: None,
+        }]
+    }
+}
+
+/// Parses `KEY=value` lines, skipping blank lines and comments. Values may be quoted, over more
+/// than one line, with escapes like `\n` in double quotes only. An `export` before a key is
+/// ignored, as shells need it but nu doesn't.
+fn parse_dotenv(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut variables = vec![];
+    let mut lines = text.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = match line.find('=') {
+            Some(at) => (line[..at].trim(), line[at + 1..].trim_start()),
+            None => return Err(format!("line {}: expected KEY=value", index + 1)),
+        };
+
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(format!("line {}: '{}' is not a valid key", index + 1, key));
+        }
+
+        let value = match value.chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                let mut quoted = value[1..].to_string();
+                loop {
+                    if let Some(value) = unquote(&quoted, quote) {
+                        break value;
+                    }
+
+                    match lines.next() {
+                        Some((_, line)) => {
+                            quoted.push('\n');
+                            quoted.push_str(line);
+                        }
+                        None => {
+                            return Err(format!("line {}: the quote isn't closed", index + 1));
+                        }
+                    }
+                }
+            }
+            _ => match value.find(" #") {
+                Some(comment) => value[..comment].trim_end().to_string(),
+                None => value.trim_end().to_string(),
+            },
+        };
+
+        variables.push((key.to_string(), value));
+    }
+
+    Ok(variables)
+}
+
+/// The value up to its closing quote, if it has been read yet.
+fn unquote(quoted: &str, quote: char) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == quote => return Some(value),
+            '\\' if quote == '"' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some(c @ '"') | Some(c @ '\\') => value.push(c),
+                Some(c) => {
+                    value.push('\\');
+                    value.push(c);
+                }
+                None => value.push('\\'),
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+async fn from_dotenv(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+    let input = args.input;
+    let concat_string = input.collect_string(tag.clone()).await?;
+
+    match parse_dotenv(&concat_string.item) {
+        Ok(variables) => {
+            let mut row = TaggedDictBuilder::new(&concat_string.tag);
+            for (key, value) in variables {
+                row.insert_untagged(key, UntaggedValue::string(value));
+            }
+
+            Ok(OutputStream::one(ReturnSuccess::value(row.into_value())))
+        }
+        Err(reason) => Err(ShellError::labeled_error_with_secondary(
+            format!("Could not parse as .env ({})", reason),
+            "input cannot be parsed as .env",
+            &tag,
+            "value originates from here",
+            &concat_string.tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_dotenv, FromDotenv, ShellError};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(FromDotenv {})?)
+    }
+
+    #[test]
+    fn parses_the_ways_values_are_written() {
+        let text = r#"
+# the database
+export DATABASE_URL=postgres://localhost/nu # for development
+GREETING="hello\n\"world\""
+PATTERN='^\d+$'
+EMPTY=
+MULTILINE="one
+two"
+"#;
+
+        let pairs = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            parse_dotenv(text),
+            Ok(pairs(&[
+                ("DATABASE_URL", "postgres://localhost/nu"),
+                ("GREETING", "hello\n\"world\""),
+                ("PATTERN", "^\\d+$"),
+                ("EMPTY", ""),
+                ("MULTILINE", "one\ntwo"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn reports_the_line_that_does_not_parse() {
+        assert_eq!(
+            parse_dotenv("A=1\nnot a variable\n"),
+            Err("line 2: expected KEY=value".to_string())
+        );
+        assert_eq!(
+            parse_dotenv("A=\"never closed\n"),
+            Err("line 1: the quote isn't closed".to_string())
+        );
+    }
+}
@@ -1,13 +1,18 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{
+    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use parking_lot::Mutex;
 
 pub struct FromJSON;
 
 #[derive(Deserialize)]
 pub struct FromJSONArgs {
     objects: bool,
+    preview: Option<Tagged<usize>>,
 }
 
 #[async_trait]
@@ -17,11 +22,18 @@ impl WholeStreamCommand for FromJSON {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from json").switch(
-            "objects",
-            "treat each line as a separate value",
-            Some('o'),
-        )
+        Signature::build("from json")
+            .switch(
+                "objects",
+                "treat each line as a separate value, parsing lines as they are read",
+                Some('o'),
+            )
+            .named(
+                "preview",
+                SyntaxShape::Int,
+                "only read and parse the input for this many values of a list",
+                Some('p'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -68,67 +80,229 @@ pub fn from_json_string_to_value(s: String, tag: impl Into<Tag>) -> nu_json::Res
     Ok(convert_json_value_to_nu_value(&v, tag))
 }
 
-async fn from_json(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let name_tag = args.call_info.name_tag.clone();
+fn parse_json_line(
+    line: &str,
+    name_tag: &Tag,
+    origin: &Tag,
+) -> Option<Result<ReturnSuccess, ShellError>> {
+    if line.trim().is_empty() {
+        return None;
+    }
 
-    let (FromJSONArgs { objects }, input) = args.process().await?;
-    let concat_string = input.collect_string(name_tag.clone()).await?;
+    match from_json_string_to_value(line.to_string(), name_tag) {
+        Ok(x) => Some(ReturnSuccess::value(x)),
+        Err(e) => {
+            let mut message = "Could not parse as JSON (".to_string();
+            message.push_str(&e.to_string());
+            message.push(')');
 
-    let string_clone: Vec<_> = concat_string.item.lines().map(|x| x.to_string()).collect();
+            Some(Err(ShellError::labeled_error_with_secondary(
+                message,
+                "input cannot be parsed as JSON",
+                name_tag,
+                "value originates from here",
+                origin,
+            )))
+        }
+    }
+}
 
-    if objects {
-        Ok(
-            futures::stream::iter(string_clone.into_iter().filter_map(move |json_str| {
-                if json_str.is_empty() {
-                    return None;
-                }
+/// Parses each line as soon as it has been read, so that large streams of newline-delimited
+/// JSON never need to be held in memory all at once.
+fn from_json_objects(input: InputStream, name_tag: Tag) -> OutputStream {
+    // The text not parsed yet, and the tag of the string it was last read from
+    let leftover = Arc::new(Mutex::new((String::new(), Tag::unknown())));
 
-                match from_json_string_to_value(json_str, &name_tag) {
-                    Ok(x) => Some(ReturnSuccess::value(x)),
-                    Err(e) => {
-                        let mut message = "Could not parse as JSON (".to_string();
-                        message.push_str(&e.to_string());
-                        message.push(')');
-
-                        Some(Err(ShellError::labeled_error_with_secondary(
-                            message,
-                            "input cannot be parsed as JSON",
-                            name_tag.clone(),
-                            "value originates from here",
-                            concat_string.tag.clone(),
-                        )))
+    let eos = futures::stream::iter(vec![
+        UntaggedValue::Primitive(Primitive::EndOfStream).into_untagged_value()
+    ]);
+
+    input
+        .chain(eos)
+        .map(move |item| {
+            let name_tag = name_tag.clone();
+
+            let (lines, origin) = match item {
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::String(chunk)),
+                    tag,
+                } => {
+                    let mut leftover = leftover.lock();
+                    let (text, origin) = &mut *leftover;
+                    text.push_str(&chunk);
+                    *origin = tag.clone();
+
+                    match text.rfind('\n') {
+                        Some(end) => {
+                            let rest = text.split_off(end + 1);
+                            (std::mem::replace(text, rest), tag)
+                        }
+                        None => (String::new(), tag),
                     }
                 }
-            }))
-            .to_output_stream(),
-        )
-    } else {
-        match from_json_string_to_value(concat_string.item, name_tag.clone()) {
-            Ok(x) => match x {
                 Value {
-                    value: UntaggedValue::Table(list),
+                    value: UntaggedValue::Primitive(Primitive::EndOfStream),
                     ..
-                } => Ok(
-                    futures::stream::iter(list.into_iter().map(ReturnSuccess::value))
-                        .to_output_stream(),
-                ),
-                x => Ok(OutputStream::one(ReturnSuccess::value(x))),
-            },
-            Err(e) => {
-                let mut message = "Could not parse as JSON (".to_string();
-                message.push_str(&e.to_string());
-                message.push(')');
-
-                Ok(OutputStream::one(Err(
-                    ShellError::labeled_error_with_secondary(
-                        message,
-                        "input cannot be parsed as JSON",
-                        name_tag,
-                        "value originates from here",
-                        concat_string.tag,
-                    ),
-                )))
+                } => std::mem::take(&mut *leftover.lock()),
+                Value { tag, .. } => {
+                    return futures::stream::iter(vec![Err(
+                        ShellError::labeled_error_with_secondary(
+                            "Expected a string from pipeline",
+                            "requires string input",
+                            &name_tag,
+                            "value originates from here",
+                            tag,
+                        ),
+                    )])
+                }
+            };
+
+            futures::stream::iter(
+                lines
+                    .lines()
+                    .filter_map(|line| parse_json_line(line, &name_tag, &origin))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .to_output_stream()
+}
+
+/// Follows JSON text as it's read to find where the first values of a list end, so that nothing
+/// past them needs to be read or parsed.
+struct ListPreview {
+    values: usize,
+    list: Option<bool>,
+    depth: usize,
+    quoted: bool,
+    escaped: bool,
+    read: usize,
+    end: Option<usize>,
+}
+
+impl ListPreview {
+    fn new(values: usize) -> ListPreview {
+        ListPreview {
+            values,
+            list: None,
+            depth: 0,
+            quoted: false,
+            escaped: false,
+            read: 0,
+            end: None,
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.end.is_some() || self.list == Some(false)
+    }
+
+    fn read(&mut self, text: &str) {
+        for (at, c) in text.char_indices() {
+            if self.done() {
+                return;
+            }
+
+            if self.quoted {
+                match c {
+                    _ if self.escaped => self.escaped = false,
+                    '\\' => self.escaped = true,
+                    '"' => self.quoted = false,
+                    _ => {}
+                }
+                continue;
             }
+
+            match c {
+                c if c.is_whitespace() => {}
+                // Anything but a list is read and parsed whole
+                _ if self.list.is_none() => {
+                    self.list = Some(c == '[');
+                    self.depth = 1;
+                    if self.values == 0 && c == '[' {
+                        self.end = Some(self.read + at + 1);
+                    }
+                }
+                '"' => self.quoted = true,
+                '[' | '{' => self.depth += 1,
+                ']' | '}' => self.depth = self.depth.saturating_sub(1),
+                ',' if self.depth == 1 => {
+                    self.values -= 1;
+                    if self.values == 0 {
+                        self.end = Some(self.read + at);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.read += text.len();
+    }
+}
+
+async fn from_json(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+
+    let (FromJSONArgs { objects, preview }, input) = args.process().await?;
+    let preview = preview.map(|values| values.item);
+
+    if objects {
+        let output = from_json_objects(input, name_tag);
+        return Ok(match preview {
+            Some(values) => output.take(values).to_output_stream(),
+            None => output,
+        });
+    }
+
+    let concat_string = match preview {
+        Some(values) => {
+            let mut preview = ListPreview::new(values);
+            let input = InputStream::from_stream(input.take_while(move |value| {
+                let more = !preview.done();
+                if let Ok(text) = value.as_string() {
+                    preview.read(&text);
+                }
+                futures::future::ready(more)
+            }));
+
+            let mut text = input.collect_string(name_tag.clone()).await?;
+            let mut preview = ListPreview::new(values);
+            preview.read(&text.item);
+            if let Some(end) = preview.end {
+                text.item.truncate(end);
+                text.item.push(']');
+            }
+
+            text
+        }
+        None => input.collect_string(name_tag.clone()).await?,
+    };
+
+    match from_json_string_to_value(concat_string.item, name_tag.clone()) {
+        Ok(x) => match x {
+            Value {
+                value: UntaggedValue::Table(list),
+                ..
+            } => Ok(
+                futures::stream::iter(list.into_iter().map(ReturnSuccess::value))
+                    .to_output_stream(),
+            ),
+            x => Ok(OutputStream::one(ReturnSuccess::value(x))),
+        },
+        Err(e) => {
+            let mut message = "Could not parse as JSON (".to_string();
+            message.push_str(&e.to_string());
+            message.push(')');
+
+            Ok(OutputStream::one(Err(
+                ShellError::labeled_error_with_secondary(
+                    message,
+                    "input cannot be parsed as JSON",
+                    name_tag,
+                    "value originates from here",
+                    concat_string.tag,
+                ),
+            )))
         }
     }
 }
@@ -136,6 +310,7 @@ async fn from_json(args: CommandArgs) -> Result<OutputStream, ShellError> {
 #[cfg(test)]
 mod tests {
     use super::FromJSON;
+    use super::ListPreview;
     use super::ShellError;
 
     #[test]
@@ -144,4 +319,16 @@ mod tests {
 
         Ok(test_examples(FromJSON {})?)
     }
+
+    #[test]
+    fn previews_the_values_of_lists_only() {
+        let mut preview = ListPreview::new(3);
+        preview.read("[1, {\"a\": [2, 3]},");
+        preview.read(" 4, 5]");
+        assert_eq!(preview.end, Some(20));
+
+        let mut preview = ListPreview::new(1);
+        preview.read("{\"a\": 1, \"b\": 2}");
+        assert_eq!(preview.end, None);
+    }
 }
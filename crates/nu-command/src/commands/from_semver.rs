@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use semver::{Identifier, Version};
+
+pub struct FromSemver;
+
+#[async_trait]
+impl WholeStreamCommand for FromSemver {
+    fn name(&self) -> &str {
+        "from semver"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from semver")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse semantic version strings, like release tags, as rows of major, minor, patch, pre-release and build."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        from_semver(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse a release tag",
+                example: "echo v1.10.0-rc.1 | from semver",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "major".to_string() => UntaggedValue::int(1).into(),
+                    "minor".to_string() => UntaggedValue::int(10).into(),
+                    "patch".to_string() => UntaggedValue::int(0).into(),
+                    "pre".to_string() => UntaggedValue::string("rc.1").into(),
+                    "build".to_string() => UntaggedValue::string("").into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Find the newest release of a list of tags",
+                example: "echo [v1.9.2 v1.10.0 v1.10.0-rc.1] | from semver | where pre == '' | sort-by major minor patch | last",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn from_semver(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let tag = args.name_tag();
+
+    Ok(args
+        .input
+        .map(move |value| ReturnSuccess::value(parse_version(&value, &tag)?))
+        .to_output_stream())
+}
+
+fn parse_version(value: &Value, tag: &Tag) -> Result<Value, ShellError> {
+    let text = value.as_string()?;
+    let trimmed = text.trim();
+    // Release tags are often written with a leading `v`
+    let trimmed = match trimmed.strip_prefix(|c: char| c == 'v' || c == 'V') {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => trimmed,
+    };
+
+    let version = Version::parse(trimmed).map_err(|err| {
+        ShellError::labeled_error_with_secondary(
+            "Could not parse as a semantic version",
+            format!("{}: {}", err, text),
+            tag,
+            "value originates from here",
+            &value.tag,
+        )
+    })?;
+
+    let mut row = TaggedDictBuilder::new(&value.tag);
+    row.insert_untagged("major", UntaggedValue::int(version.major));
+    row.insert_untagged("minor", UntaggedValue::int(version.minor));
+    row.insert_untagged("patch", UntaggedValue::int(version.patch));
+    row.insert_untagged("pre", UntaggedValue::string(identifiers(&version.pre)));
+    row.insert_untagged("build", UntaggedValue::string(identifiers(&version.build)));
+
+    Ok(row.into_value())
+}
+
+fn identifiers(identifiers: &[Identifier]) -> String {
+    identifiers
+        .iter()
+        .map(|identifier| identifier.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromSemver;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(FromSemver {})?)
+    }
+}
@@ -2,13 +2,15 @@ use crate::commands::from_delimited_data::from_delimited_data;
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::Signature;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
 
 pub struct FromTSV;
 
 #[derive(Deserialize)]
 pub struct FromTSVArgs {
     headerless: bool,
+    preview: Option<Tagged<usize>>,
 }
 
 #[async_trait]
@@ -18,11 +20,18 @@ impl WholeStreamCommand for FromTSV {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from tsv").switch(
-            "headerless",
-            "don't treat the first row as column names",
-            None,
-        )
+        Signature::build("from tsv")
+            .switch(
+                "headerless",
+                "don't treat the first row as column names",
+                None,
+            )
+            .named(
+                "preview",
+                SyntaxShape::Int,
+                "only read and parse the input for this many rows, and give them with their inferred columns",
+                Some('p'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -36,9 +45,16 @@ impl WholeStreamCommand for FromTSV {
 
 async fn from_tsv(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (FromTSVArgs { headerless }, input) = args.process().await?;
+    let (
+        FromTSVArgs {
+            headerless,
+            preview,
+        },
+        input,
+    ) = args.process().await?;
+    let preview = preview.map(|rows| rows.item);
 
-    from_delimited_data(headerless, '\t', "TSV", input, name).await
+    from_delimited_data(headerless, '\t', preview, "TSV", input, name).await
 }
 
 #[cfg(test)]
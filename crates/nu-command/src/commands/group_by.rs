@@ -1,10 +1,13 @@
 use crate::prelude::*;
+use crate::utils::parallel::par_map;
 use crate::utils::suggestions::suggestions;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    ColumnPath, ReturnSuccess, Signature, SyntaxShape, UnspannedPathMember, UntaggedValue, Value,
+};
 use nu_source::Tagged;
-use nu_value_ext::as_string;
+use nu_value_ext::{as_string, get_data_by_column_path};
 
 pub struct Command;
 
@@ -75,6 +78,11 @@ impl WholeStreamCommand for Command {
                 })
                 .into()]),
             },
+            Example {
+                description: "group rows by a nested column",
+                example: r#"open Cargo.toml | get dependencies | pivot name spec | group-by spec.version"#,
+                result: None,
+            },
             Example {
                 description: "you can also group by raw values by leaving out the argument",
                 example: "echo [1 3 1 3 2 1 1] | group-by",
@@ -238,26 +246,46 @@ pub fn group(
         Grouper::ByColumn(None)
     };
 
-    match grouper {
-        Grouper::ByColumn(Some(column_name)) => {
-            let block = Box::new(move |_, row: &Value| {
-                match row.get_data_by_key(column_name.borrow_spanned()) {
-                    Some(group_key) => Ok(as_string(&group_key)?),
-                    None => Err(suggestions(column_name.borrow_tagged(), &row)),
-                }
-            });
+    // The keys of large tables are found on several threads, then rows are grouped by them in order
+    let rows: Vec<&Value> = values.table_entries().collect();
 
-            nu_data::utils::group(&values, &Some(block), &name)
-        }
-        Grouper::ByColumn(None) => {
-            let block = Box::new(move |_, row: &Value| as_string(row));
+    let keys = match grouper {
+        Grouper::ByColumn(Some(column_name)) if column_name.item.contains('.') => {
+            let path = ColumnPath::build(&column_name.item.clone().spanned(column_name.span()));
+
+            par_map(&rows, |row| {
+                let group_key = get_data_by_column_path(row, &path, |value, member, error| {
+                    match &member.unspanned {
+                        UnspannedPathMember::String(column) => {
+                            suggestions(column.as_str().tagged(member.span), value)
+                        }
+                        UnspannedPathMember::Int(_) => error,
+                    }
+                })?;
 
-            nu_data::utils::group(&values, &Some(block), &name)
+                as_string(&group_key)
+            })
         }
-        Grouper::ByBlock => Err(ShellError::unimplemented(
-            "Block not implemented: This should never happen.",
-        )),
-    }
+        Grouper::ByColumn(Some(column_name)) => par_map(&rows, |row| {
+            match row.get_data_by_key(column_name.borrow_spanned()) {
+                Some(group_key) => Ok(as_string(&group_key)?),
+                None => Err(suggestions(column_name.borrow_tagged(), row)),
+            }
+        }),
+        Grouper::ByColumn(None) => par_map(&rows, |row| as_string(row)),
+        Grouper::ByBlock => {
+            return Err(ShellError::unimplemented(
+                "Block not implemented: This should never happen.",
+            ))
+        }
+    };
+
+    let block = Box::new(move |idx: usize, row: &Value| match keys.get(idx) {
+        Some(key) => key.clone(),
+        None => as_string(row),
+    });
+
+    nu_data::utils::group(&values, &Some(block), &name)
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 use crate::prelude::*;
+use chrono::{Local, TimeZone};
 use nu_data::config::{Conf, NuConfig};
-use nu_engine::history_path;
 use nu_engine::WholeStreamCommand;
+use nu_engine::{history_path, history_timing_path, HistoryTiming};
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
@@ -25,7 +27,7 @@ impl WholeStreamCommand for History {
     }
 
     fn usage(&self) -> &str {
-        "Display command history."
+        "Display command history, with when each command started and how long it took."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -49,12 +51,18 @@ async fn history(args: CommandArgs) -> Result<OutputStream, ShellError> {
             if let Ok(file) = File::open(path) {
                 let reader = BufReader::new(file);
                 // Skips the first line, which is a Rustyline internal
-                let output = reader.lines().skip(1).filter_map(move |line| match line {
-                    Ok(line) => Some(ReturnSuccess::value(
-                        UntaggedValue::string(line).into_value(tag.clone()),
-                    )),
-                    Err(_) => None,
-                });
+                let entries: Vec<String> = reader.lines().skip(1).filter_map(Result::ok).collect();
+                let timings = read_timings(&*config);
+                let matched = match_timings(&entries, &timings);
+
+                let output = entries
+                    .into_iter()
+                    .zip(matched)
+                    .map(move |(entry, timing)| {
+                        let timing = timing.map(|index| &timings[index]);
+                        ReturnSuccess::value(history_entry(entry, timing, tag.clone()))
+                    })
+                    .collect::<Vec<_>>();
 
                 Ok(futures::stream::iter(output).to_output_stream())
             } else {
@@ -68,10 +76,98 @@ async fn history(args: CommandArgs) -> Result<OutputStream, ShellError> {
     }
 }
 
+/// The timings recorded, in the order the commands ran.
+fn read_timings(config: &dyn Conf) -> Vec<HistoryTiming> {
+    match File::open(history_timing_path(config)) {
+        Ok(file) => BufReader::new(file)
+            .lines()
+            .filter_map(Result::ok)
+            .filter_map(|line| serde_json::from_str::<HistoryTiming>(&line).ok())
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// The index of the timing of each entry of the history, if it was recorded. Both are in the order
+/// the commands ran, but the line editor leaves some commands out, like those run twice in a row,
+/// so each entry takes the latest timing of its command before the timing of the entry after it.
+fn match_timings(entries: &[String], timings: &[HistoryTiming]) -> Vec<Option<usize>> {
+    let mut by_command: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, timing) in timings.iter().enumerate() {
+        by_command
+            .entry(timing.command.as_str())
+            .or_default()
+            .push(index);
+    }
+
+    let mut matched = vec![None; entries.len()];
+    let mut before = timings.len();
+
+    for (entry, matched) in entries.iter().zip(matched.iter_mut()).rev() {
+        let indices = match by_command.get(unescape(entry).trim()) {
+            Some(indices) => indices,
+            None => continue,
+        };
+
+        let earlier = match indices.binary_search(&before) {
+            Ok(position) | Err(position) => position,
+        };
+
+        if earlier > 0 {
+            before = indices[earlier - 1];
+            *matched = Some(before);
+        }
+    }
+
+    matched
+}
+
+/// The command of an entry, which the line editor keeps on one line by escaping new lines.
+fn unescape(entry: &str) -> String {
+    let mut command = String::with_capacity(entry.len());
+    let mut chars = entry.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                chars.next();
+                command.push('\n');
+            }
+            ('\\', Some('\\')) => {
+                chars.next();
+                command.push('\\');
+            }
+            (c, _) => command.push(c),
+        }
+    }
+
+    command
+}
+
+fn history_entry(command: String, timing: Option<&HistoryTiming>, tag: Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(&tag);
+
+    // Imported timings can be out of the range of dates, and are left empty like missing ones
+    let start = timing.and_then(|timing| Local.timestamp_opt(timing.start, 0).single());
+    match start {
+        Some(start) => row.insert_untagged("start", UntaggedValue::date(start)),
+        None => row.insert_untagged("start", UntaggedValue::nothing()),
+    }
+    match timing {
+        Some(timing) => row.insert_untagged("duration", UntaggedValue::duration(timing.duration)),
+        None => row.insert_untagged("duration", UntaggedValue::nothing()),
+    }
+
+    row.insert_untagged("command", UntaggedValue::string(command));
+    row.into_value()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::History;
-    use super::ShellError;
+    use super::{history_entry, match_timings, History, ShellError};
+    use nu_engine::HistoryTiming;
+    use nu_source::{SpannedItem, Tag};
+    use nu_value_ext::ValueExt;
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -79,4 +175,74 @@ mod tests {
 
         Ok(test_examples(History {})?)
     }
+
+    fn timing(command: &str, start: i64) -> HistoryTiming {
+        HistoryTiming {
+            command: command.to_string(),
+            start,
+            duration: 0,
+        }
+    }
+
+    fn entries(commands: &[&str]) -> Vec<String> {
+        commands.iter().map(|command| command.to_string()).collect()
+    }
+
+    #[test]
+    fn gives_each_run_of_a_command_its_own_timing() {
+        let timings = vec![timing("ls", 1), timing("date", 2), timing("ls", 3)];
+
+        assert_eq!(
+            match_timings(&entries(&["ls", "date", "ls"]), &timings),
+            vec![Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn skips_the_timings_of_commands_left_out_of_the_history() {
+        // The second ls, run right after the first, isn't in the history
+        let timings = vec![timing("ls", 1), timing("ls", 2), timing("date", 3)];
+
+        assert_eq!(
+            match_timings(&entries(&["ls", "date"]), &timings),
+            vec![Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn leaves_entries_from_before_timings_without_one() {
+        let timings = vec![timing("ls", 1)];
+
+        assert_eq!(
+            match_timings(&entries(&["ls", "echo hi", "ls"]), &timings),
+            vec![None, None, Some(0)]
+        );
+    }
+
+    #[test]
+    fn matches_commands_over_several_lines() {
+        let timings = vec![timing("echo 1\necho 2", 1)];
+
+        assert_eq!(
+            match_timings(&entries(&["echo 1\\necho 2"]), &timings),
+            vec![Some(0)]
+        );
+    }
+
+    #[test]
+    fn leaves_the_start_of_an_out_of_range_timing_empty() {
+        let entry = history_entry(
+            "ls".to_string(),
+            Some(&timing("ls", i64::MAX)),
+            Tag::unknown(),
+        );
+
+        let start = entry.get_data_by_key("start".spanned_unknown());
+        let duration = entry.get_data_by_key("duration".spanned_unknown());
+
+        assert!(start.map(|start| start.value.is_none()).unwrap_or(false));
+        assert!(duration
+            .map(|duration| duration.value.is_some())
+            .unwrap_or(false));
+    }
 }
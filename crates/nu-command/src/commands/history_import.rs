@@ -0,0 +1,397 @@
+use crate::prelude::*;
+use nu_data::config::{Conf, NuConfig};
+use nu_engine::WholeStreamCommand;
+use nu_engine::{history_path, record_history_timings, trim_history_timings, HistoryTiming};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct Arguments {
+    from: Tagged<String>,
+    path: Option<Tagged<PathBuf>>,
+}
+
+/// The shells whose history and aliases can be brought over.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub(crate) fn from_name(name: &Tagged<String>) -> Result<Shell, ShellError> {
+        match name.item.as_str() {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err(ShellError::labeled_error(
+                "Unknown shell",
+                "expected bash, zsh or fish",
+                &name.tag,
+            )),
+        }
+    }
+
+    fn history_path(self) -> Option<PathBuf> {
+        match self {
+            Shell::Bash => home_file(".bash_history"),
+            Shell::Zsh => std::env::var_os("HISTFILE")
+                .map(PathBuf::from)
+                .or_else(|| home_file(".zsh_history")),
+            Shell::Fish => std::env::var_os("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home_file(".local/share"))
+                .map(|data| data.join("fish").join("fish_history")),
+        }
+    }
+}
+
+pub(crate) fn home_file(name: &str) -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(name))
+}
+
+/// A command read from another shell's history, with when it started if the shell kept that.
+#[derive(Debug, PartialEq)]
+struct Entry {
+    command: String,
+    start: Option<i64>,
+    duration: Option<u64>,
+}
+
+pub struct HistoryImport;
+
+#[async_trait]
+impl WholeStreamCommand for HistoryImport {
+    fn name(&self) -> &str {
+        "history import"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("history import")
+            .required_named(
+                "from",
+                SyntaxShape::String,
+                "the shell the history comes from: bash, zsh or fish",
+                Some('f'),
+            )
+            .optional(
+                "path",
+                SyntaxShape::FilePath,
+                "the history file, if not where the shell keeps it by default",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Adds the history of another shell to the nu history.
+
+The commands are added as they are, so they can be searched and run again once edited into nu's syntax. When the other shell recorded when commands ran (bash with HISTTIMEFORMAT, zsh with EXTENDED_HISTORY, and fish), that is kept too."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        history_import(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Bring over the zsh history",
+                example: "history import --from zsh",
+                result: None,
+            },
+            Example {
+                description: "Bring over a bash history kept elsewhere",
+                example: "history import --from bash ~/backup/bash_history",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn history_import(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let config: Box<dyn Conf> = Box::new(NuConfig::new());
+    let tag = args.call_info.name_tag.clone();
+    let (Arguments { from, path }, _) = args.process().await?;
+
+    let shell = Shell::from_name(&from)?;
+    let (path, path_tag) = match path {
+        Some(path) => (path.item, path.tag),
+        None => match shell.history_path() {
+            Some(path) => (path, tag.clone()),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Could not find the history",
+                    "give the path of the history file",
+                    &tag,
+                ))
+            }
+        },
+    };
+
+    let contents = std::fs::read(&path).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not open history",
+            format!("could not read {}", path.display()),
+            &path_tag,
+        )
+    })?;
+    let entries = match shell {
+        Shell::Bash => parse_bash(&String::from_utf8_lossy(&contents)),
+        Shell::Zsh => parse_zsh(&String::from_utf8_lossy(&unmetafy(&contents))),
+        Shell::Fish => parse_fish(&String::from_utf8_lossy(&contents)),
+    };
+
+    append_history(&*config, &entries).map_err(|err| {
+        ShellError::labeled_error(
+            "Could not write history",
+            format!("could not add to the history: {}", err),
+            &tag,
+        )
+    })?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(format!(
+            "Imported {} commands from {}",
+            entries.len(),
+            from.item
+        ))
+        .into_value(tag),
+    )))
+}
+
+fn append_history(config: &dyn Conf, entries: &[Entry]) -> std::io::Result<()> {
+    let path = history_path(config);
+    let is_new = std::fs::metadata(&path)
+        .map(|metadata| metadata.len() == 0)
+        .unwrap_or(true);
+
+    let mut file = BufWriter::new(OpenOptions::new().create(true).append(true).open(&path)?);
+
+    // The line editor's own header, which `history` skips
+    if is_new {
+        writeln!(file, "#V2")?;
+    }
+
+    for entry in entries {
+        // The line editor keeps each entry on one line
+        let line = entry.command.replace('\\', "\\\\").replace('\n', "\\n");
+        writeln!(file, "{}", line)?;
+    }
+    file.flush()?;
+
+    let timings: Vec<HistoryTiming> = entries
+        .iter()
+        .filter_map(|entry| {
+            entry.start.map(|start| HistoryTiming {
+                command: entry.command.trim().to_string(),
+                start,
+                duration: entry.duration.unwrap_or_default(),
+            })
+        })
+        .collect();
+    record_history_timings(config, &timings)?;
+
+    trim_history_timings(config)
+}
+
+/// Bash writes `#<seconds>` before each command when HISTTIMEFORMAT is set.
+fn parse_bash(contents: &str) -> Vec<Entry> {
+    let mut entries = vec![];
+    let mut start = None;
+
+    for line in contents.lines() {
+        if let Some(seconds) = line.strip_prefix('#') {
+            if let Ok(seconds) = seconds.trim().parse() {
+                start = Some(seconds);
+                continue;
+            }
+        }
+
+        if !line.trim().is_empty() {
+            entries.push(Entry {
+                command: line.to_string(),
+                start: start.take(),
+                duration: None,
+            });
+        }
+    }
+
+    entries
+}
+
+/// Zsh writes `: <start>:<elapsed>;<command>` with EXTENDED_HISTORY, and continues commands
+/// spanning lines with a trailing backslash.
+fn parse_zsh(contents: &str) -> Vec<Entry> {
+    let mut entries = vec![];
+    let mut lines = contents.lines();
+
+    while let Some(line) = lines.next() {
+        let mut command = line.to_string();
+        while command.ends_with('\\') {
+            command.pop();
+            match lines.next() {
+                Some(next) => {
+                    command.push('\n');
+                    command.push_str(next);
+                }
+                None => break,
+            }
+        }
+
+        let mut entry = Entry {
+            command,
+            start: None,
+            duration: None,
+        };
+
+        if let Some(extended) = entry.command.strip_prefix(": ") {
+            if let Some((timing, command)) = split_once(extended, ';') {
+                if let Some((start, elapsed)) = split_once(timing, ':') {
+                    entry.start = start.trim().parse().ok();
+                    entry.duration = elapsed
+                        .trim()
+                        .parse::<u64>()
+                        .ok()
+                        .map(|seconds| seconds * 1_000_000_000);
+                    entry.command = command.to_string();
+                }
+            }
+        }
+
+        if !entry.command.trim().is_empty() {
+            entries.push(entry);
+        }
+    }
+
+    entries
+}
+
+/// Zsh escapes some bytes of its history by prefixing them with 0x83 and flipping bit 5.
+fn unmetafy(contents: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![];
+    let mut iter = contents.iter();
+
+    while let Some(byte) = iter.next() {
+        if *byte == 0x83 {
+            if let Some(next) = iter.next() {
+                bytes.push(next ^ 32);
+            }
+        } else {
+            bytes.push(*byte);
+        }
+    }
+
+    bytes
+}
+
+/// Fish keeps a list of `- cmd: <command>` items, each with a `when: <start>` line.
+fn parse_fish(contents: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = vec![];
+
+    for line in contents.lines() {
+        if let Some(command) = line.strip_prefix("- cmd: ") {
+            entries.push(Entry {
+                command: unescape_fish(command),
+                start: None,
+                duration: None,
+            });
+        } else if let Some(when) = line.trim_start().strip_prefix("when: ") {
+            if let Some(entry) = entries.last_mut() {
+                entry.start = when.trim().parse().ok();
+            }
+        }
+    }
+
+    entries
+}
+
+fn unescape_fish(command: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    unescaped
+}
+
+fn split_once(text: &str, separator: char) -> Option<(&str, &str)> {
+    let index = text.find(separator)?;
+    Some((&text[..index], &text[index + separator.len_utf8()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bash, parse_fish, parse_zsh, unmetafy, Entry};
+
+    fn entry(command: &str, start: Option<i64>, duration: Option<u64>) -> Entry {
+        Entry {
+            command: command.to_string(),
+            start,
+            duration,
+        }
+    }
+
+    #[test]
+    fn reads_bash_timestamps() {
+        let history = "ls\n#1609459200\ncd /tmp\n\ngit status\n";
+
+        assert_eq!(
+            parse_bash(history),
+            vec![
+                entry("ls", None, None),
+                entry("cd /tmp", Some(1_609_459_200), None),
+                entry("git status", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_extended_zsh_history() {
+        let history = ": 1609459200:3;make\n: 1609459260:0;for f in *; do\\\necho $f\\\ndone\nls\n";
+
+        assert_eq!(
+            parse_zsh(history),
+            vec![
+                entry("make", Some(1_609_459_200), Some(3_000_000_000)),
+                entry(
+                    "for f in *; do\necho $f\ndone",
+                    Some(1_609_459_260),
+                    Some(0)
+                ),
+                entry("ls", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmetafies_zsh_bytes() {
+        assert_eq!(unmetafy(&[b'a', 0x83, 0xa3, b'b']), vec![b'a', 0x83, b'b']);
+    }
+
+    #[test]
+    fn reads_fish_history() {
+        let history = "- cmd: echo \\\"hi\\\"\n  when: 1609459200\n- cmd: printf a\\\\nb\\n\n  when: 1609459300\n  paths:\n    - a\n";
+
+        assert_eq!(
+            parse_fish(history),
+            vec![
+                entry("echo \"hi\"", Some(1_609_459_200), None),
+                entry("printf a\\nb\n", Some(1_609_459_300), None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+
+pub struct Ignore;
+
+#[async_trait]
+impl WholeStreamCommand for Ignore {
+    fn name(&self) -> &str {
+        "ignore"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ignore")
+    }
+
+    fn usage(&self) -> &str {
+        r#"Runs the pipeline to the end and discards its output.
+
+Useful for commands run for their side effects, whose output would otherwise be shown. Output of external commands is discarded too, but errors are still reported."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        ignore(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Discard the output of a pipeline",
+                example: "echo [1 2 3] | ignore",
+                result: Some(vec![]),
+            },
+            Example {
+                description: "Run an external command without showing its output",
+                example: "^git fetch | ignore",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn ignore(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let mut input = args.input;
+    let mut error = None;
+
+    // Everything is read so the commands before run to the end, even after an error
+    while let Some(value) = input.next().await {
+        if error.is_none() && value.is_error() {
+            error = Some(value.expect_error());
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(OutputStream::empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ignore;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Ignore {})?)
+    }
+}
@@ -42,25 +42,33 @@ impl WholeStreamCommand for Command {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Insert a column with a value",
-            example: "echo [[author, commits]; ['Andrés', 1]] | insert branches 5",
-            result: Some(vec![UntaggedValue::row(indexmap! {
-                    "author".to_string() => Value::from("Andrés"),
-                    "commits".to_string() => UntaggedValue::int(1).into(),
-                    "branches".to_string() => UntaggedValue::int(5).into(),
-            })
-            .into()]),
-        },Example {
-            description: "Use in block form for more involved insertion logic",
-            example: "echo [[author, lucky_number]; ['Yehuda', 4]] | insert success { = $it.lucky_number * 10 }",
-            result: Some(vec![UntaggedValue::row(indexmap! {
-                    "author".to_string() => Value::from("Yehuda"),
-                    "lucky_number".to_string() => UntaggedValue::int(4).into(),
-                    "success".to_string() => UntaggedValue::int(40).into(),
-            })
-            .into()]),
-        }]
+        vec![
+            Example {
+                description: "Insert a column with a value",
+                example: "echo [[author, commits]; ['Andrés', 1]] | insert branches 5",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "author".to_string() => Value::from("Andrés"),
+                        "commits".to_string() => UntaggedValue::int(1).into(),
+                        "branches".to_string() => UntaggedValue::int(5).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Use in block form for more involved insertion logic",
+                example: "echo [[author, lucky_number]; ['Yehuda', 4]] | insert success { = $it.lucky_number * 10 }",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "author".to_string() => Value::from("Yehuda"),
+                        "lucky_number".to_string() => UntaggedValue::int(4).into(),
+                        "success".to_string() => UntaggedValue::int(40).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Insert into a nested column, creating the rows along the way",
+                example: "echo [[name]; [nu]] | insert meta.license MIT | get meta.license",
+                result: Some(vec![Value::from("MIT")]),
+            },
+        ]
     }
 }
 
@@ -113,9 +121,7 @@ async fn process_row(
                     };
 
                     match input {
-                        obj
-                        @
-                        Value {
+                        obj @ Value {
                             value: UntaggedValue::Row(_),
                             ..
                         } => match obj.insert_data_at_column_path(&field, result) {
@@ -0,0 +1,232 @@
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+use num_bigint::{BigInt, Sign, ToBigInt};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into filesize").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert the values at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert sizes like '1.5 GiB', '300K' or byte counts into filesizes."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a size with a unit",
+                example: "echo '1.5 KiB' | into filesize",
+                result: Some(vec![UntaggedValue::filesize(1536).into()]),
+            },
+            Example {
+                description: "Convert a count of bytes",
+                example: "echo 300 | into filesize",
+                result: Some(vec![UntaggedValue::filesize(300).into()]),
+            },
+            Example {
+                description: "Convert a column of sizes printed by another tool",
+                example: "echo [[name, size]; [nu, '12M']] | into filesize size | get size",
+                result: Some(vec![UntaggedValue::filesize(12 * 1024 * 1024).into()]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (Arguments { rest: column_paths }, input) = args.process().await?;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let size = match &input.value {
+        UntaggedValue::Primitive(Primitive::Filesize(size)) => Some(size.clone()),
+        UntaggedValue::Primitive(Primitive::Int(bytes)) => Some(bytes.clone()),
+        UntaggedValue::Primitive(Primitive::Decimal(bytes)) => bytes.with_scale(0).to_bigint(),
+        UntaggedValue::Primitive(Primitive::String(text)) => parse_filesize(text),
+        other => {
+            return Err(ShellError::labeled_error(
+                "Could not convert into a filesize",
+                format!("can't convert {} into a filesize", other.type_name()),
+                tag.span,
+            ))
+        }
+    };
+
+    match size {
+        Some(size) if size.sign() != Sign::Minus => {
+            Ok(UntaggedValue::filesize(size).into_value(tag))
+        }
+        _ => Err(ShellError::labeled_error(
+            "Could not convert into a filesize",
+            "expected a size like '1.5 GiB', '300K' or a count of bytes",
+            tag.span,
+        )),
+    }
+}
+
+/// Parses a size like `1.5 GiB`, `300K`, `4,2 MB` or `1024`. Units are counted in powers of 1024,
+/// as with nu's own `kb` and `mb`, whether or not they're written with an `i`.
+fn parse_filesize(text: &str) -> Option<BigInt> {
+    let text = text.trim();
+    let unit_start = text.find(|c: char| c.is_alphabetic()).unwrap_or(text.len());
+    let (number, unit) = text.split_at(unit_start);
+
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" | "byte" | "bytes" => 1,
+        "k" | "kb" | "kib" | "ki" => 1 << 10,
+        "m" | "mb" | "mib" | "mi" => 1 << 20,
+        "g" | "gb" | "gib" | "gi" => 1 << 30,
+        "t" | "tb" | "tib" | "ti" => 1 << 40,
+        "p" | "pb" | "pib" | "pi" => 1 << 50,
+        _ => return None,
+    };
+
+    let number = BigDecimal::from_str(&normalize_number(number.trim())?).ok()?;
+    (number * BigDecimal::from(multiplier))
+        .with_scale(0)
+        .to_bigint()
+}
+
+/// Turns a number written with the separators of any locale, like `1,234.5`, `1.234,5` or
+/// `1 234,5`, into one Rust can parse. A lone separator followed by three digits groups thousands.
+fn normalize_number(number: &str) -> Option<String> {
+    let digits: String = number
+        .chars()
+        .filter(|c| !matches!(*c, ' ' | '\u{a0}' | '\u{202f}' | '_' | '\''))
+        .collect();
+
+    if digits.is_empty()
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+    {
+        return None;
+    }
+
+    let decimal_separator = match (digits.rfind('.'), digits.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(dot.max(comma)),
+        (Some(dot), None) if digits.matches('.').count() == 1 => Some(dot),
+        (None, Some(comma)) if digits.matches(',').count() == 1 && digits.len() - comma != 4 => {
+            Some(comma)
+        }
+        _ => None,
+    };
+
+    let (integer, fraction) = match decimal_separator {
+        Some(at) => (&digits[..at], &digits[at + 1..]),
+        None => (&digits[..], ""),
+    };
+
+    // Whatever separates the whole part has to group it in thousands
+    let mut groups = integer.split(|c: char| c == '.' || c == ',');
+    let mut normalized = groups.next().unwrap_or_default().to_string();
+    for group in groups {
+        if group.len() != 3 || normalized.is_empty() {
+            return None;
+        }
+        normalized.push_str(group);
+    }
+
+    if !fraction.is_empty() {
+        normalized.push('.');
+        normalized.push_str(fraction);
+    }
+
+    if normalized.is_empty() {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_filesize, ShellError, SubCommand};
+    use num_bigint::BigInt;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn parses_sizes_written_in_many_ways() {
+        let cases = [
+            ("1024", 1024),
+            ("300K", 300 * 1024),
+            ("1.5 GiB", 3 * (1 << 29)),
+            ("1,5 GiB", 3 * (1 << 29)),
+            ("2 MB", 2 << 20),
+            ("1,024 bytes", 1024),
+            ("1.234,5 KB", 1_264_128),
+            ("1 000 kb", 1000 << 10),
+            ("12B", 12),
+        ];
+
+        for (text, bytes) in cases.iter() {
+            assert_eq!(
+                parse_filesize(text),
+                Some(BigInt::from(*bytes as u64)),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_what_is_not_a_size() {
+        for text in ["", "GiB", "1.5 parsecs", "1..5 KB", "-3 KB"].iter() {
+            assert_eq!(parse_filesize(text), None, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod command;
+mod filesize;
+
+pub use command::Command as IntoCommand;
+pub use filesize::SubCommand as IntoFilesize;
@@ -0,0 +1,235 @@
+use crate::prelude::*;
+use nu_data::keybinding::{
+    emacs_keybindings, keybinding_path, nu_keybindings, vi_command_keybindings,
+    vi_insert_keybindings, KeyPress, Keybinding, Keybindings,
+};
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+use serde::Serialize;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "keybindings list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("keybindings list")
+    }
+
+    fn usage(&self) -> &str {
+        "Lists the keybindings of the line editor, those set in keybindings.yml first"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        list(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find what Ctrl-R is bound to",
+            example: "keybindings list | where key == Ctrl-R",
+            result: None,
+        }]
+    }
+}
+
+async fn list(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let path = keybinding_path()?;
+
+    // Without the file, there are only the bindings of nu and of the edit mode
+    let configured: Keybindings = match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_yaml::from_str(&contents).map_err(|e| {
+            ShellError::labeled_error(
+                format!("Could not read {} ({})", path.display(), e),
+                "could not read the keybindings",
+                &name,
+            )
+        })?,
+        Err(_) => vec![],
+    };
+
+    let everywhere = vec![("keybindings.yml", configured), ("nu", nu_keybindings())];
+    let modes = if edit_mode().eq_ignore_ascii_case("vi") {
+        vec![
+            ("vi insert", vi_insert_keybindings()),
+            ("vi command", vi_command_keybindings()),
+        ]
+    } else {
+        vec![("emacs", emacs_keybindings())]
+    };
+
+    let rows: Vec<_> = bound(everywhere, modes)
+        .into_iter()
+        .map(|(source, keybinding)| {
+            let mut row = TaggedDictBuilder::new(&name);
+            row.insert_untagged("key", UntaggedValue::string(key_name(&keybinding.key)));
+            row.insert_untagged(
+                "binding",
+                UntaggedValue::string(spelling(&keybinding.binding)),
+            );
+            row.insert_untagged("config", UntaggedValue::string(spelling(&keybinding.key)));
+            row.insert_untagged("from", UntaggedValue::string(source));
+
+            ReturnSuccess::value(row.into_value())
+        })
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// The keybindings of the sources, leaving out those of keys bound by an earlier source that
+/// applies in every mode. The modes of vi don't hide each other's keys.
+fn bound(
+    everywhere: Vec<(&'static str, Keybindings)>,
+    modes: Vec<(&'static str, Keybindings)>,
+) -> Vec<(&'static str, Keybinding)> {
+    let mut rebound: Vec<String> = vec![];
+    let mut keybindings = vec![];
+
+    for (source, bindings) in everywhere {
+        for keybinding in bindings {
+            let key = spelling(&keybinding.key);
+            if !rebound.contains(&key) {
+                rebound.push(key);
+                keybindings.push((source, keybinding));
+            }
+        }
+    }
+
+    for (source, bindings) in modes {
+        for keybinding in bindings {
+            if !rebound.contains(&spelling(&keybinding.key)) {
+                keybindings.push((source, keybinding));
+            }
+        }
+    }
+
+    keybindings
+}
+
+/// The edit mode set in the `line_editor` section of the config, as that's where the line editor
+/// reads it from.
+fn edit_mode() -> String {
+    nu_data::config::config(Tag::unknown())
+        .ok()
+        .and_then(|config| config.get("line_editor").cloned())
+        .and_then(|editor| {
+            editor
+                .row_entries()
+                .find(|(name, _)| name.as_str() == "edit_mode")
+                .and_then(|(_, mode)| mode.as_string().ok())
+        })
+        .unwrap_or_else(|| "emacs".to_string())
+}
+
+/// How a key is usually written, like `Ctrl-R` or `Alt-f`.
+pub(crate) fn key_name(key: &KeyPress) -> String {
+    match key {
+        KeyPress::Char(' ') => "Space".to_string(),
+        KeyPress::Char(c) => c.to_string(),
+        KeyPress::Ctrl(c) => format!("Ctrl-{}", c),
+        KeyPress::Meta(c) => format!("Alt-{}", c),
+        KeyPress::F(n) => format!("F{}", n),
+        KeyPress::BackTab => "Shift-Tab".to_string(),
+        KeyPress::ControlDown => "Ctrl-Down".to_string(),
+        KeyPress::ControlLeft => "Ctrl-Left".to_string(),
+        KeyPress::ControlRight => "Ctrl-Right".to_string(),
+        KeyPress::ControlUp => "Ctrl-Up".to_string(),
+        KeyPress::ShiftDown => "Shift-Down".to_string(),
+        KeyPress::ShiftLeft => "Shift-Left".to_string(),
+        KeyPress::ShiftRight => "Shift-Right".to_string(),
+        KeyPress::ShiftUp => "Shift-Up".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// How a key or a binding is written in keybindings.yml. This is JSON, which YAML reads too, so
+/// that it fits on one line.
+pub(crate) fn spelling(value: &(impl Serialize + std::fmt::Debug)) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bound, key_name, spelling, ShellError, SubCommand};
+    use nu_data::keybinding::{
+        emacs_keybindings, nu_keybindings, Cmd, KeyPress, Keybinding, Movement,
+    };
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn names_keys_the_usual_way() {
+        assert_eq!(key_name(&KeyPress::Ctrl('R')), "Ctrl-R");
+        assert_eq!(key_name(&KeyPress::ShiftLeft), "Shift-Left");
+        assert_eq!(key_name(&KeyPress::Tab), "Tab");
+    }
+
+    #[test]
+    fn spells_bindings_as_written_in_the_config() {
+        assert_eq!(spelling(&KeyPress::Ctrl('R')), r#"{"Ctrl":"R"}"#);
+        assert_eq!(
+            spelling(&Cmd::Move(Movement::EndOfLine)),
+            r#"{"Move":"EndOfLine"}"#
+        );
+    }
+
+    #[test]
+    fn lists_the_keys_of_the_edit_mode_without_a_config() {
+        let keybindings = bound(
+            vec![("keybindings.yml", vec![]), ("nu", nu_keybindings())],
+            vec![("emacs", emacs_keybindings())],
+        );
+
+        assert!(keybindings
+            .iter()
+            .any(|(source, b)| *source == "emacs" && key_name(&b.key) == "Ctrl-R"));
+    }
+
+    #[test]
+    fn lists_the_keys_undoing_changes() {
+        let keybindings = bound(
+            vec![("keybindings.yml", vec![]), ("nu", nu_keybindings())],
+            vec![("emacs", emacs_keybindings())],
+        );
+
+        let undo: Vec<_> = keybindings
+            .iter()
+            .filter(|(_, b)| matches!(b.binding, Cmd::Undo(_)))
+            .map(|(source, b)| (*source, key_name(&b.key)))
+            .collect();
+        assert_eq!(
+            undo,
+            vec![("nu", "Alt-r".to_string()), ("emacs", "Ctrl-_".to_string())]
+        );
+    }
+
+    #[test]
+    fn leaves_out_the_keys_bound_again() {
+        let ctrl_r = Keybinding {
+            key: KeyPress::Ctrl('R'),
+            binding: Cmd::Noop,
+        };
+        let keybindings = bound(
+            vec![("keybindings.yml", vec![ctrl_r])],
+            vec![("emacs", emacs_keybindings())],
+        );
+
+        let ctrl_r: Vec<&str> = keybindings
+            .iter()
+            .filter(|(_, b)| key_name(&b.key) == "Ctrl-R")
+            .map(|(source, _)| *source)
+            .collect();
+        assert_eq!(ctrl_r, vec!["keybindings.yml"]);
+    }
+}
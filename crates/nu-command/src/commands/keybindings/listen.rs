@@ -0,0 +1,173 @@
+use super::list::{key_name, spelling};
+use crate::prelude::*;
+use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use nu_data::keybinding::KeyPress;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+use std::io::{self, Write};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "keybindings listen"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("keybindings listen")
+    }
+
+    fn usage(&self) -> &str {
+        "Shows the keys pressed until Esc, with how to write them in keybindings.yml"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        listen(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find out how to write a key in keybindings.yml",
+            example: "keybindings listen",
+            result: None,
+        }]
+    }
+}
+
+async fn listen(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+
+    let events = read_keys().map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not read the keys pressed ({})", e),
+            "could not listen to the keyboard",
+            &name,
+        )
+    })?;
+
+    let rows: Vec<_> = events
+        .iter()
+        .map(|event| {
+            let keypress = keypress(event);
+
+            let mut row = TaggedDictBuilder::new(&name);
+            row.insert_untagged("code", UntaggedValue::string(format!("{:?}", event.code)));
+            row.insert_untagged(
+                "modifiers",
+                UntaggedValue::string(format!("{:?}", event.modifiers)),
+            );
+            match &keypress {
+                Some(keypress) => {
+                    row.insert_untagged("key", UntaggedValue::string(key_name(keypress)));
+                    row.insert_untagged("config", UntaggedValue::string(spelling(keypress)));
+                }
+                None => {
+                    row.insert_untagged("key", UntaggedValue::nothing());
+                    row.insert_untagged("config", UntaggedValue::nothing());
+                }
+            }
+
+            ReturnSuccess::value(row.into_value())
+        })
+        .collect();
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// Echoes each key as it's pressed, since the table of them only shows once Esc is.
+fn read_keys() -> crossterm::Result<Vec<KeyEvent>> {
+    let mut stdout = io::stdout();
+    writeln!(stdout, "Press the keys to look up, then Esc to stop.")?;
+
+    enable_raw_mode()?;
+    let mut events = vec![];
+    let result = loop {
+        match read() {
+            Ok(Event::Key(event)) if event.code == KeyCode::Esc => break Ok(events),
+            Ok(Event::Key(event)) => {
+                let shown = match keypress(&event) {
+                    Some(keypress) => format!("{}\t{}", key_name(&keypress), spelling(&keypress)),
+                    None => format!("{:?}\tcan't be bound", event.code),
+                };
+
+                // Raw mode leaves moving back to the start of the line to us
+                if let Err(e) = write!(stdout, "{}\r\n", shown).and_then(|_| stdout.flush()) {
+                    break Err(e.into());
+                }
+                events.push(event);
+            }
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    disable_raw_mode()?;
+
+    result
+}
+
+/// The key as the line editor knows it, if it can be bound at all.
+fn keypress(event: &KeyEvent) -> Option<KeyPress> {
+    let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = event.modifiers.contains(KeyModifiers::ALT);
+    let shift = event.modifiers.contains(KeyModifiers::SHIFT);
+
+    let keypress = match event.code {
+        KeyCode::Char(c) if ctrl && !alt => KeyPress::Ctrl(c.to_ascii_uppercase()),
+        KeyCode::Char(c) if alt && !ctrl => KeyPress::Meta(c),
+        KeyCode::Char(c) if !ctrl && !alt => KeyPress::Char(c),
+        KeyCode::Left if ctrl => KeyPress::ControlLeft,
+        KeyCode::Left if shift => KeyPress::ShiftLeft,
+        KeyCode::Left => KeyPress::Left,
+        KeyCode::Right if ctrl => KeyPress::ControlRight,
+        KeyCode::Right if shift => KeyPress::ShiftRight,
+        KeyCode::Right => KeyPress::Right,
+        KeyCode::Up if ctrl => KeyPress::ControlUp,
+        KeyCode::Up if shift => KeyPress::ShiftUp,
+        KeyCode::Up => KeyPress::Up,
+        KeyCode::Down if ctrl => KeyPress::ControlDown,
+        KeyCode::Down if shift => KeyPress::ShiftDown,
+        KeyCode::Down => KeyPress::Down,
+        KeyCode::Backspace => KeyPress::Backspace,
+        KeyCode::Enter => KeyPress::Enter,
+        KeyCode::Home => KeyPress::Home,
+        KeyCode::End => KeyPress::End,
+        KeyCode::PageUp => KeyPress::PageUp,
+        KeyCode::PageDown => KeyPress::PageDown,
+        KeyCode::Tab => KeyPress::Tab,
+        KeyCode::BackTab => KeyPress::BackTab,
+        KeyCode::Delete => KeyPress::Delete,
+        KeyCode::Insert => KeyPress::Insert,
+        KeyCode::F(n) => KeyPress::F(n),
+        KeyCode::Null => KeyPress::Null,
+        KeyCode::Esc => KeyPress::Esc,
+        _ => return None,
+    };
+
+    Some(keypress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keypress, ShellError, SubCommand};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use nu_data::keybinding::KeyPress;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn knows_keys_as_the_line_editor_does() {
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        let shift_left = KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT);
+
+        assert!(matches!(keypress(&ctrl_r), Some(KeyPress::Ctrl('R'))));
+        assert!(matches!(keypress(&shift_left), Some(KeyPress::ShiftLeft)));
+    }
+}
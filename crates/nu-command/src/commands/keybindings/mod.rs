@@ -0,0 +1,9 @@
+pub mod command;
+pub mod list;
+#[cfg(feature = "crossterm")]
+pub mod listen;
+
+pub use command::Command as Keybindings;
+pub use list::SubCommand as KeybindingsList;
+#[cfg(feature = "crossterm")]
+pub use listen::SubCommand as KeybindingsListen;
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
+use nu_protocol::{Primitive, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tagged;
 use std::process::{Command, Stdio};
 
@@ -9,10 +9,11 @@ pub struct Kill;
 
 #[derive(Deserialize)]
 pub struct KillArgs {
-    pub pid: Tagged<u64>,
+    pub pid: Option<Tagged<u64>>,
     pub rest: Vec<Tagged<u64>>,
     pub force: Tagged<bool>,
     pub quiet: Tagged<bool>,
+    pub signal: Option<Value>,
 }
 
 #[async_trait]
@@ -23,7 +24,7 @@ impl WholeStreamCommand for Kill {
 
     fn signature(&self) -> Signature {
         Signature::build("kill")
-            .required(
+            .optional(
                 "pid",
                 SyntaxShape::Int,
                 "process id of process that is to be killed",
@@ -31,10 +32,16 @@ impl WholeStreamCommand for Kill {
             .rest(SyntaxShape::Int, "rest of processes to kill")
             .switch("force", "forcefully kill the process", Some('f'))
             .switch("quiet", "won't print anything to the console", Some('q'))
+            .named(
+                "signal",
+                SyntaxShape::Any,
+                "the signal to send, by name or number, like TERM or 15 (unix only)",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Kill a process using the process id."
+        "Kill processes using their process ids, or the pid column of the rows given, returning whether each was killed."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -53,62 +60,176 @@ impl WholeStreamCommand for Kill {
                 example: "kill --force 12345",
                 result: None,
             },
+            Example {
+                description: "Kill every process of a program",
+                example: "ps | where name == sleep | kill",
+                result: None,
+            },
+            Example {
+                description: "Ask processes to reload their configuration",
+                example: "kill --signal HUP 12345 12346",
+                result: None,
+            },
         ]
     }
 }
 
 async fn kill(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
     let (
         KillArgs {
             pid,
             rest,
             force,
             quiet,
+            signal,
         },
-        ..,
+        input,
     ) = args.process().await?;
-    let mut cmd = if cfg!(windows) {
-        let mut cmd = Command::new("taskkill");
 
-        if *force {
-            cmd.arg("/F");
+    let signal = match signal {
+        Some(signal) => Some(signal_arg(&signal)?),
+        None => None,
+    };
+
+    let mut pids: Vec<Tagged<u64>> = pid.into_iter().chain(rest).collect();
+    if pids.is_empty() {
+        for row in input.collect::<Vec<_>>().await {
+            pids.push(pid_of(&row)?);
         }
+    }
 
-        cmd.arg("/PID");
-        cmd.arg(pid.item().to_string());
+    if pids.is_empty() {
+        return Err(ShellError::labeled_error(
+            "No process to kill",
+            "give the pids, or rows with a pid column",
+            &name,
+        ));
+    }
 
-        // each pid must written as `/PID 0` otherwise
-        // taskkill will act as `killall` unix command
-        for id in &rest {
-            cmd.arg("/PID");
-            cmd.arg(id.item().to_string());
-        }
+    let results = pids
+        .into_iter()
+        .map(|pid| {
+            let mut cmd = kill_command(pid.item, *force, signal.as_deref(), &pid.tag)?;
+            let output = cmd.stdin(Stdio::null()).output().map_err(|e| {
+                ShellError::labeled_error(
+                    "Could not kill the process",
+                    format!("failed to run {:?} ({})", cmd, e),
+                    &pid.tag,
+                )
+            })?;
+
+            let mut row = TaggedDictBuilder::new(&name);
+            row.insert_untagged("pid", UntaggedValue::int(pid.item));
+            row.insert_untagged("killed", UntaggedValue::boolean(output.status.success()));
+            row.insert_untagged(
+                "error",
+                UntaggedValue::string(String::from_utf8_lossy(&output.stderr).trim()),
+            );
+
+            Ok(row.into_value())
+        })
+        .collect::<Result<Vec<Value>, ShellError>>()?;
 
-        cmd
+    if *quiet {
+        Ok(OutputStream::empty())
     } else {
-        let mut cmd = Command::new("kill");
+        Ok(futures::stream::iter(results).to_output_stream())
+    }
+}
+
+/// The process ids piped in are either numbers or rows with a pid column, like those of `ps`.
+fn pid_of(value: &Value) -> Result<Tagged<u64>, ShellError> {
+    let pid = match &value.value {
+        UntaggedValue::Row(_) => value.get_data_by_key("pid".spanned_unknown()),
+        _ => Some(value.clone()),
+    };
 
-        if *force {
-            cmd.arg("-9");
+    pid.and_then(|pid| pid.as_u64().ok().map(|item| item.tagged(&value.tag)))
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                "Expected a process id",
+                "expected a number, or a row with a pid column",
+                &value.tag,
+            )
+        })
+}
+
+/// The signal as `kill` takes it after a dash, like `TERM` for SIGTERM or `15`.
+fn signal_arg(signal: &Value) -> Result<String, ShellError> {
+    match &signal.value {
+        UntaggedValue::Primitive(Primitive::Int(_)) => Ok(signal.as_u64()?.to_string()),
+        UntaggedValue::Primitive(Primitive::String(name)) => {
+            let name = name.to_uppercase();
+            let name = name.trim_start_matches("SIG");
+
+            if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                Ok(name.to_string())
+            } else {
+                Err(ShellError::labeled_error(
+                    "Invalid signal",
+                    "expected a signal name like TERM, or its number",
+                    &signal.tag,
+                ))
+            }
         }
+        _ => Err(ShellError::labeled_error(
+            "Invalid signal",
+            "expected a signal name like TERM, or its number",
+            &signal.tag,
+        )),
+    }
+}
 
-        cmd.arg(pid.item().to_string());
+fn kill_command(
+    pid: u64,
+    force: bool,
+    signal: Option<&str>,
+    tag: &Tag,
+) -> Result<Command, ShellError> {
+    if cfg!(windows) {
+        if signal.is_some() {
+            return Err(ShellError::labeled_error(
+                "Signals can't be sent on Windows",
+                "use --force to terminate the process instead",
+                tag,
+            ));
+        }
 
-        cmd.args(rest.iter().map(move |id| id.item().to_string()));
+        let mut cmd = Command::new("taskkill");
 
-        cmd
-    };
+        if force {
+            cmd.arg("/F");
+        }
 
-    // pipe everything to null
-    if *quiet {
-        cmd.stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
-    }
+        cmd.arg("/PID");
+        cmd.arg(pid.to_string());
+
+        Ok(cmd)
+    } else {
+        let mut cmd = Command::new("kill");
 
-    cmd.status().expect("failed to execute shell command");
+        match (force, signal) {
+            (true, Some(_)) => {
+                return Err(ShellError::labeled_error(
+                    "Both --force and --signal were given",
+                    "--force sends KILL, so only one of them is needed",
+                    tag,
+                ))
+            }
+            (true, None) => {
+                cmd.arg("-9");
+            }
+            (false, Some(signal)) => {
+                cmd.arg(format!("-{}", signal));
+            }
+            (false, None) => {}
+        }
 
-    Ok(OutputStream::empty())
+        cmd.arg(pid.to_string());
+
+        Ok(cmd)
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
+use std::collections::VecDeque;
 
 pub struct Last;
 
@@ -55,23 +56,29 @@ impl WholeStreamCommand for Last {
 
 async fn last(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (LastArgs { rows }, input) = args.process().await?;
-    let v: Vec<_> = input.into_vec().await;
-
     let end_rows_desired = if let Some(quantity) = rows {
         *quantity as usize
     } else {
         1
     };
 
-    let beginning_rows_to_skip = if end_rows_desired < v.len() {
-        v.len() - end_rows_desired
-    } else {
-        0
-    };
-
-    let iter = v.into_iter().skip(beginning_rows_to_skip);
+    // Only the rows that may be among the last ones are kept while reading the input
+    let kept = input
+        .fold(
+            VecDeque::with_capacity(end_rows_desired.min(1024)),
+            |mut kept, row| async move {
+                if end_rows_desired > 0 {
+                    if kept.len() == end_rows_desired {
+                        kept.pop_front();
+                    }
+                    kept.push_back(row);
+                }
+                kept
+            },
+        )
+        .await;
 
-    Ok(futures::stream::iter(iter).to_output_stream())
+    Ok(futures::stream::iter(kept).to_output_stream())
 }
 
 #[cfg(test)]
@@ -88,6 +88,11 @@ pub async fn letcmd(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let value = value?;
 
+    // `let _ = ...` runs the pipeline for its side effects and throws the value away
+    if name.item == "_" || name.item == "$_" {
+        return Ok(OutputStream::empty());
+    }
+
     let name = if name.item.starts_with('$') {
         name.item.clone()
     } else {
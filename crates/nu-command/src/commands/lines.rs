@@ -43,6 +43,29 @@ fn ends_with_line_ending(st: &str) -> bool {
     }
 }
 
+/// Splits the text read into lines, starting with what was left of the previous read. The last
+/// line is left over in turn if it isn't finished yet, as the next read may go on with it.
+fn split_lines(leftover: &mut String, st: &str) -> Vec<String> {
+    leftover.push_str(st);
+
+    // Only the new text is looked at, so a long line read bit by bit isn't split again each time
+    if !st.contains('\n') {
+        return vec![];
+    }
+
+    let text = std::mem::take(leftover);
+
+    let mut lines: Vec<String> = text.lines().map(|x| x.to_string()).collect();
+
+    if !ends_with_line_ending(&text) {
+        if let Some(last) = lines.pop() {
+            leftover.push_str(&last);
+        }
+    }
+
+    lines
+}
+
 async fn lines(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let leftover_string = Arc::new(Mutex::new(String::new()));
     let args = args.evaluate_once().await?;
@@ -65,18 +88,7 @@ async fn lines(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     ..
                 } => {
                     let mut leftover_string = leftover_string.lock();
-
-                    let lo_lines = (&*leftover_string).lines().map(|x| x.to_string());
-                    let st_lines = st.lines().map(|x| x.to_string());
-                    let mut lines: Vec<String> = lo_lines.chain(st_lines).collect();
-
-                    leftover_string.clear();
-
-                    if !ends_with_line_ending(&st) {
-                        if let Some(last) = lines.pop() {
-                            leftover_string.push_str(&last);
-                        }
-                    }
+                    let lines = split_lines(&mut leftover_string, &st);
 
                     let success_lines: Vec<_> = lines
                         .iter()
@@ -117,6 +129,7 @@ async fn lines(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
 #[cfg(test)]
 mod tests {
+    use super::split_lines;
     use super::Lines;
     use super::ShellError;
 
@@ -126,4 +139,14 @@ mod tests {
 
         Ok(test_examples(Lines {})?)
     }
+
+    #[test]
+    fn joins_a_line_split_between_reads() {
+        let mut leftover = String::new();
+
+        assert_eq!(split_lines(&mut leftover, "first\nsec"), vec!["first"]);
+        assert_eq!(split_lines(&mut leftover, "ond li"), Vec::<String>::new());
+        assert_eq!(split_lines(&mut leftover, "ne\nthird"), vec!["second line"]);
+        assert_eq!(leftover, "third");
+    }
 }
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use nu_errors::ShellError;
+use nu_protocol::{ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct LoadEnv;
+
+#[derive(Deserialize)]
+pub struct LoadEnvArgs {
+    pub environ: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for LoadEnv {
+    fn name(&self) -> &str {
+        "load-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("load-env").optional(
+            "environ",
+            SyntaxShape::Any,
+            "a row of the environment variables to set, instead of the input",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Set an environment variable for each column of a row."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        load_env(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set the environment variables of a .env file",
+                example: "open .env | load-env",
+                result: None,
+            },
+            Example {
+                description: "Set two environment variables at once",
+                example: "load-env [[NAME VALUE]; [nu 1]]",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub async fn load_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let ctx = EvaluationContext::from_args(&args);
+    let (LoadEnvArgs { environ }, input) = args.process().await?;
+
+    let values: Vec<Value> = match environ {
+        Some(environ) => vec![environ],
+        None => input.collect().await,
+    };
+
+    // A table sets the variables of each of its rows
+    let rows = values.into_iter().flat_map(|value| match value.value {
+        UntaggedValue::Table(rows) => rows,
+        _ => vec![value],
+    });
+
+    // Everything is checked before any variable is set, so that a bad value sets none of them
+    let mut variables = vec![];
+    for row in rows {
+        if !row.value.is_row() {
+            return Err(ShellError::labeled_error(
+                "Expected a row of environment variables",
+                format!("found {}", row.type_name()),
+                &row.tag,
+            ));
+        }
+
+        for (name, value) in row.row_entries() {
+            let value = match &value.value {
+                UntaggedValue::Primitive(_) => value.convert_to_string(),
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        format!("Can't set {} to {}", name, value.type_name()),
+                        "environment variables hold text",
+                        &value.tag,
+                    ))
+                }
+            };
+
+            variables.push((name.clone(), value));
+        }
+    }
+
+    for (name, value) in variables {
+        ctx.scope.add_env_var(name, value);
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadEnv;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(LoadEnv {})?)
+    }
+}
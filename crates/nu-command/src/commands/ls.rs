@@ -34,6 +34,22 @@ impl WholeStreamCommand for Ls {
                 "Display the apparent directory size in place of the directory metadata size",
                 Some('d'),
             )
+            .switch(
+                "case-insensitive",
+                "Match the pattern ignoring case, even where the filesystem doesn't",
+                Some('i'),
+            )
+            .switch(
+                "recursive",
+                "List the contents of the directories found too, and theirs",
+                Some('r'),
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many levels of directories to list, implying --recursive",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -65,6 +81,16 @@ impl WholeStreamCommand for Ls {
                 example: "ls *.rs",
                 result: None,
             },
+            Example {
+                description: "List all readme files, however their names are capitalized",
+                example: "ls -i readme*",
+                result: None,
+            },
+            Example {
+                description: "List the files of a directory and of the two levels below it",
+                example: "ls --depth 3 crates",
+                result: None,
+            },
         ]
     }
 }
@@ -62,6 +62,11 @@ fn to_byte(value: &Value) -> Option<Value> {
         UntaggedValue::Primitive(Primitive::Int(num)) => {
             Some(UntaggedValue::Primitive(Primitive::Filesize(num.clone())).into_untagged_value())
         }
+        // Decimals summed with filesizes leave a fraction of a byte, which is dropped
+        UntaggedValue::Primitive(Primitive::Decimal(num)) => {
+            let (bytes, _) = num.with_scale(0).into_bigint_and_exponent();
+            Some(UntaggedValue::Primitive(Primitive::Filesize(bytes)).into_untagged_value())
+        }
         _ => None,
     }
 }
@@ -69,24 +74,39 @@ fn to_byte(value: &Value) -> Option<Value> {
 pub fn average(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
     let sum = reducer_for(Reduce::Summation);
 
+    if values.is_empty() {
+        return Err(ShellError::unexpected(
+            "Cannot perform aggregate math operation on empty data",
+        ));
+    }
+
+    // Empty cells (eg. a size that was never computed) are left out of the average
+    let values = values
+        .iter()
+        .filter(|v| !v.is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "nothing to average",
+            "nothing to average",
+            &name.span,
+        ));
+    }
+
     let number = BigDecimal::from_usize(values.len()).ok_or_else(|| {
         ShellError::labeled_error("nothing to average", "nothing to average", &name.span)
     })?;
 
     let total_rows = UntaggedValue::decimal(number);
 
-    let are_bytes = values
-        .get(0)
-        .ok_or_else(|| {
-            ShellError::unexpected("Cannot perform aggregate math operation on empty data")
-        })?
-        .is_filesize();
+    let are_bytes = values.iter().any(|v| v.is_filesize());
 
     let total = if are_bytes {
         to_byte(&sum(
             UntaggedValue::int(0).into_untagged_value(),
             values
-                .to_vec()
                 .iter()
                 .map(|v| match v {
                     Value {
@@ -105,7 +125,7 @@ pub fn average(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
             )
         })
     } else {
-        sum(UntaggedValue::int(0).into_untagged_value(), values.to_vec())
+        sum(UntaggedValue::int(0).into_untagged_value(), values)
     }?;
 
     match total {
@@ -1,5 +1,6 @@
 use crate::commands::math::reducers::{reducer_for, Reduce};
 use crate::commands::math::utils::run_with_function;
+use crate::commands::sort_by::{sort, SortOptions};
 use crate::prelude::*;
 use bigdecimal::FromPrimitive;
 use nu_engine::WholeStreamCommand;
@@ -72,7 +73,7 @@ pub fn median(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
         sorted.push(item.clone());
     }
 
-    crate::commands::sort_by::sort(&mut sorted, &[], name, false)?;
+    sort(&mut sorted, &[], name, SortOptions::default())?;
 
     match take {
         Pick::Median => {
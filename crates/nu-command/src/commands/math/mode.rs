@@ -1,4 +1,5 @@
 use crate::commands::math::utils::run_with_function;
+use crate::commands::sort_by::{sort, SortOptions};
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -72,7 +73,7 @@ pub fn mode(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
         }
     }
 
-    crate::commands::sort_by::sort(&mut modes, &[], name, false)?;
+    sort(&mut modes, &[], name, SortOptions::default())?;
     Ok(UntaggedValue::Table(modes).into_value(name))
 }
 
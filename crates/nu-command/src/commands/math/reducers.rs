@@ -1,3 +1,4 @@
+use crate::utils::parallel::par_reduce;
 use nu_data::value::{compare_values, compute_values};
 use nu_errors::ShellError;
 use nu_protocol::hir::Operator;
@@ -62,6 +63,11 @@ pub enum Reduce {
 }
 
 pub fn sum(data: Vec<Value>) -> Result<Value, ShellError> {
+    // Sums of chunks add up to the same total, since numbers are kept exactly
+    par_reduce(&data, sum_of, |partials| sum_of(&partials))
+}
+
+fn sum_of(data: &[Value]) -> Result<Value, ShellError> {
     let first_value = data
         .get(0)
         .ok_or_else(|| ShellError::unexpected(ERR_EMPTY_DATA))?;
@@ -79,7 +85,7 @@ pub fn sum(data: Vec<Value>) -> Result<Value, ShellError> {
     for value in data {
         match value.value {
             UntaggedValue::Primitive(_) => {
-                acc = match compute_values(Operator::Plus, &acc, &value) {
+                acc = match compute_values(Operator::Plus, &acc, value) {
                     Ok(v) => v.into_untagged_value(),
                     Err((left_type, right_type)) => {
                         return Err(ShellError::coerce_error(
@@ -45,6 +45,15 @@ impl WholeStreamCommand for SubCommand {
                 example: "echo [1 2 3] | math sum",
                 result: Some(vec![UntaggedValue::int(6).into()]),
             },
+            Example {
+                description: "Sum integers and decimals together",
+                example: "echo [1 2.5 3] | math sum",
+                result: Some(vec![UntaggedValue::decimal_from_float(
+                    6.5,
+                    Span::unknown(),
+                )
+                .into()]),
+            },
             Example {
                 description: "Get the disk usage for the current directory",
                 example: "ls --all --du | get size | math sum",
@@ -59,6 +68,11 @@ fn to_byte(value: &Value) -> Option<Value> {
         UntaggedValue::Primitive(Primitive::Int(num)) => {
             Some(UntaggedValue::Primitive(Primitive::Filesize(num.clone())).into_untagged_value())
         }
+        // Decimals summed with filesizes leave a fraction of a byte, which is dropped
+        UntaggedValue::Primitive(Primitive::Decimal(num)) => {
+            let (bytes, _) = num.with_scale(0).into_bigint_and_exponent();
+            Some(UntaggedValue::Primitive(Primitive::Filesize(bytes)).into_untagged_value())
+        }
         _ => None,
     }
 }
@@ -66,19 +80,30 @@ fn to_byte(value: &Value) -> Option<Value> {
 pub fn summation(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
     let sum = reducer_for(Reduce::Summation);
 
-    let first = values.get(0).ok_or_else(|| {
-        ShellError::labeled_error(
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
             "Cannot perform aggregate math operation on empty data",
             "expected input",
             name.span,
-        )
-    })?;
+        ));
+    }
 
-    match first {
-        v if v.is_filesize() => to_byte(&sum(
+    // Empty cells (eg. a size that was never computed) don't contribute to the total
+    let values = values
+        .iter()
+        .filter(|v| !v.is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if values.is_empty() {
+        return Ok(UntaggedValue::int(0).into_value(name));
+    }
+
+    // A single filesize anywhere in the column makes the total a filesize
+    if values.iter().any(|v| v.is_filesize()) {
+        to_byte(&sum(
             UntaggedValue::int(0).into_untagged_value(),
             values
-                .to_vec()
                 .iter()
                 .map(|v| match v {
                     Value {
@@ -95,24 +120,9 @@ pub fn summation(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
                 "could not convert to big decimal",
                 &name.span,
             )
-        }),
-        v if v.is_duration() => sum(UntaggedValue::int(0).into_untagged_value(), values.to_vec()),
-        // v is nothing primitive
-        v if v.is_none() => sum(
-            UntaggedValue::int(0).into_untagged_value(),
-            values
-                .to_vec()
-                .iter()
-                .map(|v| match v {
-                    Value {
-                        value: UntaggedValue::Primitive(Primitive::Nothing),
-                        ..
-                    } => UntaggedValue::int(0).into_untagged_value(),
-                    other => other.clone(),
-                })
-                .collect::<Vec<_>>(),
-        ),
-        _ => sum(UntaggedValue::int(0).into_untagged_value(), values.to_vec()),
+        })
+    } else {
+        sum(UntaggedValue::int(0).into_untagged_value(), values)
     }
 }
 
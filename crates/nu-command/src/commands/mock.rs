@@ -0,0 +1,124 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::{whole_stream_command, WholeStreamCommand};
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct Mock;
+
+#[derive(Deserialize)]
+struct MockArgs {
+    command: Tagged<String>,
+    stub: CapturedBlock,
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Mock {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mock")
+            .required("command", SyntaxShape::String, "the command to replace")
+            .required(
+                "stub",
+                SyntaxShape::Block,
+                "the block to run instead of the command",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run with the command replaced",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Runs a block with a command replaced by a stub, restoring it afterwards.
+
+The stub takes the same arguments as the command it replaces, and sees them as variables named after its parameters."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        mock(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Replace a command while the block runs",
+                example: "mock first { echo stubbed } { echo [1 2 3] | first }",
+                result: Some(vec![Value::from("stubbed")]),
+            },
+            Example {
+                description: "Test a custom command without touching the filesystem",
+                example: "def count-files [] { ls | count }; mock ls { echo [[name]; [a.txt] [b.txt]] } { count-files }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn mock(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let external_redirection = args.call_info.args.external_redirection;
+    let context = EvaluationContext::from_args(&args);
+    let (
+        MockArgs {
+            command,
+            stub,
+            mut block,
+        },
+        input,
+    ) = args.process().await?;
+
+    let replaced = match context.scope.get_command(&command.item) {
+        Some(replaced) => replaced,
+        None => {
+            return Err(ShellError::labeled_error(
+                "Can not mock a command that doesn't exist",
+                "unknown command",
+                command.tag,
+            ))
+        }
+    };
+
+    // The stub is parsed as a plain block, so it borrows the signature of the command it
+    // replaces to receive the arguments the callers pass
+    let mut stub_block = stub.block;
+    stub_block.params = replaced.signature();
+
+    block.block.set_redirect(external_redirection);
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&stub.captured.entries);
+    context.scope.add_vars(&block.captured.entries);
+    context
+        .scope
+        .add_command(command.item, whole_stream_command(stub_block));
+
+    // Collect the output while the stub is in scope, since commands in the stream might only
+    // be looked up as it is read
+    let result = match run_block(&block.block, &context, input).await {
+        Ok(mut stream) => Ok(stream.drain_vec().await),
+        Err(err) => Err(err),
+    };
+
+    context.scope.exit_scope();
+
+    Ok(futures::stream::iter(result?).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mock;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Mock {})?)
+    }
+}
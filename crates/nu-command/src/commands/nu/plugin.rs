@@ -17,6 +17,7 @@ pub struct SubCommand;
 pub struct Arguments {
     #[serde(rename = "load")]
     pub load_path: Option<Tagged<PathBuf>>,
+    pub refresh: bool,
 }
 
 #[async_trait]
@@ -26,12 +27,18 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("nu plugin").named(
-            "load",
-            SyntaxShape::FilePath,
-            "a path to load the plugins from",
-            Some('l'),
-        )
+        Signature::build("nu plugin")
+            .named(
+                "load",
+                SyntaxShape::FilePath,
+                "a path to load the plugins from",
+                Some('l'),
+            )
+            .switch(
+                "refresh",
+                "search for plugins again, asking each one for its signature instead of using the cached ones",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -39,17 +46,30 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Load all plugins in the current directory",
-            example: "nu plugin --load .",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Load all plugins in the current directory",
+                example: "nu plugin --load .",
+                result: None,
+            },
+            Example {
+                description: "Load the plugins installed or changed since nu started",
+                example: "nu plugin --refresh",
+                result: None,
+            },
+        ]
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let scope = args.scope.clone();
         let shell_manager = args.shell_manager.clone();
-        let (Arguments { load_path }, _) = args.process().await?;
+        let (Arguments { load_path, refresh }, _) = args.process().await?;
+
+        if refresh {
+            return Ok(OutputStream::one(ReturnSuccess::action(
+                CommandAction::RefreshPlugins,
+            )));
+        }
 
         if let Some(Tagged {
             item: load_path,
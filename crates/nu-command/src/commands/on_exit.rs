@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
+
+pub struct OnExit;
+
+#[derive(Deserialize)]
+pub struct OnExitArgs {
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for OnExit {
+    fn name(&self) -> &str {
+        "on-exit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("on-exit").required(
+            "block",
+            SyntaxShape::Block,
+            "the block to run when the session ends",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Run a block when the session ends.
+
+The block runs once, when the shell or script exits, whether with `exit`, Ctrl-D, Ctrl-C or an
+error, and when nu is killed or the terminal is closed. Setting another block replaces the previous one."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        on_exit(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove a scratch directory when the session ends",
+            example: "on-exit { rm -r scratch }",
+            result: None,
+        }]
+    }
+}
+
+pub async fn on_exit(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let ctx = EvaluationContext::from_args(&args);
+    let (OnExitArgs { block }, _) = args.process().await?;
+
+    ctx.scope.add_signal_handler("EXIT", block);
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnExit;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(OnExit {})?)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct OnSignal;
+
+#[derive(Deserialize)]
+pub struct OnSignalArgs {
+    signal: Tagged<String>,
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for OnSignal {
+    fn name(&self) -> &str {
+        "on-signal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("on-signal")
+            .required(
+                "signal",
+                SyntaxShape::String,
+                "the signal to handle (only INT is supported)",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run when the signal is received",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Run a block when a script is interrupted.
+
+When a script is stopped with Ctrl-C, the running pipeline (and any external
+command it started) is interrupted, the block is run, and nu exits with status 130."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        on_signal(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove a temporary file if the script is interrupted",
+            example: "on-signal INT { rm scratch.tmp }",
+            result: None,
+        }]
+    }
+}
+
+pub async fn on_signal(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let ctx = EvaluationContext::from_args(&args);
+    let (OnSignalArgs { signal, block }, _) = args.process().await?;
+
+    let name = signal.item.to_uppercase();
+    let name = name.strip_prefix("SIG").unwrap_or(&name);
+
+    if name != "INT" {
+        return Err(ShellError::labeled_error(
+            "Unsupported signal",
+            "only INT can be handled",
+            signal.tag,
+        ));
+    }
+
+    ctx.scope.add_signal_handler(name, block);
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnSignal;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(OnSignal {})?)
+    }
+}
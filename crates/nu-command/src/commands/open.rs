@@ -1,5 +1,6 @@
 use crate::commands::constants::BAT_LANGUAGES;
 use crate::prelude::*;
+use crate::utils::format_metadata;
 use encoding_rs::{Encoding, UTF_8};
 use futures_util::StreamExt;
 use log::debug;
@@ -8,7 +9,7 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::{AnchorLocation, Span, Tagged};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct Open;
 
@@ -17,6 +18,7 @@ pub struct OpenArgs {
     path: Tagged<PathBuf>,
     raw: Tagged<bool>,
     encoding: Option<Tagged<String>>,
+    with_format_metadata: bool,
 }
 
 #[async_trait]
@@ -43,6 +45,11 @@ impl WholeStreamCommand for Open {
                 "encoding to use to open file",
                 Some('e'),
             )
+            .switch(
+                "with-format-metadata",
+                "remember the comments, ordering and indentation of TOML, YAML and JSON files, so saving edits keeps them",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -72,6 +79,11 @@ documentation link at https://docs.rs/encoding_rs/0.8.23/encoding_rs/#statics"#
                 example: "open file.csv --encoding iso-8859-1 | from csv",
                 result: None,
             },
+            Example {
+                description: "Changes a value in a file without losing its comments",
+                example: "open Cargo.toml --with-format-metadata | update package.version 0.2.0 | save Cargo.toml",
+                result: None,
+            },
         ]
     }
 }
@@ -103,6 +115,7 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
             path,
             raw,
             encoding,
+            with_format_metadata,
         },
         _,
     ) = args.process().await?;
@@ -113,12 +126,7 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     // Check if the extension has a "from *" command OR "bat" supports syntax highlighting
     // AND the user doesn't want the raw output
     // In these cases, we will collect the Stream
-    let ext = if raw.item {
-        None
-    } else {
-        path.extension()
-            .map(|name| name.to_string_lossy().to_string())
-    };
+    let ext = if raw.item { None } else { format_of(&path) };
 
     if let Some(ext) = ext {
         // Check if we have a conversion command
@@ -130,6 +138,14 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 encoding,
             )
             .await?;
+
+            if let Some(AnchorLocation::File(file)) = tagged_contents.tag.anchor() {
+                match tagged_contents.as_string() {
+                    Ok(text) if with_format_metadata => format_metadata::remember(&file, &text),
+                    _ => format_metadata::forget(&file),
+                }
+            }
+
             return Ok(OutputStream::one(ReturnSuccess::action(
                 CommandAction::AutoConvert(tagged_contents, ext),
             )));
@@ -177,6 +193,18 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     Ok(OutputStream::new(final_stream))
 }
 
+/// The format a file is in, for its "from" command. This is its extension, except for .env files,
+/// which are often named like `.env.local` after what they are used for.
+fn format_of(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_string_lossy();
+    if name == ".env" || name.starts_with(".env.") {
+        return Some("dotenv".to_string());
+    }
+
+    path.extension()
+        .map(|name| name.to_string_lossy().to_string())
+}
+
 // Note that we do not output a Stream in "fetch" since it is only used by "enter" command
 // Which we expect to use a concrete Value a not a Stream
 pub async fn fetch(
@@ -205,9 +233,7 @@ pub async fn fetch(
     })?;
 
     // The extension may be used in AutoConvert later on
-    let ext = location
-        .extension()
-        .map(|name| name.to_string_lossy().to_string());
+    let ext = format_of(&location);
 
     // The tag that will used when returning a Value
     let file_tag = Tag {
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use nu_engine::shell::CdArgs;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+use nu_source::TaggedItem;
+use std::path::PathBuf;
+
+pub struct Popd;
+
+#[async_trait]
+impl WholeStreamCommand for Popd {
+    fn name(&self) -> &str {
+        "popd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("popd")
+    }
+
+    fn usage(&self) -> &str {
+        "Go back to the path left with the last pushd."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let name = args.call_info.name_tag.clone();
+        let shell_manager = args.shell_manager.clone();
+
+        let previous = shell_manager.directory_stack.lock().last().cloned();
+        let previous = previous.ok_or_else(|| {
+            ShellError::labeled_error(
+                "The directory stack is empty",
+                "no path was left with pushd",
+                &name,
+            )
+        })?;
+
+        let args = CdArgs {
+            path: Some(PathBuf::from(previous).tagged(&name)),
+        };
+        // The path stays on the stack if it can't be changed to, like when it was removed
+        let output = shell_manager.cd(args, name)?;
+        shell_manager.directory_stack.lock().pop();
+
+        Ok(output)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Go back to where you were before pushd",
+            example: "popd",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Popd;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Popd {})?)
+    }
+}
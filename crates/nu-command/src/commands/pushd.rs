@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use nu_engine::shell::CdArgs;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+
+pub struct Pushd;
+
+#[async_trait]
+impl WholeStreamCommand for Pushd {
+    fn name(&self) -> &str {
+        "pushd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pushd").required(
+            "directory",
+            SyntaxShape::FilePath,
+            "the directory to change to",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Change to a new path, remembering the current one for popd to go back to."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let name = args.call_info.name_tag.clone();
+        let shell_manager = args.shell_manager.clone();
+        let (args, _): (CdArgs, _) = args.process().await?;
+
+        let current = shell_manager.path();
+        let output = shell_manager.cd(args, name)?;
+        shell_manager.directory_stack.lock().push(current);
+
+        Ok(output)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Work in the 'src' directory, then go back with popd",
+            example: "pushd src",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pushd;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Pushd {})?)
+    }
+}
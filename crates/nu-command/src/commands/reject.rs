@@ -1,13 +1,17 @@
 use crate::prelude::*;
+use crate::utils::suggestions::suggestions;
 use nu_data::base::reject_fields;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{
+    ColumnPath, PathMember, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{HasFallibleSpan, Tagged};
+use nu_value_ext::ValueExt;
 
 #[derive(Deserialize)]
 pub struct RejectArgs {
-    rest: Vec<Tagged<String>>,
+    rest: Vec<ColumnPath>,
 }
 
 pub struct Reject;
@@ -19,7 +23,7 @@ impl WholeStreamCommand for Reject {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("reject").rest(SyntaxShape::String, "the names of columns to remove")
+        Signature::build("reject").rest(SyntaxShape::ColumnPath, "the names of columns to remove")
     }
 
     fn usage(&self) -> &str {
@@ -31,17 +35,35 @@ impl WholeStreamCommand for Reject {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Lists the files in a directory without showing the modified column",
-            example: "ls | reject modified",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Lists the files in a directory without showing the modified column",
+                example: "ls | reject modified",
+                result: None,
+            },
+            Example {
+                description: "Remove a nested column",
+                example: "echo [[name, meta]; [nu, [[version, license]; [0.26, MIT]]]] | reject meta.license | get meta.0 | get version",
+                result: None,
+            },
+        ]
     }
 }
 
+/// What `reject` keeps between rows: the columns not found in any row yet, and the first row, to
+/// suggest the columns it has.
+struct Rejecting {
+    input: InputStream,
+    fields: Vec<ColumnPath>,
+    missing: Vec<ColumnPath>,
+    first: Value,
+    name: Tag,
+    done: bool,
+}
+
 async fn reject(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (RejectArgs { rest: fields }, input) = args.process().await?;
+    let (RejectArgs { rest: fields }, mut input) = args.process().await?;
     if fields.is_empty() {
         return Err(ShellError::labeled_error(
             "Reject requires fields",
@@ -50,11 +72,110 @@ async fn reject(args: CommandArgs) -> Result<OutputStream, ShellError> {
         ));
     }
 
-    let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
+    let first = match input.next().await {
+        Some(first) => first,
+        None => return Ok(OutputStream::empty()),
+    };
+
+    // A column missing from the first row may be in the rows after it, so the error for one in no
+    // row at all comes after them
+    let state = Rejecting {
+        input: InputStream::from_stream(futures::stream::iter(vec![first.clone()]).chain(input)),
+        missing: fields.clone(),
+        fields,
+        first,
+        name,
+        done: false,
+    };
+
+    Ok(futures::stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+
+        match state.input.next().await {
+            Some(row) => {
+                state.missing.retain(|field| !has_column(&row, field));
+
+                let mut rejected = Ok(row);
+                for field in &state.fields {
+                    rejected = rejected.and_then(|row| reject_column_path(&row, field));
+                }
+
+                Some((rejected.and_then(ReturnSuccess::value), state))
+            }
+            None => {
+                let error = unknown_column(state.missing.first()?, &state.first, &state.name);
+                state.done = true;
+
+                Some((Err(error), state))
+            }
+        }
+    })
+    .to_output_stream())
+}
+
+fn has_column(row: &Value, field: &ColumnPath) -> bool {
+    row.get_data_by_column_path(field, Box::new(|_, _, error| error))
+        .is_ok()
+}
+
+fn unknown_column(field: &ColumnPath, first: &Value, name: &Tag) -> ShellError {
+    let tried = field
+        .iter()
+        .map(PathMember::as_string)
+        .collect::<Vec<_>>()
+        .join(".");
+    let span = field.maybe_span().unwrap_or_else(|| name.span);
+
+    suggestions(
+        Tagged {
+            item: tried.as_str(),
+            tag: span.into(),
+        },
+        first,
+    )
+}
+
+fn reject_column_path(value: &Value, path: &ColumnPath) -> Result<Value, ShellError> {
+    let (column, parent) = match path.split_last() {
+        Some(split) => split,
+        None => return Ok(value.clone()),
+    };
+
+    let column = column.as_string();
+
+    if parent.is_empty() {
+        return Ok(reject_column(value, &column));
+    }
+
+    let parent = ColumnPath::new(parent.to_vec());
+
+    if value
+        .get_data_by_column_path(&parent, Box::new(|_, _, error| error))
+        .is_err()
+    {
+        return Ok(value.clone());
+    }
+
+    value.swap_data_by_column_path(
+        &parent,
+        Box::new(move |nested| Ok(reject_column(nested, &column))),
+    )
+}
 
-    Ok(input
-        .map(move |item| ReturnSuccess::value(reject_fields(&item, &fields, &item.tag)))
-        .to_output_stream())
+fn reject_column(value: &Value, column: &str) -> Value {
+    match &value.value {
+        UntaggedValue::Row(_) => reject_fields(value, &[column.to_string()], &value.tag),
+        UntaggedValue::Table(rows) => UntaggedValue::table(
+            &rows
+                .iter()
+                .map(|row| reject_column(row, column))
+                .collect::<Vec<_>>(),
+        )
+        .into_value(&value.tag),
+        _ => value.clone(),
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use crate::commands::str_::case::{case_converter, rename_columns};
 use crate::prelude::*;
 use indexmap::indexmap;
 use nu_engine::WholeStreamCommand;
@@ -9,8 +10,9 @@ pub struct Rename;
 
 #[derive(Deserialize)]
 pub struct Arguments {
-    column_name: Tagged<String>,
+    column_name: Option<Tagged<String>>,
     rest: Vec<Tagged<String>>,
+    case: Option<Tagged<String>>,
 }
 
 #[async_trait]
@@ -21,12 +23,18 @@ impl WholeStreamCommand for Rename {
 
     fn signature(&self) -> Signature {
         Signature::build("rename")
-            .required(
+            .optional(
                 "column_name",
                 SyntaxShape::String,
                 "the new name for the first column",
             )
             .rest(SyntaxShape::String, "the new name for additional columns")
+            .named(
+                "case",
+                SyntaxShape::String,
+                "convert every column name to a case: camel, kebab, pascal, screaming-snake, snake or title",
+                Some('c'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -58,13 +66,54 @@ impl WholeStreamCommand for Rename {
                 })
                 .into()]),
             },
+            Example {
+                description: "Convert every column name to snake_case",
+                example: "echo [[FirstName, lastName]; [Andrés, Robalino]] | rename --case snake",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "first_name".to_string() => UntaggedValue::string("Andrés").into(),
+                        "last_name".to_string() => UntaggedValue::string("Robalino").into(),
+                })
+                .into()]),
+            },
         ]
     }
 }
 
 pub async fn rename(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (Arguments { column_name, rest }, input) = args.process().await?;
+    let (
+        Arguments {
+            column_name,
+            rest,
+            case,
+        },
+        input,
+    ) = args.process().await?;
+
+    if let Some(case) = case {
+        let case_operation = case_converter(&case.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unknown case",
+                "expected camel, kebab, pascal, screaming-snake, snake or title",
+                &case.tag,
+            )
+        })?;
+
+        return Ok(input
+            .map(move |item| {
+                ReturnSuccess::value(rename_columns(&item, item.tag(), &case_operation)?)
+            })
+            .to_output_stream());
+    }
+
+    let column_name = column_name.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected new column names",
+            "requires column names or --case",
+            &name,
+        )
+    })?;
+
     let mut new_column_names = vec![vec![column_name]];
     new_column_names.push(rest);
 
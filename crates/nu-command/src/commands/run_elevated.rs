@@ -0,0 +1,121 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
+use std::process::{Command, ExitStatus};
+
+pub struct RunElevated;
+
+#[derive(Deserialize)]
+struct RunElevatedArgs {
+    block: CapturedBlock,
+}
+
+#[async_trait]
+impl WholeStreamCommand for RunElevated {
+    fn name(&self) -> &str {
+        "run-elevated"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("run-elevated").required(
+            "block",
+            SyntaxShape::Block,
+            "the block to run as an administrator",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a block in a new nu with administrator rights, through sudo or the UAC prompt on Windows"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        run_elevated(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Add a line to the hosts file",
+            example: r#"run-elevated { echo "127.0.0.1 nu.local" | save --append /etc/hosts }"#,
+            result: None,
+        }]
+    }
+}
+
+async fn run_elevated(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (RunElevatedArgs { block }, _) = args.process().await?;
+
+    // The block runs in another nu, which only gets its source
+    if let Some((variable, _)) = block.captured.entries.iter().next() {
+        return Err(ShellError::labeled_error(
+            "Can't run a block using variables elevated",
+            format!("{} isn't known to the elevated nu", variable),
+            block.block.span,
+        ));
+    }
+
+    let source = match &block.block.source {
+        Some(source) => source.trim(),
+        None => {
+            return Err(ShellError::labeled_error(
+                "Can't run the block elevated",
+                "its source isn't known",
+                block.block.span,
+            ))
+        }
+    };
+    let source = source
+        .strip_prefix('{')
+        .and_then(|source| source.strip_suffix('}'))
+        .unwrap_or(source);
+
+    let nu = std::env::current_exe()?;
+    let status = elevate(&nu.to_string_lossy(), source).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not run the block elevated ({})", e),
+            "could not elevate",
+            &name,
+        )
+    })?;
+
+    if status.success() {
+        Ok(OutputStream::empty())
+    } else {
+        Err(ShellError::labeled_error(
+            "The elevated block failed",
+            match status.code() {
+                Some(code) => format!("exited with code {}", code),
+                None => "was stopped by a signal".to_string(),
+            },
+            &name,
+        ))
+    }
+}
+
+/// Runs nu through sudo, which shares this terminal, so the block can prompt and be interrupted
+/// like any other command.
+#[cfg(not(windows))]
+fn elevate(nu: &str, source: &str) -> std::io::Result<ExitStatus> {
+    Command::new("sudo").arg(nu).arg("-c").arg(source).status()
+}
+
+/// Runs nu through PowerShell's `Start-Process -Verb RunAs`, which shows the UAC prompt. Elevated
+/// programs get a console of their own, so the block's output shows there rather than here.
+#[cfg(windows)]
+fn elevate(nu: &str, source: &str) -> std::io::Result<ExitStatus> {
+    let quote = |text: &str| format!("'{}'", text.replace('\'', "''"));
+    // Start-Process joins its arguments with spaces, leaving the quoting of the block to us
+    let block = format!("\"{}\"", source.replace('"', "\\\""));
+
+    let script = format!(
+        "$p = Start-Process -FilePath {} -ArgumentList '-c',{} -Verb RunAs -Wait -PassThru; exit $p.ExitCode",
+        quote(nu),
+        quote(&block)
+    );
+
+    Command::new("powershell")
+        .args(&["-NoLogo", "-NoProfile", "-NonInteractive", "-Command"])
+        .arg(script)
+        .status()
+}
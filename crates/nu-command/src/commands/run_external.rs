@@ -6,9 +6,11 @@ use parking_lot::Mutex;
 use std::path::PathBuf;
 
 use nu_engine::shell::CdArgs;
-use nu_engine::WholeStreamCommand;
+use nu_engine::{evaluate_baseline_expr, WholeStreamCommand};
 use nu_errors::ShellError;
-use nu_protocol::hir::{Expression, ExternalArgs, ExternalCommand, Literal, SpannedExpression};
+use nu_protocol::hir::{
+    Expression, ExternalArgs, ExternalCommand, Literal, NamedValue, SpannedExpression,
+};
 use nu_protocol::{Signature, SyntaxShape};
 use nu_source::Tagged;
 
@@ -87,12 +89,28 @@ impl WholeStreamCommand for RunExternalCommand {
                 shell_manager: args.shell_manager.clone(),
                 ctrl_c: args.ctrl_c.clone(),
                 current_errors: Arc::new(Mutex::new(vec![])),
-                windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
             }
         };
 
         let is_interactive = self.interactive;
 
+        // Set by the parser for `err>>` redirections
+        let stderr_append = match args
+            .call_info
+            .args
+            .named
+            .as_ref()
+            .and_then(|named| named.get("append-stderr"))
+        {
+            Some(NamedValue::Value(_, target)) => {
+                let target = evaluate_baseline_expr(target, &external_context).await?;
+                let mut path = PathBuf::from(args.shell_manager.path());
+                path.push(target.as_path()?);
+                Some(path)
+            }
+            _ => None,
+        };
+
         let command = ExternalCommand {
             name,
             name_tag: args.call_info.name_tag.clone(),
@@ -105,7 +123,7 @@ impl WholeStreamCommand for RunExternalCommand {
         // If we're in interactive mode, we will "auto cd". That is, instead of interpreting
         // this as an external command, we will see it as a path and `cd` into it.
         if is_interactive {
-            if let Some(path) = maybe_autocd_dir(&command, &mut external_context).await {
+            if let Some(path) = maybe_autocd_dir(&command).await {
                 let cd_args = CdArgs {
                     path: Some(Tagged {
                         item: PathBuf::from(path),
@@ -127,6 +145,7 @@ impl WholeStreamCommand for RunExternalCommand {
             &mut external_context,
             input,
             external_redirection,
+            stderr_append,
         )
         .await;
 
@@ -134,11 +153,7 @@ impl WholeStreamCommand for RunExternalCommand {
     }
 }
 
-#[allow(unused_variables)]
-async fn maybe_autocd_dir<'a>(
-    cmd: &ExternalCommand,
-    ctx: &mut EvaluationContext,
-) -> Option<String> {
+async fn maybe_autocd_dir(cmd: &ExternalCommand) -> Option<String> {
     // We will "auto cd" if
     //   - the command name ends in a path separator, or
     //   - it's not a command on the path and no arguments were given.
@@ -154,36 +169,6 @@ async fn maybe_autocd_dir<'a>(
         None
     };
 
-    path_name.map(|name| {
-        #[cfg(windows)]
-        {
-            if name.ends_with(':') {
-                // This looks like a drive shortcut. We need to a) switch drives and b) go back to the previous directory we were viewing on that drive
-                // But first, we need to save where we are now
-                let current_path = ctx.shell_manager.path();
-
-                let split_path: Vec<_> = current_path.split(':').collect();
-                if split_path.len() > 1 {
-                    ctx.windows_drives_previous_cwd
-                        .lock()
-                        .insert(split_path[0].to_string(), current_path);
-                }
-
-                let name = name.to_uppercase();
-                let new_drive: Vec<_> = name.split(':').collect();
-
-                if let Some(val) = ctx.windows_drives_previous_cwd.lock().get(new_drive[0]) {
-                    val.to_string()
-                } else {
-                    name
-                }
-            } else {
-                name.to_string()
-            }
-        }
-        #[cfg(not(windows))]
-        {
-            name.to_string()
-        }
-    })
+    // Drives like `D:` are resolved by the shell, which knows where it was last on each of them
+    path_name.map(|name| name.to_string())
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::format_metadata;
 use nu_engine::{UnevaluatedCallInfo, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::{
@@ -130,6 +131,7 @@ macro_rules! process_binary_return_success {
 pub struct SaveArgs {
     path: Option<Tagged<PathBuf>>,
     raw: bool,
+    append: bool,
 }
 
 #[async_trait]
@@ -150,6 +152,11 @@ impl WholeStreamCommand for Save {
                 "treat values as-is rather than auto-converting based on file extension",
                 Some('r'),
             )
+            .switch(
+                "append",
+                "append to the end of the file instead of overwriting it",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -176,6 +183,7 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         SaveArgs {
             path,
             raw: save_raw,
+            append,
         },
         input,
     ) = raw_args.process().await?;
@@ -206,6 +214,13 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         full_path.push(file.item());
     }
 
+    // Files opened with their format metadata only have the values that changed rewritten
+    if !save_raw && !append && input.len() == 1 {
+        if let Some(text) = format_metadata::patch_original(&input[0], &full_path) {
+            return shell_manager.save(&full_path, text.as_bytes(), name.span, append);
+        }
+    }
+
     // TODO use label_break_value once it is stable:
     // https://github.com/rust-lang/rust/issues/48594
     #[allow(clippy::never_loop)]
@@ -245,12 +260,15 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
             } else {
                 process_unknown!('scope, input, name_tag)
             }
+        } else if append {
+            // Appended streams (such as `out>>` redirections) are written chunk after chunk
+            process_unknown!('scope, input, name_tag)
         } else {
             Ok(string_from(&input).into_bytes())
         };
     };
 
-    shell_manager.save(&full_path, &content?, name.span)
+    shell_manager.save(&full_path, &content?, name.span, append)
 }
 
 fn string_from(input: &[Value]) -> String {
@@ -0,0 +1,131 @@
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, Primitive, ReturnSuccess, ShellTypeName, Signature, TaggedDictBuilder,
+    UntaggedValue, Value,
+};
+
+/// How many different values of a column `schema` shows.
+const SAMPLES: usize = 3;
+
+pub struct Schema;
+
+#[async_trait]
+impl WholeStreamCommand for Schema {
+    fn name(&self) -> &str {
+        "schema"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("schema")
+    }
+
+    fn usage(&self) -> &str {
+        r#"Summarizes the columns of a table: the types of their values, how many are empty, and a few samples.
+
+The output can be given to `validate` to check that other tables have the same shape."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        schema(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Summarize the columns of a table",
+                example: "ls | schema",
+                result: None,
+            },
+            Example {
+                description: "Find the columns that have empty cells",
+                example: "open data.csv | schema | where nulls > 0 | get column",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn schema(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let tag = args.call_info.name_tag.clone();
+    let rows: Vec<Value> = args.input.collect().await;
+
+    let columns = merge_descriptors(&rows);
+    if columns.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Schema needs a table with columns",
+            "no columns in the input",
+            &tag,
+        ));
+    }
+
+    let summaries = columns
+        .iter()
+        .map(|column| summarize(column, &rows, &tag))
+        .collect::<Vec<_>>();
+
+    Ok(futures::stream::iter(summaries.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+fn summarize(column: &str, rows: &[Value], tag: &Tag) -> Value {
+    let mut types: IndexMap<&str, i64> = IndexMap::new();
+    let mut nulls = 0;
+    let mut samples: Vec<Value> = vec![];
+
+    for row in rows {
+        match row.get_data_by_key(column.spanned_unknown()) {
+            Some(value) if !is_null(&value) => {
+                *types.entry(column_type(&value)).or_insert(0) += 1;
+
+                if samples.len() < SAMPLES
+                    && !samples.iter().any(|sample| sample.value == value.value)
+                {
+                    samples.push(value);
+                }
+            }
+            _ => nulls += 1,
+        }
+    }
+
+    let mut type_counts = TaggedDictBuilder::new(tag);
+    for (name, count) in types {
+        type_counts.insert_untagged(name, UntaggedValue::int(count));
+    }
+
+    let mut summary = TaggedDictBuilder::new(tag);
+    summary.insert_untagged("column", UntaggedValue::string(column));
+    summary.insert_value("types", type_counts.into_value());
+    summary.insert_untagged("nulls", UntaggedValue::int(nulls));
+    summary.insert_value("samples", UntaggedValue::table(&samples).into_value(tag));
+
+    summary.into_value()
+}
+
+/// Whether a cell counts as empty.
+pub(crate) fn is_null(value: &Value) -> bool {
+    matches!(value.value, UntaggedValue::Primitive(Primitive::Nothing))
+}
+
+/// The name `schema` and `validate` use for the type of a cell.
+pub(crate) fn column_type(value: &Value) -> &'static str {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Filesize(_)) => "filesize",
+        UntaggedValue::Primitive(Primitive::FilePath(_)) => "path",
+        other => other.type_name(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Schema;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Schema {})?)
+    }
+}
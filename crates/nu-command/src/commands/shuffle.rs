@@ -1,19 +1,35 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
 
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, SeedableRng};
 
 pub struct Shuffle;
 
+#[derive(Deserialize)]
+pub struct ShuffleArgs {
+    seed: Option<Tagged<u64>>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Shuffle {
     fn name(&self) -> &str {
         "shuffle"
     }
 
+    fn signature(&self) -> Signature {
+        Signature::build("shuffle").named(
+            "seed",
+            SyntaxShape::Int,
+            "shuffle in the same order every time for the same seed",
+            Some('s'),
+        )
+    }
+
     fn usage(&self) -> &str {
         "Shuffle rows randomly."
     }
@@ -21,13 +37,31 @@ impl WholeStreamCommand for Shuffle {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         shuffle(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Shuffle rows randomly",
+                example: "echo [a b c d] | shuffle",
+                result: None,
+            },
+            Example {
+                description: "Shuffle rows the same way each time",
+                example: "echo [a b c d] | shuffle --seed 42",
+                result: None,
+            },
+        ]
+    }
 }
 
 async fn shuffle(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let input = args.input;
+    let (ShuffleArgs { seed }, input) = args.process().await?;
     let mut values: Vec<Value> = input.collect().await;
 
-    values.shuffle(&mut thread_rng());
+    match seed {
+        Some(seed) => values.shuffle(&mut StdRng::seed_from_u64(seed.item)),
+        None => values.shuffle(&mut thread_rng()),
+    }
 
     Ok(futures::stream::iter(values.into_iter().map(ReturnSuccess::value)).to_output_stream())
 }
@@ -1,10 +1,12 @@
 use crate::prelude::*;
-use nu_data::base::coerce_compare;
+use feruca::Collator;
+use nu_data::base::total_compare;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 use nu_value_ext::ValueExt;
+use std::cmp::Ordering;
 
 pub struct SortBy;
 
@@ -13,6 +15,8 @@ pub struct SortByArgs {
     rest: Vec<Tagged<String>>,
     insensitive: bool,
     reverse: bool,
+    collate: bool,
+    version: bool,
 }
 
 #[async_trait]
@@ -29,6 +33,16 @@ impl WholeStreamCommand for SortBy {
                 Some('i'),
             )
             .switch("reverse", "Sort in reverse order", Some('r'))
+            .switch(
+                "collate",
+                "Sort strings by the Unicode Collation Algorithm, by their letters before their accents and case",
+                Some('c'),
+            )
+            .switch(
+                "version",
+                "Sort strings as version numbers, so that 1.10 comes after 1.9",
+                Some('v'),
+            )
             .rest(SyntaxShape::String, "the column(s) to sort by")
     }
 
@@ -99,6 +113,24 @@ impl WholeStreamCommand for SortBy {
                     UntaggedValue::string("Truck").into(),
                 ]),
             },
+            Example {
+                description: "Sort strings with accents by the Unicode Collation Algorithm",
+                example: "echo [zebra Émile eagle] | sort-by -c",
+                result: Some(vec![
+                    UntaggedValue::string("eagle").into(),
+                    UntaggedValue::string("Émile").into(),
+                    UntaggedValue::string("zebra").into(),
+                ]),
+            },
+            Example {
+                description: "Sort version numbers and release tags",
+                example: "echo [v1.10.0 v1.9.2 v1.10.0-rc.1] | sort-by -v",
+                result: Some(vec![
+                    UntaggedValue::string("v1.9.2").into(),
+                    UntaggedValue::string("v1.10.0-rc.1").into(),
+                    UntaggedValue::string("v1.10.0").into(),
+                ]),
+            },
             Example {
                 description: "Sort strings (reversed case-insensitive)",
                 example: "echo [airplane Truck Car] | sort-by -i -r",
@@ -120,27 +152,64 @@ async fn sort_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
             rest,
             insensitive,
             reverse,
+            collate,
+            version,
         },
         mut input,
     ) = args.process().await?;
     let mut vec = input.drain_vec().await;
 
-    sort(&mut vec, &rest, &tag, insensitive)?;
-
-    if reverse {
-        vec.reverse()
-    }
+    let options = SortOptions {
+        insensitive,
+        collate: collate || collation_configured(),
+        version,
+        reverse,
+    };
+    sort(&mut vec, &rest, &tag, options)?;
 
     Ok(futures::stream::iter(vec.into_iter()).to_output_stream())
 }
 
+/// How `sort` compares values. The default compares them by their total order, increasing.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SortOptions {
+    /// Compare strings ignoring their case.
+    pub insensitive: bool,
+    /// Compare strings by the Unicode Collation Algorithm.
+    pub collate: bool,
+    /// Compare strings as version numbers.
+    pub version: bool,
+    /// Sort in decreasing order.
+    pub reverse: bool,
+}
+
+/// Sorts the values in place. The sort is stable, in reverse too: values comparing equal keep
+/// the order they came in. Values of different types are sorted by type, as `total_compare` does.
 pub fn sort(
     vec: &mut [Value],
     keys: &[Tagged<String>],
     tag: impl Into<Tag>,
-    insensitive: bool,
+    options: SortOptions,
 ) -> Result<(), ShellError> {
+    let SortOptions {
+        insensitive,
+        collate,
+        version,
+        reverse,
+    } = options;
     let tag = tag.into();
+    let mut collator = if collate {
+        Some(Collator::default())
+    } else {
+        None
+    };
+    let in_order = |ordering: Ordering| {
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
 
     if vec.is_empty() {
         return Err(ShellError::labeled_error(
@@ -151,8 +220,10 @@ pub fn sort(
     }
 
     for sort_arg in keys.iter() {
-        let match_test = &vec[0].get_data_by_key(sort_arg.borrow_spanned());
-        if match_test.is_none() {
+        let found = vec
+            .iter()
+            .any(|item| item.get_data_by_key(sort_arg.borrow_spanned()).is_some());
+        if !found {
             return Err(ShellError::labeled_error(
                 "Can not find column to sort by",
                 "invalid column",
@@ -167,34 +238,24 @@ pub fn sort(
             ..
         } => {
             let should_sort_case_insensitively = insensitive && vec.iter().all(|x| x.is_string());
-
-            if let Some(values) = vec
-                .windows(2)
-                .map(|elem| coerce_compare(&elem[0], &elem[1]))
-                .find(|elem| elem.is_err())
-            {
-                let (type_1, type_2) = values
-                    .err()
-                    .expect("An error occurred in the checking of types");
-                return Err(ShellError::labeled_error(
-                    "Not all values can be compared",
-                    format!(
-                        "Unable to sort values, as \"{}\" cannot compare against \"{}\"",
-                        type_1, type_2
-                    ),
-                    tag,
-                ));
-            }
+            let mut collator = collator
+                .as_mut()
+                .filter(|_| vec.iter().all(|x| x.is_string()));
+            let should_sort_versions = version && vec.iter().all(|x| x.is_string());
 
             vec.sort_by(|a, b| {
-                if should_sort_case_insensitively {
+                in_order(if should_sort_versions {
+                    compare_versions(&a.expect_string(), &b.expect_string())
+                } else if let Some(collator) = collator.as_deref_mut() {
+                    collator.collate(a.expect_string(), b.expect_string())
+                } else if should_sort_case_insensitively {
                     let lowercase_a_string = a.expect_string().to_ascii_lowercase();
                     let lowercase_b_string = b.expect_string().to_ascii_lowercase();
 
                     lowercase_a_string.cmp(&lowercase_b_string)
                 } else {
-                    coerce_compare(a, b).expect("Unimplemented BUG: What about primitives that don't have an order defined?").compare()
-                }
+                    total_compare(a, b)
+                })
             });
         }
         _ => {
@@ -218,17 +279,134 @@ pub fn sort(
                     })
                     .collect::<Vec<Option<Value>>>()
             };
-            vec.sort_by_cached_key(calc_key);
+
+            // Compute every key up front so the (stable) sort doesn't look them up repeatedly
+            let mut keyed = vec
+                .iter()
+                .map(|item| (calc_key(item), item.clone()))
+                .collect::<Vec<_>>();
+            keyed.sort_by(|(left, _), (right, _)| {
+                in_order(compare_keys(left, right, collator.as_mut(), version))
+            });
+
+            for (slot, (_, item)) in vec.iter_mut().zip(keyed) {
+                *slot = item;
+            }
         }
     };
 
     Ok(())
 }
 
+/// Compares sort keys column by column. Rows missing a column sort after the rows that have it.
+fn compare_keys(
+    left: &[Option<Value>],
+    right: &[Option<Value>],
+    mut collator: Option<&mut Collator>,
+    version: bool,
+) -> Ordering {
+    for (left, right) in left.iter().zip(right.iter()) {
+        let ordering = match (left, right, collator.as_deref_mut()) {
+            (Some(left), Some(right), _) if version && left.is_string() && right.is_string() => {
+                compare_versions(&left.expect_string(), &right.expect_string())
+            }
+            (Some(left), Some(right), Some(collator)) if left.is_string() && right.is_string() => {
+                collator.collate(left.expect_string(), right.expect_string())
+            }
+            (Some(left), Some(right), _) => total_compare(&left.value, &right.value),
+            (Some(_), None, _) => Ordering::Less,
+            (None, Some(_), _) => Ordering::Greater,
+            (None, None, _) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Whether the config asks for strings to be sorted by the Unicode Collation Algorithm.
+fn collation_configured() -> bool {
+    nu_data::config::config(Tag::unknown())
+        .ok()
+        .and_then(|cfg| {
+            cfg.get("sort_collation")
+                .map(|collation| collation.as_string())
+        })
+        .and_then(|collation| collation.ok())
+        .map(|collation| collation == "unicode")
+        .unwrap_or(false)
+}
+
+/// Compares version numbers like `1.10.0`, `v2.0` or `1.0.0-rc.1` part by part, numbers by their
+/// value, the way semver orders them: a pre-release comes before its release, and build metadata
+/// after a `+` is ignored. Parts that aren't numbers are compared as strings, after numbers.
+pub fn compare_versions(left: &str, right: &str) -> Ordering {
+    let (left_release, left_pre) = split_version(left);
+    let (right_release, right_pre) = split_version(right);
+
+    let release = compare_parts(
+        &pad_release(&left_release, right_release.len()),
+        &pad_release(&right_release, left_release.len()),
+    );
+
+    release.then_with(|| match (left_pre, right_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(left), Some(right)) => compare_parts(&left, &right),
+    })
+}
+
+/// The dotted parts of a version's release and pre-release, without a leading `v`.
+fn split_version(version: &str) -> (Vec<&str>, Option<Vec<&str>>) {
+    let version = version.trim();
+    let version = match version.strip_prefix(|c: char| c == 'v' || c == 'V') {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => version,
+    };
+    let version = version.split('+').next().unwrap_or_default();
+
+    match version.find('-') {
+        Some(index) => (
+            version[..index].split('.').collect(),
+            Some(version[index + 1..].split('.').collect()),
+        ),
+        None => (version.split('.').collect(), None),
+    }
+}
+
+/// A missing release part counts as zero, so `1.2` and `1.2.0` are the same version.
+fn pad_release<'a>(parts: &[&'a str], len: usize) -> Vec<&'a str> {
+    let mut padded = parts.to_vec();
+    padded.resize(len.max(parts.len()), "0");
+    padded
+}
+
+fn compare_parts(left: &[&str], right: &[&str]) -> Ordering {
+    for (left, right) in left.iter().zip(right.iter()) {
+        let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+            (Ok(left), Ok(right)) => left.cmp(&right),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => left.cmp(right),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    left.len().cmp(&right.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::ShellError;
-    use super::SortBy;
+    use super::{compare_versions, SortBy};
+    use std::cmp::Ordering;
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -236,4 +414,30 @@ mod tests {
 
         Ok(test_examples(SortBy {})?)
     }
+
+    #[test]
+    fn versions_compare_by_their_numbers() {
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("v2.0.0", "1.99.99"), Ordering::Greater);
+        assert_eq!(compare_versions("1.2", "1.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.2.0+build.5", "1.2.0"), Ordering::Equal);
+    }
+
+    #[test]
+    fn pre_releases_come_before_their_release() {
+        assert_eq!(compare_versions("1.0.0-rc.1", "1.0.0"), Ordering::Less);
+        assert_eq!(
+            compare_versions("1.0.0-alpha", "1.0.0-alpha.1"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions("1.0.0-rc.2", "1.0.0-rc.10"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions("1.0.0-beta", "1.0.0-alpha"),
+            Ordering::Greater
+        );
+        assert_eq!(compare_versions("1.0.0-1", "1.0.0-alpha"), Ordering::Less);
+    }
 }
@@ -49,7 +49,13 @@ pub async fn source(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let contents = std::fs::read_to_string(&filename.item);
     match contents {
         Ok(contents) => {
+            let file = std::fs::canonicalize(&filename.item)
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|_| filename.item.clone());
+
+            let previous = ctx.scope.set_current_file(Some(file));
             let result = crate::script::run_script_standalone(contents, true, &ctx, false).await;
+            ctx.scope.set_current_file(previous);
 
             if let Err(err) = result {
                 ctx.error(err.into());
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct SourceEnv;
+
+#[derive(Deserialize)]
+pub struct SourceEnvArgs {
+    pub filename: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SourceEnv {
+    fn name(&self) -> &str {
+        "source-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("source-env").required(
+            "filename",
+            SyntaxShape::String,
+            "the filepath to the script file to take the environment of",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Runs a script file, keeping only the environment variables it sets."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        source_env(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Set up the environment of a project",
+            example: "source-env env.nu",
+            result: None,
+        }]
+    }
+}
+
+pub async fn source_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let ctx = EvaluationContext::from_args(&args);
+    let (SourceEnvArgs { filename }, _) = args.process().await?;
+
+    let contents = std::fs::read_to_string(&filename.item).map_err(|_| {
+        ShellError::labeled_error(
+            "Can't load file to source",
+            "can't load file",
+            filename.span(),
+        )
+    })?;
+    let file = std::fs::canonicalize(&filename.item)
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| filename.item.clone());
+
+    // The script gets a scope of its own, so that only its environment outlives it, and like a
+    // custom command without --env, it can't move the caller to another directory
+    let cwd = ctx.shell_manager.path();
+    let previous = ctx.scope.set_current_file(Some(file));
+    ctx.scope.enter_scope();
+
+    let result = crate::script::run_script_standalone(contents, true, &ctx, false).await;
+
+    ctx.scope.exit_scope_with_env();
+    ctx.scope.set_current_file(previous);
+    if ctx.shell_manager.path() != cwd {
+        ctx.shell_manager.set_path(cwd);
+    }
+
+    if let Err(err) = result {
+        ctx.error(err.into());
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SourceEnv;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SourceEnv {})?)
+    }
+}
@@ -14,10 +14,16 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str camel-case").rest(
-            SyntaxShape::ColumnPath,
-            "optionally convert text to camelCase by column paths",
-        )
+        Signature::build("str camel-case")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert text to camelCase by column paths",
+            )
+            .switch(
+                "columns",
+                "convert the column names instead of the values",
+                Some('c'),
+            )
     }
 
     fn usage(&self) -> &str {
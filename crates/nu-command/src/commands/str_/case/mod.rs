@@ -3,8 +3,14 @@ pub mod kebab_case;
 pub mod pascal_case;
 pub mod screaming_snake_case;
 pub mod snake_case;
+pub mod title_case;
 
 use crate::prelude::*;
+use inflector::cases::{
+    camelcase::to_camel_case, kebabcase::to_kebab_case, pascalcase::to_pascal_case,
+    screamingsnakecase::to_screaming_snake_case, snakecase::to_snake_case,
+    titlecase::to_title_case,
+};
 use nu_errors::ShellError;
 use nu_protocol::ShellTypeName;
 use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
@@ -15,10 +21,12 @@ pub use camel_case::SubCommand as CamelCase;
 pub use pascal_case::SubCommand as PascalCase;
 pub use screaming_snake_case::SubCommand as ScreamingSnakeCase;
 pub use snake_case::SubCommand as SnakeCase;
+pub use title_case::SubCommand as TitleCase;
 
 #[derive(Deserialize)]
 struct Arguments {
     rest: Vec<ColumnPath>,
+    columns: bool,
 }
 
 pub async fn operate<F>(
@@ -29,20 +37,23 @@ pub async fn operate<F>(
 where
     F: Fn(&str) -> String + Send + Sync + 'static,
 {
-    let (Arguments { rest }, input) = args.process().await?;
+    let (Arguments { rest, columns }, input) = args.process().await?;
+
+    let convert: fn(&Value, Tag, &F) -> Result<Value, ShellError> =
+        if columns { rename_columns } else { action };
 
     let column_paths: Vec<_> = rest;
     Ok(input
         .map(move |v| {
             if column_paths.is_empty() {
-                ReturnSuccess::value(action(&v, v.tag(), &case_operation)?)
+                ReturnSuccess::value(convert(&v, v.tag(), case_operation)?)
             } else {
                 let mut ret = v;
 
                 for path in &column_paths {
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, old.tag(), &case_operation)),
+                        Box::new(move |old| convert(old, old.tag(), case_operation)),
                     )?;
                 }
 
@@ -74,3 +85,54 @@ where
         }
     }
 }
+
+/// Converts the column names of a row (or of every row in a table), leaving the values untouched
+pub fn rename_columns<F>(
+    input: &Value,
+    tag: impl Into<Tag>,
+    case_operation: &F,
+) -> Result<Value, ShellError>
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    match &input.value {
+        UntaggedValue::Row(row) => {
+            let mut renamed = IndexMap::new();
+
+            for (key, value) in row.entries.iter() {
+                renamed.insert(case_operation(key), value.clone());
+            }
+
+            Ok(UntaggedValue::Row(renamed.into()).into_value(tag))
+        }
+        UntaggedValue::Table(rows) => {
+            let rows = rows
+                .iter()
+                .map(|row| rename_columns(row, row.tag(), case_operation))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(UntaggedValue::Table(rows).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a row",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Looks up the conversion for a case name as accepted by `rename --case`
+pub fn case_converter(name: &str) -> Option<fn(&str) -> String> {
+    match name {
+        "camel" => Some(to_camel_case),
+        "kebab" => Some(to_kebab_case),
+        "pascal" => Some(to_pascal_case),
+        "screaming-snake" => Some(to_screaming_snake_case),
+        "snake" => Some(to_snake_case),
+        "title" => Some(to_title_case),
+        _ => None,
+    }
+}
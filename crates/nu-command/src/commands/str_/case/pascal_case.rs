@@ -14,10 +14,16 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str pascal-case").rest(
-            SyntaxShape::ColumnPath,
-            "optionally convert text to PascalCase by column paths",
-        )
+        Signature::build("str pascal-case")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert text to PascalCase by column paths",
+            )
+            .switch(
+                "columns",
+                "convert the column names instead of the values",
+                Some('c'),
+            )
     }
 
     fn usage(&self) -> &str {
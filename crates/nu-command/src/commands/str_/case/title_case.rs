@@ -0,0 +1,77 @@
+use super::operate;
+use crate::prelude::*;
+use inflector::cases::titlecase::to_title_case;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str title-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str title-case")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert text to Title Case by column paths",
+            )
+            .switch(
+                "columns",
+                "convert the column names instead of the values",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "converts a string to Title Case"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args, &to_title_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to Title Case",
+            example: "echo 'NuShell' | str title-case",
+            result: Some(vec![Value::from("Nu Shell")]),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{to_title_case, SubCommand};
+    use crate::commands::str_::case::action;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn title_case_from_kebab() {
+        let word = string("this-is-the-first-case");
+        let expected = string("This Is The First Case");
+
+        let actual = action(&word, Tag::unknown(), &to_title_case).unwrap();
+        assert_eq!(actual, expected);
+    }
+    #[test]
+    fn title_case_from_camel() {
+        let word = string("thisIsTheSecondCase");
+        let expected = string("This Is The Second Case");
+
+        let actual = action(&word, Tag::unknown(), &to_title_case).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,10 +1,39 @@
+use super::find_replace::{self, FindReplace};
+use super::substring::{self, Substring};
+use super::trim::{self, ActionMode};
+use super::units::Unit;
+use super::{downcase, to_integer, upcase};
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::{as_string, ValueExt};
 
 pub struct Command;
 
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    upcase: bool,
+    downcase: bool,
+    trim: bool,
+    substring: Option<Value>,
+    replace: Option<Value>,
+    #[serde(rename(deserialize = "to-int"))]
+    to_int: bool,
+}
+
+/// The string function picked with a flag, for applying it without naming a subcommand.
+#[derive(Clone)]
+enum Operation {
+    Upcase,
+    Downcase,
+    Trim,
+    Substring(Substring),
+    Replace(FindReplace),
+    ToInt,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Command {
     fn name(&self) -> &str {
@@ -12,20 +41,159 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str").rest(
-            SyntaxShape::ColumnPath,
-            "optionally convert by column paths",
-        )
+        Signature::build("str")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert by column paths",
+            )
+            .switch("upcase", "upcase text, like `str upcase`", None)
+            .switch("downcase", "downcase text, like `str downcase`", None)
+            .switch("trim", "trim whitespace, like `str trim`", None)
+            .named(
+                "substring",
+                SyntaxShape::Any,
+                "substring text by the indexes [start end], like `str substring`",
+                None,
+            )
+            .named(
+                "replace",
+                SyntaxShape::Any,
+                "replace every match of a pattern, given as [find replacement], like `str find-replace --all`",
+                None,
+            )
+            .switch("to-int", "convert text to integers, like `str to-int`", None)
     }
 
     fn usage(&self) -> &str {
-        "Apply string function."
+        r#"Apply string function.
+
+Without a subcommand, one of the flags picks the function to apply to the strings in the pipeline, or to the given columns of each row. With neither, the available subcommands are listed."#
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        Ok(OutputStream::one(ReturnSuccess::value(
-            UntaggedValue::string(get_help(&Command, &args.scope)).into_value(Tag::unknown()),
-        )))
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Upcase a column of each row",
+                example: "echo [[name]; [nu]] | str --upcase name | get name",
+                result: Some(vec![Value::from("NU")]),
+            },
+            Example {
+                description: "Replace text in the strings of the pipeline",
+                example: "echo 'a-b-c' | str --replace ['-' '+']",
+                result: Some(vec![Value::from("a+b+c")]),
+            },
+            Example {
+                description: "Convert text to integers",
+                example: "echo ' 42 ' | str --to-int",
+                result: Some(vec![UntaggedValue::int(42).into()]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let scope = args.scope.clone();
+    let (
+        Arguments {
+            rest: column_paths,
+            upcase,
+            downcase,
+            trim,
+            substring,
+            replace,
+            to_int,
+        },
+        input,
+    ) = args.process().await?;
+
+    let mut operations = vec![];
+    if upcase {
+        operations.push(Operation::Upcase);
+    }
+    if downcase {
+        operations.push(Operation::Downcase);
+    }
+    if trim {
+        operations.push(Operation::Trim);
+    }
+    if let Some(range) = substring {
+        let range = substring::process_arguments(range, name.clone())?;
+        operations.push(Operation::Substring(range.into()));
+    }
+    if let Some(replace) = replace {
+        operations.push(Operation::Replace(find_and_replacement(&replace)?));
+    }
+    if to_int {
+        operations.push(Operation::ToInt);
+    }
+
+    let operation = match operations.len() {
+        0 => {
+            return Ok(OutputStream::one(ReturnSuccess::value(
+                UntaggedValue::string(get_help(&Command, &scope)).into_value(Tag::unknown()),
+            )))
+        }
+        1 => operations.remove(0),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "str takes one string function at a time",
+                "pick one of --upcase, --downcase, --trim, --substring, --replace and --to-int",
+                &name,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &operation, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let operation = operation.clone();
+
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &operation, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, operation: &Operation, tag: Tag) -> Result<Value, ShellError> {
+    match operation {
+        Operation::Upcase => upcase::action(input, tag),
+        Operation::Downcase => downcase::action(input, tag),
+        Operation::Trim => trim::action(input, tag, None, &trim::trim, ActionMode::Local),
+        Operation::Substring(range) => substring::action(input, range, Unit::Graphemes, tag),
+        Operation::Replace(options) => find_replace::action(input, options, tag, true),
+        Operation::ToInt => to_integer::action(input, tag, 10),
+    }
+}
+
+fn find_and_replacement(replace: &Value) -> Result<FindReplace, ShellError> {
+    let parts: Vec<String> = replace
+        .table_entries()
+        .map(as_string)
+        .collect::<Result<_, _>>()?;
+
+    match parts.as_slice() {
+        [find, replacement] => Ok(FindReplace(find.clone(), replacement.clone())),
+        _ => Err(ShellError::labeled_error(
+            "Expected a pattern and its replacement",
+            "needs [find replacement]",
+            &replace.tag,
+        )),
     }
 }
 
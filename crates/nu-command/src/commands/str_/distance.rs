@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    levenshtein_distance, ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    compare: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str distance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str distance")
+            .required(
+                "compare",
+                SyntaxShape::String,
+                "the string to measure the distance to",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally measure the distance of strings by column paths",
+            )
+            .switch("insensitive", "compare case insensitively", Some('i'))
+    }
+
+    fn usage(&self) -> &str {
+        "outputs the edit (Levenshtein) distance between each string and another string"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the edit distance between two strings",
+                example: "echo 'nushell' | str distance 'nutshell'",
+                result: Some(vec![UntaggedValue::int(1).into_untagged_value()]),
+            },
+            Example {
+                description: "Get the edit distance ignoring case",
+                example: "echo 'NuShell' | str distance -i 'nushell'",
+                result: Some(vec![UntaggedValue::int(0).into_untagged_value()]),
+            },
+            Example {
+                description: "Find the files with names closest to a typo",
+                example:
+                    "ls | insert d { get name | str distance 'Cargo.tmol' } | sort-by d | first 3",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            compare,
+            rest,
+            insensitive,
+        },
+        input,
+    ) = args.process().await?;
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &compare, insensitive, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let compare = compare.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &compare, insensitive, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+pub(crate) fn distance(left: &str, right: &str, insensitive: bool) -> usize {
+    if insensitive {
+        levenshtein_distance(&left.to_lowercase(), &right.to_lowercase())
+    } else {
+        levenshtein_distance(left, right)
+    }
+}
+
+fn action(
+    input: &Value,
+    compare: &str,
+    insensitive: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(UntaggedValue::int(distance(s, compare, insensitive)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{action, SubCommand};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn counts_edits_between_strings() {
+        let word = string("kitten");
+        let expected = UntaggedValue::int(3).into_untagged_value();
+
+        let actual = action(&word, "sitting", false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
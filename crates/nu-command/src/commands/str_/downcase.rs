@@ -70,7 +70,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+pub(crate) fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
             Ok(UntaggedValue::string(s.to_ascii_lowercase()).into_value(tag))
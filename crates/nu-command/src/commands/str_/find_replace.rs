@@ -61,7 +61,7 @@ impl WholeStreamCommand for SubCommand {
 }
 
 #[derive(Clone)]
-struct FindReplace(String, String);
+pub(crate) struct FindReplace(pub(crate) String, pub(crate) String);
 
 async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (
@@ -98,7 +98,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(
+pub(crate) fn action(
     input: &Value,
     options: &FindReplace,
     tag: impl Into<Tag>,
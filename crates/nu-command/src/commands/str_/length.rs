@@ -1,3 +1,4 @@
+use super::units::Unit;
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -10,6 +11,9 @@ pub struct SubCommand;
 
 #[derive(Deserialize)]
 struct Arguments {
+    bytes: bool,
+    #[serde(rename = "code-points")]
+    code_points: bool,
     rest: Vec<ColumnPath>,
 }
 
@@ -20,14 +24,21 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str length").rest(
-            SyntaxShape::ColumnPath,
-            "optionally find length of text by column paths",
-        )
+        Signature::build("str length")
+            .switch("bytes", "count bytes instead of characters", Some('b'))
+            .switch(
+                "code-points",
+                "count unicode code points instead of characters",
+                None,
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally find length of text by column paths",
+            )
     }
 
     fn usage(&self) -> &str {
-        "outputs the lengths of the strings in the pipeline"
+        "outputs the lengths of the strings in the pipeline, counting the characters a reader sees"
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -49,25 +60,43 @@ impl WholeStreamCommand for SubCommand {
                     UntaggedValue::int(5).into_untagged_value(),
                 ]),
             },
+            Example {
+                description: "Count an emoji made of several code points once",
+                example: "echo '👍🏽' | str length",
+                result: Some(vec![UntaggedValue::int(1).into_untagged_value()]),
+            },
+            Example {
+                description: "Return the length in bytes",
+                example: "echo '👍🏽' | str length --bytes",
+                result: Some(vec![UntaggedValue::int(8).into_untagged_value()]),
+            },
         ]
     }
 }
 
 async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let (Arguments { rest }, input) = args.process().await?;
+    let (
+        Arguments {
+            bytes,
+            code_points,
+            rest,
+        },
+        input,
+    ) = args.process().await?;
     let column_paths: Vec<_> = rest;
+    let unit = Unit::from_flags(bytes, code_points);
 
     Ok(input
         .map(move |v| {
             if column_paths.is_empty() {
-                ReturnSuccess::value(action(&v, v.tag())?)
+                ReturnSuccess::value(action(&v, unit, v.tag())?)
             } else {
                 let mut ret = v;
 
                 for path in &column_paths {
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, old.tag())),
+                        Box::new(move |old| action(old, unit, old.tag())),
                     )?;
                 }
 
@@ -77,10 +106,10 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+fn action(input: &Value, unit: Unit, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::int(s.len()).into_value(tag))
+            Ok(UntaggedValue::int(unit.count(s)).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
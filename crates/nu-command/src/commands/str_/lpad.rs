@@ -1,3 +1,4 @@
+use super::units::Unit;
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -12,6 +13,8 @@ use nu_value_ext::ValueExt;
 struct Arguments {
     length: Tagged<usize>,
     character: Tagged<String>,
+    #[serde(rename = "code-points")]
+    code_points: bool,
     rest: Vec<ColumnPath>,
 }
 
@@ -32,6 +35,11 @@ impl WholeStreamCommand for SubCommand {
                 "character to pad with",
                 Some('c'),
             )
+            .switch(
+                "code-points",
+                "count unicode code points instead of characters",
+                None,
+            )
             .rest(
                 SyntaxShape::ColumnPath,
                 "optionally check if string contains pattern by column paths",
@@ -83,18 +91,20 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         Arguments {
             length,
             character,
+            code_points,
             rest,
         },
         input,
     ) = args.process().await?;
     let column_paths: Vec<_> = rest;
+    let unit = Unit::from_flags(false, code_points);
 
     Ok(input
         .map(move |v| {
             let len = length.item;
             let character = character.item.clone();
             if column_paths.is_empty() {
-                ReturnSuccess::value(action(&v, len, character, v.tag())?)
+                ReturnSuccess::value(action(&v, len, character, unit, v.tag())?)
             } else {
                 let mut ret = v;
 
@@ -102,7 +112,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     let str_clone = character.clone();
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, len, str_clone, old.tag())),
+                        Box::new(move |old| action(old, len, str_clone, unit, old.tag())),
                     )?;
                 }
 
@@ -116,17 +126,16 @@ fn action(
     input: &Value,
     length: usize,
     character: String,
+    unit: Unit,
     tag: impl Into<Tag>,
 ) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            if length < s.len() {
-                Ok(
-                    UntaggedValue::string(s.chars().take(length).collect::<String>())
-                        .into_value(tag),
-                )
+            let count = unit.count(s);
+            if length < count {
+                Ok(UntaggedValue::string(unit.slice(s, 0, length)).into_value(tag))
             } else {
-                let mut res = character.repeat(length - s.chars().count());
+                let mut res = character.repeat(length - count);
                 res += s.as_ref();
                 Ok(UntaggedValue::string(res).into_value(tag))
             }
@@ -144,7 +153,7 @@ fn action(
 
 #[cfg(test)]
 mod tests {
-    use super::{action, SubCommand};
+    use super::{action, SubCommand, Unit};
     use nu_errors::ShellError;
     use nu_protocol::UntaggedValue;
     use nu_source::Tag;
@@ -164,7 +173,18 @@ mod tests {
         let pad_len = 10;
         let expected = UntaggedValue::string("0000000123").into_untagged_value();
 
-        let actual = action(&word, pad_len, pad_char, Tag::unknown()).unwrap();
+        let actual = action(&word, pad_len, pad_char, Unit::Graphemes, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn left_pad_counts_graphemes() {
+        let word = string("ne\u{303}e");
+        let pad_char = '.'.to_string();
+        let pad_len = 4;
+        let expected = UntaggedValue::string(".ne\u{303}e").into_untagged_value();
+
+        let actual = action(&word, pad_len, pad_char, Unit::Graphemes, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
 
@@ -175,7 +195,7 @@ mod tests {
         let pad_len = 3;
         let expected = UntaggedValue::string("123").into_untagged_value();
 
-        let actual = action(&word, pad_len, pad_char, Tag::unknown()).unwrap();
+        let actual = action(&word, pad_len, pad_char, Unit::Graphemes, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
 }
@@ -1,8 +1,9 @@
 mod capitalize;
-mod case;
+pub(crate) mod case;
 mod collect;
 mod command;
 mod contains;
+mod distance;
 mod downcase;
 mod ends_with;
 mod find_replace;
@@ -10,14 +11,17 @@ pub mod from;
 mod index_of;
 mod length;
 mod lpad;
+mod normalize;
 mod reverse;
 mod rpad;
+mod similarity;
 mod starts_with;
 mod substring;
 mod to_datetime;
 mod to_decimal;
 mod to_integer;
 mod trim;
+mod units;
 mod upcase;
 
 pub use capitalize::SubCommand as StrCapitalize;
@@ -26,9 +30,11 @@ pub use case::kebab_case::SubCommand as StrKebabCase;
 pub use case::pascal_case::SubCommand as StrPascalCase;
 pub use case::screaming_snake_case::SubCommand as StrScreamingSnakeCase;
 pub use case::snake_case::SubCommand as StrSnakeCase;
+pub use case::title_case::SubCommand as StrTitleCase;
 pub use collect::SubCommand as StrCollect;
 pub use command::Command as Str;
 pub use contains::SubCommand as StrContains;
+pub use distance::SubCommand as StrDistance;
 pub use downcase::SubCommand as StrDowncase;
 pub use ends_with::SubCommand as StrEndsWith;
 pub use find_replace::SubCommand as StrFindReplace;
@@ -36,10 +42,13 @@ pub use from::SubCommand as StrFrom;
 pub use index_of::SubCommand as StrIndexOf;
 pub use length::SubCommand as StrLength;
 pub use lpad::SubCommand as StrLPad;
+pub use normalize::SubCommand as StrNormalize;
 pub use reverse::SubCommand as StrReverse;
 pub use rpad::SubCommand as StrRPad;
+pub use similarity::SubCommand as StrSimilarity;
 pub use starts_with::SubCommand as StrStartsWith;
 pub use substring::SubCommand as StrSubstring;
+pub(crate) use to_datetime::parse_date;
 pub use to_datetime::SubCommand as StrToDatetime;
 pub use to_decimal::SubCommand as StrToDecimal;
 pub use to_integer::SubCommand as StrToInteger;
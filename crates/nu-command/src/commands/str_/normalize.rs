@@ -0,0 +1,170 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Deserialize)]
+struct Arguments {
+    form: Tagged<String>,
+    rest: Vec<ColumnPath>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str normalize")
+            .required(
+                "form",
+                SyntaxShape::String,
+                "the unicode normalization form: nfc, nfd, nfkc or nfkd",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally normalize text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "normalizes the unicode representation of text, so that equal looking strings compare equal"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Combine letters and their accents into single code points",
+                example: "echo 'cafe\u{301}' | str normalize nfc",
+                result: Some(vec![Value::from("caf\u{e9}")]),
+            },
+            Example {
+                description: "Split letters from their accents",
+                example: "echo 'caf\u{e9}' | str normalize nfd",
+                result: Some(vec![Value::from("cafe\u{301}")]),
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (Arguments { form, rest }, input) = args.process().await?;
+
+    let form = match form.item.to_lowercase().as_str() {
+        "nfc" => Form::Nfc,
+        "nfd" => Form::Nfd,
+        "nfkc" => Form::Nfkc,
+        "nfkd" => Form::Nfkd,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unknown normalization form",
+                "expected nfc, nfd, nfkc or nfkd",
+                form.tag.span,
+            ))
+        }
+    };
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, form, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, form, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, form: Form, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            let normalized: String = match form {
+                Form::Nfc => s.nfc().collect(),
+                Form::Nfd => s.nfd().collect(),
+                Form::Nfkc => s.nfkc().collect(),
+                Form::Nfkd => s.nfkd().collect(),
+            };
+
+            Ok(UntaggedValue::string(normalized).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{action, Form, SubCommand};
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    fn composes_and_decomposes() {
+        let composed = string("caf\u{e9}");
+        let decomposed = string("cafe\u{301}");
+
+        assert_eq!(
+            action(&decomposed, Form::Nfc, Tag::unknown()).unwrap(),
+            composed
+        );
+        assert_eq!(
+            action(&composed, Form::Nfd, Tag::unknown()).unwrap(),
+            decomposed
+        );
+    }
+
+    #[test]
+    fn compatibility_forms_fold_ligatures() {
+        let word = string("\u{fb01}le");
+
+        assert_eq!(
+            action(&word, Form::Nfkc, Tag::unknown()).unwrap(),
+            string("file")
+        );
+    }
+}
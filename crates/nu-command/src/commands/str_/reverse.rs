@@ -1,3 +1,4 @@
+use super::units::Unit;
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -10,6 +11,8 @@ pub struct SubCommand;
 
 #[derive(Deserialize)]
 struct Arguments {
+    #[serde(rename = "code-points")]
+    code_points: bool,
     rest: Vec<ColumnPath>,
 }
 
@@ -20,10 +23,16 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str reverse").rest(
-            SyntaxShape::ColumnPath,
-            "optionally reverse text by column paths",
-        )
+        Signature::build("str reverse")
+            .switch(
+                "code-points",
+                "reverse unicode code points instead of characters",
+                None,
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally reverse text by column paths",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -35,29 +44,37 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Return the reversals of multiple strings",
-            example: "echo 'Nushell' | str reverse",
-            result: Some(vec![UntaggedValue::string("llehsuN").into_untagged_value()]),
-        }]
+        vec![
+            Example {
+                description: "Return the reversals of multiple strings",
+                example: "echo 'Nushell' | str reverse",
+                result: Some(vec![UntaggedValue::string("llehsuN").into_untagged_value()]),
+            },
+            Example {
+                description: "Keep emoji whole when reversing",
+                example: "echo 'hi👋🏽' | str reverse",
+                result: Some(vec![UntaggedValue::string("👋🏽ih").into_untagged_value()]),
+            },
+        ]
     }
 }
 
 async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let (Arguments { rest }, input) = args.process().await?;
+    let (Arguments { code_points, rest }, input) = args.process().await?;
     let column_paths: Vec<_> = rest;
+    let unit = Unit::from_flags(false, code_points);
 
     Ok(input
         .map(move |v| {
             if column_paths.is_empty() {
-                ReturnSuccess::value(action(&v, v.tag())?)
+                ReturnSuccess::value(action(&v, unit, v.tag())?)
             } else {
                 let mut ret = v;
 
                 for path in &column_paths {
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, old.tag())),
+                        Box::new(move |old| action(old, unit, old.tag())),
                     )?;
                 }
 
@@ -67,10 +84,10 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+fn action(input: &Value, unit: Unit, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::string(s.chars().rev().collect::<String>()).into_value(tag))
+            Ok(UntaggedValue::string(unit.reverse(s)).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -0,0 +1,157 @@
+use crate::commands::str_::distance::distance;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    compare: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str similarity"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str similarity")
+            .required("compare", SyntaxShape::String, "the string to compare to")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally compare strings by column paths",
+            )
+            .switch("insensitive", "compare case insensitively", Some('i'))
+    }
+
+    fn usage(&self) -> &str {
+        "outputs how similar each string is to another string, from 0 (nothing alike) to 1 (equal)"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        operate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compare two strings",
+                example: "echo 'nushell' | str similarity 'nutshell'",
+                result: Some(vec![UntaggedValue::decimal_from_float(
+                    0.875,
+                    Span::unknown(),
+                )
+                .into_untagged_value()]),
+            },
+            Example {
+                description: "Keep the names that look like a typo of 'Andres'",
+                example: "echo [Andres Andrés Jonathan] | where { = $(echo $it | str similarity 'Andres') > 0.8 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let (
+        Arguments {
+            compare,
+            rest,
+            insensitive,
+        },
+        input,
+    ) = args.process().await?;
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v, &compare, insensitive, v.tag())?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let compare = compare.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &compare, insensitive, old.tag())),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn similarity(left: &str, right: &str, insensitive: bool) -> f64 {
+    let longest = std::cmp::max(left.chars().count(), right.chars().count());
+
+    if longest == 0 {
+        return 1.0;
+    }
+
+    1.0 - (distance(left, right, insensitive) as f64 / longest as f64)
+}
+
+fn action(
+    input: &Value,
+    compare: &str,
+    insensitive: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(UntaggedValue::decimal_from_float(
+            similarity(s, compare, insensitive),
+            tag.span,
+        )
+        .into_value(tag)),
+        other => {
+            let got = format!("got {}", other.type_name());
+
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::similarity;
+    use super::ShellError;
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(SubCommand {})?)
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn empty_strings_are_equal() {
+        assert_eq!(similarity("", "", false), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn unrelated_strings_are_not_similar() {
+        assert_eq!(similarity("abc", "xyz", false), 0.0);
+    }
+}
@@ -1,3 +1,4 @@
+use super::units::Unit;
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
@@ -14,6 +15,9 @@ use std::convert::TryInto;
 #[derive(Deserialize)]
 struct Arguments {
     range: Value,
+    bytes: bool,
+    #[serde(rename = "code-points")]
+    code_points: bool,
     rest: Vec<ColumnPath>,
 }
 
@@ -32,6 +36,12 @@ impl WholeStreamCommand for SubCommand {
                 SyntaxShape::Any,
                 "the indexes to substring [start end]",
             )
+            .switch("bytes", "index by bytes instead of characters", Some('b'))
+            .switch(
+                "code-points",
+                "index by unicode code points instead of characters",
+                None,
+            )
             .rest(
                 SyntaxShape::ColumnPath,
                 "optionally substring text by column paths",
@@ -73,12 +83,17 @@ impl WholeStreamCommand for SubCommand {
                 example: "echo 'good nushell' | str substring ',7'",
                 result: Some(vec![Value::from("good nu")]),
             },
+            Example {
+                description: "Keep letters with combining accents whole",
+                example: "echo 'cafe\u{301} au lait' | str substring '0,4'",
+                result: Some(vec![Value::from("cafe\u{301}")]),
+            },
         ]
     }
 }
 
 #[derive(Clone)]
-struct Substring(isize, isize);
+pub(crate) struct Substring(isize, isize);
 
 impl From<(isize, isize)> for Substring {
     fn from(input: (isize, isize)) -> Substring {
@@ -91,15 +106,24 @@ struct SubstringText(String, String);
 async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
 
-    let (Arguments { range, rest }, input) = args.process().await?;
+    let (
+        Arguments {
+            range,
+            bytes,
+            code_points,
+            rest,
+        },
+        input,
+    ) = args.process().await?;
 
     let column_paths: Vec<_> = rest;
     let options = process_arguments(range, name)?.into();
+    let unit = Unit::from_flags(bytes, code_points);
 
     Ok(input
         .map(move |v| {
             if column_paths.is_empty() {
-                ReturnSuccess::value(action(&v, &options, v.tag())?)
+                ReturnSuccess::value(action(&v, &options, unit, v.tag())?)
             } else {
                 let mut ret = v;
 
@@ -108,7 +132,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, &options, old.tag())),
+                        Box::new(move |old| action(old, &options, unit, old.tag())),
                     )?;
                 }
 
@@ -118,12 +142,17 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+pub(crate) fn action(
+    input: &Value,
+    options: &Substring,
+    unit: Unit,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
     let tag = tag.into();
 
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            let len: isize = s.len().try_into().map_err(|_| {
+            let len: isize = unit.count(s).try_into().map_err(|_| {
                 ShellError::labeled_error(
                     "could not perform substring",
                     "could not perform substring",
@@ -150,15 +179,12 @@ fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Val
                         "End must be greater than or equal to Start",
                         tag.span,
                     )),
-                    Ordering::Less => Ok(UntaggedValue::string(if end == isize::max_value() {
-                        s.chars().skip(start as usize).collect::<String>()
-                    } else {
-                        s.chars()
-                            .skip(start as usize)
-                            .take((end - start) as usize)
-                            .collect::<String>()
-                    })
-                    .into_value(tag)),
+                    Ordering::Less => {
+                        Ok(
+                            UntaggedValue::string(unit.slice(s, start as usize, end as usize))
+                                .into_value(tag),
+                        )
+                    }
                 }
             } else {
                 Ok(UntaggedValue::string("").into_value(tag))
@@ -175,7 +201,10 @@ fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Val
     }
 }
 
-fn process_arguments(range: Value, name: impl Into<Tag>) -> Result<(isize, isize), ShellError> {
+pub(crate) fn process_arguments(
+    range: Value,
+    name: impl Into<Tag>,
+) -> Result<(isize, isize), ShellError> {
     let name = name.into();
 
     let search = match &range.value {
@@ -277,7 +306,7 @@ fn process_arguments(range: Value, name: impl Into<Tag>) -> Result<(isize, isize
 #[cfg(test)]
 mod tests {
     use super::ShellError;
-    use super::{action, SubCommand, Substring};
+    use super::{action, SubCommand, Substring, Unit};
     use nu_source::Tag;
     use nu_test_support::value::string;
 
@@ -338,7 +367,13 @@ mod tests {
 
         for expectation in cases.iter() {
             let expected = expectation.expected;
-            let actual = action(&word, &expectation.options(), Tag::unknown()).unwrap();
+            let actual = action(
+                &word,
+                &expectation.options(),
+                Unit::Graphemes,
+                Tag::unknown(),
+            )
+            .unwrap();
 
             assert_eq!(actual, string(expected));
         }
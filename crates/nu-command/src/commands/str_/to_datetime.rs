@@ -103,6 +103,11 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
+/// Parses the string as a date, guessing its format.
+pub(crate) fn parse_date(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    action(input, &None, tag)
+}
+
 fn action(
     input: &Value,
     options: &Option<DatetimeFormat>,
@@ -95,7 +95,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
         .to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>, radix: u32) -> Result<Value, ShellError> {
+pub(crate) fn action(input: &Value, tag: impl Into<Tag>, radix: u32) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
             let trimmed = s.trim();
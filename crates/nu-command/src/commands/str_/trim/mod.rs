@@ -10,6 +10,7 @@ use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 use std::iter::FromIterator;
 
+pub(crate) use trim_both_ends::trim;
 pub use trim_both_ends::SubCommand as Trim;
 pub use trim_left::SubCommand as TrimLeft;
 pub use trim_right::SubCommand as TrimRight;
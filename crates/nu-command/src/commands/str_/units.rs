@@ -0,0 +1,86 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// What the `str` subcommands count text in.
+///
+/// Grapheme clusters are the default since they are what a reader sees as one character: an
+/// emoji or a letter followed by combining accents is a single grapheme, and counting or cutting
+/// by graphemes never splits it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Graphemes,
+    CodePoints,
+    Bytes,
+}
+
+impl Unit {
+    pub fn from_flags(bytes: bool, code_points: bool) -> Unit {
+        if bytes {
+            Unit::Bytes
+        } else if code_points {
+            Unit::CodePoints
+        } else {
+            Unit::Graphemes
+        }
+    }
+
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            Unit::Graphemes => text.graphemes(true).count(),
+            Unit::CodePoints => text.chars().count(),
+            Unit::Bytes => text.len(),
+        }
+    }
+
+    /// The units from `start` up to, but not including, `end`. Cutting by bytes may leave partial
+    /// characters at either end, which are replaced with U+FFFD.
+    pub fn slice(self, text: &str, start: usize, end: usize) -> String {
+        let take = end.saturating_sub(start);
+
+        match self {
+            Unit::Graphemes => text.graphemes(true).skip(start).take(take).collect(),
+            Unit::CodePoints => text.chars().skip(start).take(take).collect(),
+            Unit::Bytes => {
+                let bytes = text.as_bytes();
+                let start = start.min(bytes.len());
+                let end = end.min(bytes.len()).max(start);
+                String::from_utf8_lossy(&bytes[start..end]).to_string()
+            }
+        }
+    }
+
+    pub fn reverse(self, text: &str) -> String {
+        match self {
+            Unit::Graphemes => text.graphemes(true).rev().collect(),
+            _ => text.chars().rev().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+
+    #[test]
+    fn counts_combining_characters_once() {
+        let text = "e\u{301}t\u{e9}";
+
+        assert_eq!(Unit::Graphemes.count(text), 3);
+        assert_eq!(Unit::CodePoints.count(text), 4);
+        assert_eq!(Unit::Bytes.count(text), 6);
+    }
+
+    #[test]
+    fn slices_without_splitting_graphemes() {
+        let text = "👨‍👩‍👧 family";
+
+        assert_eq!(Unit::Graphemes.slice(text, 0, 1), "👨‍👩‍👧");
+        assert_eq!(Unit::Graphemes.slice(text, 2, 100), "family");
+        assert_eq!(Unit::Bytes.slice("abc", 1, 2), "b");
+    }
+
+    #[test]
+    fn reverses_graphemes_whole() {
+        assert_eq!(Unit::Graphemes.reverse("ae\u{301}"), "e\u{301}a");
+        assert_eq!(Unit::CodePoints.reverse("ae\u{301}"), "\u{301}ea");
+    }
+}
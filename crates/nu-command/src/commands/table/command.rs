@@ -1,7 +1,7 @@
 use crate::commands::table::options::{ConfigExtensions, NuConfig as TableConfiguration};
 use crate::prelude::*;
 use crate::primitive::get_color_config;
-use nu_data::value::{format_leaf, style_leaf};
+use nu_data::value::{format_leaf_with_precision, style_leaf};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
@@ -49,7 +49,15 @@ pub fn from_list(
         .into_iter()
         .map(|x| StyledString::new(x, header_style))
         .collect();
-    let entries = values_to_entries(values, &mut headers, configuration, starting_idx, &color_hm);
+    let mut entries =
+        values_to_entries(values, &mut headers, configuration, starting_idx, &color_hm);
+
+    if let Some(max_width) = configuration.max_column_width() {
+        for cell in headers.iter_mut().chain(entries.iter_mut().flatten()) {
+            cell.contents = nu_table::truncate(&cell.contents, max_width);
+        }
+    }
+
     nu_table::Table {
         headers,
         data: entries,
@@ -65,6 +73,7 @@ fn values_to_entries(
     color_hm: &HashMap<String, ansi_term::Style>,
 ) -> Vec<Vec<StyledString>> {
     let disable_indexes = configuration.disabled_indexes();
+    let float_precision = configuration.float_precision();
     let mut entries = vec![];
 
     if headers.is_empty() {
@@ -81,11 +90,13 @@ fn values_to_entries(
                             value: UntaggedValue::Row(..),
                             ..
                         } => StyledString::new(
-                            format_leaf(&UntaggedValue::nothing()).plain_string(100_000),
+                            format_leaf_with_precision(&UntaggedValue::nothing(), float_precision)
+                                .plain_string(100_000),
                             style_leaf(&UntaggedValue::nothing(), &color_hm),
                         ),
                         _ => StyledString::new(
-                            format_leaf(value).plain_string(100_000),
+                            format_leaf_with_precision(value, float_precision)
+                                .plain_string(100_000),
                             style_leaf(value, &color_hm),
                         ),
                     }
@@ -98,12 +109,14 @@ fn values_to_entries(
                             let data = value.get_data(&d.contents);
 
                             StyledString::new(
-                                format_leaf(data.borrow()).plain_string(100_000),
+                                format_leaf_with_precision(data.borrow(), float_precision)
+                                    .plain_string(100_000),
                                 style_leaf(data.borrow(), &color_hm),
                             )
                         }
                         _ => StyledString::new(
-                            format_leaf(&UntaggedValue::nothing()).plain_string(100_000),
+                            format_leaf_with_precision(&UntaggedValue::nothing(), float_precision)
+                                .plain_string(100_000),
                             style_leaf(&UntaggedValue::nothing(), &color_hm),
                         ),
                     }
@@ -140,10 +153,16 @@ fn values_to_entries(
             0,
             StyledString::new(
                 "#".to_owned(),
-                TextStyle::new()
-                    .alignment(Alignment::Center)
-                    .fg(ansi_term::Color::Green)
-                    .bold(Some(true)),
+                TextStyle::new().alignment(Alignment::Center).style(
+                    color_hm
+                        .get("index_color")
+                        .unwrap_or(
+                            &ansi_term::Style::default()
+                                .bold()
+                                .fg(ansi_term::Color::Green),
+                        )
+                        .to_owned(),
+                ),
             ),
         );
     }
@@ -187,6 +206,9 @@ async fn table(
 
     let term_width = args.host.lock().width();
 
+    #[cfg(feature = "crossterm")]
+    let mut pager = super::pager::Pager::new(configuration.pager_mode());
+
     while !finished {
         let mut new_input: VecDeque<Value> = VecDeque::new();
 
@@ -234,6 +256,17 @@ async fn table(
         }
 
         let input: Vec<Value> = new_input.into();
+        let count = input.len();
+
+        // Tables shown through the pager are held back until the whole input is read
+        #[cfg(feature = "crossterm")]
+        let input = match &mut pager {
+            Some(pager) => {
+                pager.push(start_number, input);
+                vec![]
+            }
+            None => input,
+        };
 
         if !input.is_empty() {
             let t = from_list(&input, &configuration, start_number, &color_hm);
@@ -241,7 +274,18 @@ async fn table(
             draw_table(&t, term_width, &color_hm);
         }
 
-        start_number += input.len();
+        start_number += count;
+    }
+
+    #[cfg(feature = "crossterm")]
+    {
+        if let Some(pager) = pager {
+            pager
+                .show(&configuration, term_width, &color_hm)
+                .map_err(|e| {
+                    ShellError::untagged_runtime_error(format!("Could not page table ({})", e))
+                })?;
+        }
     }
 
     Ok(OutputStream::empty())
@@ -1,4 +1,6 @@
 pub mod command;
-mod options;
+pub(crate) mod options;
+#[cfg(feature = "crossterm")]
+mod pager;
 
 pub use command::Command as Table;
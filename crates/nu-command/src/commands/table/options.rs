@@ -1,5 +1,6 @@
+use nu_data::base::shape::DEFAULT_FLOAT_PRECISION;
 pub use nu_data::config::NuConfig;
-use nu_data::primitive::lookup_ansi_color_style;
+use nu_data::primitive::{lookup_ansi_color_style, theme_style};
 use nu_protocol::Value;
 use nu_table::{Alignment, TextStyle};
 use std::fmt::Debug;
@@ -8,6 +9,20 @@ pub trait ConfigExtensions: Debug + Send {
     fn table_mode(&self) -> nu_table::Theme;
     fn disabled_indexes(&self) -> bool;
     fn header_style(&self) -> TextStyle;
+    fn pager_mode(&self) -> PagerMode;
+    fn max_column_width(&self) -> Option<usize>;
+    fn float_precision(&self) -> usize;
+}
+
+/// How tables taller than the terminal are shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PagerMode {
+    /// An interactive viewer that can scroll, search and hide columns
+    Viewer,
+    /// A screen at a time, waiting for a key press in between
+    Paginate,
+    /// All at once
+    Never,
 }
 
 pub fn header_alignment_from_value(align_value: Option<&Value>) -> nu_table::Alignment {
@@ -51,9 +66,10 @@ pub fn table_mode(config: &NuConfig) -> nu_table::Theme {
 
     vars.get("table_mode")
         .map_or(nu_table::Theme::compact(), |mode| match mode.as_string() {
-            Ok(m) if m == "basic" => nu_table::Theme::basic(),
+            Ok(m) if m == "basic" || m == "ascii" => nu_table::Theme::basic(),
             Ok(m) if m == "compact" => nu_table::Theme::compact(),
             Ok(m) if m == "light" => nu_table::Theme::light(),
+            Ok(m) if m == "unicode" => nu_table::Theme::thin(),
             Ok(m) if m == "thin" => nu_table::Theme::thin(),
             Ok(m) if m == "with_love" => nu_table::Theme::with_love(),
             Ok(m) if m == "compact_double" => nu_table::Theme::compact_double(),
@@ -72,6 +88,36 @@ pub fn disabled_indexes(config: &NuConfig) -> bool {
         .map_or(false, |x| x.as_bool().unwrap_or(false))
 }
 
+pub fn max_column_width(config: &NuConfig) -> Option<usize> {
+    let vars = &config.vars;
+
+    vars.get("table_max_column_width")
+        .and_then(|width| width.as_u64().ok())
+        .filter(|width| *width > 0)
+        .map(|width| width as usize)
+}
+
+pub fn float_precision(config: &NuConfig) -> usize {
+    let vars = &config.vars;
+
+    vars.get("float_precision")
+        .and_then(|precision| precision.as_u64().ok())
+        .map_or(DEFAULT_FLOAT_PRECISION, |precision| precision as usize)
+}
+
+pub fn pager_mode(config: &NuConfig) -> PagerMode {
+    pager_mode_from_value(config.vars.get("table_pager"))
+}
+
+/// The pager holds the whole table back until it's read, so it's only used when asked for.
+pub fn pager_mode_from_value(mode_value: Option<&Value>) -> PagerMode {
+    match mode_value.map(|mode| mode.as_string()) {
+        Some(Ok(m)) if m == "viewer" => PagerMode::Viewer,
+        Some(Ok(m)) if m == "paginate" => PagerMode::Paginate,
+        _ => PagerMode::Never,
+    }
+}
+
 impl ConfigExtensions for NuConfig {
     fn header_style(&self) -> TextStyle {
         // FIXME: I agree, this is the long way around, please suggest and alternative.
@@ -80,7 +126,8 @@ impl ConfigExtensions for NuConfig {
             Some(s) => {
                 lookup_ansi_color_style(s.as_string().unwrap_or_else(|_| "green".to_string()))
             }
-            None => ansi_term::Color::Green.normal(),
+            None => theme_style(self.vars.get("color_theme"), "header_color")
+                .unwrap_or_else(|| ansi_term::Color::Green.normal()),
         };
         let head_bold = get_color_from_key_and_subkey(self, "color_config", "header_bold");
         let head_bold_bool = match head_bold {
@@ -108,4 +155,34 @@ impl ConfigExtensions for NuConfig {
     fn disabled_indexes(&self) -> bool {
         disabled_indexes(self)
     }
+
+    fn pager_mode(&self) -> PagerMode {
+        pager_mode(self)
+    }
+
+    fn max_column_width(&self) -> Option<usize> {
+        max_column_width(self)
+    }
+
+    fn float_precision(&self) -> usize {
+        float_precision(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pager_mode_from_value, PagerMode};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn pages_only_when_asked_to() {
+        let mode =
+            |m: &str| pager_mode_from_value(Some(&UntaggedValue::string(m).into_untagged_value()));
+
+        assert_eq!(pager_mode_from_value(None), PagerMode::Never);
+        assert_eq!(mode("never"), PagerMode::Never);
+        assert_eq!(mode("viewer"), PagerMode::Viewer);
+        assert_eq!(mode("paginate"), PagerMode::Paginate);
+        assert_eq!(mode("sometimes"), PagerMode::Never);
+    }
 }
@@ -0,0 +1,351 @@
+use crate::commands::table::command::from_list;
+use crate::commands::table::options::{NuConfig as TableConfiguration, PagerMode};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use nu_data::base::reject_fields;
+use nu_protocol::{UntaggedValue, Value};
+use nu_table::render_table;
+use std::collections::HashMap;
+use std::io::{self, Stdout, Write};
+
+/// Holds back the rows of a table so that, if it turns out taller than the terminal, it can be
+/// paged instead of printed all at once.
+pub struct Pager {
+    mode: PagerMode,
+    height: usize,
+    batches: Vec<(usize, Vec<Value>)>,
+}
+
+impl Pager {
+    /// A pager for the given mode, as long as stdout is a terminal.
+    pub fn new(mode: PagerMode) -> Option<Pager> {
+        if mode == PagerMode::Never {
+            return None;
+        }
+
+        let (_, height) = term_size::dimensions_stdout()?;
+
+        Some(Pager {
+            mode,
+            height,
+            batches: vec![],
+        })
+    }
+
+    /// Holds back a batch of rows, numbered from `start_number`.
+    pub fn push(&mut self, start_number: usize, values: Vec<Value>) {
+        self.batches.push((start_number, values));
+    }
+
+    pub fn show(
+        self,
+        configuration: &TableConfiguration,
+        term_width: usize,
+        color_hm: &HashMap<String, ansi_term::Style>,
+    ) -> crossterm::Result<()> {
+        #[cfg(windows)]
+        {
+            let _ = ansi_term::enable_ansi_support();
+        }
+
+        let batches = &self.batches;
+        let render = |hidden: &[String]| -> Vec<String> {
+            batches
+                .iter()
+                .flat_map(|(start_number, values)| {
+                    let values: Vec<Value> = values
+                        .iter()
+                        .map(|value| match &value.value {
+                            UntaggedValue::Row(_) if !hidden.is_empty() => {
+                                reject_fields(value, hidden, &value.tag)
+                            }
+                            _ => value.clone(),
+                        })
+                        .collect();
+
+                    let table = from_list(&values, configuration, *start_number, color_hm);
+
+                    render_table(&table, term_width, color_hm)
+                        .lines()
+                        .map(String::from)
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        };
+
+        let lines = render(&[]);
+
+        if lines.len() < self.height {
+            for line in lines {
+                println!("{}", line);
+            }
+
+            return Ok(());
+        }
+
+        match self.mode {
+            PagerMode::Paginate => paginate(&lines, self.height),
+            _ => Viewer {
+                render: &render,
+                lines,
+                hidden: vec![],
+                top: 0,
+                height: self.height,
+                search: String::new(),
+                matched: None,
+                status: String::new(),
+            }
+            .run(),
+        }
+    }
+}
+
+fn is_quit(key: &KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => true,
+        KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+/// Prints a screen of lines at a time, waiting for a key press in between.
+fn paginate(lines: &[String], height: usize) -> crossterm::Result<()> {
+    let mut stdout = io::stdout();
+    let page = height.saturating_sub(1).max(1);
+
+    let mut shown = page.min(lines.len());
+    for line in &lines[..shown] {
+        writeln!(stdout, "{}", line)?;
+    }
+
+    while shown < lines.len() {
+        let key = wait_for_key(
+            &mut stdout,
+            "-- more -- (space: next page, enter: next line, q: quit)",
+        )?;
+
+        let step = match key.code {
+            _ if is_quit(&key) => break,
+            KeyCode::Char(' ') | KeyCode::PageDown => page,
+            KeyCode::Enter | KeyCode::Down | KeyCode::Char('j') => 1,
+            _ => continue,
+        };
+
+        let next = (shown + step).min(lines.len());
+        for line in &lines[shown..next] {
+            writeln!(stdout, "{}", line)?;
+        }
+        shown = next;
+    }
+
+    Ok(())
+}
+
+/// Shows a status line under the output and waits for a key press, clearing it afterwards.
+fn wait_for_key(stdout: &mut Stdout, status: &str) -> crossterm::Result<KeyEvent> {
+    execute!(
+        stdout,
+        SetAttribute(Attribute::Reverse),
+        Print(status),
+        SetAttribute(Attribute::Reset)
+    )?;
+
+    enable_raw_mode()?;
+    let key = next_key();
+    disable_raw_mode()?;
+
+    execute!(stdout, Print("\r"), Clear(ClearType::CurrentLine))?;
+
+    key
+}
+
+fn next_key() -> crossterm::Result<KeyEvent> {
+    loop {
+        if let Event::Key(key) = read()? {
+            return Ok(key);
+        }
+    }
+}
+
+/// A full screen view of the table that can be scrolled, searched, and have columns hidden.
+struct Viewer<'a> {
+    render: &'a dyn Fn(&[String]) -> Vec<String>,
+    lines: Vec<String>,
+    hidden: Vec<String>,
+    top: usize,
+    height: usize,
+    search: String,
+    matched: Option<usize>,
+    status: String,
+}
+
+impl<'a> Viewer<'a> {
+    fn run(mut self) -> crossterm::Result<()> {
+        let mut stdout = io::stdout();
+
+        execute!(stdout, EnterAlternateScreen, Hide)?;
+        enable_raw_mode()?;
+
+        let result = self.event_loop(&mut stdout);
+
+        disable_raw_mode()?;
+        execute!(stdout, Show, LeaveAlternateScreen)?;
+
+        result
+    }
+
+    fn event_loop(&mut self, stdout: &mut Stdout) -> crossterm::Result<()> {
+        loop {
+            self.draw(stdout)?;
+            self.status.clear();
+
+            let key = match read()? {
+                Event::Key(key) => key,
+                Event::Resize(_, rows) => {
+                    self.height = rows as usize;
+                    self.scroll_to(self.top);
+                    continue;
+                }
+                _ => continue,
+            };
+
+            match key.code {
+                _ if is_quit(&key) => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') | KeyCode::Enter => self.scroll_to(self.top + 1),
+                KeyCode::Up | KeyCode::Char('k') => self.scroll_to(self.top.saturating_sub(1)),
+                KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_to(self.top + self.page()),
+                KeyCode::PageUp | KeyCode::Char('b') => {
+                    self.scroll_to(self.top.saturating_sub(self.page()))
+                }
+                KeyCode::Home | KeyCode::Char('g') => self.scroll_to(0),
+                KeyCode::End | KeyCode::Char('G') => self.scroll_to(self.lines.len()),
+                KeyCode::Char('/') => {
+                    if let Some(search) = self.read_input(stdout, "/")? {
+                        self.search = search;
+                        self.matched = None;
+                        self.find_next();
+                    }
+                }
+                KeyCode::Char('n') => self.find_next(),
+                KeyCode::Char('-') => {
+                    if let Some(column) = self.read_input(stdout, "hide column: ")? {
+                        self.hidden.push(column);
+                        self.rerender();
+                    }
+                }
+                KeyCode::Char('+') => {
+                    self.hidden.clear();
+                    self.rerender();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn page(&self) -> usize {
+        self.height.saturating_sub(1).max(1)
+    }
+
+    fn scroll_to(&mut self, top: usize) {
+        self.top = top.min(self.lines.len().saturating_sub(self.page()));
+    }
+
+    fn find_next(&mut self) {
+        if self.search.is_empty() {
+            return;
+        }
+
+        let from = self.matched.map_or(self.top, |matched| matched + 1);
+        let search = &self.search;
+
+        match self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(from)
+            .find(|(_, line)| line.contains(search.as_str()))
+        {
+            Some((idx, _)) => {
+                self.matched = Some(idx);
+                self.scroll_to(idx);
+            }
+            None => self.status = format!("pattern not found: {}", self.search),
+        }
+    }
+
+    fn rerender(&mut self) {
+        self.lines = (self.render)(&self.hidden);
+        self.matched = None;
+        self.scroll_to(self.top);
+    }
+
+    /// Reads a line of text typed on the status line, or `None` if cancelled with escape.
+    fn read_input(
+        &mut self,
+        stdout: &mut Stdout,
+        prompt: &str,
+    ) -> crossterm::Result<Option<String>> {
+        let mut input = String::new();
+
+        let result = loop {
+            self.status = format!("{}{}", prompt, input);
+            self.draw(stdout)?;
+
+            if let Event::Key(key) = read()? {
+                match key.code {
+                    KeyCode::Enter => break Some(input),
+                    KeyCode::Esc => break None,
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+            }
+        };
+
+        self.status.clear();
+
+        Ok(result)
+    }
+
+    fn draw(&self, stdout: &mut Stdout) -> crossterm::Result<()> {
+        queue!(stdout, Clear(ClearType::All))?;
+
+        for (row, line) in self
+            .lines
+            .iter()
+            .skip(self.top)
+            .take(self.page())
+            .enumerate()
+        {
+            queue!(stdout, MoveTo(0, row as u16), Print(line))?;
+        }
+
+        let status = if self.status.is_empty() {
+            format!(
+                "lines {}-{} of {} (arrows: scroll, /: search, n: next match, -: hide column, +: show all columns, q: quit)",
+                self.top + 1,
+                (self.top + self.page()).min(self.lines.len()),
+                self.lines.len()
+            )
+        } else {
+            self.status.clone()
+        };
+
+        queue!(
+            stdout,
+            MoveTo(0, self.page() as u16),
+            SetAttribute(Attribute::Reverse),
+            Print(status),
+            SetAttribute(Attribute::Reset)
+        )?;
+
+        stdout.flush()
+    }
+}
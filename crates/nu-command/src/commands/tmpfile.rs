@@ -0,0 +1,131 @@
+use crate::prelude::*;
+use lazy_static::lazy_static;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use parking_lot::Mutex;
+use std::io::Write;
+use tempfile::TempPath;
+
+lazy_static! {
+    // Dropping a `TempPath` deletes its file, so the files live until `remove_temp_files` runs
+    static ref TEMP_FILES: Mutex<Vec<TempPath>> = Mutex::new(vec![]);
+}
+
+/// Deletes every file created by `tmpfile` so far
+pub fn remove_temp_files() {
+    TEMP_FILES.lock().clear();
+}
+
+pub struct TmpFile;
+
+#[derive(Deserialize)]
+pub struct TmpFileArgs {
+    extension: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for TmpFile {
+    fn name(&self) -> &str {
+        "tmpfile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tmpfile").named(
+            "extension",
+            SyntaxShape::String,
+            "the extension to give the file, for programs that care about it",
+            Some('e'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Save the input to a temporary file and return its path.
+
+This fills the role of `<(...)` in bash: external commands that only read
+from files can be given the output of a pipeline. The files are removed
+when nu exits."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        tmpfile(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compare the output of two pipelines with an external diff",
+                example: "^diff $(ls | get name | to json | tmpfile) $(ls .. | get name | to json | tmpfile)",
+                result: None,
+            },
+            Example {
+                description: "Give the temporary file an extension",
+                example: "echo '{\"name\": \"nu\"}' | tmpfile --extension json",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn tmpfile(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (TmpFileArgs { extension }, input) = args.process().await?;
+
+    let mut contents: Vec<u8> = vec![];
+
+    for value in input.collect::<Vec<Value>>().await {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => contents.extend(s.as_bytes()),
+            UntaggedValue::Primitive(Primitive::Binary(b)) => contents.extend(b),
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "tmpfile requires string or binary data",
+                    "requires string or binary input",
+                    &name_tag,
+                    "consider converting the value first, e.g. with `to json`",
+                    value.tag.span,
+                ))
+            }
+        }
+    }
+
+    let suffix = match &extension {
+        Some(extension) => format!(".{}", extension.item.trim_start_matches('.')),
+        None => String::new(),
+    };
+
+    let write_error = |e: std::io::Error| {
+        ShellError::labeled_error(
+            format!("Could not create temporary file ({})", e),
+            "could not create temporary file",
+            &name_tag,
+        )
+    };
+
+    let mut file = tempfile::Builder::new()
+        .prefix("nu-")
+        .suffix(&suffix)
+        .tempfile()
+        .map_err(write_error)?;
+    file.write_all(&contents).map_err(write_error)?;
+
+    let path = file.into_temp_path();
+    let output = UntaggedValue::string(path.to_string_lossy()).into_value(&name_tag);
+    TEMP_FILES.lock().push(path);
+
+    Ok(OutputStream::one(ReturnSuccess::value(output)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::TmpFile;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(TmpFile {})?)
+    }
+}
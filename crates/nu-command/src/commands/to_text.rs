@@ -0,0 +1,287 @@
+use crate::prelude::*;
+use nu_data::value::format_leaf;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::get_data_by_column_path;
+use std::path::PathBuf;
+
+pub struct ToText;
+
+#[derive(Deserialize)]
+pub struct ToTextArgs {
+    template: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ToText {
+    fn name(&self) -> &str {
+        "to text"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to text").named(
+            "template",
+            SyntaxShape::String,
+            "a template (or the path to a template file) to render the input through",
+            Some('t'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Convert table into plain text, optionally rendered through a template.
+
+Templates interpolate columns with '{column}' (nested paths such as
+'{package.name}' work too) and '{.}' for the current value. Tables are
+looped over with '{#each}...{/each}', or '{#each column}...{/each}' for a
+table stored in a column. If the template contains no '{' it is read as
+the path to a template file."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_text(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Outputs each value on its own line",
+                example: "echo [1 2 3] | to text",
+                result: Some(vec![UntaggedValue::string("1\n2\n3").into()]),
+            },
+            Example {
+                description: "Render every row through a template",
+                example: "echo [[name, size]; [a.txt, 10] [b.txt, 20]] | to text --template '{#each}{name}={size};{/each}'",
+                result: Some(vec![UntaggedValue::string("a.txt=10;b.txt=20;").into()]),
+            },
+            Example {
+                description: "Render a report from a template file",
+                example: "ls | to text --template report.tpl | save report.txt",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn to_text(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (ToTextArgs { template }, input) = args.process().await?;
+
+    let input: Vec<Value> = input.collect().await;
+
+    let tag = if input.is_empty() {
+        name_tag
+    } else {
+        input[0].tag()
+    };
+
+    let output = match template {
+        Some(template) => {
+            let source = template_source(&template, &cwd)?;
+            let nodes = parse_template(&source, &template.tag)?;
+
+            let context = if input.len() == 1 {
+                input[0].clone()
+            } else {
+                UntaggedValue::table(&input).into_value(&tag)
+            };
+
+            let mut output = String::new();
+            render(&nodes, &context, &mut output);
+            output
+        }
+        None => input
+            .iter()
+            .map(|value| format_leaf(value).plain_string(100_000))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(output).into_value(tag),
+    )))
+}
+
+fn template_source(template: &Tagged<String>, cwd: &PathBuf) -> Result<String, ShellError> {
+    if template.item.contains('{') {
+        return Ok(template.item.clone());
+    }
+
+    std::fs::read_to_string(cwd.join(&template.item)).map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not read template file ({})", e),
+            "could not read template",
+            &template.tag,
+        )
+    })
+}
+
+#[derive(Debug, PartialEq)]
+enum TemplateNode {
+    Text(String),
+    Field(String),
+    Each(Option<String>, Vec<TemplateNode>),
+}
+
+fn parse_template(input: &str, tag: &Tag) -> Result<Vec<TemplateNode>, ShellError> {
+    // Each open `{#each}` gets its own frame, finished frames are folded into their parent
+    let mut frames: Vec<(Option<String>, Vec<TemplateNode>)> = vec![(None, vec![])];
+    let mut rest = input;
+
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Unclosed '{' in template",
+                    "expected a matching '}'",
+                    tag,
+                ))
+            }
+        };
+
+        if start > 0 {
+            push_node(&mut frames, TemplateNode::Text(rest[..start].to_string()));
+        }
+
+        let tag_body = rest[start + 1..end].trim();
+
+        if let Some(path) = tag_body.strip_prefix("#each") {
+            let path = path.trim();
+            let path = if path.is_empty() || path == "." {
+                None
+            } else {
+                Some(path.to_string())
+            };
+
+            frames.push((path, vec![]));
+        } else if tag_body == "/each" {
+            if frames.len() == 1 {
+                return Err(ShellError::labeled_error(
+                    "Unexpected '{/each}' in template",
+                    "no '{#each}' to close",
+                    tag,
+                ));
+            }
+
+            let (path, nodes) = frames.pop().expect("frames can not be empty here");
+            push_node(&mut frames, TemplateNode::Each(path, nodes));
+        } else {
+            push_node(&mut frames, TemplateNode::Field(tag_body.to_string()));
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        push_node(&mut frames, TemplateNode::Text(rest.to_string()));
+    }
+
+    if frames.len() > 1 {
+        return Err(ShellError::labeled_error(
+            "Unclosed '{#each}' in template",
+            "expected a matching '{/each}'",
+            tag,
+        ));
+    }
+
+    Ok(frames.pop().map(|(_, nodes)| nodes).unwrap_or_default())
+}
+
+fn push_node(frames: &mut Vec<(Option<String>, Vec<TemplateNode>)>, node: TemplateNode) {
+    if let Some((_, nodes)) = frames.last_mut() {
+        nodes.push(node);
+    }
+}
+
+fn lookup(context: &Value, path: &str) -> Option<Value> {
+    if path == "." {
+        return Some(context.clone());
+    }
+
+    let path = ColumnPath::build(&path.to_string().spanned_unknown());
+
+    get_data_by_column_path(context, &path, move |_, _, error| error).ok()
+}
+
+fn render(nodes: &[TemplateNode], context: &Value, output: &mut String) {
+    for node in nodes {
+        match node {
+            TemplateNode::Text(text) => output.push_str(text),
+            TemplateNode::Field(path) => {
+                // Like `format`, a column that doesn't match emits nothing
+                if let Some(value) = lookup(context, path) {
+                    output.push_str(&format_leaf(&value).plain_string(100_000));
+                }
+            }
+            TemplateNode::Each(path, body) => {
+                let target = match path {
+                    Some(path) => lookup(context, path),
+                    None => Some(context.clone()),
+                };
+
+                match target {
+                    Some(Value {
+                        value: UntaggedValue::Table(rows),
+                        ..
+                    }) => {
+                        for row in rows.iter() {
+                            render(body, row, output);
+                        }
+                    }
+                    Some(value) => render(body, &value, output),
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_template, ShellError, TemplateNode, ToText};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(ToText {})?)
+    }
+
+    #[test]
+    fn parses_nested_loops() -> Result<(), ShellError> {
+        let nodes = parse_template(
+            "{#each}{name}:{#each deps}{.} {/each}{/each}",
+            &Tag::unknown(),
+        )?;
+
+        assert_eq!(
+            nodes,
+            vec![TemplateNode::Each(
+                None,
+                vec![
+                    TemplateNode::Field("name".to_string()),
+                    TemplateNode::Text(":".to_string()),
+                    TemplateNode::Each(
+                        Some("deps".to_string()),
+                        vec![
+                            TemplateNode::Field(".".to_string()),
+                            TemplateNode::Text(" ".to_string()),
+                        ]
+                    ),
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors_on_unclosed_loops() {
+        assert!(parse_template("{#each}{name}", &Tag::unknown()).is_err());
+        assert!(parse_template("{name}{/each}", &Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, hir::ExternalRedirection, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
+};
+use nu_source::{Span, Tagged};
+
+pub struct Try;
+
+#[derive(Deserialize)]
+struct TryArgs {
+    block: CapturedBlock,
+    catch: Option<Tagged<String>>,
+    catch_block: Option<CapturedBlock>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Try {
+    fn name(&self) -> &str {
+        "try"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("try")
+            .required("block", SyntaxShape::Block, "the block to run")
+            .optional(
+                "catch",
+                SyntaxShape::String,
+                "the word `catch`, followed by the block to run if the first one fails",
+            )
+            .optional(
+                "catch_block",
+                SyntaxShape::Block,
+                "the block to run with the error, as a row of message, label and span",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Runs a block, and if it fails, runs another block with the error.
+
+The error is given to the catch block as $it, or as its input when $it isn't used, in a row with the message, the label and the span of the error. Without a catch block, the error is dropped. Either way, what the first block output before failing is kept."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        try_(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Run a block that may fail",
+                example: "try { echo [1 2] | get name } catch { echo 'no name' }",
+                result: Some(vec![Value::from("no name")]),
+            },
+            Example {
+                description: "Drop the error of a block",
+                example: "try { echo [1 2] | get name }",
+                result: Some(vec![]),
+            },
+            Example {
+                description: "Keep going through a list when the block fails for some rows",
+                example:
+                    "echo [[name]; [a] [b]] | each { try { echo $it | get size } catch { get message } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn try_(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let external_redirection = raw_args.call_info.args.external_redirection;
+    let tag = raw_args.call_info.name_tag.clone();
+
+    let context = EvaluationContext::from_args(&raw_args);
+    let (
+        TryArgs {
+            mut block,
+            catch,
+            catch_block,
+        },
+        input,
+    ) = raw_args.process().await?;
+
+    let catch_block = match (catch, catch_block) {
+        (Some(word), Some(catch_block)) if word.item == "catch" => Some(catch_block),
+        (None, None) => None,
+        (Some(word), _) => {
+            return Err(ShellError::labeled_error(
+                "Expected a catch block",
+                "expected `catch { ... }`",
+                word.tag,
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(ShellError::labeled_error(
+                "Expected a catch block",
+                "expected `catch { ... }`",
+                tag,
+            ))
+        }
+    };
+
+    // The block may fail, so its errors are kept off the terminal, like `do -i`
+    let block_redirection = match external_redirection {
+        ExternalRedirection::None => ExternalRedirection::Stderr,
+        ExternalRedirection::Stdout => ExternalRedirection::StdoutAndStderr,
+        x => x,
+    };
+    block.block.set_redirect(block_redirection);
+
+    let known_errors = context.get_errors().len();
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+    let (mut output, error) = match run_block(&block.block, &context, input).await {
+        Ok(mut stream) => {
+            let mut output = stream.drain_vec().await;
+
+            let error = match output.iter().position(|value| value.is_error()) {
+                Some(index) => Some(output.split_off(index)[0].expect_error()),
+                None => context
+                    .with_errors(|errors| errors.drain(known_errors.min(errors.len())..).next()),
+            };
+
+            (output, error)
+        }
+        Err(error) => (vec![], Some(error)),
+    };
+    context.scope.exit_scope();
+
+    let (error, catch_block) = match (error, catch_block) {
+        (Some(error), Some(catch_block)) => (error, catch_block),
+        _ => return Ok(futures::stream::iter(output).to_output_stream()),
+    };
+
+    let error = error_value(error, &tag);
+    context.scope.enter_scope();
+    context.scope.add_vars(&catch_block.captured.entries);
+
+    let catch_input = if !catch_block.block.params.positional.is_empty() {
+        context
+            .scope
+            .add_var(catch_block.block.params.positional[0].0.name(), error);
+        InputStream::empty()
+    } else {
+        InputStream::one(error)
+    };
+
+    let result = run_block(&catch_block.block, &context, catch_input).await;
+    let caught = match result {
+        Ok(mut stream) => stream.drain_vec().await,
+        Err(error) => {
+            context.scope.exit_scope();
+            return Err(error);
+        }
+    };
+    context.scope.exit_scope();
+
+    output.extend(caught);
+    Ok(futures::stream::iter(output).to_output_stream())
+}
+
+/// A row describing an error, with its message, label and span. Errors without a span of their own
+/// point at the `try` that caught them.
+fn error_value(error: ShellError, tag: &Tag) -> Value {
+    let (message, label, span) = match error.into_diagnostic() {
+        Some(diagnostic) => match diagnostic.labels.first() {
+            Some(label) => (
+                diagnostic.message.clone(),
+                label.message.clone(),
+                Span::new(label.range.start, label.range.end),
+            ),
+            None => (diagnostic.message.clone(), String::new(), tag.span),
+        },
+        None => (
+            "External command failed".to_string(),
+            String::new(),
+            tag.span,
+        ),
+    };
+
+    let mut span_dict = TaggedDictBuilder::new(tag);
+    span_dict.insert_untagged("start", UntaggedValue::int(span.start()));
+    span_dict.insert_untagged("end", UntaggedValue::int(span.end()));
+
+    let mut dict = TaggedDictBuilder::new(tag);
+    dict.insert_untagged("message", UntaggedValue::string(message));
+    dict.insert_untagged("label", UntaggedValue::string(label));
+    dict.insert_value("span", span_dict.into_value());
+
+    dict.into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{error_value, Try};
+    use nu_source::{Span, SpannedItem, Tag};
+    use nu_value_ext::ValueExt;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Try {})?)
+    }
+
+    #[test]
+    fn errors_become_rows() {
+        let error = ShellError::labeled_error("Not found", "here", Span::new(3, 7));
+        let row = error_value(error, &Tag::unknown());
+
+        let field = |name: &str| {
+            row.get_data_by_key(name.spanned_unknown())
+                .expect("a field of the error")
+        };
+
+        assert_eq!(field("message").as_string().unwrap(), "Not found");
+        assert_eq!(field("label").as_string().unwrap(), "here");
+        assert_eq!(
+            field("span")
+                .get_data_by_key("start".spanned_unknown())
+                .expect("a start")
+                .as_u64()
+                .unwrap(),
+            3
+        );
+    }
+}
@@ -1,10 +1,19 @@
 use crate::prelude::*;
+use crate::utils::parallel::par_reduce;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, UntaggedValue};
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::get_data_by_column_path;
+use std::collections::HashSet;
 
 pub struct Uniq;
 
+#[derive(Deserialize)]
+pub struct UniqArgs {
+    count: bool,
+    column: Option<ColumnPath>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Uniq {
     fn name(&self) -> &str {
@@ -12,7 +21,14 @@ impl WholeStreamCommand for Uniq {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("uniq").switch("count", "Count the unique rows", Some('c'))
+        Signature::build("uniq")
+            .switch("count", "Count the unique rows", Some('c'))
+            .named(
+                "column",
+                SyntaxShape::ColumnPath,
+                "compare rows by the value of this column instead of the whole row",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -50,70 +66,104 @@ impl WholeStreamCommand for Uniq {
                     .into(),
                 ]),
             },
+            Example {
+                description: "Keep the first row for each distinct value of a column",
+                example: "echo [[name, type]; [a.txt, File] [src, Dir] [b.txt, File]] | uniq --column type | get name",
+                result: Some(vec![
+                    UntaggedValue::string("a.txt").into(),
+                    UntaggedValue::string("src").into(),
+                ]),
+            },
         ]
     }
 }
 
 async fn uniq(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once().await?;
-    let should_show_count = args.has("count");
-    let input = args.input;
-    let uniq_values = {
-        let mut counter = IndexMap::<nu_protocol::Value, usize>::new();
-        for line in input.into_vec().await {
-            *counter.entry(line).or_insert(0) += 1;
-        }
-        counter
+    let (UniqArgs { count, column }, input) = args.process().await?;
+    let should_show_count = count;
+
+    let key_of = move |value: &Value| match &column {
+        Some(path) => get_data_by_column_path(value, path, move |_, _, error| error)
+            .unwrap_or_else(|_| UntaggedValue::nothing().into_untagged_value()),
+        None => value.clone(),
     };
 
+    if !should_show_count {
+        // Without counts, a value can be passed along as soon as it is first seen
+        let mut seen = HashSet::new();
+
+        return Ok(input
+            .filter(move |value| futures::future::ready(seen.insert(key_of(value))))
+            .to_output_stream());
+    }
+
+    // Large inputs are counted in chunks on several threads. Merging the counts in the order of
+    // the chunks keeps each row where it was first seen.
+    let values = input.into_vec().await;
+    let uniq_values = par_reduce(
+        &values,
+        |chunk| {
+            let mut counter = IndexMap::<Value, (Value, usize)>::new();
+            for line in chunk {
+                counter
+                    .entry(key_of(line))
+                    .or_insert_with(|| (line.clone(), 0))
+                    .1 += 1;
+            }
+            Ok(counter)
+        },
+        |partials| {
+            let mut counter = IndexMap::<Value, (Value, usize)>::new();
+            for (key, (line, count)) in partials.into_iter().flatten() {
+                counter.entry(key).or_insert_with(|| (line, 0)).1 += count;
+            }
+            Ok(counter)
+        },
+    )?
+    .into_iter()
+    .map(|(_, item)| item);
+
     let mut values_vec_deque = VecDeque::new();
 
-    if should_show_count {
-        for item in uniq_values {
-            use nu_protocol::Value;
-            let value = {
-                match item.0.value {
-                    UntaggedValue::Row(mut row) => {
-                        row.entries.insert(
-                            "count".to_string(),
-                            UntaggedValue::int(item.1).into_untagged_value(),
-                        );
-                        Value {
-                            value: UntaggedValue::Row(row),
-                            tag: item.0.tag,
-                        }
+    for item in uniq_values {
+        let value = {
+            match item.0.value {
+                UntaggedValue::Row(mut row) => {
+                    row.entries.insert(
+                        "count".to_string(),
+                        UntaggedValue::int(item.1).into_untagged_value(),
+                    );
+                    Value {
+                        value: UntaggedValue::Row(row),
+                        tag: item.0.tag,
                     }
-                    UntaggedValue::Primitive(p) => {
-                        let mut map = IndexMap::<String, Value>::new();
-                        map.insert(
-                            "value".to_string(),
-                            UntaggedValue::Primitive(p).into_untagged_value(),
-                        );
-                        map.insert(
-                            "count".to_string(),
-                            UntaggedValue::int(item.1).into_untagged_value(),
-                        );
-                        Value {
-                            value: UntaggedValue::row(map),
-                            tag: item.0.tag,
-                        }
-                    }
-                    UntaggedValue::Table(_) => {
-                        return Err(ShellError::labeled_error(
-                            "uniq -c cannot operate on tables.",
-                            "source",
-                            item.0.tag.span,
-                        ))
+                }
+                UntaggedValue::Primitive(p) => {
+                    let mut map = IndexMap::<String, Value>::new();
+                    map.insert(
+                        "value".to_string(),
+                        UntaggedValue::Primitive(p).into_untagged_value(),
+                    );
+                    map.insert(
+                        "count".to_string(),
+                        UntaggedValue::int(item.1).into_untagged_value(),
+                    );
+                    Value {
+                        value: UntaggedValue::row(map),
+                        tag: item.0.tag,
                     }
-                    UntaggedValue::Error(_) | UntaggedValue::Block(_) => item.0,
                 }
-            };
-            values_vec_deque.push_back(value);
-        }
-    } else {
-        for item in uniq_values {
-            values_vec_deque.push_back(item.0);
-        }
+                UntaggedValue::Table(_) => {
+                    return Err(ShellError::labeled_error(
+                        "uniq -c cannot operate on tables.",
+                        "source",
+                        item.0.tag.span,
+                    ))
+                }
+                UntaggedValue::Error(_) | UntaggedValue::Block(_) => item.0,
+            }
+        };
+        values_vec_deque.push_back(value);
     }
 
     Ok(futures::stream::iter(values_vec_deque).to_output_stream())
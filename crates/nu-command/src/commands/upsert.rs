@@ -0,0 +1,162 @@
+use crate::prelude::*;
+use nu_engine::run_block;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::CapturedBlock, ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+
+use futures::stream::once;
+
+pub struct Command;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    column: ColumnPath,
+    value: Value,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "upsert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("upsert")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column to update or insert",
+            )
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "the value to give the cell(s), or a block given the row and the previous value",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Update a column, or insert it where it's missing."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        upsert(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set a column, whether the rows have it or not",
+                example: "echo [[name]; [nu]] | upsert stars 5",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "name".to_string() => Value::from("nu"),
+                        "stars".to_string() => UntaggedValue::int(5).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Count with a block given the row and the previous value, which is nothing where the column is missing",
+                example: "echo [[name, visits]; [home, 1]] | upsert visits {|row, old| = $old + 1 }",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "name".to_string() => Value::from("home"),
+                        "visits".to_string() => UntaggedValue::int(2).into(),
+                })
+                .into()]),
+            },
+        ]
+    }
+}
+
+async fn process_row(
+    context: Arc<EvaluationContext>,
+    input: Value,
+    value: Arc<Value>,
+    column: Arc<ColumnPath>,
+) -> Result<Value, ShellError> {
+    let value = match &value.value {
+        UntaggedValue::Block(block) => {
+            let old = input
+                .get_data_by_column_path(&column, Box::new(|_, _, error| error))
+                .unwrap_or_else(|_| UntaggedValue::nothing().into_value(&input.tag));
+
+            run_with_row(&context, block, &input, old).await?
+        }
+        _ => (*value).clone(),
+    };
+
+    input.insert_data_at_column_path(&column, value)
+}
+
+/// Runs the block with the row and the previous value as its parameters, or with the row as `$it`
+/// and its input when the block has none.
+async fn run_with_row(
+    context: &EvaluationContext,
+    block: &CapturedBlock,
+    row: &Value,
+    old: Value,
+) -> Result<Value, ShellError> {
+    let params = &block.block.params.positional;
+
+    context.scope.enter_scope();
+    context.scope.add_vars(&block.captured.entries);
+
+    let input_stream = if params.is_empty() {
+        context.scope.add_var("$it", row.clone());
+        let for_block = row.clone();
+        once(async { Ok(for_block) }).to_input_stream()
+    } else {
+        context.scope.add_var(params[0].0.name(), row.clone());
+        if let Some(param) = params.get(1) {
+            context.scope.add_var(param.0.name(), old);
+        }
+        InputStream::empty()
+    };
+
+    let result = run_block(&block.block, context, input_stream).await;
+    context.scope.exit_scope();
+
+    let values = result?.drain_vec().await;
+
+    let errors = context.get_errors();
+    if let Some(error) = errors.first() {
+        return Err(error.clone());
+    }
+
+    Ok(match values.len() {
+        0 => UntaggedValue::nothing().into_value(&row.tag),
+        1 => values[0].value.clone().into_value(&row.tag),
+        _ => UntaggedValue::table(&values).into_value(&row.tag),
+    })
+}
+
+async fn upsert(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let context = Arc::new(EvaluationContext::from_args(&raw_args));
+    let (Arguments { column, value }, input) = raw_args.process().await?;
+    let value = Arc::new(value);
+    let column = Arc::new(column);
+
+    Ok(input
+        .then(move |input| {
+            let context = context.clone();
+            let value = value.clone();
+            let column = column.clone();
+
+            async { ReturnSuccess::value(process_row(context, input, value, column).await?) }
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Command {})?)
+    }
+}
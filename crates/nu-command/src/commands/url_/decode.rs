@@ -0,0 +1,93 @@
+use super::DefaultArguments;
+use crate::commands::encode_::{bytes_of, decoded_value, operate};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct UrlDecode;
+
+#[async_trait]
+impl WholeStreamCommand for UrlDecode {
+    fn name(&self) -> &str {
+        "url decode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url decode")
+            .rest(SyntaxShape::ColumnPath, "optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "decodes a percent-encoded string"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (DefaultArguments { rest }, input) = args.process().await?;
+
+        operate(input, rest, |value| {
+            let decoded = percent_decode(bytes_of(value)?).ok_or_else(|| {
+                ShellError::labeled_error(
+                    "value could not be url decoded",
+                    "invalid percent-encoding",
+                    value.tag.span,
+                )
+            })?;
+
+            Ok(decoded_value(decoded, value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Decode a query parameter",
+            example: "echo 'fish%20%26%20chips' | url decode",
+            result: Some(vec![Value::from("fish & chips")]),
+        }]
+    }
+}
+
+fn percent_decode(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut bytes = bytes.iter();
+
+    while let Some(byte) = bytes.next() {
+        if *byte == b'%' {
+            let high = (*bytes.next()? as char).to_digit(16)?;
+            let low = (*bytes.next()? as char).to_digit(16)?;
+
+            output.push((high * 16 + low) as u8);
+        } else {
+            output.push(*byte);
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{percent_decode, UrlDecode};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(UrlDecode {})?)
+    }
+
+    #[test]
+    fn decodes_multibyte_characters() {
+        assert_eq!(
+            percent_decode(b"a%C3%B1o%2f1"),
+            Some("año/1".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_escapes() {
+        assert_eq!(percent_decode(b"100%"), None);
+        assert_eq!(percent_decode(b"%4"), None);
+    }
+}
@@ -0,0 +1,75 @@
+use super::DefaultArguments;
+use crate::commands::encode_::{bytes_of, operate};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct UrlEncode;
+
+#[async_trait]
+impl WholeStreamCommand for UrlEncode {
+    fn name(&self) -> &str {
+        "url encode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url encode")
+            .rest(SyntaxShape::ColumnPath, "optionally operate by column path")
+    }
+
+    fn usage(&self) -> &str {
+        "percent-encodes a string so it can be used as part of a url"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let (DefaultArguments { rest }, input) = args.process().await?;
+
+        operate(input, rest, |value| {
+            let encoded = percent_encode(bytes_of(value)?);
+            Ok(UntaggedValue::string(encoded).into_value(value.tag()))
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Encode a query parameter",
+            example: "echo 'fish & chips' | url encode",
+            result: Some(vec![Value::from("fish%20%26%20chips")]),
+        }]
+    }
+}
+
+/// Escapes everything except the unreserved characters of RFC 3986
+fn percent_encode(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len());
+
+    for byte in bytes {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                output.push(*byte as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::{percent_encode, UrlEncode};
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(UrlEncode {})?)
+    }
+
+    #[test]
+    fn encodes_multibyte_characters() {
+        assert_eq!(percent_encode("año/1".as_bytes()), "a%C3%B1o%2F1");
+    }
+}
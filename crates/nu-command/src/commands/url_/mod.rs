@@ -1,4 +1,6 @@
 mod command;
+mod decode;
+mod encode;
 mod host;
 mod path;
 mod query;
@@ -10,6 +12,8 @@ use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, ShellTypeName, UntaggedV
 use url::Url;
 
 pub use command::Url as UrlCommand;
+pub use decode::UrlDecode;
+pub use encode::UrlEncode;
 pub use host::UrlHost;
 pub use path::UrlPath;
 pub use query::UrlQuery;
@@ -0,0 +1,205 @@
+use crate::commands::schema::{column_type, is_null};
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Validate;
+
+#[derive(Deserialize)]
+pub struct ValidateArgs {
+    schema: Value,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Validate {
+    fn name(&self) -> &str {
+        "validate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("validate").required(
+            "schema",
+            SyntaxShape::Any,
+            "a row naming the type of each column, or the output of `schema`",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Checks that every row of a table matches a schema, passing the rows through as they're checked.
+
+The schema is a row with the type each column should have, such as `string` or `integer`. Several types can be allowed with `|` (as in `integer|nothing`), and `any` allows every type. The output of `schema` can be used as well, allowing the types it found.
+
+The first cell that doesn't match is reported as an error pointing at the cell, and no rows are passed on after it."#
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        validate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check the types of the columns before saving",
+                example: "open people.csv | validate $(echo [[name age]; [string integer]]) | save people.json",
+                result: None,
+            },
+            Example {
+                description: "Check that new data has the shape of the old",
+                example: "let expected = $(open old.csv | schema); open new.csv | validate $expected",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn validate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (ValidateArgs { schema }, input) = args.process().await?;
+
+    let expected = expected_types(&schema)?;
+
+    // Rows pass on as they're checked, up to the first that doesn't match
+    Ok(input
+        .enumerate()
+        .scan(false, move |failed, (index, row)| {
+            if *failed {
+                return futures::future::ready(None);
+            }
+
+            let checked = match check_row(&row, index, &expected, &name_tag) {
+                Ok(()) => ReturnSuccess::value(row),
+                Err(error) => {
+                    *failed = true;
+                    Err(error)
+                }
+            };
+
+            futures::future::ready(Some(checked))
+        })
+        .to_output_stream())
+}
+
+fn check_row(
+    row: &Value,
+    index: usize,
+    expected: &[(String, Vec<String>)],
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    for (column, allowed) in expected {
+        let found = match row.get_data_by_key(column.as_str().spanned_unknown()) {
+            Some(value) => value,
+            None if allowed.iter().any(|t| t == "any" || t == "nothing") => continue,
+            None => {
+                return Err(ShellError::labeled_error(
+                    format!("Row {} does not match the schema", index),
+                    format!("missing column '{}'", column),
+                    span_or(&row.tag, name_tag),
+                ))
+            }
+        };
+
+        let found_type = if is_null(&found) {
+            "nothing"
+        } else {
+            column_type(&found)
+        };
+
+        if !allowed.iter().any(|t| t == "any" || t == found_type) {
+            return Err(ShellError::labeled_error(
+                format!("Row {} does not match the schema", index),
+                format!(
+                    "column '{}' should be {}, found {}",
+                    column,
+                    allowed.join(" or "),
+                    found_type
+                ),
+                span_or(&found.tag, name_tag),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The types allowed in each column, read from a row of type names or from the output of
+/// `schema`.
+fn expected_types(schema: &Value) -> Result<Vec<(String, Vec<String>)>, ShellError> {
+    let invalid = || {
+        ShellError::labeled_error(
+            "Expected a schema",
+            "needs a row of column types or the output of `schema`",
+            &schema.tag,
+        )
+    };
+
+    match &schema.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .iter()
+            .map(|(column, types)| {
+                let types = types.as_string().map_err(|_| invalid())?;
+                Ok((
+                    column.clone(),
+                    types.split('|').map(|t| t.trim().to_string()).collect(),
+                ))
+            })
+            .collect(),
+        UntaggedValue::Table(rows)
+            if rows.len() == 1
+                && rows[0]
+                    .get_data_by_key("column".spanned_unknown())
+                    .is_none() =>
+        {
+            expected_types(&rows[0])
+        }
+        UntaggedValue::Table(rows) => rows
+            .iter()
+            .map(|row| {
+                let column = row
+                    .get_data_by_key("column".spanned_unknown())
+                    .ok_or_else(invalid)?
+                    .as_string()?;
+                let mut types: Vec<String> = row
+                    .get_data_by_key("types".spanned_unknown())
+                    .ok_or_else(invalid)?
+                    .row_entries()
+                    .map(|(name, _)| name.clone())
+                    .collect();
+
+                // Columns `schema` found empty cells in may be empty
+                let has_nulls = row
+                    .get_data_by_key("nulls".spanned_unknown())
+                    .map(|nulls| nulls.as_i64().map(|n| n > 0).unwrap_or(false))
+                    .unwrap_or(false);
+                if has_nulls {
+                    types.push("nothing".to_string());
+                }
+
+                Ok((column, types))
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
+fn span_or(tag: &Tag, fallback: &Tag) -> Span {
+    if tag.span.is_unknown() {
+        fallback.span
+    } else {
+        tag.span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::Validate;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(Validate {})?)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
+};
+use nu_source::Spanned;
+
+pub struct ViewSource;
+
+#[derive(Deserialize)]
+pub struct ViewSourceArgs {
+    item: Value,
+}
+
+#[async_trait]
+impl WholeStreamCommand for ViewSource {
+    fn name(&self) -> &str {
+        "view source"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("view source").required(
+            "item",
+            SyntaxShape::Any,
+            "the name of a custom command or alias, or a block",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Shows the source of a custom command, alias, or block, and where it was defined."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        view_source(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the source of a custom command",
+                example: "def greet [name] { echo $name }; view source greet",
+                result: None,
+            },
+            Example {
+                description: "Show the source of a block stored in a variable",
+                example: "let double = { = $it * 2 }; view source $double",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn view_source(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let (ViewSourceArgs { item }, _) = args.process().await?;
+
+    let source = match &item.value {
+        UntaggedValue::Block(captured) => block_source(&captured.block, &item.tag)?,
+        UntaggedValue::Primitive(Primitive::String(name)) => {
+            if let Some(blocks) = scope.get_custom_commands_with_name(name) {
+                block_source(&blocks[0], &item.tag)?
+            } else if let Some(aliases) = scope.get_aliases_with_name(name) {
+                alias_source(name, &aliases[0], &item.tag)
+            } else if scope.has_command(name) {
+                return Err(ShellError::labeled_error(
+                    "Built-in commands have no nu source",
+                    format!("{} is built in", name),
+                    &item.tag,
+                ));
+            } else {
+                return Err(ShellError::labeled_error(
+                    "No custom command or alias with that name",
+                    "not found",
+                    &item.tag,
+                ));
+            }
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected the name of a command or a block",
+                format!("found {}", item.type_name()),
+                &item.tag,
+            ))
+        }
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(source)))
+}
+
+fn source_row(source: &str, file: &Option<String>, span: Span, tag: &Tag) -> Value {
+    let mut row = TaggedDictBuilder::new(tag);
+
+    row.insert_untagged("source", UntaggedValue::string(source));
+    row.insert_untagged(
+        "file",
+        match file {
+            Some(file) => UntaggedValue::filepath(file),
+            None => UntaggedValue::nothing(),
+        },
+    );
+    row.insert_untagged("start", UntaggedValue::int(span.start()));
+    row.insert_untagged("end", UntaggedValue::int(span.end()));
+
+    row.into_value()
+}
+
+fn block_source(block: &Block, tag: &Tag) -> Result<Value, ShellError> {
+    match &block.source {
+        Some(source) => Ok(source_row(source, &block.source_file, block.span, tag)),
+        None => Err(ShellError::labeled_error(
+            "The source of this block wasn't kept",
+            "no source",
+            tag,
+        )),
+    }
+}
+
+fn alias_source(name: &str, expansion: &[Spanned<String>], tag: &Tag) -> Value {
+    let words: Vec<&str> = expansion.iter().map(|word| word.item.as_str()).collect();
+    let span = match (expansion.first(), expansion.last()) {
+        (Some(first), Some(last)) => first.span.until(last.span),
+        _ => Span::unknown(),
+    };
+
+    source_row(
+        &format!("alias {} = {}", name, words.join(" ")),
+        &None,
+        span,
+        tag,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ViewSource;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        Ok(test_examples(ViewSource {})?)
+    }
+}
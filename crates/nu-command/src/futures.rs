@@ -1,6 +1,6 @@
 use futures::stream::Stream;
 use std::pin::Pin;
-use std::sync::{mpsc, Arc, Mutex};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
 use std::task::{self, Poll, Waker};
 use std::thread;
 
@@ -106,6 +106,89 @@ impl<T: Send + 'static> Drop for ThreadedReceiver<T> {
     }
 }
 
+struct ByteLimitState {
+    in_flight: usize,
+    closed: bool,
+}
+
+/// Limits the bytes a thread may send ahead of the stream consuming them, on top of the limit the
+/// channel puts on the number of values. The thread reserves the bytes of each value before
+/// sending it, and the stream releases them once it has taken the value.
+#[derive(Clone)]
+pub struct ByteLimit {
+    limit: usize,
+    state: Arc<(Mutex<ByteLimitState>, Condvar)>,
+}
+
+impl ByteLimit {
+    pub fn new(limit: usize) -> ByteLimit {
+        ByteLimit {
+            limit,
+            state: Arc::new((
+                Mutex::new(ByteLimitState {
+                    in_flight: 0,
+                    closed: false,
+                }),
+                Condvar::new(),
+            )),
+        }
+    }
+
+    /// Waits until the bytes fit under the limit. A value bigger than the limit still goes once
+    /// nothing else is in flight. Gives false if the consuming side has gone away.
+    pub fn reserve(&self, bytes: usize) -> bool {
+        let (state, freed) = &*self.state;
+        let mut state = state.lock().expect("ByteLimit state shouldn't be poisoned");
+
+        while !state.closed && state.in_flight > 0 && state.in_flight + bytes > self.limit {
+            state = freed
+                .wait(state)
+                .expect("ByteLimit state shouldn't be poisoned");
+        }
+
+        if state.closed {
+            return false;
+        }
+
+        state.in_flight += bytes;
+        true
+    }
+
+    /// The consuming side, which releases the bytes of the values it takes and lets the thread
+    /// know once it's dropped.
+    pub fn releaser(&self) -> ByteLimitReleaser {
+        ByteLimitReleaser {
+            limit: self.clone(),
+        }
+    }
+
+    fn update(&self, update: impl FnOnce(&mut ByteLimitState)) {
+        let (state, freed) = &*self.state;
+        match state.lock() {
+            Ok(mut state) => update(&mut state),
+            Err(mut poisoned_err) => update(poisoned_err.get_mut()),
+        }
+        freed.notify_all();
+    }
+}
+
+pub struct ByteLimitReleaser {
+    limit: ByteLimit,
+}
+
+impl ByteLimitReleaser {
+    pub fn release(&self, bytes: usize) {
+        self.limit
+            .update(|state| state.in_flight = state.in_flight.saturating_sub(bytes));
+    }
+}
+
+impl Drop for ByteLimitReleaser {
+    fn drop(&mut self) {
+        self.limit.update(|state| state.closed = true);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod threaded_receiver {
@@ -146,4 +229,49 @@ mod tests {
             assert_eq!(true, result.unwrap().is_err());
         }
     }
+
+    mod byte_limit {
+        use super::super::ByteLimit;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        #[test]
+        fn waits_for_the_bytes_in_flight_to_be_released() {
+            let limit = ByteLimit::new(10);
+            let releaser = limit.releaser();
+            let (tx, rx) = mpsc::channel();
+
+            assert!(limit.reserve(6));
+            let th = std::thread::spawn(move || {
+                let reserved = limit.reserve(6);
+                let _ = tx.send(());
+                reserved
+            });
+
+            assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+            releaser.release(6);
+            assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+            assert!(th.join().unwrap());
+        }
+
+        #[test]
+        fn lets_a_value_bigger_than_the_limit_through_alone() {
+            let limit = ByteLimit::new(10);
+            let _releaser = limit.releaser();
+
+            assert!(limit.reserve(100));
+        }
+
+        #[test]
+        fn stops_waiting_once_the_consumer_is_dropped() {
+            let limit = ByteLimit::new(10);
+            let releaser = limit.releaser();
+
+            assert!(limit.reserve(10));
+            let th = std::thread::spawn(move || limit.reserve(1));
+
+            drop(releaser);
+            assert!(!th.join().unwrap());
+        }
+    }
 }
@@ -15,6 +15,7 @@ pub mod utils;
 #[cfg(test)]
 mod examples;
 
+pub use crate::commands::tmpfile::remove_temp_files;
 pub use crate::maybe_print_errors::maybe_print_errors;
 
 pub use nu_data::config;
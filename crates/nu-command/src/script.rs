@@ -1,5 +1,6 @@
 use crate::maybe_print_errors;
 use crate::prelude::*;
+use crate::remove_temp_files;
 use futures_codec::FramedRead;
 use nu_engine::path::canonicalize;
 use nu_engine::run_block;
@@ -34,13 +35,22 @@ fn chomp_newline(s: &str) -> &str {
 }
 
 pub fn print_err(err: ShellError, source: &Text) {
+    print_file_err(err, "shell", source)
+}
+
+/// Prints an error pointing into a file, such as the config file.
+pub fn print_file_err(err: ShellError, file: &str, source: &Text) {
     if let Some(diag) = err.into_diagnostic() {
         let source = source.to_string();
         let mut files = codespan_reporting::files::SimpleFiles::new();
-        files.add("shell", source);
+        files.add(file.to_string(), source);
 
         let writer = codespan_reporting::term::termcolor::StandardStream::stderr(
-            codespan_reporting::term::termcolor::ColorChoice::Always,
+            if nu_table::colors_enabled() {
+                codespan_reporting::term::termcolor::ColorChoice::Always
+            } else {
+                codespan_reporting::term::termcolor::ColorChoice::Never
+            },
         );
         let config = codespan_reporting::term::Config::default();
 
@@ -62,6 +72,7 @@ pub async fn process_script(
         LineResult::Success(script_text.to_string())
     } else {
         let line = chomp_newline(script_text);
+        ctx.scope.forget_pipeline_buffer();
 
         let (block, err) = nu_parser::parse(&line, span_offset, &ctx.scope);
 
@@ -117,44 +128,9 @@ pub async fn process_script(
                     && Path::new(&name).is_dir()
                     && !crate::commands::classified::external::did_find_command(&name)
                 {
-                    // Here we work differently if we're in Windows because of the expected Windows behavior
-                    #[cfg(windows)]
-                    {
-                        if name.ends_with(':') {
-                            // This looks like a drive shortcut. We need to a) switch drives and b) go back to the previous directory we were viewing on that drive
-                            // But first, we need to save where we are now
-                            let current_path = ctx.shell_manager.path();
-
-                            let split_path: Vec<_> = current_path.split(':').collect();
-                            if split_path.len() > 1 {
-                                ctx.windows_drives_previous_cwd
-                                    .lock()
-                                    .insert(split_path[0].to_string(), current_path);
-                            }
-
-                            let name = name.to_uppercase();
-                            let new_drive: Vec<_> = name.split(':').collect();
-
-                            if let Some(val) =
-                                ctx.windows_drives_previous_cwd.lock().get(new_drive[0])
-                            {
-                                ctx.shell_manager.set_path(val.to_string());
-                                return LineResult::Success(line.to_string());
-                            } else {
-                                ctx.shell_manager
-                                    .set_path(format!("{}\\", name.to_string()));
-                                return LineResult::Success(line.to_string());
-                            }
-                        } else {
-                            ctx.shell_manager.set_path(name.to_string());
-                            return LineResult::Success(line.to_string());
-                        }
-                    }
-                    #[cfg(not(windows))]
-                    {
-                        ctx.shell_manager.set_path(name.to_string());
-                        return LineResult::Success(line.to_string());
-                    }
+                    // On Windows, a drive like `D:` goes back to where the shell last was on it
+                    ctx.shell_manager.set_path(name.to_string());
+                    return LineResult::Success(line.to_string());
                 }
             }
         }
@@ -188,47 +164,55 @@ pub async fn process_script(
         let result = run_block(&block, ctx, input_stream).await;
 
         match result {
-            Ok(input) => {
-                // Running a pipeline gives us back a stream that we can then
-                // work through. At the top level, we just want to pull on the
-                // values to compute them.
-                use futures::stream::TryStreamExt;
-
-                let context = RunnableContext {
-                    input,
-                    shell_manager: ctx.shell_manager.clone(),
-                    host: ctx.host.clone(),
-                    ctrl_c: ctx.ctrl_c.clone(),
-                    current_errors: ctx.current_errors.clone(),
-                    scope: ctx.scope.clone(),
-                    name: Tag::unknown(),
-                };
-
-                if let Ok(mut output_stream) =
-                    crate::commands::autoview::command::autoview(context).await
-                {
-                    loop {
-                        match output_stream.try_next().await {
-                            Ok(Some(ReturnSuccess::Value(Value {
-                                value: UntaggedValue::Error(e),
-                                ..
-                            }))) => return LineResult::Error(line.to_string(), e),
-                            Ok(Some(_item)) => {
-                                if ctx.ctrl_c.load(Ordering::SeqCst) {
-                                    break;
-                                }
-                            }
-                            Ok(None) => break,
-                            Err(e) => return LineResult::Error(line.to_string(), e),
-                        }
+            Ok(input) => match view_output(input, ctx).await {
+                Ok(()) => LineResult::Success(line.to_string()),
+                Err(err) => LineResult::Error(line.to_string(), err),
+            },
+            Err(err) => LineResult::Error(line.to_string(), err),
+        }
+    }
+}
+
+/// Shows values the same way the output of a pipeline is shown, through autoview
+pub async fn print_values(values: Vec<Value>, ctx: &EvaluationContext) -> Result<(), ShellError> {
+    view_output(values.into(), ctx).await
+}
+
+async fn view_output(input: InputStream, ctx: &EvaluationContext) -> Result<(), ShellError> {
+    // Running a pipeline gives us back a stream that we can then
+    // work through. At the top level, we just want to pull on the
+    // values to compute them.
+    use futures::stream::TryStreamExt;
+
+    let context = RunnableContext {
+        input,
+        shell_manager: ctx.shell_manager.clone(),
+        host: ctx.host.clone(),
+        ctrl_c: ctx.ctrl_c.clone(),
+        current_errors: ctx.current_errors.clone(),
+        scope: ctx.scope.clone(),
+        name: Tag::unknown(),
+    };
+
+    if let Ok(mut output_stream) = crate::commands::autoview::command::autoview(context).await {
+        loop {
+            match output_stream.try_next().await {
+                Ok(Some(ReturnSuccess::Value(Value {
+                    value: UntaggedValue::Error(e),
+                    ..
+                }))) => return Err(e),
+                Ok(Some(_item)) => {
+                    if ctx.ctrl_c.load(Ordering::SeqCst) {
+                        break;
                     }
                 }
-
-                LineResult::Success(line.to_string())
+                Ok(None) => break,
+                Err(e) => return Err(e),
             }
-            Err(err) => LineResult::Error(line.to_string(), err),
         }
     }
+
+    Ok(())
 }
 
 pub async fn run_script_standalone(
@@ -239,6 +223,12 @@ pub async fn run_script_standalone(
 ) -> Result<(), Box<dyn Error>> {
     let line = process_script(&script_text, context, redirect_stdin, 0, false).await;
 
+    // Scripts run with `source`, at startup or on login are part of an interactive session, which
+    // Ctrl-C must not end
+    if exit_on_error {
+        run_interrupt_handler(context).await;
+    }
+
     match line {
         LineResult::Success(line) => {
             let error_code = {
@@ -254,7 +244,8 @@ pub async fn run_script_standalone(
 
             maybe_print_errors(&context, Text::from(line));
             if error_code != 0 && exit_on_error {
-                std::process::exit(error_code);
+                run_exit_hook(context).await;
+                exit(error_code);
             }
         }
 
@@ -265,7 +256,8 @@ pub async fn run_script_standalone(
 
             maybe_print_errors(&context, Text::from(line));
             if exit_on_error {
-                std::process::exit(1);
+                run_exit_hook(context).await;
+                exit(1);
             }
         }
 
@@ -274,3 +266,42 @@ pub async fn run_script_standalone(
 
     Ok(())
 }
+
+/// Runs the block registered with `on-signal INT` if the script was interrupted, then exits
+async fn run_interrupt_handler(context: &EvaluationContext) {
+    if !context.ctrl_c.load(Ordering::SeqCst) {
+        return;
+    }
+
+    if let Some(handler) = context.scope.get_signal_handler("INT") {
+        // Let the handler's own pipelines run to completion
+        context.ctrl_c.store(false, Ordering::SeqCst);
+
+        context.scope.enter_scope();
+        context.scope.add_vars(&handler.captured.entries);
+        let result = run_block(&handler.block, context, InputStream::empty()).await;
+        context.scope.exit_scope();
+
+        match result {
+            Ok(stream) => {
+                let _ = stream.into_vec().await;
+            }
+            Err(err) => context.error(err),
+        }
+
+        maybe_print_errors(context, Text::from(""));
+        run_exit_hook(context).await;
+        exit(130);
+    }
+}
+
+/// Runs the block set with `on-exit`, showing its errors, as the session ends
+pub async fn run_exit_hook(context: &EvaluationContext) {
+    context.run_exit_hook().await;
+    maybe_print_errors(context, Text::from(""));
+}
+
+fn exit(code: i32) -> ! {
+    remove_temp_files();
+    std::process::exit(code)
+}
@@ -1,3 +1,5 @@
 pub mod arguments;
+pub(crate) mod format_metadata;
+pub(crate) mod parallel;
 pub mod suggestions;
 pub mod test_bins;
@@ -0,0 +1,633 @@
+//! Keeps the text of files opened with `open --with-format-metadata`, so that saving them after an
+//! edit rewrites only the values that changed. Comments, key order and indentation stay as they
+//! were written.
+//!
+//! Only changes to existing scalar values can be written back this way. Anything else, such as
+//! adding a key or changing a table into a string, makes `save` serialize the whole value as usual.
+//!
+//! What is remembered of a file is dropped when it's opened again without the flag, and when it's
+//! saved. It's also not used if the file has changed since, so a stale copy is never written back.
+
+use crate::commands::from_json::from_json_string_to_value;
+use crate::commands::from_toml::from_toml_string_to_value;
+use crate::commands::from_yaml::from_yaml_string_to_value;
+use lazy_static::lazy_static;
+use nu_protocol::{Primitive, UntaggedValue, Value};
+use nu_source::{AnchorLocation, Tag};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+lazy_static! {
+    static ref ORIGINALS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+/// Remembers the text `file` had when it was opened.
+pub(crate) fn remember(file: &str, text: &str) {
+    ORIGINALS.lock().insert(file.to_string(), text.to_string());
+}
+
+/// Drops what was remembered of `file`, as it was opened again without its format metadata.
+pub(crate) fn forget(file: &str) {
+    ORIGINALS.lock().remove(file);
+}
+
+/// The text of the file `value` was opened from with its edits written in, if the file was opened
+/// with its format metadata and the edits can be made in place.
+pub(crate) fn patch_original(value: &Value, destination: &Path) -> Option<String> {
+    let file = match value.tag.anchor() {
+        Some(AnchorLocation::File(file)) => file,
+        _ => return None,
+    };
+    let original = ORIGINALS.lock().remove(&file)?;
+
+    if std::fs::read_to_string(&file).ok()? != original {
+        return None;
+    }
+
+    let extension = Path::new(&file).extension()?.to_string_lossy().to_string();
+    if destination.extension()?.to_string_lossy() != extension {
+        return None;
+    }
+
+    patch(&original, &extension, value)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Key {
+    Name(String),
+    Index(usize),
+}
+
+/// Rewrites `original`, written in the format of `extension`, so that it holds `edited`.
+fn patch(original: &str, extension: &str, edited: &Value) -> Option<String> {
+    let mut changes = vec![];
+    diff(
+        &parse(original, extension)?,
+        edited,
+        &mut vec![],
+        &mut changes,
+    )?;
+
+    let mut edits = changes
+        .into_iter()
+        .map(|(path, value)| {
+            let range = locate(original, extension, &path)?;
+            let literal = render(&original[range.clone()], &value)?;
+            Some((range, literal))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // Replace from the end of the text so the ranges still to be replaced stay valid
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+    let mut text = original.to_string();
+    for (range, literal) in edits {
+        text.replace_range(range, &literal);
+    }
+
+    // Make sure the text reads back as the edited value before trusting it with the file
+    let mut leftover = vec![];
+    diff(
+        &parse(&text, extension)?,
+        edited,
+        &mut vec![],
+        &mut leftover,
+    )?;
+
+    if leftover.is_empty() {
+        Some(text)
+    } else {
+        None
+    }
+}
+
+fn parse(text: &str, extension: &str) -> Option<Value> {
+    let text = text.to_string();
+
+    match extension {
+        "toml" => from_toml_string_to_value(text, Tag::unknown()).ok(),
+        "yaml" | "yml" => from_yaml_string_to_value(text, Tag::unknown()).ok(),
+        "json" => from_json_string_to_value(text, Tag::unknown()).ok(),
+        _ => None,
+    }
+}
+
+/// Collects the scalars that differ between `before` and `after`, or fails if their shapes differ.
+fn diff(
+    before: &Value,
+    after: &Value,
+    path: &mut Vec<Key>,
+    changes: &mut Vec<(Vec<Key>, Primitive)>,
+) -> Option<()> {
+    match (&before.value, &after.value) {
+        (UntaggedValue::Row(before), UntaggedValue::Row(after)) => {
+            if before.entries.len() != after.entries.len() {
+                return None;
+            }
+
+            for (key, before) in before.entries.iter() {
+                path.push(Key::Name(key.clone()));
+                diff(before, after.entries.get(key)?, path, changes)?;
+                path.pop();
+            }
+        }
+        (UntaggedValue::Table(before), UntaggedValue::Table(after)) => {
+            if before.len() != after.len() {
+                return None;
+            }
+
+            for (index, (before, after)) in before.iter().zip(after.iter()).enumerate() {
+                path.push(Key::Index(index));
+                diff(before, after, path, changes)?;
+                path.pop();
+            }
+        }
+        (UntaggedValue::Primitive(before), UntaggedValue::Primitive(after)) => {
+            if before != after {
+                changes.push((path.clone(), after.clone()));
+            }
+        }
+        _ => return None,
+    }
+
+    Some(())
+}
+
+/// The literal for `value`, quoted the way `old` was where possible.
+fn render(old: &str, value: &Primitive) -> Option<String> {
+    match value {
+        Primitive::String(s) => Some(if old.starts_with('\'') && !s.contains(&['\'', '\n'][..]) {
+            format!("'{}'", s)
+        } else if !old.starts_with(&['"', '\''][..]) && is_plain(s) {
+            s.clone()
+        } else {
+            serde_json::Value::String(s.clone()).to_string()
+        }),
+        Primitive::Int(i) => Some(i.to_string()),
+        Primitive::Decimal(d) => Some(d.to_string()),
+        Primitive::Boolean(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn is_plain(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | '/'))
+}
+
+/// The range of the text holding the scalar at `path`.
+fn locate(text: &str, extension: &str, path: &[Key]) -> Option<Range<usize>> {
+    match extension {
+        "toml" => locate_toml(text, &names(path)?),
+        "yaml" | "yml" => locate_yaml(text, &names(path)?),
+        "json" => json_scalars(text)?
+            .into_iter()
+            .find(|(scalar, _)| scalar == path)
+            .map(|(_, range)| range),
+        _ => None,
+    }
+}
+
+/// The keys of a path that doesn't go through any lists.
+fn names(path: &[Key]) -> Option<Vec<String>> {
+    path.iter()
+        .map(|key| match key {
+            Key::Name(name) => Some(name.clone()),
+            Key::Index(_) => None,
+        })
+        .collect()
+}
+
+/// Each line of the text with the offset it starts at.
+fn lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = 0;
+
+    text.split('\n').map(move |line| {
+        let line_start = start;
+        start += line.len() + 1;
+        (line_start, line.trim_end_matches('\r'))
+    })
+}
+
+fn locate_toml(text: &str, path: &[String]) -> Option<Range<usize>> {
+    // Keys under an array of tables are never looked for, so they go under no table at all
+    let mut table = Some(vec![]);
+
+    for (start, line) in lines(text) {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("[[") {
+            table = None;
+        } else if let Some(header) = trimmed.strip_prefix('[') {
+            table = Some(split_keys(&header[..header.find(']')?]));
+        } else if let (Some(table), Some(equals)) = (&table, find_unquoted(line, '=')) {
+            let mut keys = table.clone();
+            keys.extend(split_keys(&line[..equals]));
+
+            if keys == path {
+                return scalar_range(line, equals + 1, &[]).map(|r| start + r.start..start + r.end);
+            }
+        }
+    }
+
+    None
+}
+
+fn locate_yaml(text: &str, path: &[String]) -> Option<Range<usize>> {
+    // The keys enclosing the current line with their indentation. Lists enclose with no key.
+    let mut enclosing: Vec<(usize, Option<String>)> = vec![];
+
+    for (start, line) in lines(text) {
+        let content = line.trim_start();
+        let indent = line.len() - content.len();
+
+        if content.is_empty() || content.starts_with('#') || content.starts_with("---") {
+            continue;
+        }
+
+        while matches!(enclosing.last(), Some((outer, _)) if *outer >= indent) {
+            enclosing.pop();
+        }
+
+        if content.starts_with('-') {
+            enclosing.push((indent, None));
+            continue;
+        }
+
+        let colon = match find_unquoted(line, ':') {
+            Some(colon) => colon,
+            None => continue,
+        };
+        let key = unquote(line[..colon].trim());
+
+        let is_match = enclosing.len() + 1 == path.len()
+            && enclosing
+                .iter()
+                .zip(path.iter())
+                .all(|((_, outer), name)| outer.as_ref() == Some(name))
+            && key == path[path.len() - 1];
+
+        if is_match {
+            return scalar_range(line, colon + 1, &[]).map(|r| start + r.start..start + r.end);
+        }
+
+        enclosing.push((indent, Some(key)));
+    }
+
+    None
+}
+
+/// Splits dotted TOML keys such as `a."b.c"` into their parts.
+fn split_keys(keys: &str) -> Vec<String> {
+    let mut parts = vec![];
+    let mut rest = keys;
+
+    while let Some(dot) = find_unquoted(rest, '.') {
+        parts.push(unquote(rest[..dot].trim()));
+        rest = &rest[dot + 1..];
+    }
+    parts.push(unquote(rest.trim()));
+
+    parts
+}
+
+fn unquote(key: &str) -> String {
+    let quoted = key.len() >= 2
+        && ((key.starts_with('"') && key.ends_with('"'))
+            || (key.starts_with('\'') && key.ends_with('\'')));
+
+    if quoted {
+        key[1..key.len() - 1].to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+/// The offset of the first `wanted` outside of quotes and before any comment.
+fn find_unquoted(line: &str, wanted: char) -> Option<usize> {
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == wanted => return Some(index),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' => return None,
+            None => {}
+        }
+    }
+
+    None
+}
+
+/// The range of the scalar starting at or after `from` in `line`. Plain scalars end at any of
+/// `stops`, a comment, or the end of the line.
+fn scalar_range(line: &str, from: usize, stops: &[char]) -> Option<Range<usize>> {
+    let rest = &line[from..];
+    let start = from + (rest.len() - rest.trim_start().len());
+    let rest = &line[start..];
+
+    let len = match rest.chars().next()? {
+        '"' => {
+            if rest.starts_with("\"\"\"") {
+                return None;
+            }
+            let mut escaped = false;
+            rest.char_indices()
+                .skip(1)
+                .find(|(_, c)| {
+                    let closes = !escaped && *c == '"';
+                    escaped = !escaped && *c == '\\';
+                    closes
+                })
+                .map(|(index, _)| index + 1)?
+        }
+        '\'' => {
+            if rest.starts_with("'''") {
+                return None;
+            }
+            rest[1..].find('\'')? + 2
+        }
+        '[' | '{' | '|' | '>' | '&' | '*' => return None,
+        _ => {
+            let mut previous = ' ';
+            let end = rest
+                .char_indices()
+                .find(|(_, c)| {
+                    let ends = stops.contains(c) || (*c == '#' && previous.is_whitespace());
+                    previous = *c;
+                    ends
+                })
+                .map(|(index, _)| index)
+                .unwrap_or_else(|| rest.len());
+            rest[..end].trim_end().len()
+        }
+    };
+
+    Some(start..start + len)
+}
+
+/// Every scalar in a JSON document with its path and range.
+fn json_scalars(text: &str) -> Option<Vec<(Vec<Key>, Range<usize>)>> {
+    let mut scalars = vec![];
+    let mut pos = 0;
+
+    json_value(text, &mut pos, &mut vec![], &mut scalars)?;
+
+    Some(scalars)
+}
+
+fn json_value(
+    text: &str,
+    pos: &mut usize,
+    path: &mut Vec<Key>,
+    scalars: &mut Vec<(Vec<Key>, Range<usize>)>,
+) -> Option<()> {
+    skip_whitespace(text, pos);
+
+    match text[*pos..].chars().next()? {
+        '{' => {
+            *pos += 1;
+            skip_whitespace(text, pos);
+            if text[*pos..].starts_with('}') {
+                *pos += 1;
+                return Some(());
+            }
+
+            loop {
+                skip_whitespace(text, pos);
+                let key = scalar_range(text, *pos, &[])?;
+                let name: String = serde_json::from_str(&text[key.clone()]).ok()?;
+                *pos = key.end;
+
+                skip_whitespace(text, pos);
+                *pos += text[*pos..].strip_prefix(':').map(|_| 1)?;
+
+                path.push(Key::Name(name));
+                json_value(text, pos, path, scalars)?;
+                path.pop();
+
+                if !json_separator(text, pos, '}')? {
+                    return Some(());
+                }
+            }
+        }
+        '[' => {
+            *pos += 1;
+            skip_whitespace(text, pos);
+            if text[*pos..].starts_with(']') {
+                *pos += 1;
+                return Some(());
+            }
+
+            for index in 0.. {
+                path.push(Key::Index(index));
+                json_value(text, pos, path, scalars)?;
+                path.pop();
+
+                if !json_separator(text, pos, ']')? {
+                    break;
+                }
+            }
+
+            Some(())
+        }
+        _ => {
+            let range = scalar_range(text, *pos, &[',', '}', ']', '\n', '\r'])?;
+            *pos = range.end;
+            scalars.push((path.clone(), range));
+
+            Some(())
+        }
+    }
+}
+
+/// Steps over a `,` or the `close` of a container, telling whether more items follow.
+fn json_separator(text: &str, pos: &mut usize, close: char) -> Option<bool> {
+    skip_whitespace(text, pos);
+
+    match text[*pos..].chars().next()? {
+        ',' => {
+            *pos += 1;
+            Some(true)
+        }
+        c if c == close => {
+            *pos += 1;
+            Some(false)
+        }
+        _ => None,
+    }
+}
+
+fn skip_whitespace(text: &str, pos: &mut usize) {
+    let rest = &text[*pos..];
+    *pos += rest.len() - rest.trim_start().len();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, patch, patch_original, remember};
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::{AnchorLocation, Span, Tag};
+
+    fn edited(text: &str, extension: &str, column: &str, new: &str) -> Option<String> {
+        edited_to(text, extension, column, Value::from(new))
+    }
+
+    fn edited_to(text: &str, extension: &str, column: &str, new: Value) -> Option<String> {
+        let mut value = parse(text, extension)?;
+        let path =
+            nu_value_ext::as_column_path(&UntaggedValue::string(column).into_value(Tag::unknown()))
+                .ok()?;
+        value = nu_value_ext::replace_data_at_column_path(&value, &path.item, new)?;
+
+        patch(text, extension, &value)
+    }
+
+    #[test]
+    fn keeps_toml_comments_and_order() {
+        let text = "# The package\n[package]\nname = \"nu\"  # the name\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1.0\"\n";
+
+        assert_eq!(
+            edited(text, "toml", "package.version", "0.2.0"),
+            Some("# The package\n[package]\nname = \"nu\"  # the name\nversion = \"0.2.0\"\n\n[dependencies]\nserde = \"1.0\"\n".to_string())
+        );
+    }
+
+    #[test]
+    fn finds_dotted_and_quoted_toml_keys() {
+        let text = "[package]\nmetadata.docs = 'old'\n\"version\" = 1 # bumped by hand\n";
+
+        assert_eq!(
+            edited(text, "toml", "package.metadata.docs", "new"),
+            Some(
+                "[package]\nmetadata.docs = 'new'\n\"version\" = 1 # bumped by hand\n".to_string()
+            )
+        );
+        assert_eq!(
+            edited_to(
+                text,
+                "toml",
+                "package.version",
+                UntaggedValue::int(2).into_untagged_value()
+            ),
+            Some(
+                "[package]\nmetadata.docs = 'old'\n\"version\" = 2 # bumped by hand\n".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_toml_arrays_of_tables_to_be_serialized() {
+        let text = "[[bin]]\nname = \"nu\"\n";
+
+        assert_eq!(edited(text, "toml", "bin.0.name", "nushell"), None);
+    }
+
+    #[test]
+    fn keeps_yaml_indentation_and_comments() {
+        let text = "server:\n    host: localhost # where to listen\n    port: '8080'\nname: demo\n";
+
+        assert_eq!(
+            edited(text, "yaml", "server.host", "example.com"),
+            Some(
+                "server:\n    host: example.com # where to listen\n    port: '8080'\nname: demo\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            edited(text, "yaml", "server.port", "9090"),
+            Some(
+                "server:\n    host: localhost # where to listen\n    port: '9090'\nname: demo\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn tells_apart_yaml_keys_of_the_same_name() {
+        let text = "a:\n  name: x\nb:\n  name: y\n";
+        let expected = Some("a:\n  name: x\nb:\n  name: z\n".to_string());
+
+        assert_eq!(edited(text, "yaml", "b.name", "z"), expected);
+        assert_eq!(edited(text, "yml", "b.name", "z"), expected);
+    }
+
+    #[test]
+    fn leaves_yaml_lists_to_be_serialized() {
+        let text = "items:\n  - one\n  - two\n";
+
+        assert_eq!(edited(text, "yaml", "items.1", "three"), None);
+    }
+
+    #[test]
+    fn keeps_json_layout() {
+        let text = "{\n  \"name\": \"nu\",\n  \"tags\": [\"shell\",   \"rust\"]\n}\n";
+
+        assert_eq!(
+            edited(text, "json", "tags.1", "data"),
+            Some("{\n  \"name\": \"nu\",\n  \"tags\": [\"shell\",   \"data\"]\n}\n".to_string())
+        );
+    }
+
+    #[test]
+    fn finds_json_values_after_escaped_strings() {
+        let text = r#"{"a": {"say": "\"hi\"", "n": 1}}"#;
+
+        assert_eq!(
+            edited_to(
+                text,
+                "json",
+                "a.n",
+                UntaggedValue::int(2).into_untagged_value()
+            ),
+            Some(r#"{"a": {"say": "\"hi\"", "n": 2}}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn leaves_new_keys_to_be_serialized() {
+        for (text, with_a_new_key, extension) in &[
+            ("a = 1\n", "a = 1\nb = 2\n", "toml"),
+            ("a: 1\n", "a: 1\nb: 2\n", "yaml"),
+            (r#"{"a": 1}"#, r#"{"a": 1, "b": 2}"#, "json"),
+        ] {
+            let edited = parse(with_a_new_key, extension).expect("parses");
+
+            assert_eq!(patch(text, extension, &edited), None);
+        }
+    }
+
+    #[test]
+    fn uses_what_was_remembered_once_and_only_while_the_file_is_unchanged() {
+        let dir = tempfile::tempdir().expect("a temporary directory");
+        let path = dir.path().join("settings.toml");
+        let file = path.to_string_lossy().to_string();
+
+        let mut value = parse("a = 3 # three\n", "toml").expect("parses");
+        value.tag = Tag {
+            anchor: Some(AnchorLocation::File(file.clone())),
+            span: Span::unknown(),
+        };
+
+        std::fs::write(&path, "a = 1 # one\n").expect("writes");
+        remember(&file, "a = 1 # one\n");
+        std::fs::write(&path, "a = 2 # changed elsewhere\n").expect("writes");
+        assert_eq!(patch_original(&value, &path), None);
+
+        std::fs::write(&path, "a = 1 # one\n").expect("writes");
+        remember(&file, "a = 1 # one\n");
+        assert_eq!(
+            patch_original(&value, &path),
+            Some("a = 3 # one\n".to_string())
+        );
+        assert_eq!(patch_original(&value, &path), None);
+    }
+}
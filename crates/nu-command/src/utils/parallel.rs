@@ -0,0 +1,99 @@
+//! Aggregations that split large inputs across threads and merge what each thread found. Small
+//! inputs are handled on the calling thread, where they finish before threads would start.
+
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use rayon::prelude::*;
+
+/// The fewest values worth splitting across threads.
+pub const PARALLEL_THRESHOLD: usize = 100_000;
+
+const CHUNK_SIZE: usize = 10_000;
+
+/// Reduces each chunk of the values on its own thread, then merges the partial results. If more
+/// than one chunk fails, which of their errors is returned isn't known in advance.
+pub fn par_reduce<T, R, M>(values: &[Value], reduce: R, merge: M) -> Result<T, ShellError>
+where
+    T: Send,
+    R: Fn(&[Value]) -> Result<T, ShellError> + Sync,
+    M: FnOnce(Vec<T>) -> Result<T, ShellError>,
+{
+    if values.len() < PARALLEL_THRESHOLD {
+        return reduce(values);
+    }
+
+    let partials = values
+        .par_chunks(CHUNK_SIZE)
+        .map(|chunk| reduce(chunk))
+        .collect::<Result<Vec<T>, ShellError>>()?;
+
+    merge(partials)
+}
+
+/// Maps each item on the thread pool, keeping them in order.
+pub fn par_map<V, T, F>(items: &[V], map: F) -> Vec<T>
+where
+    V: Sync,
+    T: Send,
+    F: Fn(&V) -> T + Sync,
+{
+    if items.len() < PARALLEL_THRESHOLD {
+        items.iter().map(map).collect()
+    } else {
+        items.par_iter().map(map).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{par_map, par_reduce, PARALLEL_THRESHOLD};
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn numbers(count: usize) -> Vec<Value> {
+        (0..count)
+            .map(|n| UntaggedValue::int(n as i64).into_untagged_value())
+            .collect()
+    }
+
+    fn total(values: &[Value]) -> Result<i64, ShellError> {
+        values.iter().map(|v| v.as_i64()).sum()
+    }
+
+    #[test]
+    fn merges_the_partial_results_of_large_inputs() -> Result<(), ShellError> {
+        let count = PARALLEL_THRESHOLD * 2 + 1;
+        let mut chunks = 0;
+
+        let sum = par_reduce(&numbers(count), total, |partials| {
+            chunks = partials.len();
+            Ok(partials.into_iter().sum())
+        })?;
+
+        assert_eq!(sum, (count * (count - 1) / 2) as i64);
+        assert!(chunks > 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reduces_small_inputs_at_once() -> Result<(), ShellError> {
+        let sum = par_reduce(&numbers(10), total, |_| {
+            Err(ShellError::unexpected("small inputs aren't merged"))
+        })?;
+
+        assert_eq!(sum, 45);
+
+        Ok(())
+    }
+
+    #[test]
+    fn maps_in_order() {
+        let items: Vec<usize> = (0..PARALLEL_THRESHOLD + 1).collect();
+
+        assert_eq!(
+            par_map(&items, |n| n * 2),
+            items.iter().map(|n| n * 2).collect::<Vec<_>>()
+        );
+    }
+}
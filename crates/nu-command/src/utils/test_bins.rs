@@ -17,6 +17,11 @@ pub fn nonu() {
     args().iter().skip(1).for_each(|arg| print!("{}", arg));
 }
 
+pub fn eecho() {
+    // Write all the arguments passed to stderr instead of stdout
+    eprintln!("{}", args()[1..].join(" "));
+}
+
 pub fn iecho() {
     // println! panics if stdout gets closed, whereas writeln gives us an error
     let mut stdout = io::stdout();
@@ -0,0 +1,39 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reads_simple_aliases_as_nu_aliases() {
+    Playground::setup("alias_import_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "bashrc",
+            r#"
+                export EDITOR=vim
+                alias ll='ls -l'
+                alias up='cd .. && ls'
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                alias import --from bash bashrc
+                | str collect ';'
+            "#
+        ));
+
+        assert_eq!(actual.out, "alias ll = ls -l");
+    })
+}
+
+#[test]
+fn an_alias_can_still_be_named_import() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            alias import = echo imported; import
+        "#
+    ));
+
+    assert_eq!(actual.out, "imported");
+}
@@ -0,0 +1,62 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn passes_when_condition_is_true() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            assert 1 + 1 == 2; echo ok
+        "#
+    ));
+
+    assert_eq!(actual.out, "ok");
+}
+
+#[test]
+fn fails_with_message_when_condition_is_false() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            assert --message "math is broken" 1 + 1 == 3
+        "#
+    ));
+
+    assert!(actual.err.contains("Assertion failed: math is broken"));
+}
+
+#[test]
+fn equal_shows_both_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            assert equal $(echo [1 2 3] | count) 4
+        "#
+    ));
+
+    assert!(actual.err.contains("left: 3"));
+    assert!(actual.err.contains("right: 4"));
+}
+
+#[test]
+fn error_passes_when_block_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            assert error { echo [1 2] | get name }; echo ok
+        "#
+    ));
+
+    assert_eq!(actual.out, "ok");
+}
+
+#[test]
+fn error_fails_when_block_succeeds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            assert error { echo ok }
+        "#
+    ));
+
+    assert!(actual.err.contains("ran without an error"));
+}
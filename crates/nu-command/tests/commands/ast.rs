@@ -0,0 +1,52 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn lists_the_tokens() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast 'ls | where size > 10kb' | get tokens.kind | str collect ','
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "baseline,pipe,baseline,baseline,baseline,baseline"
+    );
+}
+
+#[test]
+fn groups_commands_into_pipelines() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast 'echo 1; echo 2 | str length' | get lite.0.1 | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn reports_why_a_pipeline_does_not_parse() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast 'echo [1 2' | get error | empty?
+        "#
+    ));
+
+    assert_eq!(actual.out, "false");
+}
+
+#[test]
+fn does_not_keep_definitions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast 'def ast-test-def [] { echo hi }' | echo $(which ast-test-def | count)
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
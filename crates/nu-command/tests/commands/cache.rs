@@ -0,0 +1,104 @@
+use nu_test_support::fs::executable_path;
+use nu_test_support::playground::{Dirs, Playground};
+use std::process::Command;
+
+/// Runs the script with its own cache directory in the sandbox, so that no other test's outputs
+/// are returned.
+fn run(dirs: &Dirs, script: &str) -> String {
+    let output = Command::new(executable_path())
+        .current_dir(dirs.test())
+        .env("NU_CACHE_DIR", dirs.test().join("cache"))
+        .env("NU_DATA_DIR", dirs.test().join("data"))
+        .arg("--skip-plugins")
+        .arg("-c")
+        .arg(script)
+        .output()
+        .expect("couldn't run nu");
+
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn returns_the_cached_output_within_the_ttl() {
+    Playground::setup("cache_test_1", |dirs, _| {
+        let actual = run(
+            &dirs,
+            "let first = $(cache 1hr { random uuid }); \
+             let second = $(cache 1hr { random uuid }); \
+             = $first == $second",
+        );
+
+        assert_eq!(actual, "true");
+    })
+}
+
+#[test]
+fn caches_each_input_apart() {
+    Playground::setup("cache_test_2", |dirs, _| {
+        let actual = run(
+            &dirs,
+            "let first = $(echo 1 | cache 1hr { random uuid }); \
+             let second = $(echo 2 | cache 1hr { random uuid }); \
+             = $first == $second",
+        );
+
+        assert_eq!(actual, "false");
+    })
+}
+
+#[test]
+fn runs_the_block_again_after_the_ttl() {
+    Playground::setup("cache_test_3", |dirs, _| {
+        let actual = run(
+            &dirs,
+            "let first = $(cache 0sec { random uuid }); \
+             let second = $(cache 0sec { random uuid }); \
+             = $first == $second",
+        );
+
+        assert_eq!(actual, "false");
+    })
+}
+
+#[test]
+fn caches_each_directory_apart() {
+    Playground::setup("cache_test_4", |dirs, sandbox| {
+        sandbox.mkdir("one").mkdir("two");
+
+        let actual = run(
+            &dirs,
+            "cd one; let first = $(cache 1hr { pwd | path basename }); \
+             cd ../two; let second = $(cache 1hr { pwd | path basename }); \
+             echo $first $second | str collect ' '",
+        );
+
+        assert_eq!(actual, "one two");
+    })
+}
+
+#[test]
+fn caches_each_environment_apart() {
+    Playground::setup("cache_test_5", |dirs, _| {
+        let actual = run(
+            &dirs,
+            "let-env GREETING = hello; let first = $(cache 1hr { echo $nu.env.GREETING }); \
+             let-env GREETING = bye; let second = $(cache 1hr { echo $nu.env.GREETING }); \
+             echo $first $second | str collect ' '",
+        );
+
+        assert_eq!(actual, "hello bye");
+    })
+}
+
+#[test]
+fn removes_the_outputs_that_expired() {
+    Playground::setup("cache_test_6", |dirs, _| {
+        run(&dirs, "cache 0sec { echo 1 }; cache 0sec { echo 2 }");
+
+        let kept = std::fs::read_dir(dirs.test().join("cache").join("blocks"))
+            .expect("the cached outputs")
+            .count();
+
+        assert_eq!(kept, 1);
+    })
+}
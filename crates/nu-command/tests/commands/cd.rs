@@ -227,6 +227,41 @@ fn filesystem_change_directory_to_symlink_relative() {
     })
 }
 
+#[test]
+fn filesystem_change_to_an_abbreviated_path() {
+    Playground::setup("cd_test_23", |dirs, sandbox| {
+        sandbox.mkdir("src/parser/registry").mkdir("scripts");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                cd sr/p/r
+                echo $(pwd)
+            "#
+        );
+
+        assert_eq!(
+            PathBuf::from(actual.out),
+            dirs.test().join("src").join("parser").join("registry")
+        );
+    })
+}
+
+#[test]
+fn filesystem_abbreviated_path_that_is_ambiguous() {
+    Playground::setup("cd_test_24", |dirs, sandbox| {
+        sandbox.mkdir("src").mkdir("scripts");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cd s"
+        );
+
+        assert!(actual.err.contains("could be any of"));
+        assert!(actual.err.contains("scripts"));
+    })
+}
+
 #[test]
 fn valuesystem_change_from_current_path_using_relative_path() {
     Playground::setup("cd_test_13", |dirs, sandbox| {
@@ -417,6 +452,42 @@ fn valuesystem_change_to_a_path_containing_spaces() {
     })
 }
 
+#[test]
+fn valuesystem_change_to_a_path_written_with_dots() {
+    Playground::setup("cd_test_21", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.formats(),
+            r#"
+                enter cargo_sample.toml
+                cd dependencies.pancurses
+                pwd
+                exit
+            "#
+        );
+
+        assert_eq!(
+            PathBuf::from(actual.out),
+            PathBuf::from("/dependencies/pancurses")
+        );
+    })
+}
+
+#[test]
+fn valuesystem_list_a_path_written_with_dots() {
+    Playground::setup("cd_test_22", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.formats(),
+            r#"
+                enter cargo_sample.toml
+                ls dependencies.pancurses | get version
+                exit
+            "#
+        );
+
+        assert_eq!(actual.out, "0.16");
+    })
+}
+
 #[test]
 fn valuesystem_path_not_found() {
     Playground::setup("cd_test_19", |dirs, _| {
@@ -0,0 +1,40 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_parts_of_the_current_date() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date | get | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"["year","month","day","hour","minute","second","timezone"]"#
+    );
+}
+
+#[test]
+fn parses_and_formats_a_date_in_utc() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date "2021-02-27 14:30:00 +01:00" --utc --format "%Y-%m-%d %H:%M"
+        "#
+    ));
+
+    assert_eq!(actual.out, "2021-02-27 13:30");
+}
+
+#[test]
+fn keeps_a_parsed_date_a_date() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date "2021-02-27" | date format "%d/%m"
+        "#
+    ));
+
+    assert_eq!(actual.out, "27/02");
+}
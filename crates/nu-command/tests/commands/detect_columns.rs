@@ -0,0 +1,46 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+const CONTAINERS: &str = "CONTAINER ID   IMAGE    STATUS
+4b2c1e0f9a7d   ubuntu   Up 2 hours
+9f8e7d6c5b4a   nginx    Exited (0) 3 days ago
+";
+
+#[test]
+fn detects_the_columns_under_the_headers() {
+    Playground::setup("detect_columns_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("containers.txt", CONTAINERS)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open containers.txt --raw
+                | detect columns
+                | where IMAGE == nginx
+                | get STATUS
+            "#
+        ));
+
+        assert_eq!(actual.out, "Exited (0) 3 days ago");
+    })
+}
+
+#[test]
+fn names_the_columns_without_headers() {
+    Playground::setup("detect_columns_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("containers.txt", CONTAINERS)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open containers.txt --raw
+                | detect columns --skip 1 --no-headers
+                | get Column2
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, "ubuntu,nginx");
+    })
+}
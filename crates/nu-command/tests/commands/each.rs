@@ -71,3 +71,39 @@ fn each_implicit_it_in_block() {
 
     assert_eq!(actual.out, "ace");
 }
+
+#[test]
+fn each_keep_empty_emits_nothing_for_empty_results() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo [1 2 3] | each --keep-empty { if $it == 2 { echo $it } { } } | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn each_over_a_range() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        1..1000 | each { = $it * 2 } | first 3 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,4,6]");
+}
+
+#[test]
+fn each_refuses_more_block_params_than_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1 2 | each {|a, b| echo $a }
+        "#
+    ));
+
+    assert!(actual.err.contains("names the parameter $b"));
+}
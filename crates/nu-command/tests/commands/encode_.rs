@@ -0,0 +1,53 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn base64_round_trips_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 'username:password' | encode base64 | decode base64
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "username:password");
+}
+
+#[test]
+fn decoding_non_utf8_produces_binary() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 'ff00' | decode hex | encode base64
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "/wA=");
+}
+
+#[test]
+fn url_encodes_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[q]; ['a b&c']] | url encode q | get q
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "a%20b%26c");
+}
+
+#[test]
+fn error_when_decoding_invalid_hex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 'abc' | decode hex
+        "#
+        )
+    );
+
+    assert!(actual.err.contains("could not be hex decoded"));
+}
@@ -0,0 +1,40 @@
+use nu_test_support::fs::executable_path;
+use nu_test_support::playground::Playground;
+use std::process::Command;
+
+fn exit_code(cwd: &std::path::Path, script: &str) -> Option<i32> {
+    Command::new(executable_path())
+        .current_dir(cwd)
+        .arg("--skip-plugins")
+        .arg("-c")
+        .arg(script)
+        .output()
+        .expect("couldn't run nu")
+        .status
+        .code()
+}
+
+#[test]
+fn exits_with_the_code_given() {
+    Playground::setup("exit_test_1", |dirs, _| {
+        assert_eq!(exit_code(dirs.test(), "exit 42"), Some(42));
+    })
+}
+
+#[test]
+fn exits_with_the_code_given_right_away() {
+    Playground::setup("exit_test_2", |dirs, _| {
+        assert_eq!(
+            exit_code(dirs.test(), "exit --now 3; echo never | save ran.txt"),
+            Some(3)
+        );
+        assert!(!dirs.test().join("ran.txt").exists());
+    })
+}
+
+#[test]
+fn exits_with_zero_by_default() {
+    Playground::setup("exit_test_3", |dirs, _| {
+        assert_eq!(exit_code(dirs.test(), "exit"), Some(0));
+    })
+}
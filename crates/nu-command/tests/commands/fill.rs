@@ -0,0 +1,52 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn fills_strings_to_a_width() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo nu | fill --width 5 --character '-' | str length
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn aligns_the_numbers_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name count]; [a 7] [b 120]]
+            | fill -w 4 -a right -c '.' count
+            | get count
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "...7,.120");
+}
+
+#[test]
+fn centers_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo nu | fill -w 6 -a center -c '*'
+        "#
+    ));
+
+    assert_eq!(actual.out, "**nu**");
+}
+
+#[test]
+fn errors_on_an_unknown_alignment() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo nu | fill -w 6 -a top
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown alignment"));
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -65,3 +65,59 @@ fn gets_first_row_when_no_amount_given() {
         assert_eq!(actual.out, "1");
     })
 }
+
+#[test]
+fn stops_parsing_the_input_after_the_rows_taken() {
+    Playground::setup("first_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "malformed.csv",
+            r#"
+                name,stars
+                nu,5
+                this row is missing a column
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open malformed.csv
+                | first
+                | get name
+            "#
+        ));
+
+        assert_eq!(actual.out, "nu");
+        assert!(actual.err.is_empty());
+    })
+}
+
+#[test]
+fn stops_reading_the_input_after_the_rows_taken() {
+    Playground::setup("first_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "stars.txt",
+            r#"
+                name,stars
+                nu,5
+            "#,
+        )]);
+
+        // The number after the text can't be parsed, and is never read
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open stars.txt
+                | lines
+                | each { build-string $it (char newline) }
+                | append 5
+                | from csv
+                | first
+                | get name
+            "#
+        ));
+
+        assert_eq!(actual.out, "nu");
+        assert!(actual.err.is_empty());
+    })
+}
@@ -182,3 +182,19 @@ fn flatten_more_than_one_column_that_are_subtables_not_supported() {
         assert!(actual.err.contains("but is flattened already"));
     })
 }
+
+#[test]
+fn flatten_all_expands_every_level() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "meta": {"repo": {"stars": 1}}, "tags": ["shell", "rust"]}]'
+            | from json
+            | flatten --all
+            | get stars
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,1]");
+}
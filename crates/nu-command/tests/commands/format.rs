@@ -42,6 +42,53 @@ fn can_use_variables() {
     assert_eq!(actual.out, "nu is a new type of shell");
 }
 
+#[test]
+fn doubled_brackets_are_literal() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        open cargo_sample.toml
+            | get package
+            | format "{{{name}}}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "{nu}");
+}
+
+#[test]
+fn unclosed_column_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        open cargo_sample.toml
+            | get package
+            | format "{name"
+        "#
+    ));
+
+    assert!(actual.err.contains("Unclosed column"));
+}
+
+#[test]
+fn formatted_rows_can_be_saved_as_lines() {
+    Playground::setup("format_save_raw_test", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [[name age]; [Tom 7] [Linda 1]]
+                | format "{name},{age}"
+                | str collect $(char newline)
+                | save --raw pets.txt
+            "#
+        ));
+
+        let actual = nu!(cwd: dirs.test(), "open pets.txt | lines | last");
+
+        assert_eq!(actual.out, "Linda,1");
+    })
+}
+
 #[test]
 fn format_filesize_works() {
     Playground::setup("format_filesize_test_1", |dirs, sandbox| {
@@ -91,3 +138,54 @@ fn format_filesize_works_with_nonempty_files() {
         },
     )
 }
+
+#[test]
+fn format_number_rounds_and_groups_digits() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[item price]; [teapot 1249] [tea 3.456]]
+            | format number price --decimals 2 --thousands ","
+            | get price
+            | str collect "|"
+        "#
+    ));
+
+    assert_eq!(actual.out, "1,249.00|3.46");
+}
+
+#[test]
+fn format_number_rejects_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "ten" | format number -d 2
+        "#
+    ));
+
+    assert!(actual.err.contains("not a number"));
+}
+
+#[test]
+fn format_duration_shows_a_single_unit() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 2hr | format duration min
+        "#
+    ));
+
+    assert_eq!(actual.out, "120 min");
+}
+
+#[test]
+fn format_duration_rejects_unknown_units() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 2hr | format duration fortnights
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown duration unit"));
+}
@@ -95,3 +95,31 @@ fn errors_if_block_given_evaluates_more_than_one_row() {
         assert!(actual.err.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_nested_column() {
+    Playground::setup("group_by_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.json",
+            r#"
+                [
+                    {"name": "Andrés", "meta": {"country": "EC"}},
+                    {"name": "Jonathan", "meta": {"country": "NZ"}},
+                    {"name": "Yehuda", "meta": {"country": "EC"}}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.json
+                | group-by meta.country
+                | get EC
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
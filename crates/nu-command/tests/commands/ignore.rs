@@ -0,0 +1,56 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn discards_the_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] | ignore
+        "#
+    ));
+
+    assert!(actual.out.is_empty());
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn runs_the_pipeline_to_the_end() {
+    Playground::setup("ignore_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [b.txt c.txt] | each { touch $it } | ignore; ls | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[test]
+fn discards_external_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ^echo hello | ignore
+        "#
+    ));
+
+    assert!(actual.out.is_empty());
+}
+
+#[test]
+fn reports_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2] | get name | ignore
+        "#
+    ));
+
+    assert!(!actual.err.is_empty());
+}
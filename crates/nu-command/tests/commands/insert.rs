@@ -43,3 +43,17 @@ fn sets_the_column_from_an_invocation() {
 
     assert_eq!(actual.out, "true");
 }
+
+#[test]
+fn creates_missing_rows_along_a_nested_column_path() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.metadata.nu.license MIT
+            | get package.metadata.nu.license
+        "#
+    ));
+
+    assert_eq!(actual.out, "MIT");
+}
@@ -0,0 +1,53 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn into_filesize_with_a_unit() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "1.5 KiB" | into filesize | each { into-int $it }
+        "#
+    ));
+
+    assert_eq!(actual.out, "1536");
+}
+
+#[test]
+fn into_filesize_with_a_decimal_comma() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "2,5K" | into filesize | each { into-int $it }
+        "#
+    ));
+
+    assert_eq!(actual.out, "2560");
+}
+
+#[test]
+fn into_filesize_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name, size]; [a, "300"] [b, "1M"]]
+        | into filesize size
+        | get size
+        | math sum
+        | each { into-int $it }
+        "#
+    ));
+
+    assert_eq!(actual.out, "1048876");
+}
+
+#[test]
+fn into_filesize_rejects_unknown_units() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "3 parsecs" | into filesize
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not convert into a filesize"));
+}
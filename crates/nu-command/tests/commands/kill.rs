@@ -0,0 +1,40 @@
+use nu_test_support::{nu, pipeline};
+
+// The pids used are above the largest one Linux hands out, so no process has them
+
+#[test]
+fn reports_the_processes_it_could_not_kill() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            kill 4194399 | get killed
+        "#
+    ));
+
+    assert_eq!(actual.out, "false");
+}
+
+#[test]
+fn kills_the_pids_of_the_rows_piped_in() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[pid name]; [4194398 first] [4194399 second]] | kill | get pid | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "8388797");
+}
+
+#[cfg(unix)]
+#[test]
+fn rejects_invalid_signals() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            kill --signal "not a signal" 4194399
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid signal"));
+}
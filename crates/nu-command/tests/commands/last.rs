@@ -64,3 +64,15 @@ fn requests_more_rows_than_table_has() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn gets_the_last_rows_of_a_long_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 1..100000 | last 2 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[99999,100000]");
+}
@@ -0,0 +1,37 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn sets_a_variable_for_each_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[GREETING NAME]; [hello nu]] | load-env; echo $nu.env.GREETING $nu.env.NAME
+        "#
+    ));
+
+    assert_eq!(actual.out, "hellonu");
+}
+
+#[test]
+fn sets_the_variables_given_as_an_argument() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            load-env [[COUNT]; [3]]; echo $nu.env.COUNT
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn errors_if_a_value_is_not_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            load-env [[LIST]; [[1 2]]]
+        "#
+    ));
+
+    assert!(actual.err.contains("Can't set LIST to table"));
+}
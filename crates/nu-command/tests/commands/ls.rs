@@ -327,3 +327,92 @@ fn list_all_columns() {
         },
     );
 }
+
+#[test]
+fn lists_files_matching_the_pattern_ignoring_case() {
+    Playground::setup("ls_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("README.md"),
+            EmptyFile("readme.txt"),
+            EmptyFile("notes.md"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --case-insensitive ReadMe*
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn lists_link_counts_and_inodes_as_numbers() {
+    Playground::setup("ls_test_11", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt"), EmptyFile("jonathan.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -l
+                | where num_links == 1 && inode > 0
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn lists_the_directories_below_recursively() {
+    Playground::setup("ls_test_12", |dirs, sandbox| {
+        sandbox
+            .within("src")
+            .with_files(vec![EmptyFile("main.rs")])
+            .within("src/commands")
+            .with_files(vec![EmptyFile("ls.rs"), EmptyFile("cd.rs")])
+            .within("src/commands/shells")
+            .with_files(vec![EmptyFile("value.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --recursive
+                | where type == File
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "4");
+    })
+}
+
+#[test]
+fn lists_down_to_the_depth_given() {
+    Playground::setup("ls_test_13", |dirs, sandbox| {
+        sandbox
+            .within("src")
+            .with_files(vec![EmptyFile("main.rs")])
+            .within("src/commands")
+            .with_files(vec![EmptyFile("ls.rs"), EmptyFile("cd.rs")])
+            .within("src/commands/shells")
+            .with_files(vec![EmptyFile("value.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --depth 2 src
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out.split(',').count(), 5);
+        assert!(!actual.out.contains("value.rs"));
+    })
+}
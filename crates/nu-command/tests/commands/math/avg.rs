@@ -23,3 +23,27 @@ fn can_average_bytes() {
 
     assert_eq!(actual.out, "1.6 KB");
 }
+
+#[test]
+fn average_skips_empty_cells() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            into-int $(echo [$nothing 1kb 3kb] | math avg)
+        "#
+    ));
+
+    assert_eq!(actual.out, "2048");
+}
+
+#[test]
+fn can_average_bytes_with_decimals() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            into-int $(echo [1kb 2048.0] | math avg)
+        "#
+    ));
+
+    assert_eq!(actual.out, "1536");
+}
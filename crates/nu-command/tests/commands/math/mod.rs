@@ -209,6 +209,150 @@ fn duration_math_with_negative() {
     assert_eq!(actual.out, "-6day");
 }
 
+#[test]
+fn duration_math_with_seconds() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 2min + 30sec
+        "#
+    ));
+
+    assert_eq!(actual.out, "2min 30sec");
+}
+
+#[test]
+fn duration_comparison() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 90sec > 1min
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn date_minus_duration() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = $(date "2021-03-01 00:00:00 +00:00") - 1day | date format "%Y-%m-%d"
+        "#
+    ));
+
+    assert_eq!(actual.out, "2021-02-28");
+}
+
+#[test]
+fn duration_plus_date() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 2hr + $(date "2021-03-01 23:00:00 +00:00") | date format "%Y-%m-%d %H"
+        "#
+    ));
+
+    assert_eq!(actual.out, "2021-03-02 01");
+}
+
+#[test]
+fn where_compares_durations() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [[name took]; [fast 3sec] [slow 2min]] | where took > 10sec | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "slow");
+}
+
+#[test]
+fn parenthesized_math_as_an_argument() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo (2 + 3) | = $it * 2
+        "#
+    ));
+
+    assert_eq!(actual.out, "10");
+}
+
+#[test]
+fn math_without_equals_in_a_block() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [[n]; [4] [7]] | insert half { $it.n / 2 } | get half | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "5.5");
+}
+
+#[test]
+fn duration_scaled_by_numbers() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 2min * 3 + 90sec / 1.5
+        "#
+    ));
+
+    assert_eq!(actual.out, "7min");
+}
+
+#[test]
+fn duration_divided_by_duration() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1hr / 20min
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn filesize_scaled_by_decimal() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 10kb * 1.5 == 15kb
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn filesize_division_by_zero() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 10kb / 0
+        "#
+    ));
+
+    assert!(actual.err.contains("division by zero"));
+}
+
+#[test]
+fn decimals_are_shown_rounded() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 2 / 3
+        "#
+    ));
+
+    assert_eq!(actual.out, "0.6667");
+}
+
 #[test]
 fn compound_comparison() {
     let actual = nu!(
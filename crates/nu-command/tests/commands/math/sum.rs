@@ -85,3 +85,39 @@ fn sum_of_a_row_containing_a_table_is_an_error() {
         .err
         .contains("Attempted to compute values that can't be operated on"));
 }
+
+#[test]
+fn sum_of_filesizes_skips_empty_cells() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            into-int $(echo [$nothing 1kb 2kb] | math sum)
+        "#
+    ));
+
+    assert_eq!(actual.out, "3072");
+}
+
+#[test]
+fn sum_of_a_large_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..200000 | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "20000100000");
+}
+
+#[test]
+fn sum_of_filesizes_and_decimals_is_a_filesize() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            into-int $(echo [1kb 512.5] | math sum)
+        "#
+    ));
+
+    assert_eq!(actual.out, "1536");
+}
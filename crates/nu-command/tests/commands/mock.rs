@@ -0,0 +1,55 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn replaces_command_used_by_custom_commands() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            def count-files [] { ls | count }; mock ls { echo [[name]; [a] [b] [c]] } { count-files }
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn stub_receives_the_arguments() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            mock first { echo $rows } { echo [a b c] | first 5 }
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn restores_the_command_afterwards() {
+    Playground::setup("mock_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt"), EmptyFile("b.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                let mocked = $(mock ls { echo [] } { ls | count }); ls | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn fails_for_unknown_commands() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            mock not-a-command { echo 1 } { echo 2 }
+        "#
+    ));
+
+    assert!(actual.err.contains("doesn't exist"));
+}
@@ -1,20 +1,29 @@
+mod alias_import;
 mod append;
+mod assert;
+mod ast;
 mod autoenv;
 mod autoenv_trust;
 mod autoenv_untrust;
+mod cache;
 mod cal;
 mod cd;
 mod compact;
 mod count;
 mod cp;
+mod date;
 mod def;
 mod default;
+mod detect_columns;
 mod drop;
 mod each;
 mod echo;
 mod empty;
+mod encode_;
 mod enter;
 mod every;
+mod exit;
+mod fill;
 mod first;
 mod flatten;
 mod format;
@@ -23,38 +32,55 @@ mod group_by;
 mod hash_;
 mod headers;
 mod histogram;
+mod ignore;
 mod insert;
+mod into_filesize;
 mod into_int;
 mod keep;
+mod kill;
 mod last;
 mod lines;
+mod load_env;
 mod ls;
 mod math;
 mod merge;
 mod mkdir;
+mod mock;
 mod move_;
+mod on_exit;
+mod on_signal;
 mod open;
 mod parse;
 mod path;
 mod prepend;
+mod pushd;
 mod random;
 mod range;
 mod reduce;
+mod reject;
 mod rename;
 mod reverse;
 mod rm;
 mod save;
+mod schema;
 mod select;
 mod semicolon;
+mod shells;
+mod shuffle;
 mod skip;
 mod sort_by;
+mod source_env;
 mod split_by;
 mod split_column;
 mod split_row;
 mod str_;
+mod tmpfile;
 mod touch;
+mod try_;
 mod uniq;
 mod update;
+mod upsert;
+mod view_source;
 mod where_;
 mod which;
 mod with_env;
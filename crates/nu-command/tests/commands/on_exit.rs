@@ -0,0 +1,78 @@
+#[cfg(unix)]
+use nu_test_support::fs::executable_path;
+use nu_test_support::fs::file_contents;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn runs_the_block_when_the_session_ends() {
+    Playground::setup("on_exit_test_1", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                on-exit { echo bye | save exited.txt }; echo hello
+            "#
+        ));
+
+        assert_eq!(actual.out, "hello");
+        assert_eq!(file_contents(dirs.test().join("exited.txt")), "bye");
+    })
+}
+
+#[test]
+fn runs_the_block_on_exit() {
+    Playground::setup("on_exit_test_2", |dirs, _| {
+        nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                on-exit { echo bye | save exited.txt }; exit
+            "#
+        ));
+
+        assert_eq!(file_contents(dirs.test().join("exited.txt")), "bye");
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn runs_the_block_and_exits_when_terminated() {
+    use std::process::{Command, Stdio};
+    use std::thread::sleep;
+    use std::time::{Duration, Instant};
+
+    Playground::setup("on_exit_test_3", |dirs, _| {
+        let started = dirs.test().join("started.txt");
+
+        let mut script = Command::new(executable_path())
+            .current_dir(dirs.test())
+            .arg("--skip-plugins")
+            .arg("-c")
+            .arg(
+                "on-exit { echo bye | save exited.txt }; \
+                 echo started | save started.txt; \
+                 sleep 30sec",
+            )
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("couldn't run nu");
+
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while !started.exists() && Instant::now() < deadline {
+            sleep(Duration::from_millis(50));
+        }
+        assert!(started.exists(), "the script never started");
+
+        let killed = Command::new("kill")
+            .arg("-TERM")
+            .arg(script.id().to_string())
+            .status()
+            .expect("couldn't send the signal");
+        assert!(killed.success());
+
+        let status = script.wait().expect("couldn't wait for nu");
+
+        assert_eq!(status.code(), Some(143));
+        assert_eq!(file_contents(dirs.test().join("exited.txt")), "bye");
+    })
+}
@@ -0,0 +1,75 @@
+use nu_test_support::{nu, pipeline};
+
+#[cfg(unix)]
+use nu_test_support::fs::executable_path;
+#[cfg(unix)]
+use nu_test_support::playground::Playground;
+
+#[test]
+fn errors_on_unsupported_signals() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            on-signal HUP { echo bye }
+        "#
+    ));
+
+    assert!(actual.err.contains("Unsupported signal"));
+}
+
+#[test]
+fn registering_a_handler_does_not_run_it() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            on-signal SIGINT { echo interrupted }; echo done
+        "#
+    ));
+
+    assert_eq!(actual.out, "done");
+}
+
+#[cfg(unix)]
+#[test]
+fn an_interrupted_script_runs_the_handler_and_exits() {
+    use std::process::{Command, Stdio};
+    use std::thread::sleep;
+    use std::time::{Duration, Instant};
+
+    Playground::setup("on_signal_test_1", |dirs, _| {
+        let started = dirs.test().join("started.txt");
+        let cleaned = dirs.test().join("cleaned.txt");
+
+        let mut script = Command::new(executable_path())
+            .current_dir(dirs.test())
+            .arg("--skip-plugins")
+            .arg("-c")
+            .arg(
+                "on-signal INT { echo cleaned | save cleaned.txt }; \
+                 echo started | save started.txt; \
+                 sleep 30sec",
+            )
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("couldn't run nu");
+
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while !started.exists() && Instant::now() < deadline {
+            sleep(Duration::from_millis(50));
+        }
+        assert!(started.exists(), "the script never started");
+
+        let killed = Command::new("kill")
+            .arg("-INT")
+            .arg(script.id().to_string())
+            .status()
+            .expect("couldn't send the signal");
+        assert!(killed.success());
+
+        let status = script.wait().expect("couldn't wait for nu");
+
+        assert_eq!(status.code(), Some(130));
+        assert!(cleaned.exists());
+    })
+}
@@ -95,25 +95,25 @@ fn parses_more_bson_complexity() {
 // with three tables named `strings`, `ints`, and `floats`.
 // The table_values represent the values for the tables:
 //
-// ━━━━┯━━━━━━━┯━━━━━━━━━━┯━━━━━━┯━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-//  #  │ x     │ y        │ z    │ f
-// ────┼───────┼──────────┼──────┼──────────────────────────────────────────────────────────────────────
-//   0 │ hello │ <binary> │      │
-//   1 │ hello │ <binary> │      │
-//   2 │ hello │ <binary> │      │
-//   3 │ hello │ <binary> │      │
-//   4 │ world │ <binary> │      │
-//   5 │ world │ <binary> │      │
-//   6 │       │          │    1 │
-//   7 │       │          │   42 │
-//   8 │       │          │  425 │
-//   9 │       │          │ 4253 │
-//  10 │       │          │      │
-//  11 │       │          │      │                                                    3.400000000000000
-//  12 │       │          │      │                                                    3.141592650000000
-//  13 │       │          │      │                                                    23.00000000000000
-//  14 │       │          │      │ this string that doesn't really belong here but sqlite is what it is
-// ━━━━┷━━━━━━━┷━━━━━━━━━━┷━━━━━━┷━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// ━━━━┯━━━━━━━┯━━━━━━━━━━━━━━━━━━━┯━━━━━━┯━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  #  │ x     │ y                 │ z    │ f
+// ────┼───────┼───────────────────┼──────┼──────────────────────────────────────────────────────────────────────
+//   0 │ hello │ <binary: 2 bytes> │      │
+//   1 │ hello │ <binary: 5 bytes> │      │
+//   2 │ hello │ <binary: 5 bytes> │      │
+//   3 │ hello │ <binary: 5 bytes> │      │
+//   4 │ world │ <binary: 5 bytes> │      │
+//   5 │ world │ <binary: 5 bytes> │      │
+//   6 │       │                   │    1 │
+//   7 │       │                   │   42 │
+//   8 │       │                   │  425 │
+//   9 │       │                   │ 4253 │
+//  10 │       │                   │      │
+//  11 │       │                   │      │                                                    3.400000000000000
+//  12 │       │                   │      │                                                    3.141592650000000
+//  13 │       │                   │      │                                                    23.00000000000000
+//  14 │       │                   │      │ this string that doesn't really belong here but sqlite is what it is
+// ━━━━┷━━━━━━━┷━━━━━━━━━━━━━━━━━━━┷━━━━━━┷━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //
 // We can see here that each table has different columns. `strings` has `x` and `y`, while
 // `ints` has just `z`, and `floats` has only the column `f`. This means, in general, when working
@@ -0,0 +1,68 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn popd_goes_back_to_where_pushd_left() {
+    Playground::setup("pushd_test_1", |dirs, sandbox| {
+        sandbox.mkdir("src");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd src
+                cd ..
+                cd src
+                popd
+                pwd | path basename
+            "#
+        );
+
+        assert_eq!(actual.out, "pushd_test_1");
+    })
+}
+
+#[test]
+fn dirs_lists_the_stack_from_the_current_path() {
+    Playground::setup("pushd_test_2", |dirs, sandbox| {
+        sandbox.mkdir("src").mkdir("docs");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd src
+                pushd ../docs
+                dirs | get path | path basename | str collect ","
+            "#
+        );
+
+        assert_eq!(actual.out, "docs,src,pushd_test_2");
+    })
+}
+
+#[test]
+fn pushd_to_a_missing_directory_leaves_the_stack_alone() {
+    Playground::setup("pushd_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                pushd i_dont_exist
+                dirs | count
+            "#
+        );
+
+        assert!(actual.err.contains("directory not found"));
+        assert_eq!(actual.out, "1");
+    })
+}
+
+#[test]
+fn popd_errors_when_the_stack_is_empty() {
+    Playground::setup("pushd_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "popd"
+        );
+
+        assert!(actual.err.contains("The directory stack is empty"));
+    })
+}
@@ -43,3 +43,17 @@ fn selects_some_rows() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn slices_a_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..10000000000
+            | range 5..7
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[6,7,8]");
+}
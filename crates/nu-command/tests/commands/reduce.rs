@@ -105,3 +105,16 @@ fn error_reduce_empty() {
 
     assert!(actual.err.contains("needs input"));
 }
+
+#[test]
+fn reduce_with_named_block_params() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo 1 2 3 | reduce {|it, acc| = $acc + $it }
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "6");
+}
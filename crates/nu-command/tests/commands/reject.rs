@@ -0,0 +1,63 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn regular_columns() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo [
+                [first_name, last_name, rusty_at, type];
+
+                [Andrés Robalino 10/11/2013 A]
+                [Jonathan Turner 10/12/2013 B]
+                [Yehuda Katz 10/11/2013 A]
+            ]
+            | reject type first_name
+            | get
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["last_name","rusty_at"]"#);
+}
+
+#[test]
+fn nested_columns() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "meta": {"version": "0.26.1", "license": "MIT"}}'
+            | from json
+            | reject meta.license
+            | get meta
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"version":"0.26.1"}"#);
+}
+
+#[test]
+fn errors_if_no_row_has_the_column() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo [[name, type]; [nu, shell] [zsh, shell]]
+            | reject nmae
+        "#
+    ));
+
+    assert!(actual.err.contains("did you mean 'name'?"));
+}
+
+#[test]
+fn rejects_a_column_missing_from_the_first_row() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu"}, {"name": "zsh", "type": "shell"}]'
+            | from json
+            | reject type
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"name":"nu"},{"name":"zsh"}]"#);
+    assert!(actual.err.is_empty());
+}
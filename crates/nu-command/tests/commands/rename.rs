@@ -93,3 +93,17 @@ fn errors_if_no_columns_present() {
         );
     })
 }
+
+#[test]
+fn changes_the_case_of_every_column_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[FirstName, last-name]; [Andrés, Robalino]]
+            | rename --case snake
+            | get last_name
+            "#
+    ));
+
+    assert_eq!(actual.out, "Robalino");
+}
@@ -47,3 +47,64 @@ fn writes_out_csv() {
         assert!(actual.contains("nu,0.14,A new type of shell,MIT,2018"));
     })
 }
+
+#[test]
+fn appends_to_existing_file() {
+    Playground::setup("save_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("log.txt", "first\n")]);
+
+        let expected_file = dirs.test().join("log.txt");
+
+        nu!(
+            cwd: dirs.test(),
+            r#"echo "second" | save --append --raw log.txt"#,
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, "first\nsecond");
+    })
+}
+
+#[test]
+fn keeps_the_format_of_files_opened_with_format_metadata() {
+    Playground::setup("save_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "Cargo.toml",
+            "# The shell\n[package]\nname = \"nu\"   # keep me\nversion = \"0.1.0\"\n",
+        )]);
+
+        let expected_file = dirs.test().join("Cargo.toml");
+
+        nu!(
+            cwd: dirs.test(),
+            "open Cargo.toml --with-format-metadata | update package.version 0.2.0 | save Cargo.toml"
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(
+            actual,
+            "# The shell\n[package]\nname = \"nu\"   # keep me\nversion = \"0.2.0\"\n"
+        );
+    })
+}
+
+#[test]
+fn needs_a_path_to_save_a_part_of_a_file() {
+    Playground::setup("save_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tags.json",
+            r#"{"name": "nu", "tags": ["shell"]}"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open tags.json | get tags | append rust | save"
+        );
+
+        assert!(actual.err.contains("Save requires a filepath"));
+        assert_eq!(
+            file_contents(dirs.test().join("tags.json")),
+            r#"{"name": "nu", "tags": ["shell"]}"#
+        );
+    })
+}
@@ -0,0 +1,92 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn counts_the_types_of_each_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name age]; [alice 30] [bob "thirty"] [carol 25]]
+            | schema
+            | where column == age
+            | get types.integer
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn counts_empty_cells() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name age]; [alice 30] [bob $nothing] [carol $nothing]]
+            | schema
+            | where column == age
+            | get nulls
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn validate_passes_matching_tables_through() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name age]; [alice 30] [bob 25]]
+            | validate $(echo [[name age]; [string integer]])
+            | get age
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "55");
+}
+
+#[test]
+fn validate_reports_the_row_and_column_that_do_not_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name age]; [alice 30] [bob "thirty"]]
+            | validate $(echo [[name age]; [string integer]])
+        "#
+    ));
+
+    assert!(actual.err.contains("Row 1 does not match the schema"));
+    assert!(actual
+        .err
+        .contains("column 'age' should be integer, found string"));
+}
+
+#[test]
+fn validate_passes_rows_on_as_they_are_checked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[n]; [1] [2] [three]]
+            | validate $(echo [[n]; [integer]])
+            | first 2
+            | get n
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn validate_accepts_the_output_of_schema() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let expected = $(echo [[name age]; [alice 30]] | schema);
+            echo [[name age]; [bob 25]] | validate $expected | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "bob");
+}
@@ -27,3 +27,33 @@ fn semicolon_lhs_error_stops_processing() {
 
     assert!(!actual.out.contains("done"));
 }
+
+#[test]
+fn semicolon_runs_the_whole_lhs_before_the_rhs() {
+    Playground::setup("semicolon_test_2", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [a.txt b.txt c.txt] | each { touch $it }; echo done
+            "#
+        ));
+
+        assert!(dirs.test().join("a.txt").exists());
+        assert!(dirs.test().join("b.txt").exists());
+        assert!(dirs.test().join("c.txt").exists());
+        assert_eq!(actual.out, "done");
+    })
+}
+
+#[test]
+fn semicolon_lhs_error_in_a_later_value_stops_processing() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+    r#"
+        echo [1 0] | each { = 1 / $it }; echo done
+    "#
+    ));
+
+    assert!(!actual.out.contains("done"));
+    assert!(actual.err.contains("division by zero"));
+}
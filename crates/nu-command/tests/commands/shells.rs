@@ -0,0 +1,60 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn lists_the_shells_entered() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", r#"
+            enter cargo_sample.toml
+            shells | count
+        "#
+    );
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn next_wraps_around_to_the_first_shell() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", r#"
+            enter cargo_sample.toml
+            n
+            shells | where active | get name
+        "#
+    );
+
+    assert_eq!(actual.out, "filesystem");
+}
+
+#[test]
+fn prev_wraps_around_to_the_last_shell() {
+    Playground::setup("shells_test_1", |dirs, sandbox| {
+        sandbox.mkdir("here").mkdir("there");
+
+        let actual = nu!(
+            cwd: dirs.test(), r#"
+                enter here
+                enter ../there
+                n
+                p
+                p
+                pwd | path basename
+            "#
+        );
+
+        assert_eq!(actual.out, "here");
+    })
+}
+
+#[test]
+fn commands_run_in_the_active_shell() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", r#"
+            enter cargo_sample.toml
+            cd package
+            ls | get version
+        "#
+    );
+
+    assert_eq!(actual.out, "0.1.1");
+}
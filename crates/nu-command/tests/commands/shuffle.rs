@@ -0,0 +1,33 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..100 | shuffle | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "5050");
+}
+
+#[test]
+fn shuffles_the_same_way_for_the_same_seed() {
+    let first = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..100 | shuffle --seed 7 | to json
+        "#
+    ));
+
+    let second = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..100 | shuffle -s 7 | to json
+        "#
+    ));
+
+    assert_eq!(first.out, second.out);
+    assert_ne!(first.out, "");
+}
@@ -44,20 +44,17 @@ fn by_invalid_column() {
 }
 
 #[test]
-fn by_invalid_types() {
+fn by_mixed_types() {
     let actual = nu!(
         cwd: "tests/fixtures/formats", pipeline(
         r#"
-            open cargo_sample.toml --raw
-            | echo [1 "foo"]
+            echo [$true "foo" 2 1.5 "bar"]
             | sort-by
+            | to json
         "#
     ));
 
-    assert!(actual.err.contains("Not all values can be compared"));
-    assert!(actual
-        .err
-        .contains("Unable to sort values, as \"integer\" cannot compare against \"string\""));
+    assert_eq!(actual.out, r#"[1.5,2,"bar","foo",true]"#);
 }
 
 #[test]
@@ -144,3 +141,107 @@ fn ls_sort_by_type_name_insensitive() {
 
     assert_eq!(actual.out, json_output);
 }
+
+#[test]
+fn sorts_rows_missing_the_column_last() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name":"a"},{"name":"b","size":3},{"name":"c","size":1.5}]'
+            | from json
+            | sort-by size
+            | get name
+            | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "cba");
+}
+
+#[test]
+fn sorts_mixed_numbers_by_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, size]; [a, 3] [b, 1.5] [c, 2]]
+            | sort-by size -r
+            | get name
+            | str collect
+        "#
+    ));
+
+    assert_eq!(actual.out, "acb");
+}
+
+#[test]
+fn collates_accented_and_capitalized_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [Zoë] [élan] [Eve] [eve] [zack]]
+            | sort-by name --collate
+            | get name
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "élan,eve,Eve,zack,Zoë");
+}
+
+#[test]
+fn sorts_release_tags_as_versions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [v1.10.0 v1.9.2 v1.10.0-rc.1 v1.2]
+            | sort-by --version
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "v1.2,v1.9.2,v1.10.0-rc.1,v1.10.0");
+}
+
+#[test]
+fn sorts_columns_as_versions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, version]; [nu, '0.26.1'] [rustc, '1.49.0'] [git, '0.9.10']]
+            | sort-by version -v -r
+            | get name
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "rustc,nu,git");
+}
+
+#[test]
+fn keeps_ties_in_their_order_when_reversed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, size]; [a, 1] [b, 2] [c, 1] [d, 2]]
+            | sort-by size --reverse
+            | get name
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "b,d,a,c");
+}
+
+#[test]
+fn keeps_tied_values_in_their_order_when_reversed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [b A a B]
+            | sort-by -i -r
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "b,B,A,a");
+}
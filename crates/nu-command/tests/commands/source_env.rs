@@ -0,0 +1,60 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_the_environment_the_script_sets() {
+    Playground::setup("source_env_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "env.nu",
+            "let-env GREETING = hello\nlet name = nu\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                source-env env.nu; echo $nu.env.GREETING
+            "#
+        ));
+
+        assert_eq!(actual.out, "hello");
+    })
+}
+
+#[test]
+fn forgets_the_commands_the_script_defines() {
+    Playground::setup("source_env_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "env.nu",
+            "let-env GREETING = hello\ndef greet [] { echo hi }\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                source-env env.nu; greet
+            "#
+        ));
+
+        assert!(!actual.out.contains("hi"));
+        assert!(!actual.err.is_empty());
+    })
+}
+
+#[test]
+fn stays_in_the_same_directory() {
+    Playground::setup("source_env_test_3", |dirs, sandbox| {
+        sandbox
+            .mkdir("elsewhere")
+            .with_files(vec![FileWithContent("env.nu", "cd elsewhere\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                source-env env.nu; pwd | path basename
+            "#
+        ));
+
+        assert_eq!(actual.out, "source_env_test_3");
+    })
+}
@@ -354,3 +354,133 @@ fn str_reverse() {
 
     assert!(actual.out.contains("llehsun"));
 }
+
+#[test]
+fn str_length_counts_graphemes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "👍🏽 ok" | str length
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn str_length_counts_bytes_and_code_points() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "👍🏽" | str length --bytes
+        "#
+    ));
+
+    assert_eq!(actual.out, "8");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "👍🏽" | str length --code-points
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn substring_keeps_emoji_whole() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "👨‍👩‍👧 family" | str substring "0,1"
+        "#
+    ));
+
+    assert_eq!(actual.out, "👨‍👩‍👧");
+}
+
+#[test]
+fn str_normalize_makes_strings_comparable() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "é" | str normalize nfd | str length --code-points
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn str_distance_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name]; [Andres] [Jonathan]]
+        | str distance Andrés name
+        | get name
+        | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,7]");
+}
+
+#[test]
+fn str_case_converts_column_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[first-name, meta]; [Andrés, [[home-town]; [Guayaquil]]]]
+        | str camel-case --columns meta
+        | get meta.homeTown
+        "#
+    ));
+
+    assert_eq!(actual.out, "Guayaquil");
+}
+
+#[test]
+fn str_flags_apply_a_function_to_a_column() {
+    Playground::setup("str_test_flags", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [dependency]
+                name = "  nu  "
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str --trim dependency.name | get dependency.name"
+        );
+
+        assert_eq!(actual.out, "nu");
+    })
+}
+
+#[test]
+fn str_flags_replace_every_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "1-2-3" | str --replace ["-" ""] | str --to-int | each { = $it + 1 }
+        "#
+    ));
+
+    assert_eq!(actual.out, "124");
+}
+
+#[test]
+fn str_flags_are_one_at_a_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "nu" | str --upcase --trim
+        "#
+    ));
+
+    assert!(actual.err.contains("one string function at a time"));
+}
@@ -0,0 +1,37 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn writes_the_input_to_a_file() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell" | tmpfile | each { open $it }
+        "#
+    ));
+
+    assert_eq!(actual.out, "nushell");
+}
+
+#[test]
+fn gives_the_file_an_extension() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu"}' | tmpfile --extension json | each { open $it | get name }
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn errors_on_structured_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]] | tmpfile
+        "#
+    ));
+
+    assert!(actual.err.contains("requires string or binary data"));
+}
@@ -0,0 +1,64 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn runs_the_catch_block_with_the_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo [1 2] | get name } catch { get message }
+        "#
+    ));
+
+    assert!(!actual.out.is_empty());
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn skips_the_catch_block_without_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo fine } catch { echo failed }
+        "#
+    ));
+
+    assert_eq!(actual.out, "fine");
+}
+
+#[test]
+fn keeps_a_loop_going_when_a_row_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[size]; [1] [2]]
+            | each { try { echo $it | get weight } catch { echo 1 } }
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn errors_have_a_span() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo [1 2] | get name } catch { get span.end }
+        "#
+    ));
+
+    assert!(actual.out.parse::<usize>().is_ok());
+}
+
+#[test]
+fn expects_the_catch_keyword() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo fine } rescue { echo failed }
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a catch block"));
+}
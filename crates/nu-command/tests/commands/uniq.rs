@@ -209,3 +209,46 @@ fn uniq_simple_vals_strs() {
     print!("{}", expected.out);
     assert_eq!(actual.out, expected.out);
 }
+
+#[test]
+fn uniq_by_column_counts_rows() {
+    Playground::setup("uniq_test_column", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | uniq --column type --count
+                | get count
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[2,1]");
+    })
+}
+
+#[test]
+fn uniq_counts_rows_seen_in_different_chunks() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..150000
+            | append 1
+            | uniq -c
+            | first
+            | get count
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
@@ -0,0 +1,75 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn updates_a_column_that_is_present() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, stars]; [nu, 5]]
+            | upsert stars 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"nu","stars":1}"#);
+}
+
+#[test]
+fn inserts_a_column_that_is_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | upsert stars 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"nu","stars":1}"#);
+}
+
+#[test]
+fn gives_the_block_the_row_and_the_previous_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, visits]; [home, 1] [about, 4]]
+            | upsert visits {|row, old| = $old + 1 }
+            | upsert label {|row, old| echo $row.name }
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"home","visits":2,"label":"home"},{"name":"about","visits":5,"label":"about"}]"#
+    );
+}
+
+#[test]
+fn gives_the_block_nothing_for_a_missing_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | upsert visits {|row, old| echo $old | empty? }
+            | get visits
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn runs_a_block_without_parameters_on_the_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | upsert meta.shout { get name | str upcase }
+            | get meta.shout
+        "#
+    ));
+
+    assert_eq!(actual.out, "NU");
+}
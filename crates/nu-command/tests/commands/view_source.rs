@@ -0,0 +1,77 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn shows_the_source_of_a_custom_command() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            def greet [] { echo hi }; view source greet | get source
+        "#
+    ));
+
+    assert_eq!(actual.out, "def greet [] { echo hi }");
+}
+
+#[test]
+fn keeps_the_spacing_and_comments_of_a_custom_command() {
+    Playground::setup("view_source_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            "def  greet [] {\n    # says hi\n    echo hi\n}",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                source greet.nu; view source greet | get source | lines | str collect '|'
+            "#
+        ));
+
+        assert_eq!(actual.out, "def  greet [] {|    # says hi|    echo hi|}");
+    })
+}
+
+#[test]
+fn shows_the_source_of_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let double = { = $it * 2 }; view source $double | get source
+        "#
+    ));
+
+    assert_eq!(actual.out, "{ = $it * 2 }");
+}
+
+#[test]
+fn shows_the_file_a_command_was_sourced_from() {
+    Playground::setup("view_source_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            "def greet [] { echo hi }",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                source greet.nu; view source greet | get file | path basename
+            "#
+        ));
+
+        assert_eq!(actual.out, "greet.nu");
+    })
+}
+
+#[test]
+fn built_in_commands_have_no_source() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            view source ls
+        "#
+    ));
+
+    assert!(actual.err.contains("Built-in commands have no nu source"));
+}
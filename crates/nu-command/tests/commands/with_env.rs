@@ -64,3 +64,13 @@ fn with_env_shorthand_nested_quotes() {
 
     assert_eq!(actual.out, "-arg \"hello world\"");
 }
+
+#[test]
+fn with_env_refuses_block_params() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "with-env [FOO bar] {|x| echo $x }"
+    );
+
+    assert!(actual.err.contains("names the parameter $x"));
+}
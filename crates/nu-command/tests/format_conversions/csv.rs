@@ -207,3 +207,86 @@ fn from_csv_text_skipping_headers_to_table() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn from_csv_text_previewing_the_first_rows() {
+    Playground::setup("filter_from_csv_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.txt",
+            r#"
+                first_name,last_name,rusty_luck
+                Andrés,Robalino,1
+                "Jonathan, ""JT""",Turner,1
+                Yehuda,Katz,1,and,a,row,that,does,not,parse
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from csv --preview 2
+                | get rows.first_name
+                | str collect "|"
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"Andrés|Jonathan, "JT""#);
+    })
+}
+
+#[test]
+fn from_csv_text_previewing_the_inferred_columns() {
+    Playground::setup("filter_from_csv_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.txt",
+            r#"
+                id,price,name
+                1,2,3
+                2,2.5,tea
+                3,oops,not in the preview
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open prices.txt
+                | from csv --preview 2
+                | get columns
+                | each { echo $it.name $it.type | str collect ":" }
+                | str collect "|"
+            "#
+        ));
+
+        assert_eq!(actual.out, "id:integer|price:decimal|name:string");
+    })
+}
+
+#[test]
+fn from_csv_text_previewing_rows_typed_by_their_columns() {
+    Playground::setup("filter_from_csv_test_8", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.txt",
+            r#"
+                id,price,name
+                1,2,3
+                2,2.5,tea
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open prices.txt
+                | from csv --preview 2
+                | get rows
+                | first
+                | get name
+                | str length
+            "#
+        ));
+
+        assert_eq!(actual.out, "1");
+    })
+}
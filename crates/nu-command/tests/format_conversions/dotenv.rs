@@ -0,0 +1,54 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn opens_env_files_as_a_row() {
+    Playground::setup("from_dotenv_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            ".env.local",
+            "# where the data is\nexport DATABASE_URL=\"postgres://localhost/nu\"\nDEBUG=true # for now\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open .env.local | each { echo $it.DATABASE_URL $it.DEBUG }
+            "#
+        ));
+
+        assert_eq!(actual.out, "postgres://localhost/nutrue");
+    })
+}
+
+#[test]
+fn loads_env_files_into_the_environment() {
+    Playground::setup("from_dotenv_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(".env", "GREETING='hello'\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open .env | load-env; echo $nu.env.GREETING
+            "#
+        ));
+
+        assert_eq!(actual.out, "hello");
+    })
+}
+
+#[test]
+fn errors_on_lines_that_are_not_variables() {
+    Playground::setup("from_dotenv_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(".env", "A=1\nnot a variable\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open .env
+            "#
+        ));
+
+        assert!(actual.err.contains("Could not parse as .env (line 2"));
+    })
+}
@@ -70,6 +70,20 @@ fn from_json_text_recognizing_objects_independently_to_table() {
     })
 }
 
+#[test]
+fn from_json_objects_joins_lines_split_across_chunks() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Yehuda", ' '"rusty_luck": 1}'
+            | from json --objects
+            | get rusty_luck
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
 #[test]
 fn table_to_json_text() {
     Playground::setup("filter_to_json_test", |dirs, sandbox| {
@@ -98,3 +112,32 @@ fn table_to_json_text() {
         assert_eq!(actual.out, "JonAndrehudaTZ");
     })
 }
+
+#[test]
+fn decimals_keep_the_digits_they_were_written_with() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"price": 0.1, "rate": 2.0}'
+            | from json
+            | format "{price} {rate}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "0.1 2.0");
+}
+
+#[test]
+fn from_json_previewing_the_first_values_of_a_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a, [b]"}, {"name": "c"}, {"name": "and what is not JSON'
+            | from json --preview 2
+            | get name
+            | str collect "|"
+        "#
+    ));
+
+    assert_eq!(actual.out, "a, [b]|c");
+}
@@ -1,11 +1,13 @@
 mod bson;
 mod csv;
+mod dotenv;
 mod eml;
 mod html;
 mod ics;
 mod json;
 mod markdown;
 mod ods;
+mod semver;
 mod sqlite;
 mod ssv;
 mod toml;
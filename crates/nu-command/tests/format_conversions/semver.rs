@@ -0,0 +1,27 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn parses_release_tags() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo v1.10.0-rc.1+build.5
+            | from semver
+            | format "{major} {minor} {patch} {pre} {build}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "1 10 0 rc.1 build.5");
+}
+
+#[test]
+fn fails_on_invalid_versions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1.x | from semver
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not parse as a semantic version"));
+}
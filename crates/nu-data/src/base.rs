@@ -12,6 +12,7 @@ use nu_value_ext::ValueExt;
 use num_bigint::BigInt;
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, new, Serialize)]
 pub struct Operation {
@@ -118,6 +119,35 @@ pub fn coerce_compare(
     }
 }
 
+/// A total ordering over values, for sorting data that mixes types.
+///
+/// Values are grouped by kind first (numbers, then text, booleans, dates, everything else, and
+/// finally nothing). Within a group, values that `coerce_compare` understands are compared by
+/// their contents, so integers, decimals and filesizes sort together.
+pub fn total_compare(left: &UntaggedValue, right: &UntaggedValue) -> Ordering {
+    fn rank(value: &UntaggedValue) -> u8 {
+        match value {
+            UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Filesize(_)) => 0,
+            UntaggedValue::Primitive(Primitive::String(_))
+            | UntaggedValue::Primitive(Primitive::FilePath(_)) => 1,
+            UntaggedValue::Primitive(Primitive::Boolean(_)) => 2,
+            UntaggedValue::Primitive(Primitive::Date(_)) => 3,
+            UntaggedValue::Primitive(Primitive::Nothing) => 5,
+            _ => 4,
+        }
+    }
+
+    match rank(left).cmp(&rank(right)) {
+        Ordering::Equal => match coerce_compare(left, right) {
+            Ok(values) => values.compare(),
+            Err(_) => left.cmp(right),
+        },
+        ordering => ordering,
+    }
+}
+
 pub fn coerce_compare_primitive(
     left: &Primitive,
     right: &Primitive,
@@ -146,6 +176,7 @@ pub fn coerce_compare_primitive(
         (String(left), String(right)) => CompareValues::String(left.clone(), right.clone()),
         (Date(left), Date(right)) => CompareValues::Date(*left, *right),
         (Date(left), Duration(right)) => CompareValues::DateDuration(*left, right.clone()),
+        (Duration(left), Duration(right)) => CompareValues::Ints(left.clone(), right.clone()),
         (Boolean(left), Boolean(right)) => CompareValues::Booleans(*left, *right),
         (FilePath(left), String(right)) => {
             CompareValues::String(left.as_path().display().to_string(), right.clone())
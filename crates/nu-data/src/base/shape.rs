@@ -51,8 +51,12 @@ pub enum InlineShape {
 pub struct FormatInlineShape {
     shape: InlineShape,
     column: Option<Column>,
+    float_precision: usize,
 }
 
+/// The decimal places decimals are rounded to, unless `float_precision` is set.
+pub const DEFAULT_FLOAT_PRECISION: usize = 4;
+
 impl InlineShape {
     pub fn from_primitive(primitive: &Primitive) -> InlineShape {
         match primitive {
@@ -118,6 +122,7 @@ impl InlineShape {
         FormatInlineShape {
             shape: self,
             column: Some(column.into()),
+            float_precision: DEFAULT_FLOAT_PRECISION,
         }
     }
 
@@ -125,6 +130,7 @@ impl InlineShape {
         FormatInlineShape {
             shape: self,
             column: None,
+            float_precision: DEFAULT_FLOAT_PRECISION,
         }
     }
 
@@ -201,6 +207,16 @@ impl InlineShape {
         }
     }
 
+    /// Rounds the decimal to `precision` places, if it has more.
+    pub fn format_decimal(decimal: &BigDecimal, precision: usize) -> String {
+        let (_, places) = decimal.as_bigint_and_exponent();
+        if places > precision as i64 {
+            format!("{:.*}", precision, decimal.round(precision as i64))
+        } else {
+            format!("{}", decimal)
+        }
+    }
+
     pub fn render_doc(doc: &DebugDocBuilder) -> String {
         let mut w = Vec::new();
         doc.to_doc()
@@ -210,6 +226,14 @@ impl InlineShape {
     }
 }
 
+impl FormatInlineShape {
+    /// Rounds decimals to `precision` places, as read from `float_precision` for the whole render.
+    pub fn with_float_precision(mut self, precision: usize) -> FormatInlineShape {
+        self.float_precision = precision;
+        self
+    }
+}
+
 impl PrettyDebug for FormatInlineShape {
     fn pretty(&self) -> DebugDocBuilder {
         let column = &self.column;
@@ -217,10 +241,9 @@ impl PrettyDebug for FormatInlineShape {
         match &self.shape {
             InlineShape::Nothing => DbgDocBldr::blank(),
             InlineShape::Int(int) => DbgDocBldr::primitive(format!("{}", int)),
-            InlineShape::Decimal(decimal) => DbgDocBldr::description(format_primitive(
-                &Primitive::Decimal(decimal.clone()),
-                None,
-            )),
+            InlineShape::Decimal(decimal) => {
+                DbgDocBldr::description(InlineShape::format_decimal(decimal, self.float_precision))
+            }
             InlineShape::Range(range) => {
                 let (left, left_inclusion) = &range.from;
                 let (right, right_inclusion) = &range.to;
@@ -234,9 +257,16 @@ impl PrettyDebug for FormatInlineShape {
                     ),
                 };
 
-                left.clone().format().pretty()
+                left.clone()
+                    .format()
+                    .with_float_precision(self.float_precision)
+                    .pretty()
                     + DbgDocBldr::operator(op)
-                    + right.clone().format().pretty()
+                    + right
+                        .clone()
+                        .format()
+                        .with_float_precision(self.float_precision)
+                        .pretty()
             }
             InlineShape::Bytesize(bytesize) => {
                 let bytes = InlineShape::format_bytes(bytesize, None);
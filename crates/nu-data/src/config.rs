@@ -1,5 +1,6 @@
 mod conf;
 mod nuconfig;
+pub mod schema;
 
 pub mod tests;
 
@@ -156,24 +157,43 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
 }
 
 #[cfg(feature = "directories")]
-pub fn config_path() -> Result<PathBuf, ShellError> {
-    use directories_next::ProjectDirs;
+fn project_dirs() -> Result<directories_next::ProjectDirs, ShellError> {
+    directories_next::ProjectDirs::from("org", "nushell", "nu")
+        .ok_or_else(|| ShellError::untagged_runtime_error("Couldn't find project directory"))
+}
+
+/// A directory moved elsewhere through an environment variable, like `NU_CONFIG_DIR`.
+fn dir_from_env(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
 
-    let dir = ProjectDirs::from("org", "nushell", "nu")
-        .ok_or_else(|| ShellError::untagged_runtime_error("Couldn't find project directory"))?;
-    let path = ProjectDirs::config_dir(&dir).to_owned();
+fn create_dir(path: PathBuf, name: &str) -> Result<PathBuf, ShellError> {
     std::fs::create_dir_all(&path).map_err(|err| {
-        ShellError::untagged_runtime_error(&format!("Couldn't create {} path:\n{}", "config", err))
+        ShellError::untagged_runtime_error(&format!("Couldn't create {} path:\n{}", name, err))
     })?;
 
     Ok(path)
 }
 
+/// Where the config files are kept, `$NU_CONFIG_DIR` if set and the platform's config directory
+/// otherwise.
+#[cfg(feature = "directories")]
+pub fn config_path() -> Result<PathBuf, ShellError> {
+    let path = match dir_from_env("NU_CONFIG_DIR") {
+        Some(path) => path,
+        None => project_dirs()?.config_dir().to_owned(),
+    };
+
+    create_dir(path, "config")
+}
+
 #[cfg(not(feature = "directories"))]
 pub fn config_path() -> Result<PathBuf, ShellError> {
     // FIXME: unsure if this should be error or a simple default
 
-    Ok(std::path::PathBuf::from("/"))
+    Ok(dir_from_env("NU_CONFIG_DIR").unwrap_or_else(|| PathBuf::from("/")))
 }
 
 pub fn default_path() -> Result<PathBuf, ShellError> {
@@ -191,28 +211,79 @@ pub fn default_path_for(file: &Option<PathBuf>) -> Result<PathBuf, ShellError> {
     Ok(filename)
 }
 
+/// Where the history and other data of the user are kept, `$NU_DATA_DIR` if set and the
+/// platform's local data directory otherwise. The directory may not exist yet.
 #[cfg(feature = "directories")]
-pub fn user_data() -> Result<PathBuf, ShellError> {
-    use directories_next::ProjectDirs;
+pub fn user_data_dir() -> Result<PathBuf, ShellError> {
+    match dir_from_env("NU_DATA_DIR") {
+        Some(path) => Ok(path),
+        None => Ok(project_dirs()?.data_local_dir().to_owned()),
+    }
+}
 
-    let dir = ProjectDirs::from("org", "nushell", "nu")
-        .ok_or_else(|| ShellError::untagged_runtime_error("Couldn't find project directory"))?;
-    let path = ProjectDirs::data_local_dir(&dir).to_owned();
-    std::fs::create_dir_all(&path).map_err(|err| {
-        ShellError::untagged_runtime_error(&format!(
-            "Couldn't create {} path:\n{}",
-            "user data", err
-        ))
-    })?;
+#[cfg(not(feature = "directories"))]
+pub fn user_data_dir() -> Result<PathBuf, ShellError> {
+    // FIXME: unsure if this should be error or a simple default
 
-    Ok(path)
+    Ok(dir_from_env("NU_DATA_DIR").unwrap_or_else(|| PathBuf::from("/")))
 }
 
-#[cfg(not(feature = "directories"))]
+/// The directory of the user data, created if it doesn't exist yet.
 pub fn user_data() -> Result<PathBuf, ShellError> {
+    create_dir(user_data_dir()?, "user data")
+}
+
+/// Where what can be rebuilt, like the plugin cache, is kept, `$NU_CACHE_DIR` if set and the
+/// platform's cache directory otherwise. The directory may not exist yet.
+#[cfg(feature = "directories")]
+pub fn user_cache_dir() -> Result<PathBuf, ShellError> {
+    match dir_from_env("NU_CACHE_DIR") {
+        Some(path) => Ok(path),
+        None => Ok(project_dirs()?.cache_dir().to_owned()),
+    }
+}
+
+#[cfg(not(feature = "directories"))]
+pub fn user_cache_dir() -> Result<PathBuf, ShellError> {
     // FIXME: unsure if this should be error or a simple default
 
-    Ok(std::path::PathBuf::from("/"))
+    Ok(dir_from_env("NU_CACHE_DIR").unwrap_or_else(|| PathBuf::from("/")))
+}
+
+/// The cache directory, created if it doesn't exist yet.
+pub fn user_cache() -> Result<PathBuf, ShellError> {
+    create_dir(user_cache_dir()?, "cache")
+}
+
+/// The file or directory in the cache directory, moved there from the data directory where older
+/// versions of nu kept it.
+pub fn user_cache_path_for(name: &str, legacy_name: &str) -> Result<PathBuf, ShellError> {
+    let mut path = user_cache()?;
+    path.push(name);
+
+    if let Ok(mut legacy) = user_data() {
+        legacy.push(legacy_name);
+        migrate(&legacy, &path);
+    }
+
+    Ok(path)
+}
+
+/// Moves what's left at a legacy location to where it's kept now, unless something is there
+/// already. Failing to move it only means starting over, so errors are ignored.
+pub fn migrate(legacy: &Path, path: &Path) {
+    if legacy == path || !legacy.exists() || path.exists() {
+        return;
+    }
+
+    trace!("migrating {:?} to {:?}", legacy, path);
+
+    if fs::rename(legacy, path).is_err() && legacy.is_file() {
+        // Renaming fails across file systems, where files have to be copied
+        if fs::copy(legacy, path).is_ok() {
+            let _ = fs::remove_file(legacy);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -291,8 +362,19 @@ pub fn read(
     }
 }
 
+/// Reads the config without the settings holding the wrong type of value, so that their defaults
+/// are used instead. Those are reported when nu starts.
+pub fn read_valid(
+    tag: impl Into<Tag>,
+    at: &Option<PathBuf>,
+) -> Result<IndexMap<String, Value>, ShellError> {
+    let mut config = read(tag, at)?;
+    schema::validate(&mut config, "");
+    Ok(config)
+}
+
 pub fn config(tag: impl Into<Tag>) -> Result<IndexMap<String, Value>, ShellError> {
-    read(tag, &None)
+    read_valid(tag, &None)
 }
 
 pub fn write(config: &IndexMap<String, Value>, at: &Option<PathBuf>) -> Result<(), ShellError> {
@@ -324,3 +406,28 @@ fn touch(path: &Path) -> io::Result<()> {
         Err(e) => Err(e),
     }
 }
+
+#[cfg(test)]
+mod migration_tests {
+    use super::migrate;
+    use std::fs;
+
+    #[test]
+    fn moves_legacy_files_unless_replaced() {
+        let dir = std::env::temp_dir().join(format!("nu_migrate_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("a temporary directory");
+        let legacy = dir.join("legacy.json");
+        let path = dir.join("moved.json");
+
+        fs::write(&legacy, "old").expect("the legacy file");
+        migrate(&legacy, &path);
+        assert!(!legacy.exists());
+        assert_eq!(fs::read_to_string(&path).expect("the moved file"), "old");
+
+        fs::write(&legacy, "older").expect("the legacy file");
+        migrate(&legacy, &path);
+        assert_eq!(fs::read_to_string(&path).expect("the moved file"), "old");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
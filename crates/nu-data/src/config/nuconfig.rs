@@ -1,4 +1,4 @@
-use crate::config::{last_modified, read, Conf, Status};
+use crate::config::{last_modified, read_valid, Conf, Status};
 use indexmap::IndexMap;
 use nu_protocol::Value;
 use nu_source::Tag;
@@ -30,7 +30,7 @@ impl Conf for NuConfig {
     fn reload(&mut self) {
         let vars = &mut self.vars;
 
-        if let Ok(variables) = read(Tag::unknown(), &None) {
+        if let Ok(variables) = read_valid(Tag::unknown(), &None) {
             vars.extend(variables);
 
             self.modified_at = if let Ok(status) = last_modified(&None) {
@@ -51,7 +51,7 @@ impl NuConfig {
         match &config_file {
             None => NuConfig::new(),
             Some(_) => {
-                let vars = if let Ok(variables) = read(Tag::unknown(), &config_file) {
+                let vars = if let Ok(variables) = read_valid(Tag::unknown(), &config_file) {
                     variables
                 } else {
                     IndexMap::default()
@@ -66,7 +66,7 @@ impl NuConfig {
     }
 
     pub fn new() -> NuConfig {
-        let vars = if let Ok(variables) = read(Tag::unknown(), &None) {
+        let vars = if let Ok(variables) = read_valid(Tag::unknown(), &None) {
             variables
         } else {
             IndexMap::default()
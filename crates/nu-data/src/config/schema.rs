@@ -0,0 +1,303 @@
+use crate::config::convert_toml_value_to_nu_value;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{closest_names, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::{Span, Tag};
+use std::path::Path;
+
+/// What a setting is expected to hold.
+#[derive(Debug)]
+pub enum Expected {
+    Boolean,
+    Integer,
+    String,
+    /// A table of strings
+    Strings,
+    /// A row of anything, like the environment variables
+    Row,
+    /// A row of settings of its own
+    Section(&'static [(&'static str, Expected)]),
+}
+
+impl Expected {
+    fn description(&self) -> &'static str {
+        match self {
+            Expected::Boolean => "a boolean",
+            Expected::Integer => "an integer",
+            Expected::String => "a string",
+            Expected::Strings => "a table of strings",
+            Expected::Row | Expected::Section(_) => "a section",
+        }
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        match (self, &value.value) {
+            (Expected::Boolean, UntaggedValue::Primitive(Primitive::Boolean(_))) => true,
+            (Expected::Integer, UntaggedValue::Primitive(Primitive::Int(_))) => true,
+            (Expected::String, UntaggedValue::Primitive(Primitive::String(_))) => true,
+            (Expected::Strings, UntaggedValue::Table(values)) => values
+                .iter()
+                .all(|value| matches!(value.value, UntaggedValue::Primitive(Primitive::String(_)))),
+            (Expected::Row, UntaggedValue::Row(_)) => true,
+            (Expected::Section(_), UntaggedValue::Row(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The settings nu knows of, with what each of them holds.
+pub const SETTINGS: &[(&str, Expected)] = &[
+    ("path", Expected::Strings),
+    ("env", Expected::Row),
+    ("startup", Expected::Strings),
+    ("plugin_dirs", Expected::Strings),
+    ("skip_welcome_message", Expected::Boolean),
+    ("ctrlc_exit", Expected::Boolean),
+    ("nonzero_exit_errors", Expected::Boolean),
+    ("complete_from_path", Expected::Boolean),
+    ("rm_always_trash", Expected::Boolean),
+    ("disable_table_indexes", Expected::Boolean),
+    ("table_mode", Expected::String),
+    ("table_max_column_width", Expected::Integer),
+    ("table_pager", Expected::String),
+    ("float_precision", Expected::Integer),
+    ("pivot_mode", Expected::String),
+    ("filesize_format", Expected::String),
+    ("sort_collation", Expected::String),
+    ("pipeline_buffer_size", Expected::Integer),
+    ("pipeline_buffer_bytes", Expected::Integer),
+    ("edit_mode", Expected::String),
+    ("key_timeout", Expected::Integer),
+    ("history_size", Expected::Integer),
+    ("completion_mode", Expected::String),
+    ("prompt", Expected::String),
+    ("prompt_segments", Expected::Strings),
+    ("prompt_separator", Expected::String),
+    ("prompt_indicator", Expected::String),
+    ("prompt_indicator_vi", Expected::String),
+    ("prompt_indicator_failure", Expected::Boolean),
+    ("prompt_continuation", Expected::String),
+    // Colors are strings, except for a few switches like `header_bold`
+    ("color_config", Expected::Row),
+    ("color_theme", Expected::String),
+    ("textview", Expected::Row),
+    (
+        "shell_integration",
+        Expected::Section(&[
+            ("title", Expected::Boolean),
+            ("cwd", Expected::Boolean),
+            ("marks", Expected::Boolean),
+        ]),
+    ),
+    (
+        "line_editor",
+        Expected::Section(&[
+            ("max_history_size", Expected::Integer),
+            ("history_duplicates", Expected::String),
+            ("history_ignore_space", Expected::Boolean),
+            ("completion_type", Expected::String),
+            ("completion_prompt_limit", Expected::Integer),
+            ("completion_match_method", Expected::String),
+            ("keyseq_timeout_ms", Expected::Integer),
+            ("edit_mode", Expected::String),
+            ("auto_add_history", Expected::Boolean),
+            ("bell_style", Expected::String),
+            ("color_mode", Expected::String),
+            ("tab_stop", Expected::Integer),
+        ]),
+    ),
+];
+
+/// Reads the config file and reports the settings nu doesn't know of, and those holding the wrong
+/// type of value. Errors point into the returned contents of the file.
+pub fn check(path: &Path) -> Result<(String, Vec<ShellError>), ShellError> {
+    let contents = std::fs::read_to_string(path)?;
+    let parsed: toml::Value = toml::from_str(&contents).map_err(|err| {
+        ShellError::untagged_runtime_error(format!("Couldn't parse config file:\n{}", err))
+    })?;
+
+    let problems = match convert_toml_value_to_nu_value(&parsed, Tag::unknown()).value {
+        UntaggedValue::Row(mut row) => validate(&mut row.entries, &contents),
+        _ => vec![],
+    };
+
+    Ok((contents, problems))
+}
+
+/// Removes the settings holding the wrong type of value, so that their defaults are used instead,
+/// returning what's wrong with the config. Errors point into `contents` when it's the config file.
+pub fn validate(entries: &mut IndexMap<String, Value>, contents: &str) -> Vec<ShellError> {
+    let mut problems = vec![];
+    validate_section(entries, SETTINGS, None, contents, &mut problems);
+    problems
+}
+
+fn validate_section(
+    entries: &mut IndexMap<String, Value>,
+    settings: &[(&str, Expected)],
+    section: Option<&str>,
+    contents: &str,
+    problems: &mut Vec<ShellError>,
+) {
+    let mut invalid = vec![];
+
+    for (key, value) in entries.iter_mut() {
+        let span = locate(contents, section, key);
+
+        let expected = match settings.iter().find(|(name, _)| *name == key.as_str()) {
+            Some((_, expected)) => expected,
+            None => {
+                let names = settings.iter().map(|(name, _)| name.to_string());
+                let label = match closest_names(key, names).first() {
+                    Some(name) => format!("did you mean '{}'?", name),
+                    None => "nu doesn't use this setting".to_string(),
+                };
+
+                problems.push(ShellError::labeled_error(
+                    format!("Unknown setting {}", qualified(section, key)),
+                    label,
+                    span,
+                ));
+                continue;
+            }
+        };
+
+        if !expected.matches(value) {
+            problems.push(ShellError::labeled_error(
+                format!(
+                    "Invalid setting {}, using its default instead",
+                    qualified(section, key)
+                ),
+                format!(
+                    "expected {}, found {}",
+                    expected.description(),
+                    value.type_name()
+                ),
+                span,
+            ));
+            invalid.push(key.clone());
+            continue;
+        }
+
+        if let (Expected::Section(settings), UntaggedValue::Row(row)) = (expected, &mut value.value)
+        {
+            validate_section(
+                &mut row.entries,
+                settings,
+                Some(key.as_str()),
+                contents,
+                problems,
+            );
+        }
+    }
+
+    for key in invalid {
+        entries.shift_remove(&key);
+    }
+}
+
+fn qualified(section: Option<&str>, key: &str) -> String {
+    match section {
+        Some(section) => format!("{}.{}", section, key),
+        None => key.to_string(),
+    }
+}
+
+/// Where the key is set in the config file, in the section if it's in one.
+fn locate(contents: &str, section: Option<&str>, key: &str) -> Span {
+    let mut current: Option<&str> = None;
+    let mut offset = 0;
+
+    for line in contents.split('\n') {
+        let start = offset;
+        offset += line.len() + 1;
+
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            current = Some(trimmed.trim_matches(|c| c == '[' || c == ']').trim());
+            continue;
+        }
+
+        if current != section {
+            continue;
+        }
+
+        let name = trimmed
+            .split('=')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"');
+        if name == key && trimmed.contains('=') {
+            let column = line.find(key).unwrap_or(0);
+            return Span::new(start + column, start + line.trim_end().len());
+        }
+    }
+
+    // A whole section is written as a table header
+    if section.is_none() {
+        let header = format!("[{}]", key);
+        if let Some(start) = contents.find(&header) {
+            return Span::new(start, start + header.len());
+        }
+    }
+
+    Span::unknown()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate;
+    use crate::config::convert_toml_value_to_nu_value;
+    use nu_protocol::UntaggedValue;
+    use nu_source::{HasFallibleSpan, Tag};
+
+    fn entries(contents: &str) -> indexmap::IndexMap<String, nu_protocol::Value> {
+        let parsed: toml::Value = toml::from_str(contents).expect("a valid config");
+        match convert_toml_value_to_nu_value(&parsed, Tag::unknown()).value {
+            UntaggedValue::Row(row) => row.entries,
+            _ => panic!("expected a row"),
+        }
+    }
+
+    #[test]
+    fn suggests_the_setting_meant_by_a_typo() {
+        let contents = "tabel_mode = \"light\"\n";
+        let mut config = entries(contents);
+
+        let problems = validate(&mut config, contents);
+
+        assert_eq!(problems.len(), 1);
+        let message = format!("{:?}", problems[0]);
+        assert!(message.contains("Unknown setting tabel_mode"));
+        assert!(message.contains("did you mean 'table_mode'?"));
+    }
+
+    #[test]
+    fn drops_settings_of_the_wrong_type() {
+        let contents = "ctrlc_exit = \"yes\"\ntable_mode = \"light\"\n\n[line_editor]\ntab_stop = \"four\"\nedit_mode = \"vi\"\n";
+        let mut config = entries(contents);
+
+        let problems = validate(&mut config, contents);
+
+        assert_eq!(problems.len(), 2);
+        assert!(!config.contains_key("ctrlc_exit"));
+        assert!(config.contains_key("table_mode"));
+
+        let line_editor = config.get("line_editor").expect("the line editor section");
+        let keys: Vec<String> = line_editor.row_entries().map(|(k, _)| k.clone()).collect();
+        assert_eq!(keys, vec!["edit_mode".to_string()]);
+    }
+
+    #[test]
+    fn points_at_the_setting_in_its_section() {
+        let contents = "edit_mode = \"vi\"\n[line_editor]\nedit_mode = 3\n";
+        let mut config = entries(contents);
+
+        let problems = validate(&mut config, contents);
+
+        assert_eq!(problems.len(), 1);
+        let span = problems[0].maybe_span().expect("a span");
+        assert_eq!(&contents[span.start()..span.end()], "edit_mode = 3");
+    }
+}
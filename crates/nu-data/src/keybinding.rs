@@ -1,3 +1,466 @@
+use serde::{Deserialize, Serialize};
+
 pub fn keybinding_path() -> Result<std::path::PathBuf, nu_errors::ShellError> {
     crate::config::default_path_for(&Some(std::path::PathBuf::from("keybindings.yml")))
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KeyPress {
+    /// Unsupported escape sequence (on unix platform)
+    UnknownEscSeq,
+    /// ⌫ or `KeyPress::Ctrl('H')`
+    Backspace,
+    /// ⇤ (usually Shift-Tab)
+    BackTab,
+    /// Paste (on unix platform)
+    BracketedPasteStart,
+    /// Paste (on unix platform)
+    BracketedPasteEnd,
+    /// Single char
+    Char(char),
+    /// Ctrl-↓
+    ControlDown,
+    /// Ctrl-←
+    ControlLeft,
+    /// Ctrl-→
+    ControlRight,
+    /// Ctrl-↑
+    ControlUp,
+    /// Ctrl-char
+    Ctrl(char),
+    /// ⌦
+    Delete,
+    /// ↓ arrow key
+    Down,
+    /// ⇲
+    End,
+    /// ↵ or `KeyPress::Ctrl('M')`
+    Enter,
+    /// Escape or `KeyPress::Ctrl('[')`
+    Esc,
+    /// Function key
+    F(u8),
+    /// ⇱
+    Home,
+    /// Insert key
+    Insert,
+    /// ← arrow key
+    Left,
+    /// Escape-char or Alt-char
+    Meta(char),
+    /// `KeyPress::Char('\0')`
+    Null,
+    /// ⇟
+    PageDown,
+    /// ⇞
+    PageUp,
+    /// → arrow key
+    Right,
+    /// Shift-↓
+    ShiftDown,
+    /// Shift-←
+    ShiftLeft,
+    /// Shift-→
+    ShiftRight,
+    /// Shift-↑
+    ShiftUp,
+    /// ⇥ or `KeyPress::Ctrl('I')`
+    Tab,
+    /// ↑ arrow key
+    Up,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Cmd {
+    /// abort
+    Abort, // Miscellaneous Command
+    /// accept-line
+    AcceptLine,
+    /// beginning-of-history
+    BeginningOfHistory,
+    /// capitalize-word
+    CapitalizeWord,
+    /// clear-screen
+    ClearScreen,
+    /// complete
+    Complete,
+    /// complete-backward
+    CompleteBackward,
+    /// complete-hint
+    CompleteHint,
+    /// downcase-word
+    DowncaseWord,
+    /// vi-eof-maybe
+    EndOfFile,
+    /// end-of-history
+    EndOfHistory,
+    /// forward-search-history
+    ForwardSearchHistory,
+    /// history-search-backward
+    HistorySearchBackward,
+    /// history-search-forward
+    HistorySearchForward,
+    /// Insert text
+    Insert { repeat: RepeatCount, string: String },
+    /// Interrupt signal (Ctrl-C)
+    Interrupt,
+    /// backward-delete-char, backward-kill-line, backward-kill-word
+    /// delete-char, kill-line, kill-word, unix-line-discard, unix-word-rubout,
+    /// vi-delete, vi-delete-to, vi-rubout
+    Kill(Movement),
+    /// backward-char, backward-word, beginning-of-line, end-of-line,
+    /// forward-char, forward-word, vi-char-search, vi-end-word, vi-next-word,
+    /// vi-prev-word
+    Move(Movement),
+    /// next-history
+    NextHistory,
+    /// No action
+    Noop,
+    /// vi-replace
+    Overwrite(char),
+    /// previous-history
+    PreviousHistory,
+    /// quoted-insert
+    QuotedInsert,
+    /// vi-change-char
+    ReplaceChar { repeat: RepeatCount, ch: char },
+    /// vi-change-to, vi-substitute
+    Replace {
+        movement: Movement,
+        replacement: Option<String>,
+    },
+    /// reverse-search-history
+    ReverseSearchHistory,
+    /// self-insert
+    SelfInsert { repeat: RepeatCount, ch: char },
+    /// Suspend signal (Ctrl-Z on unix platform)
+    Suspend,
+    /// transpose-chars
+    TransposeChars,
+    /// transpose-words
+    TransposeWords(RepeatCount),
+    /// undo
+    Undo(RepeatCount),
+    /// Unsupported / unexpected
+    Unknown,
+    /// upcase-word
+    UpcaseWord,
+    /// vi-yank-to
+    ViYankTo(Movement),
+    /// yank, vi-put
+    Yank { repeat: RepeatCount, anchor: Anchor },
+    /// yank-pop
+    YankPop,
+    /// moves cursor to the line above or switches to prev history entry if
+    /// the cursor is already on the first line
+    LineUpOrPreviousHistory(RepeatCount),
+    /// moves cursor to the line below or switches to next history entry if
+    /// the cursor is already on the last line
+    LineDownOrNextHistory(RepeatCount),
+    /// accepts the line when cursor is at the end of the text (non including
+    /// trailing whitespace), inserts newline character otherwise
+    AcceptOrInsertLine,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Movement {
+    /// Whole current line (not really a movement but a range)
+    WholeLine,
+    /// beginning-of-line
+    BeginningOfLine,
+    /// end-of-line
+    EndOfLine,
+    /// backward-word, vi-prev-word
+    BackwardWord { repeat: RepeatCount, word: Word }, // Backward until start of word
+    /// forward-word, vi-end-word, vi-next-word
+    ForwardWord {
+        repeat: RepeatCount,
+        at: At,
+        word: Word,
+    }, // Forward until start/end of word
+    /// vi-char-search
+    ViCharSearch {
+        repeat: RepeatCount,
+        search: CharSearch,
+    },
+    /// vi-first-print
+    ViFirstPrint,
+    /// backward-char
+    BackwardChar(RepeatCount),
+    /// forward-char
+    ForwardChar(RepeatCount),
+    /// move to the same column on the previous line
+    LineUp(RepeatCount),
+    /// move to the same column on the next line
+    LineDown(RepeatCount),
+    /// Whole user input (not really a movement but a range)
+    WholeBuffer,
+    /// beginning-of-buffer
+    BeginningOfBuffer,
+    /// end-of-buffer
+    EndOfBuffer,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+enum InputMode {
+    /// Vi Command/Alternate
+    Command,
+    /// Insert/Input mode
+    Insert,
+    /// Overwrite mode
+    Replace,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Word {
+    /// non-blanks characters
+    Big,
+    /// alphanumeric characters
+    Emacs,
+    /// alphanumeric (and '_') characters
+    Vi,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum At {
+    /// Start of word.
+    Start,
+    /// Before end of word.
+    BeforeEnd,
+    /// After end of word.
+    AfterEnd,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Anchor {
+    /// After cursor
+    After,
+    /// Before cursor
+    Before,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum CharSearch {
+    /// Forward search
+    Forward(char),
+    /// Forward search until
+    ForwardBefore(char),
+    /// Backward search
+    Backward(char),
+    /// Backward search until
+    BackwardAfter(char),
+}
+
+/// The number of times one command should be repeated.
+pub type RepeatCount = usize;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Keybinding {
+    pub key: KeyPress,
+    pub binding: Cmd,
+}
+
+pub type Keybindings = Vec<Keybinding>;
+
+/// The keybindings nu sets itself, which apply in every edit mode.
+pub fn nu_keybindings() -> Keybindings {
+    vec![
+        bind(
+            KeyPress::ControlLeft,
+            Cmd::Move(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Vi,
+            }),
+        ),
+        bind(
+            KeyPress::ControlRight,
+            Cmd::Move(Movement::ForwardWord {
+                repeat: 1,
+                at: At::AfterEnd,
+                word: Word::Vi,
+            }),
+        ),
+        bind(KeyPress::Meta('r'), Cmd::Undo(usize::MAX)),
+        bind(KeyPress::BracketedPasteStart, Cmd::Noop),
+    ]
+}
+
+/// The keybindings of the line editor in emacs mode. Key sequences, like Ctrl-X Ctrl-U, are left
+/// out, since keybindings.yml can't bind them.
+pub fn emacs_keybindings() -> Keybindings {
+    let mut keybindings = common_keybindings();
+    keybindings.extend(vec![
+        bind(KeyPress::Ctrl('A'), Cmd::Move(Movement::BeginningOfLine)),
+        bind(KeyPress::Ctrl('B'), Cmd::Move(Movement::BackwardChar(1))),
+        bind(KeyPress::Ctrl('E'), Cmd::Move(Movement::EndOfLine)),
+        bind(KeyPress::Ctrl('F'), Cmd::Move(Movement::ForwardChar(1))),
+        bind(KeyPress::Ctrl('G'), Cmd::Abort),
+        bind(KeyPress::Esc, Cmd::Abort),
+        bind(KeyPress::Backspace, Cmd::Kill(Movement::BackwardChar(1))),
+        bind(KeyPress::Tab, Cmd::Complete),
+        bind(KeyPress::BackTab, Cmd::CompleteBackward),
+        bind(KeyPress::Ctrl('K'), Cmd::Kill(Movement::EndOfLine)),
+        bind(KeyPress::Ctrl('L'), Cmd::ClearScreen),
+        bind(KeyPress::Ctrl('N'), Cmd::NextHistory),
+        bind(KeyPress::Ctrl('P'), Cmd::PreviousHistory),
+        bind(
+            KeyPress::Meta('\x7f'),
+            Cmd::Kill(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Emacs,
+            }),
+        ),
+        bind(KeyPress::Meta('<'), Cmd::BeginningOfHistory),
+        bind(KeyPress::Meta('>'), Cmd::EndOfHistory),
+        bind(
+            KeyPress::Meta('b'),
+            Cmd::Move(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Emacs,
+            }),
+        ),
+        bind(KeyPress::Meta('c'), Cmd::CapitalizeWord),
+        bind(
+            KeyPress::Meta('d'),
+            Cmd::Kill(Movement::ForwardWord {
+                repeat: 1,
+                at: At::AfterEnd,
+                word: Word::Emacs,
+            }),
+        ),
+        bind(
+            KeyPress::Meta('f'),
+            Cmd::Move(Movement::ForwardWord {
+                repeat: 1,
+                at: At::AfterEnd,
+                word: Word::Emacs,
+            }),
+        ),
+        bind(KeyPress::Meta('l'), Cmd::DowncaseWord),
+        bind(KeyPress::Meta('n'), Cmd::HistorySearchForward),
+        bind(KeyPress::Meta('p'), Cmd::HistorySearchBackward),
+        bind(KeyPress::Meta('t'), Cmd::TransposeWords(1)),
+        bind(KeyPress::Meta('u'), Cmd::UpcaseWord),
+        bind(KeyPress::Meta('y'), Cmd::YankPop),
+    ]);
+
+    keybindings
+}
+
+/// The keybindings of the line editor in vi's insert mode. Esc switches to command mode.
+pub fn vi_insert_keybindings() -> Keybindings {
+    let mut keybindings = common_keybindings();
+    keybindings.extend(vec![
+        bind(KeyPress::Backspace, Cmd::Kill(Movement::BackwardChar(1))),
+        bind(KeyPress::Tab, Cmd::Complete),
+        bind(KeyPress::BackTab, Cmd::CompleteBackward),
+    ]);
+
+    keybindings
+}
+
+/// The single keys of the line editor in vi's command mode. Those taking a motion, like `d` or
+/// `c`, and those switching to insert mode, like `i` or `a`, are left out.
+pub fn vi_command_keybindings() -> Keybindings {
+    vec![
+        bind(KeyPress::Char('h'), Cmd::Move(Movement::BackwardChar(1))),
+        bind(KeyPress::Char('l'), Cmd::Move(Movement::ForwardChar(1))),
+        bind(KeyPress::Char('j'), Cmd::LineDownOrNextHistory(1)),
+        bind(KeyPress::Char('k'), Cmd::LineUpOrPreviousHistory(1)),
+        bind(KeyPress::Char('0'), Cmd::Move(Movement::BeginningOfLine)),
+        bind(KeyPress::Char('^'), Cmd::Move(Movement::ViFirstPrint)),
+        bind(KeyPress::Char('$'), Cmd::Move(Movement::EndOfLine)),
+        bind(
+            KeyPress::Char('b'),
+            Cmd::Move(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Vi,
+            }),
+        ),
+        bind(
+            KeyPress::Char('B'),
+            Cmd::Move(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Big,
+            }),
+        ),
+        bind(
+            KeyPress::Char('e'),
+            Cmd::Move(Movement::ForwardWord {
+                repeat: 1,
+                at: At::BeforeEnd,
+                word: Word::Vi,
+            }),
+        ),
+        bind(
+            KeyPress::Char('w'),
+            Cmd::Move(Movement::ForwardWord {
+                repeat: 1,
+                at: At::Start,
+                word: Word::Vi,
+            }),
+        ),
+        bind(KeyPress::Char('x'), Cmd::Kill(Movement::ForwardChar(1))),
+        bind(KeyPress::Char('X'), Cmd::Kill(Movement::BackwardChar(1))),
+        bind(KeyPress::Char('D'), Cmd::Kill(Movement::EndOfLine)),
+        bind(
+            KeyPress::Char('p'),
+            Cmd::Yank {
+                repeat: 1,
+                anchor: Anchor::After,
+            },
+        ),
+        bind(
+            KeyPress::Char('P'),
+            Cmd::Yank {
+                repeat: 1,
+                anchor: Anchor::Before,
+            },
+        ),
+        bind(KeyPress::Char('u'), Cmd::Undo(1)),
+        bind(KeyPress::Char('/'), Cmd::ReverseSearchHistory),
+        bind(KeyPress::Ctrl('L'), Cmd::ClearScreen),
+        bind(KeyPress::Enter, Cmd::AcceptLine),
+    ]
+}
+
+/// The keybindings of every edit mode.
+fn common_keybindings() -> Keybindings {
+    vec![
+        bind(KeyPress::Home, Cmd::Move(Movement::BeginningOfLine)),
+        bind(KeyPress::End, Cmd::Move(Movement::EndOfLine)),
+        bind(KeyPress::Left, Cmd::Move(Movement::BackwardChar(1))),
+        bind(KeyPress::Right, Cmd::Move(Movement::ForwardChar(1))),
+        bind(KeyPress::Up, Cmd::LineUpOrPreviousHistory(1)),
+        bind(KeyPress::Down, Cmd::LineDownOrNextHistory(1)),
+        bind(KeyPress::Delete, Cmd::Kill(Movement::ForwardChar(1))),
+        bind(KeyPress::Enter, Cmd::AcceptLine),
+        bind(KeyPress::Ctrl('C'), Cmd::Interrupt),
+        bind(KeyPress::Ctrl('D'), Cmd::EndOfFile),
+        bind(KeyPress::Ctrl('R'), Cmd::ReverseSearchHistory),
+        bind(KeyPress::Ctrl('S'), Cmd::ForwardSearchHistory),
+        bind(KeyPress::Ctrl('T'), Cmd::TransposeChars),
+        bind(KeyPress::Ctrl('U'), Cmd::Kill(Movement::BeginningOfLine)),
+        bind(KeyPress::Ctrl('V'), Cmd::QuotedInsert),
+        bind(
+            KeyPress::Ctrl('W'),
+            Cmd::Kill(Movement::BackwardWord {
+                repeat: 1,
+                word: Word::Big,
+            }),
+        ),
+        bind(
+            KeyPress::Ctrl('Y'),
+            Cmd::Yank {
+                repeat: 1,
+                anchor: Anchor::Before,
+            },
+        ),
+        bind(KeyPress::Ctrl('Z'), Cmd::Suspend),
+        bind(KeyPress::Ctrl('_'), Cmd::Undo(1)),
+    ]
+}
+
+fn bind(key: KeyPress, binding: Cmd) -> Keybinding {
+    Keybinding { key, binding }
+}
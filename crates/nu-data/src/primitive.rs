@@ -4,6 +4,8 @@ use nu_source::Tag;
 use nu_table::{Alignment, TextStyle};
 use std::collections::HashMap;
 
+pub use nu_table::colors_enabled;
+
 pub fn number(number: impl Into<Number>) -> Primitive {
     let number = number.into();
 
@@ -121,9 +123,47 @@ fn update_hashmap(key: &str, val: &Value, hm: &mut HashMap<String, Style>) {
     }
 }
 
+/// The styles the `color_theme` set in the config gives instead of the defaults.
+fn theme_styles(theme: Option<&Value>) -> Vec<(&'static str, Style)> {
+    match theme.map(|theme| theme.as_string()) {
+        // Things are told apart by brightness, and by blue and yellow rather than red and green,
+        // which many color-blind people can't tell apart
+        Some(Ok(name)) if name == "high_contrast" => vec![
+            ("primitive_int", Color::Cyan.bold()),
+            ("primitive_decimal", Color::Cyan.bold()),
+            ("primitive_filesize", Color::Cyan.bold()),
+            ("primitive_string", Color::White.bold()),
+            ("primitive_line", Color::White.bold()),
+            ("primitive_columnpath", Color::White.bold()),
+            ("primitive_pattern", Color::White.bold()),
+            ("primitive_boolean", Color::Yellow.bold()),
+            ("primitive_date", Color::Blue.bold()),
+            ("primitive_duration", Color::Blue.bold()),
+            ("primitive_range", Color::White.bold()),
+            ("primitive_path", Color::White.bold().underline()),
+            ("primitive_binary", Color::White.bold()),
+            ("separator_color", Color::White.bold()),
+            ("header_color", Color::Yellow.bold()),
+            ("header_bold", Color::Yellow.bold()),
+            ("index_color", Color::Cyan.bold()),
+            ("leading_trailing_space_bg", Style::default().reverse()),
+        ],
+        _ => vec![],
+    }
+}
+
+/// The style the `color_theme` set in the config gives to the key, if it isn't the default theme.
+pub fn theme_style(theme: Option<&Value>, key: &str) -> Option<Style> {
+    theme_styles(theme)
+        .into_iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, style)| style)
+}
+
 pub fn get_color_config() -> HashMap<String, Style> {
     // create the hashmap
     let mut hm: HashMap<String, Style> = HashMap::new();
+    let config = crate::config::config(Tag::unknown());
     // set some defaults
     hm.insert("primitive_int".to_string(), Color::White.normal());
     hm.insert("primitive_decimal".to_string(), Color::White.normal());
@@ -149,8 +189,15 @@ pub fn get_color_config() -> HashMap<String, Style> {
         Style::default().on(Color::RGB(128, 128, 128)),
     );
 
+    // the theme replaces the defaults, while the colors set one by one replace both
+    if let Ok(config) = &config {
+        for (key, style) in theme_styles(config.get("color_theme")) {
+            hm.insert(string_to_lookup_value(key), style);
+        }
+    }
+
     // populate hashmap from config values
-    if let Ok(config) = crate::config::config(Tag::unknown()) {
+    if let Ok(config) = config {
         if let Some(primitive_color_vars) = config.get("color_config") {
             for (key, value) in primitive_color_vars.row_entries() {
                 match key.as_ref() {
@@ -82,7 +82,12 @@ pub fn unsafe_compute_values(
         (UntaggedValue::Primitive(lhs), UntaggedValue::Primitive(rhs)) => match (lhs, rhs) {
             (Primitive::Filesize(x), Primitive::Int(y)) => match operator {
                 Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(x * y))),
-                Operator::Divide => Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y))),
+                Operator::Divide => {
+                    if y.is_zero() {
+                        return Ok(zero_division_error());
+                    }
+                    Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y)))
+                }
                 _ => Err((left.type_name(), right.type_name())),
             },
             (Primitive::Int(x), Primitive::Filesize(y)) => match operator {
@@ -106,19 +111,52 @@ pub fn compute_values(
                 let result = match operator {
                     Operator::Plus => Ok(x + y),
                     Operator::Minus => Ok(x - y),
+                    // How many times one size fits in the other
+                    Operator::Divide => {
+                        return compute_values(
+                            operator,
+                            &UntaggedValue::Primitive(Primitive::Int(x.clone())),
+                            &UntaggedValue::Primitive(Primitive::Int(y.clone())),
+                        )
+                    }
                     _ => Err((left.type_name(), right.type_name())),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Filesize(result)))
             }
             (Primitive::Filesize(x), Primitive::Int(y)) => match operator {
                 Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(x * y))),
-                Operator::Divide => Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y))),
+                Operator::Divide => {
+                    if y.is_zero() {
+                        return Ok(zero_division_error());
+                    }
+                    Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y)))
+                }
                 _ => Err((left.type_name(), right.type_name())),
             },
             (Primitive::Int(x), Primitive::Filesize(y)) => match operator {
                 Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(x * y))),
                 _ => Err((left.type_name(), right.type_name())),
             },
+            (Primitive::Filesize(x), Primitive::Decimal(y)) => {
+                let result = match operator {
+                    Operator::Multiply => Ok(scale(x, y, operator)),
+                    Operator::Divide => {
+                        if y.is_zero() {
+                            return Ok(zero_division_error());
+                        }
+                        Ok(scale(x, y, operator))
+                    }
+                    _ => Err((left.type_name(), right.type_name())),
+                }?;
+
+                Ok(UntaggedValue::Primitive(Primitive::Filesize(result)))
+            }
+            (Primitive::Decimal(x), Primitive::Filesize(y)) => match operator {
+                Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(scale(
+                    y, x, operator,
+                )))),
+                _ => Err((left.type_name(), right.type_name())),
+            },
             (Primitive::Int(x), Primitive::Int(y)) => match operator {
                 Operator::Plus => Ok(UntaggedValue::Primitive(Primitive::Int(x + y))),
                 Operator::Minus => Ok(UntaggedValue::Primitive(Primitive::Int(x - y))),
@@ -257,6 +295,30 @@ pub fn compute_values(
                             Err(_) => Err(("Date", "Duration overflow")),
                         }
                     }
+                    Operator::Minus => {
+                        match Primitive::into_chrono_duration(rhs.clone(), Span::unknown()) {
+                            Ok(y) => match x.checked_sub_signed(y) {
+                                Some(value) => Ok(value),
+                                None => Err(("Date", "Duration and date subtraction overflow")),
+                            },
+                            Err(_) => Err(("Date", "Duration overflow")),
+                        }
+                    }
+                    _ => Err((left.type_name(), right.type_name())),
+                }?;
+                Ok(UntaggedValue::Primitive(Primitive::Date(result)))
+            }
+            (Primitive::Duration(_), Primitive::Date(y)) => {
+                let result = match operator {
+                    Operator::Plus => {
+                        match Primitive::into_chrono_duration(lhs.clone(), Span::unknown()) {
+                            Ok(x) => match y.checked_add_signed(x) {
+                                Some(value) => Ok(value),
+                                None => Err(("Date", "Duration and date addition overflow")),
+                            },
+                            Err(_) => Err(("Date", "Duration overflow")),
+                        }
+                    }
                     _ => Err((left.type_name(), right.type_name())),
                 }?;
                 Ok(UntaggedValue::Primitive(Primitive::Date(result)))
@@ -265,6 +327,28 @@ pub fn compute_values(
                 let result = match operator {
                     Operator::Plus => Ok(x + y),
                     Operator::Minus => Ok(x - y),
+                    // How many times one duration fits in the other
+                    Operator::Divide => {
+                        return compute_values(
+                            operator,
+                            &UntaggedValue::Primitive(Primitive::Int(x.clone())),
+                            &UntaggedValue::Primitive(Primitive::Int(y.clone())),
+                        )
+                    }
+                    _ => Err((left.type_name(), right.type_name())),
+                }?;
+
+                Ok(UntaggedValue::Primitive(Primitive::Duration(result)))
+            }
+            (Primitive::Duration(x), Primitive::Int(y)) => {
+                let result = match operator {
+                    Operator::Multiply => Ok(x * y),
+                    Operator::Divide => {
+                        if y.is_zero() {
+                            return Ok(zero_division_error());
+                        }
+                        Ok(x / y)
+                    }
                     _ => Err((left.type_name(), right.type_name())),
                 }?;
 
@@ -274,6 +358,7 @@ pub fn compute_values(
                 let result = match operator {
                     Operator::Plus => Ok(x + y),
                     Operator::Minus => Ok(x - y),
+                    Operator::Multiply => Ok(x * y),
                     _ => Err((left.type_name(), right.type_name())),
                 }?;
 
@@ -281,24 +366,46 @@ pub fn compute_values(
             }
             (Primitive::Duration(x), Primitive::Decimal(y)) => {
                 let result = match operator {
+                    Operator::Multiply => Ok(scale(x, y, operator)),
                     Operator::Divide => {
                         if y.is_zero() {
                             return Ok(zero_division_error());
                         }
-                        let y = y.as_bigint_and_exponent();
-                        Ok(x / y.0)
+                        Ok(scale(x, y, operator))
                     }
                     _ => Err((left.type_name(), right.type_name())),
                 }?;
 
                 Ok(UntaggedValue::Primitive(Primitive::Duration(result)))
             }
+            (Primitive::Decimal(x), Primitive::Duration(y)) => match operator {
+                Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Duration(scale(
+                    y, x, operator,
+                )))),
+                _ => Err((left.type_name(), right.type_name())),
+            },
             _ => Err((left.type_name(), right.type_name())),
         },
         _ => Err((left.type_name(), right.type_name())),
     }
 }
 
+/// Multiplies or divides a whole number of bytes or nanoseconds by a decimal, dropping the
+/// fraction of one left over.
+fn scale(
+    x: &num_bigint::BigInt,
+    y: &bigdecimal::BigDecimal,
+    operator: Operator,
+) -> num_bigint::BigInt {
+    let x = bigdecimal::BigDecimal::from(x.clone());
+    let scaled = match operator {
+        Operator::Divide => x / y,
+        _ => x * y,
+    };
+
+    scaled.with_scale(0).as_bigint_and_exponent().0
+}
+
 /// If left is {{ Operator }} right
 pub fn compare_values(
     operator: Operator,
@@ -334,6 +441,17 @@ pub fn format_leaf<'a>(value: impl Into<&'a UntaggedValue>) -> DebugDocBuilder {
     InlineShape::from_value(value.into()).format().pretty()
 }
 
+/// Formats a leaf as `format_leaf` does, rounding decimals to `float_precision` places.
+pub fn format_leaf_with_precision<'a>(
+    value: impl Into<&'a UntaggedValue>,
+    float_precision: usize,
+) -> DebugDocBuilder {
+    InlineShape::from_value(value.into())
+        .format()
+        .with_float_precision(float_precision)
+        .pretty()
+}
+
 pub fn style_leaf<'a>(
     value: impl Into<&'a UntaggedValue>,
     color_hash_map: &HashMap<String, ansi_term::Style>,
@@ -8,6 +8,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 pub fn basic_evaluation_context() -> Result<EvaluationContext, Box<dyn Error>> {
+    crate::evaluate::variables::record_start_time();
+
     Ok(EvaluationContext {
         scope: Scope::new(),
         host: Arc::new(parking_lot::Mutex::new(Box::new(BasicHost))),
@@ -15,6 +17,5 @@ pub fn basic_evaluation_context() -> Result<EvaluationContext, Box<dyn Error>> {
         ctrl_c: Arc::new(AtomicBool::new(false)),
         user_recently_used_autoenv_untrust: Arc::new(AtomicBool::new(false)),
         shell_manager: basic_shell_manager::basic_shell_manager()?,
-        windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
     })
 }
@@ -10,5 +10,6 @@ pub fn basic_shell_manager() -> Result<ShellManager, Box<dyn Error>> {
     Ok(ShellManager {
         current_shell: Arc::new(AtomicUsize::new(0)),
         shells: Arc::new(Mutex::new(vec![Box::new(FilesystemShell::basic()?)])),
+        directory_stack: Arc::new(Mutex::new(vec![])),
     })
 }
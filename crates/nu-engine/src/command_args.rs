@@ -157,10 +157,22 @@ impl EvaluatedCommandArgs {
 
     /// Get the nth positional argument, error if not possible
     pub fn expect_nth(&self, pos: usize) -> Result<&Value, ShellError> {
-        self.call_info
-            .args
-            .nth(pos)
-            .ok_or_else(|| ShellError::unimplemented("Better error: expect_nth"))
+        self.call_info.args.nth(pos).ok_or_else(|| {
+            let error = ShellError::labeled_error(
+                "Missing argument",
+                format!("expected argument {} of this command", pos + 1),
+                &self.call_info.name_tag,
+            )
+            .with_hint("run the command with --help to see the arguments it takes");
+
+            match pos
+                .checked_sub(1)
+                .and_then(|last| self.call_info.args.nth(last))
+            {
+                Some(last) => error.with_label("after this argument", last.tag.span),
+                None => error,
+            }
+        })
     }
 
     pub fn get(&self, name: &str) -> Option<&Value> {
@@ -0,0 +1,107 @@
+//! A debugger for scripts run with `nu --debugger`. The script stops at each `debug break`, where
+//! its variables and input can be looked at before continuing or stepping through it one
+//! pipeline at a time.
+
+use crate::evaluation_context::EvaluationContext;
+use nu_data::value::format_leaf;
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use nu_source::Span;
+use parking_lot::{const_mutex, Mutex};
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STEPPING: AtomicBool = AtomicBool::new(false);
+
+/// The script being debugged, to show where it stopped.
+static SOURCE: Mutex<String> = const_mutex(String::new());
+
+const HELP: &str = "commands: (c)ontinue, (s)tep, (v)ars, (i)nput, (q)uit";
+
+/// Makes `debug break` stop the given script.
+pub fn enable_debugger(source: &str) {
+    *SOURCE.lock() = source.to_string();
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn is_debugger_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Whether the script should stop before the next pipeline.
+pub(crate) fn is_stepping() -> bool {
+    STEPPING.load(Ordering::SeqCst)
+}
+
+/// Stops the script at `span` and takes debugger commands until told to go on. Quitting gives an
+/// error, which ends the script the way any other error would, exit hook and all.
+pub fn break_into_debugger(
+    context: &EvaluationContext,
+    span: Span,
+    input: &[Value],
+) -> Result<(), ShellError> {
+    STEPPING.store(false, Ordering::SeqCst);
+
+    eprintln!("stopped at {}", location(span));
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        eprint!("(debug) ");
+        let _ = io::stderr().flush();
+
+        // Carry on with the script if there is nothing left to read the commands from
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => return Ok(()),
+        };
+
+        match line.trim() {
+            "c" | "continue" => return Ok(()),
+            "s" | "step" => {
+                STEPPING.store(true, Ordering::SeqCst);
+                return Ok(());
+            }
+            "v" | "vars" => {
+                for (name, value) in context.scope.get_vars() {
+                    eprintln!("{} = {}", name, describe(&value));
+                }
+            }
+            "i" | "input" if input.is_empty() => eprintln!("no input"),
+            "i" | "input" => {
+                for value in input {
+                    eprintln!("{}", describe(value));
+                }
+            }
+            "q" | "quit" => {
+                return Err(ShellError::labeled_error(
+                    "Quit in the debugger",
+                    "quit here",
+                    span,
+                ))
+            }
+            "" => {}
+            _ => eprintln!("{}", HELP),
+        }
+    }
+}
+
+fn describe(value: &Value) -> String {
+    format_leaf(&value.value).plain_string(100)
+}
+
+/// The line number and text of the code at `span`.
+fn location(span: Span) -> String {
+    let source = SOURCE.lock();
+
+    match source.get(span.start()..span.end()) {
+        Some(code) => format!(
+            "line {}: {}",
+            source[..span.start()].matches('\n').count() + 1,
+            code.trim()
+        ),
+        None => "an unknown location".to_string(),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::evaluate::expr::run_expression_block;
+use crate::evaluate::expr::{run_expression_block, run_pipeline_expression};
 use crate::evaluate::internal::run_internal_command;
 use crate::evaluation_context::EvaluationContext;
 use async_recursion::async_recursion;
@@ -8,7 +8,7 @@ use nu_parser::ParserScope;
 use nu_protocol::hir::{
     Block, Call, ClassifiedCommand, Expression, Pipeline, SpannedExpression, Synthetic,
 };
-use nu_protocol::{ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::{PositionalType, ReturnSuccess, UntaggedValue, Value};
 use nu_source::{Span, Tag};
 use nu_stream::InputStream;
 use nu_stream::ToOutputStream;
@@ -20,6 +20,8 @@ pub async fn run_block(
     ctx: &EvaluationContext,
     mut input: InputStream,
 ) -> Result<InputStream, ShellError> {
+    expect_block_params(block, ctx)?;
+
     let mut output: Result<InputStream, ShellError> = Ok(InputStream::empty());
     for (_, definition) in block.definitions.iter() {
         ctx.scope.add_definition(definition.clone());
@@ -89,44 +91,23 @@ pub async fn run_block(
             match output {
                 Ok(inp) if inp.is_empty() => {}
                 Ok(inp) => {
-                    let mut output_stream = inp.to_output_stream();
-
-                    match output_stream.try_next().await {
-                        Ok(Some(ReturnSuccess::Value(Value {
-                            value: UntaggedValue::Error(e),
-                            ..
-                        }))) => {
-                            return Err(e);
-                        }
-                        Ok(Some(_item)) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
-                            }
-                            if ctx.ctrl_c.load(Ordering::SeqCst) {
-                                // This early return doesn't return the result
-                                // we have so far, but breaking out of this loop
-                                // causes lifetime issues. A future contribution
-                                // could attempt to return the current output.
-                                // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
-                                return Ok(InputStream::empty());
-                            }
-                        }
-                        Ok(None) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
-                            }
-                        }
-                        Err(e) => {
-                            return Err(e);
-                        }
+                    if finish_statement(inp, ctx).await? {
+                        // This early return doesn't return the result
+                        // we have so far, but breaking out of this loop
+                        // causes lifetime issues. A future contribution
+                        // could attempt to return the current output.
+                        // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
+                        return Ok(InputStream::empty());
                     }
                 }
                 Err(e) => {
                     return Err(e);
                 }
             }
+            if crate::debugger::is_stepping() {
+                crate::debugger::break_into_debugger(ctx, pipeline.span, &[])?;
+            }
+
             output = run_pipeline(pipeline, ctx, input).await;
 
             input = InputStream::empty();
@@ -136,6 +117,58 @@ pub async fn run_block(
     output
 }
 
+/// Fails for a block naming a parameter, like `{|row, old| ...}`, that the command running it
+/// gave no value. Commands bind the values they give their blocks before running them, so a block
+/// naming more parameters than it's given, or any at all where it's given none, is refused here.
+fn expect_block_params(block: &Block, ctx: &EvaluationContext) -> Result<(), ShellError> {
+    for (param, _) in &block.params.positional {
+        if let PositionalType::Mandatory(name, _) = param {
+            if name.starts_with('$') && ctx.scope.get_var(name).is_none() {
+                return Err(ShellError::labeled_error(
+                    format!("The block names the parameter {}, but isn't given it", name),
+                    "block parameter without a value",
+                    block.span,
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the output of a statement to its end, so all of it happens before the next statement
+/// on the line starts. Returns whether the statement was interrupted.
+async fn finish_statement(input: InputStream, ctx: &EvaluationContext) -> Result<bool, ShellError> {
+    let mut output_stream = input.to_output_stream();
+
+    loop {
+        match output_stream.try_next().await? {
+            Some(ReturnSuccess::Value(Value {
+                value: UntaggedValue::Error(e),
+                ..
+            })) => {
+                return Err(e);
+            }
+            Some(_item) => {
+                if let Some(err) = ctx.get_errors().get(0) {
+                    ctx.clear_errors();
+                    return Err(err.clone());
+                }
+                if ctx.ctrl_c.load(Ordering::SeqCst) {
+                    return Ok(true);
+                }
+            }
+            None => {
+                if let Some(err) = ctx.get_errors().get(0) {
+                    ctx.clear_errors();
+                    return Err(err.clone());
+                }
+                return Ok(false);
+            }
+        }
+    }
+}
+
 #[async_recursion]
 async fn run_pipeline(
     commands: &Pipeline,
@@ -204,7 +237,7 @@ async fn run_pipeline(
                 }
             }
 
-            ClassifiedCommand::Expr(expr) => run_expression_block(&*expr, ctx).await?,
+            ClassifiedCommand::Expr(expr) => run_pipeline_expression(&*expr, ctx).await?,
 
             ClassifiedCommand::Error(err) => return Err(err.into()),
 
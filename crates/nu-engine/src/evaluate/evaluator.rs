@@ -39,7 +39,7 @@ pub async fn evaluate_baseline_expr(
         }
         Expression::Variable(var, _) => evaluate_reference(&var, ctx, tag),
         Expression::Command => unimplemented!(),
-        Expression::Invocation(block) => evaluate_invocation(block, ctx).await,
+        Expression::Invocation(block) => evaluate_invocation(block, ctx, tag).await,
         Expression::ExternalCommand(_) => unimplemented!(),
         Expression::Binary(binary) => {
             // TODO: If we want to add short-circuiting, we'll need to move these down
@@ -272,6 +272,7 @@ fn evaluate_reference(name: &str, ctx: &EvaluationContext, tag: Tag) -> Result<V
 async fn evaluate_invocation(
     block: &hir::Block,
     ctx: &EvaluationContext,
+    tag: Tag,
 ) -> Result<Value, ShellError> {
     // FIXME: we should use a real context here
     let input = match ctx.scope.get_var("$it") {
@@ -286,13 +287,16 @@ async fn evaluate_invocation(
 
     let output = result.into_vec().await;
 
+    // The error is returned here, so it shouldn't be reported again once the pipeline is done
     if let Some(e) = ctx.get_errors().get(0) {
+        ctx.clear_errors();
         return Err(e.clone());
     }
 
+    // Tables and nothing are tagged with the invocation, so errors about them point at it
     match output.len() {
-        x if x > 1 => Ok(UntaggedValue::Table(output).into_value(Tag::unknown())),
+        x if x > 1 => Ok(UntaggedValue::Table(output).into_value(tag)),
         1 => Ok(output[0].clone()),
-        _ => Ok(UntaggedValue::nothing().into_value(Tag::unknown())),
+        _ => Ok(UntaggedValue::nothing().into_value(tag)),
     }
 }
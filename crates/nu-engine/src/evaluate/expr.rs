@@ -3,9 +3,11 @@ use crate::evaluate_baseline_expr;
 use log::{log_enabled, trace};
 
 use crate::evaluation_context::EvaluationContext;
+use crate::range_iterator::RangeIterator;
 use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::hir::SpannedExpression;
+use nu_protocol::{Primitive, UntaggedValue, Value};
 use nu_stream::{InputStream, ToInputStream};
 
 pub(crate) async fn run_expression_block(
@@ -21,3 +23,18 @@ pub(crate) async fn run_expression_block(
 
     Ok(once(async { Ok(output) }).to_input_stream())
 }
+
+/// Runs an expression heading a pipeline, like `1..10 | each { ... }`, where a range is passed on
+/// one number at a time rather than as a single value.
+pub(crate) async fn run_pipeline_expression(
+    expr: &SpannedExpression,
+    ctx: &EvaluationContext,
+) -> Result<InputStream, ShellError> {
+    match evaluate_baseline_expr(expr, ctx).await? {
+        Value {
+            value: UntaggedValue::Primitive(Primitive::Range(range)),
+            tag,
+        } => Ok(futures::stream::iter(RangeIterator::new(*range, tag)).to_input_stream()),
+        output => Ok(once(async { Ok(output) }).to_input_stream()),
+    }
+}
@@ -1,21 +1,26 @@
 use crate::call_info::UnevaluatedCallInfo;
 use crate::command_args::RawCommandArgs;
+use crate::evaluate::evaluator::evaluate_baseline_expr;
 use crate::evaluation_context::EvaluationContext;
 use crate::filesystem::filesystem_shell::FilesystemShell;
+use crate::plugin::build_plugin::{plugin_dirs, search_paths};
+use crate::plugin::cache::PluginCache;
 use crate::shell::help_shell::HelpShell;
 use crate::shell::value_shell::ValueShell;
 use futures::StreamExt;
 use log::{log_enabled, trace};
 use nu_errors::ShellError;
-use nu_protocol::hir::{ExternalRedirection, InternalCommand};
+use nu_protocol::hir::{ExternalRedirection, InternalCommand, NamedValue};
 use nu_protocol::{CommandAction, Primitive, ReturnSuccess, UntaggedValue, Value};
 use nu_source::{PrettyDebug, Span, Tag};
-use nu_stream::{trace_stream, InputStream, ToInputStream};
+use nu_stream::{trace_stream, InputStream, ToInputStream, ToOutputStream};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 pub(crate) async fn run_internal_command(
-    command: InternalCommand,
+    mut command: InternalCommand,
     context: &EvaluationContext,
     input: InputStream,
 ) -> Result<InputStream, ShellError> {
@@ -34,15 +39,42 @@ pub(crate) async fn run_internal_command(
             .store(true, Ordering::SeqCst);
     }
 
-    let result = {
-        context
-            .run_command(
-                internal_command?,
-                Tag::unknown_anchor(command.name_span),
-                command.args.clone(),
-                objects,
-            )
-            .await?
+    // External commands append their stderr to the target of `err>>` themselves
+    let stderr_append = if command.name == "run_external" {
+        None
+    } else {
+        take_stderr_append(&mut command, context).await?
+    };
+
+    let result = context
+        .run_command(
+            internal_command?,
+            Tag::unknown_anchor(command.name_span),
+            command.args.clone(),
+            objects,
+        )
+        .await;
+
+    let result = match (result, stderr_append) {
+        (Ok(result), Some(path)) => result
+            .filter(move |item| {
+                let appended = match item {
+                    Err(err)
+                    | Ok(ReturnSuccess::Value(Value {
+                        value: UntaggedValue::Error(err),
+                        ..
+                    })) => append_error(&path, err).is_ok(),
+                    _ => false,
+                };
+
+                futures::future::ready(!appended)
+            })
+            .to_output_stream(),
+        (Ok(result), None) => result,
+        (Err(err), Some(path)) if append_error(&path, &err).is_ok() => {
+            return Ok(InputStream::empty())
+        }
+        (Err(err), _) => return Err(err),
     };
 
     let head = Arc::new(command.args.head.clone());
@@ -62,7 +94,10 @@ pub(crate) async fn run_internal_command(
                                 context.shell_manager.set_path(path);
                                 InputStream::empty()
                             }
-                            CommandAction::Exit => std::process::exit(0), // TODO: save history.txt
+                            CommandAction::Exit(code) => {
+                                context.run_exit_hook().await;
+                                std::process::exit(code) // TODO: save history.txt
+                            }
                             CommandAction::Error(err) => {
                                 context.error(err);
                                 InputStream::empty()
@@ -105,7 +140,8 @@ pub(crate) async fn run_internal_command(
                                                         value: UntaggedValue::Table(list),
                                                         ..
                                                     })) => {
-                                                        for l in list {
+                                                        for mut l in list {
+                                                            anchor_to(&mut l, &contents_tag);
                                                             output.push(Ok(l));
                                                         }
                                                     }
@@ -113,9 +149,10 @@ pub(crate) async fn run_internal_command(
                                                         value,
                                                         ..
                                                     })) => {
-                                                        output
-                                                            .push(Ok(value
-                                                                .into_value(contents_tag.clone())));
+                                                        let mut value =
+                                                            value.into_value(contents_tag.clone());
+                                                        anchor_to(&mut value, &contents_tag);
+                                                        output.push(Ok(value));
                                                     }
                                                     Err(e) => output.push(Err(e)),
                                                     _ => {}
@@ -184,9 +221,13 @@ pub(crate) async fn run_internal_command(
                                 InputStream::from_stream(futures::stream::iter(vec![]))
                             }
                             CommandAction::AddPlugins(path) => {
-                                match crate::plugin::build_plugin::scan(vec![
-                                    std::path::PathBuf::from(path),
-                                ]) {
+                                // Libraries are loaded from a directory given explicitly
+                                let path = std::path::PathBuf::from(path);
+                                match crate::plugin::build_plugin::scan(
+                                    vec![path.clone()],
+                                    &[path],
+                                    false,
+                                ) {
                                     Ok(plugins) => {
                                         context.add_commands(
                                             plugins
@@ -205,6 +246,38 @@ pub(crate) async fn run_internal_command(
                                     }
                                 }
                             }
+                            CommandAction::RefreshPlugins => {
+                                // Plugins loaded before are replaced, commands of nu's own are kept
+                                let loaded: Vec<String> = PluginCache::load()
+                                    .plugins
+                                    .into_iter()
+                                    .map(|plugin| plugin.signature.name)
+                                    .collect();
+
+                                match crate::plugin::build_plugin::scan(
+                                    search_paths(),
+                                    &plugin_dirs(),
+                                    true,
+                                ) {
+                                    Ok(plugins) => {
+                                        context.add_commands(
+                                            plugins
+                                                .into_iter()
+                                                .filter(|p| {
+                                                    loaded.iter().any(|name| name == p.name())
+                                                        || !context.is_command_registered(p.name())
+                                                })
+                                                .collect(),
+                                        );
+
+                                        InputStream::empty()
+                                    }
+                                    Err(reason) => {
+                                        context.error(reason);
+                                        InputStream::empty()
+                                    }
+                                }
+                            }
                             CommandAction::PreviousShell => {
                                 context.shell_manager.prev();
                                 InputStream::empty()
@@ -213,10 +286,11 @@ pub(crate) async fn run_internal_command(
                                 context.shell_manager.next();
                                 InputStream::empty()
                             }
-                            CommandAction::LeaveShell => {
+                            CommandAction::LeaveShell(code) => {
                                 context.shell_manager.remove_at_current();
                                 if context.shell_manager.is_empty() {
-                                    std::process::exit(0); // TODO: save history.txt
+                                    context.run_exit_hook().await;
+                                    std::process::exit(code); // TODO: save history.txt
                                 }
                                 InputStream::empty()
                             }
@@ -257,3 +331,77 @@ pub(crate) async fn run_internal_command(
             .take_while(|x| futures::future::ready(!x.is_error())),
     ))
 }
+
+/// Takes the target of an `err>>` redirection out of the arguments of the command, as the path of
+/// the file its errors are appended to.
+async fn take_stderr_append(
+    command: &mut InternalCommand,
+    context: &EvaluationContext,
+) -> Result<Option<PathBuf>, ShellError> {
+    let target = match command
+        .args
+        .named
+        .as_mut()
+        .and_then(|named| named.named.shift_remove("append-stderr"))
+    {
+        Some(NamedValue::Value(_, target)) => target,
+        _ => return Ok(None),
+    };
+
+    let target = evaluate_baseline_expr(&target, context).await?;
+    let mut path = PathBuf::from(context.shell_manager.path());
+    path.push(target.as_path()?);
+
+    Ok(Some(path))
+}
+
+/// Appends the message of the error to the file, as a line of its own. The file has none of the
+/// source the error points into, so only the message is written.
+fn append_error(path: &Path, error: &ShellError) -> std::io::Result<()> {
+    let message = match error.clone().into_diagnostic() {
+        Some(diagnostic) => diagnostic.message,
+        None => error.to_string(),
+    };
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(file, "{}", message)
+}
+
+/// Marks the values converted from a file, or from a page fetched, as coming from there, so that
+/// `save` writes them back to it. Only the values themselves are anchored: a part of one, saved on
+/// its own, would replace the whole file. Their cells keep only where the file was named, for
+/// errors about them to point at.
+fn anchor_to(value: &mut Value, origin: &Tag) {
+    if value.tag.anchor.is_none() {
+        value.tag = origin.clone();
+    }
+
+    point_cells_at(value, origin.span);
+}
+
+fn point_cells_at(value: &mut Value, span: Span) {
+    match &mut value.value {
+        UntaggedValue::Row(row) => {
+            for cell in row.entries.values_mut() {
+                point_at(cell, span);
+            }
+        }
+        UntaggedValue::Table(rows) => {
+            for row in rows.iter_mut() {
+                point_at(row, span);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn point_at(value: &mut Value, span: Span) {
+    if value.tag.anchor.is_none() {
+        value.tag.span = span;
+    }
+
+    point_cells_at(value, span);
+}
@@ -2,13 +2,32 @@ use crate::whole_stream_command::{whole_stream_command, Command};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
-use nu_protocol::{hir::Block, Value};
+use nu_protocol::{
+    hir::{Block, CapturedBlock},
+    Value,
+};
 use nu_source::Spanned;
 use std::sync::Arc;
 
+/// How far a stage running on a thread of its own, like an external command, may get ahead of the
+/// stage consuming its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineBuffer {
+    /// The number of values in flight.
+    pub values: usize,
+    /// The bytes of text and binary data in flight.
+    pub bytes: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct Scope {
     frames: Arc<parking_lot::Mutex<Vec<ScopeFrame>>>,
+    /// The script being sourced, so its definitions remember where they came from
+    current_file: Arc<parking_lot::Mutex<Option<String>>>,
+    /// The pipeline buffer of the config, read once for each line rather than by every stage
+    pipeline_buffer: Arc<parking_lot::Mutex<Option<PipelineBuffer>>>,
+    /// The exit code of the last external command to finish
+    last_exit_code: Arc<parking_lot::Mutex<Option<i32>>>,
 }
 
 impl Default for Scope {
@@ -21,6 +40,9 @@ impl Scope {
     pub fn new() -> Scope {
         Scope {
             frames: Arc::new(parking_lot::Mutex::new(vec![ScopeFrame::new()])),
+            current_file: Arc::new(parking_lot::Mutex::new(None)),
+            pipeline_buffer: Arc::new(parking_lot::Mutex::new(None)),
+            last_exit_code: Arc::new(parking_lot::Mutex::new(None)),
         }
     }
     pub fn get_command(&self, name: &str) -> Option<Command> {
@@ -185,6 +207,70 @@ impl Scope {
             frame.env.extend(env_vars)
         }
     }
+
+    /// Leaves the current scope like `exit_scope`, handing the environment variables it set to the
+    /// scope it returns to.
+    pub fn exit_scope_with_env(&self) {
+        let mut frames = self.frames.lock();
+        if let Some(frame) = frames.pop() {
+            if let Some(caller) = frames.last_mut() {
+                caller.env.extend(frame.env);
+            }
+        }
+    }
+
+    pub fn get_signal_handler(&self, signal: &str) -> Option<CapturedBlock> {
+        for frame in self.frames.lock().iter().rev() {
+            if let Some(handler) = frame.signal_handlers.get(signal) {
+                return Some(handler.clone());
+            }
+        }
+
+        None
+    }
+
+    /// Sets the script being sourced, returning the previous one so it can be restored.
+    pub fn set_current_file(&self, file: Option<String>) -> Option<String> {
+        std::mem::replace(&mut *self.current_file.lock(), file)
+    }
+
+    /// How far a stage may get ahead of the next one, only read with `read` the first time it's
+    /// needed after `forget_pipeline_buffer`.
+    pub fn pipeline_buffer(&self, read: impl FnOnce() -> PipelineBuffer) -> PipelineBuffer {
+        *self.pipeline_buffer.lock().get_or_insert_with(read)
+    }
+
+    /// Has the next line read the pipeline buffer of the config again, as it may have changed.
+    pub fn forget_pipeline_buffer(&self) {
+        *self.pipeline_buffer.lock() = None;
+    }
+
+    pub fn set_last_exit_code(&self, code: i32) {
+        *self.last_exit_code.lock() = Some(code);
+    }
+
+    /// The exit code of the last external command to finish since it was last taken.
+    pub fn take_last_exit_code(&self) -> Option<i32> {
+        self.last_exit_code.lock().take()
+    }
+
+    pub fn add_signal_handler(&self, signal: impl Into<String>, handler: CapturedBlock) {
+        if let Some(frame) = self.frames.lock().last_mut() {
+            frame.signal_handlers.insert(signal.into(), handler);
+        }
+    }
+
+    /// Removes the handler from every frame, returning the innermost one, so that it runs once.
+    pub fn take_signal_handler(&self, signal: &str) -> Option<CapturedBlock> {
+        let mut handler = None;
+        for frame in self.frames.lock().iter_mut().rev() {
+            if let Some(found) = frame.signal_handlers.shift_remove(signal) {
+                handler = handler.or(Some(found));
+            }
+        }
+
+        handler
+    }
 }
 
 impl ParserScope for Scope {
@@ -196,7 +282,11 @@ impl ParserScope for Scope {
         self.get_command(name).is_some()
     }
 
-    fn add_definition(&self, block: Block) {
+    fn add_definition(&self, mut block: Block) {
+        if block.source_file.is_none() {
+            block.source_file = self.current_file.lock().clone();
+        }
+
         if let Some(frame) = self.frames.lock().last_mut() {
             let name = block.params.name.clone();
             frame.custom_commands.insert(name.clone(), block.clone());
@@ -247,6 +337,7 @@ pub struct ScopeFrame {
     pub commands: IndexMap<String, Command>,
     pub custom_commands: IndexMap<String, Block>,
     pub aliases: IndexMap<String, Vec<Spanned<String>>>,
+    pub signal_handlers: IndexMap<String, CapturedBlock>,
 }
 
 impl ScopeFrame {
@@ -281,6 +372,7 @@ impl ScopeFrame {
             commands: IndexMap::new(),
             custom_commands: IndexMap::new(),
             aliases: IndexMap::new(),
+            signal_handlers: IndexMap::new(),
         }
     }
 }
@@ -367,3 +459,26 @@ impl ScopeFrame {
 //     }
 
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::{PipelineBuffer, Scope};
+
+    #[test]
+    fn reads_the_pipeline_buffer_once_for_each_line() {
+        let scope = Scope::new();
+        let mut reads = 0;
+
+        let mut read = |values| {
+            reads += 1;
+            PipelineBuffer { values, bytes: 64 }
+        };
+        assert_eq!(scope.pipeline_buffer(|| read(4)).values, 4);
+        assert_eq!(scope.pipeline_buffer(|| read(8)).values, 4);
+
+        scope.forget_pipeline_buffer();
+        assert_eq!(scope.pipeline_buffer(|| read(8)).values, 8);
+
+        assert_eq!(reads, 2);
+    }
+}
@@ -1,8 +1,23 @@
 use crate::history_path::history_path;
+use chrono::{Local, TimeZone};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// When nu started, in seconds since the Unix epoch, or zero until it has been recorded.
+static STARTED: AtomicI64 = AtomicI64::new(0);
+
+/// Remembers the first call as the time nu started.
+pub(crate) fn record_start_time() {
+    let _ = STARTED.compare_exchange(
+        0,
+        Local::now().timestamp(),
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    );
+}
 
 pub fn nu(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     let tag = tag.into();
@@ -39,21 +54,55 @@ pub fn nu(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value,
     let temp = std::env::temp_dir();
     nu_dict.insert_value("temp-dir", UntaggedValue::filepath(temp).into_value(&tag));
 
+    let config_dir = nu_data::config::config_path()?;
+    nu_dict.insert_value(
+        "config-dir",
+        UntaggedValue::filepath(config_dir).into_value(&tag),
+    );
+
+    // Only reported, as reading $nu shouldn't create directories
+    if let Ok(data_dir) = nu_data::config::user_data_dir() {
+        nu_dict.insert_value(
+            "data-dir",
+            UntaggedValue::filepath(data_dir).into_value(&tag),
+        );
+    }
+
+    if let Ok(cache_dir) = nu_data::config::user_cache_dir() {
+        nu_dict.insert_value(
+            "cache-dir",
+            UntaggedValue::filepath(cache_dir).into_value(&tag),
+        );
+    }
+
     let config = nu_data::config::default_path()?;
     nu_dict.insert_value(
         "config-path",
-        UntaggedValue::filepath(config).into_value(&tag),
+        UntaggedValue::filepath(config.clone()).into_value(&tag),
     );
 
     #[cfg(feature = "rustyline-support")]
-    {
-        let keybinding_path = nu_data::keybinding::keybinding_path()?;
+    let keybinding_path = Some(nu_data::keybinding::keybinding_path()?);
+    #[cfg(not(feature = "rustyline-support"))]
+    let keybinding_path: Option<std::path::PathBuf> = None;
+
+    if let Some(keybinding_path) = &keybinding_path {
         nu_dict.insert_value(
             "keybinding-path",
-            UntaggedValue::filepath(keybinding_path).into_value(&tag),
+            UntaggedValue::filepath(keybinding_path.clone()).into_value(&tag),
         );
     }
 
+    let config_files = std::iter::once(config)
+        .chain(keybinding_path)
+        .filter(|path| path.is_file())
+        .map(|path| UntaggedValue::filepath(path).into_value(&tag))
+        .collect::<Vec<_>>();
+    nu_dict.insert_value(
+        "config-files",
+        UntaggedValue::table(&config_files).into_value(&tag),
+    );
+
     let config: Box<dyn nu_data::config::Conf> = Box::new(nu_data::config::NuConfig::new());
     let history = history_path(&config);
     nu_dict.insert_value(
@@ -61,5 +110,17 @@ pub fn nu(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value,
         UntaggedValue::filepath(history).into_value(&tag),
     );
 
+    if let Ok(exe) = std::env::current_exe() {
+        nu_dict.insert_value("current-exe", UntaggedValue::filepath(exe).into_value(&tag));
+    }
+
+    let started = STARTED.load(Ordering::SeqCst);
+    if started != 0 {
+        nu_dict.insert_value(
+            "started",
+            UntaggedValue::date(Local.timestamp(started, 0)).into_value(&tag),
+        );
+    }
+
     Ok(nu_dict.into_value())
 }
@@ -1,6 +1,7 @@
 use crate::call_info::UnevaluatedCallInfo;
 use crate::command_args::CommandArgs;
 use crate::env::host::Host;
+use crate::evaluate::block::run_block;
 use crate::evaluate::scope::Scope;
 use crate::shell::shell_manager::ShellManager;
 use crate::whole_stream_command::Command;
@@ -10,7 +11,7 @@ use nu_protocol::hir;
 use nu_source::Tag;
 use nu_stream::{InputStream, OutputStream};
 use parking_lot::Mutex;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -21,9 +22,6 @@ pub struct EvaluationContext {
     pub ctrl_c: Arc<AtomicBool>,
     pub user_recently_used_autoenv_untrust: Arc<AtomicBool>,
     pub shell_manager: ShellManager,
-
-    /// Windows-specific: keep track of previous cwd on each drive
-    pub windows_drives_previous_cwd: Arc<Mutex<std::collections::HashMap<String, String>>>,
 }
 
 impl EvaluationContext {
@@ -35,7 +33,6 @@ impl EvaluationContext {
             ctrl_c: args.ctrl_c.clone(),
             shell_manager: args.shell_manager.clone(),
             user_recently_used_autoenv_untrust: Arc::new(AtomicBool::new(false)),
-            windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
         }
     }
 
@@ -86,6 +83,30 @@ impl EvaluationContext {
         self.scope.has_command(name)
     }
 
+    /// Runs the block set with `on-exit`, once, as the session ends. Its errors are added to the
+    /// others for the caller to show.
+    pub async fn run_exit_hook(&self) {
+        let hook = match self.scope.take_signal_handler("EXIT") {
+            Some(hook) => hook,
+            None => return,
+        };
+
+        // The session may be ending because it was interrupted, which mustn't stop the hook
+        self.ctrl_c.store(false, Ordering::SeqCst);
+
+        self.scope.enter_scope();
+        self.scope.add_vars(&hook.captured.entries);
+        let result = run_block(&hook.block, self, InputStream::empty()).await;
+        self.scope.exit_scope();
+
+        match result {
+            Ok(stream) => {
+                let _ = stream.into_vec().await;
+            }
+            Err(err) => self.error(err),
+        }
+    }
+
     pub(crate) async fn run_command(
         &self,
         command: Command,
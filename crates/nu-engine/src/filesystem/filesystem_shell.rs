@@ -1,6 +1,8 @@
 use crate::command_args::EvaluatedWholeStreamCommandArgs;
 use crate::filesystem::dir_info::{DirBuilder, DirInfo};
-use crate::filesystem::path::canonicalize;
+use crate::filesystem::path::{
+    canonicalize, drive_of, expand_abbreviation, glob_root, is_case_insensitive,
+};
 use crate::filesystem::utils::FileStructure;
 use crate::maybe_text_codec::{MaybeTextCodec, StringOrBinary};
 use crate::shell::shell_args::{CdArgs, CopyArgs, LsArgs, MkdirArgs, MvArgs, RemoveArgs};
@@ -15,7 +17,7 @@ use nu_source::{Span, Tag};
 use nu_stream::{Interruptible, OutputStream, ToOutputStream};
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::io::{Error, ErrorKind};
+use std::io::{Error, ErrorKind, Write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -30,6 +32,8 @@ use nu_source::Tagged;
 pub struct FilesystemShell {
     pub(crate) path: String,
     pub(crate) last_path: String,
+    /// The directory last visited on each drive, which `D:` goes back to on Windows
+    pub(crate) drive_paths: HashMap<char, String>,
 }
 
 impl std::fmt::Debug for FilesystemShell {
@@ -43,6 +47,7 @@ impl Clone for FilesystemShell {
         FilesystemShell {
             path: self.path.clone(),
             last_path: self.path.clone(),
+            drive_paths: self.drive_paths.clone(),
         }
     }
 }
@@ -54,9 +59,12 @@ impl FilesystemShell {
             Err(_) => PathBuf::from("/"),
         };
 
+        let path = path.to_string_lossy().to_string();
+
         Ok(FilesystemShell {
-            path: path.to_string_lossy().to_string(),
-            last_path: path.to_string_lossy().to_string(),
+            drive_paths: drive_paths(&path),
+            last_path: path.clone(),
+            path,
         })
     }
 
@@ -65,10 +73,73 @@ impl FilesystemShell {
         let path = path.display().to_string();
         let last_path = path.clone();
 
-        Ok(FilesystemShell { path, last_path })
+        Ok(FilesystemShell {
+            drive_paths: drive_paths(&path),
+            path,
+            last_path,
+        })
+    }
+
+    /// On Windows, `D:` and `D:src` are relative to the directory last visited on drive D, or to
+    /// its root if nu hasn't been there, as in cmd.exe. Other paths are returned as they are.
+    fn resolve_drive_relative(&self, path: &Path) -> PathBuf {
+        #[cfg(windows)]
+        {
+            use crate::filesystem::path::drive_relative;
+
+            if let Some((drive, rest)) = path.to_str().and_then(drive_relative) {
+                let base = match self.drive_paths.get(&drive) {
+                    Some(base) => PathBuf::from(base),
+                    None => PathBuf::from(format!("{}:\\", drive)),
+                };
+
+                return if rest.is_empty() {
+                    base
+                } else {
+                    base.join(rest)
+                };
+            }
+        }
+
+        path.to_path_buf()
+    }
+
+    /// The directory a path abbreviated to the start of each of its steps, like `s/p/r`, is
+    /// short for, as long as it's short for only one.
+    fn expand_abbreviated_dir(&self, target: &Path, tag: &Tag) -> Result<PathBuf, ShellError> {
+        let mut expanded = expand_abbreviation(self.path(), target);
+        let not_found =
+            || ShellError::labeled_error("Cannot change to directory", "directory not found", tag);
+
+        match expanded.len() {
+            0 => Err(not_found()),
+            1 => canonicalize(self.path(), expanded.remove(0)).map_err(|_| not_found()),
+            _ => {
+                let names: Vec<_> = expanded
+                    .iter()
+                    .filter_map(|path| canonicalize(self.path(), path).ok())
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect();
+
+                Err(ShellError::labeled_error(
+                    "Cannot change to directory",
+                    format!("could be any of {}", names.join(", ")),
+                    tag,
+                ))
+            }
+        }
     }
 }
 
+fn drive_paths(path: &str) -> HashMap<char, String> {
+    let mut drive_paths = HashMap::new();
+    if let Some(drive) = drive_of(path) {
+        drive_paths.insert(drive, path.to_string());
+    }
+
+    drive_paths
+}
+
 pub fn homedir_if_possible() -> Option<PathBuf> {
     #[cfg(feature = "dirs")]
     {
@@ -98,6 +169,9 @@ impl Shell for FilesystemShell {
             long,
             short_names,
             du,
+            case_insensitive,
+            recursive,
+            depth,
         }: LsArgs,
         name_tag: Tag,
         ctrl_c: Arc<AtomicBool>,
@@ -106,7 +180,7 @@ impl Shell for FilesystemShell {
         let (path, p_tag) = match path {
             Some(p) => {
                 let p_tag = p.tag;
-                let mut p = p.item;
+                let mut p = self.resolve_drive_relative(&p.item);
                 if p.is_dir() {
                     if is_empty_dir(&p) {
                         return Ok(OutputStream::empty());
@@ -126,7 +200,14 @@ impl Shell for FilesystemShell {
 
         let hidden_dir_specified = is_hidden_dir(&path);
 
-        let mut paths = glob::glob(&path.to_string_lossy())
+        // Patterns match the way the filesystem finds names, unless told to ignore case
+        let options = glob::MatchOptions {
+            case_sensitive: !(case_insensitive || is_case_insensitive(&glob_root(&path))),
+            require_literal_separator: false,
+            require_literal_leading_dot: false,
+        };
+
+        let mut paths = glob::glob_with(&path.to_string_lossy(), options)
             .map_err(|e| ShellError::labeled_error(e.to_string(), "invalid pattern", &p_tag))?
             .peekable();
 
@@ -140,25 +221,54 @@ impl Shell for FilesystemShell {
 
         let mut hidden_dirs = vec![];
 
+        // What the pattern matches is the first level, which a recursive listing goes below
+        let max_depth = match depth {
+            Some(depth) => Some(depth.item),
+            None if recursive => Some(usize::MAX),
+            None => None,
+        };
+        let walk_tag = name_tag.clone();
+
+        let paths = paths
+            .filter_map(move |path| {
+                let path = match path.map_err(|e| ShellError::from(e.into_error())) {
+                    Ok(path) => path,
+                    Err(err) => return Some(Err(err)),
+                };
+
+                if path_contains_hidden_folder(&path, &hidden_dirs) {
+                    return None;
+                }
+
+                if !all && !hidden_dir_specified && is_hidden_dir(&path) {
+                    if path.is_dir() {
+                        hidden_dirs.push(path);
+                    }
+                    return None;
+                }
+
+                Some(Ok(path))
+            })
+            .flat_map(
+                move |path| -> Box<dyn Iterator<Item = Result<PathBuf, ShellError>> + Send> {
+                    match (path, max_depth) {
+                        (Ok(path), Some(max_depth)) if max_depth > 1 && is_real_dir(&path) => {
+                            let below = Descendants::new(&path, max_depth, all, walk_tag.clone());
+                            Box::new(std::iter::once(Ok(path)).chain(below))
+                        }
+                        (path, _) => Box::new(std::iter::once(path)),
+                    }
+                },
+            );
+
         // Generated stream: impl Stream<Item = Result<ReturnSuccess, ShellError>
 
         Ok(futures::stream::iter(paths.filter_map(move |path| {
-            let path = match path.map_err(|e| ShellError::from(e.into_error())) {
+            let path = match path {
                 Ok(path) => path,
                 Err(err) => return Some(Err(err)),
             };
 
-            if path_contains_hidden_folder(&path, &hidden_dirs) {
-                return None;
-            }
-
-            if !all && !hidden_dir_specified && is_hidden_dir(&path) {
-                if path.is_dir() {
-                    hidden_dirs.push(path);
-                }
-                return None;
-            }
-
             let metadata = match std::fs::symlink_metadata(&path) {
                 Ok(metadata) => Some(metadata),
                 Err(e) => {
@@ -204,13 +314,11 @@ impl Shell for FilesystemShell {
                 if target == Path::new("-") {
                     PathBuf::from(&self.last_path)
                 } else {
-                    let path = canonicalize(self.path(), target).map_err(|_| {
-                        ShellError::labeled_error(
-                            "Cannot change to directory",
-                            "directory not found",
-                            &tag,
-                        )
-                    })?;
+                    let target = self.resolve_drive_relative(&target);
+                    let path = match canonicalize(self.path(), &target) {
+                        Ok(path) => path,
+                        Err(_) => self.expand_abbreviated_dir(&target, &tag)?,
+                    };
 
                     if !path.is_dir() {
                         return Err(ShellError::labeled_error(
@@ -689,7 +797,7 @@ impl Shell for FilesystemShell {
     }
 
     fn set_path(&mut self, path: String) {
-        let pathbuf = PathBuf::from(&path);
+        let pathbuf = self.resolve_drive_relative(Path::new(&path));
         let path = match canonicalize(self.path(), pathbuf.as_path()) {
             Ok(path) => {
                 let _ = std::env::set_current_dir(&path);
@@ -703,6 +811,10 @@ impl Shell for FilesystemShell {
         };
         self.last_path = self.path.clone();
         self.path = path.to_string_lossy().to_string();
+
+        if let Some(drive) = drive_of(&self.path) {
+            self.drive_paths.insert(drive, self.path.clone());
+        }
     }
 
     fn open(
@@ -767,8 +879,23 @@ impl Shell for FilesystemShell {
         full_path: &PathBuf,
         save_data: &[u8],
         name: Span,
+        append: bool,
     ) -> Result<OutputStream, ShellError> {
-        match std::fs::write(full_path, save_data) {
+        let result = if append {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(full_path)
+                .and_then(|file| {
+                    let mut writer = std::io::BufWriter::new(file);
+                    writer.write_all(save_data)?;
+                    writer.flush()
+                })
+        } else {
+            std::fs::write(full_path, save_data)
+        };
+
+        match result {
             Ok(_) => Ok(OutputStream::empty()),
             Err(e) => Err(ShellError::labeled_error(
                 e.to_string(),
@@ -1016,25 +1143,21 @@ pub(crate) fn dir_entry_dict(
                     UntaggedValue::string(umask::Mode::from(mode).to_string()),
                 );
 
-                let nlinks = md.nlink();
-                dict.insert_untagged("num_links", UntaggedValue::string(nlinks.to_string()));
-
-                let inode = md.ino();
-                dict.insert_untagged("inode", UntaggedValue::string(inode.to_string()));
+                dict.insert_untagged("num_links", UntaggedValue::int(md.nlink()));
+                dict.insert_untagged("inode", UntaggedValue::int(md.ino()));
 
-                if let Some(user) = users::get_user_by_uid(md.uid()) {
-                    dict.insert_untagged(
-                        "uid",
-                        UntaggedValue::string(user.name().to_string_lossy()),
-                    );
-                }
+                // Ids without a name, as on files from another machine, are shown as numbers
+                let owner = match users::get_user_by_uid(md.uid()) {
+                    Some(user) => UntaggedValue::string(user.name().to_string_lossy()),
+                    None => UntaggedValue::int(md.uid()),
+                };
+                dict.insert_untagged("uid", owner);
 
-                if let Some(group) = users::get_group_by_gid(md.gid()) {
-                    dict.insert_untagged(
-                        "group",
-                        UntaggedValue::string(group.name().to_string_lossy()),
-                    );
-                }
+                let group = match users::get_group_by_gid(md.gid()) {
+                    Some(group) => UntaggedValue::string(group.name().to_string_lossy()),
+                    None => UntaggedValue::int(md.gid()),
+                };
+                dict.insert_untagged("group", group);
             }
         }
     }
@@ -1091,6 +1214,96 @@ pub(crate) fn dir_entry_dict(
     Ok(dict.into_value())
 }
 
+/// A directory, rather than a link to one, which a recursive listing would never get out of if it
+/// pointed back up the tree.
+fn is_real_dir(path: &Path) -> bool {
+    std::fs::symlink_metadata(path)
+        .map(|metadata| metadata.is_dir())
+        .unwrap_or(false)
+}
+
+/// The entries below a directory, down to a depth, in the order `**` would list them. Each
+/// directory is only read once the listing gets to it, so that the rows of a large tree stream out
+/// as they are found. A directory that can't be read is an error, after which the listing goes on.
+struct Descendants {
+    /// The entries of each directory being listed, with how deep they are
+    pending: Vec<(std::vec::IntoIter<PathBuf>, usize)>,
+    /// An error reading the directory just listed, to be returned after it
+    failed: Option<ShellError>,
+    max_depth: usize,
+    all: bool,
+    tag: Tag,
+}
+
+impl Descendants {
+    fn new(dir: &Path, max_depth: usize, all: bool, tag: Tag) -> Descendants {
+        let mut descendants = Descendants {
+            pending: vec![],
+            failed: None,
+            max_depth,
+            all,
+            tag,
+        };
+        descendants.enter(dir, 2);
+        descendants
+    }
+
+    fn enter(&mut self, dir: &Path, depth: usize) {
+        let entries: Result<Vec<PathBuf>, _> = std::fs::read_dir(dir).and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect()
+        });
+
+        match entries {
+            Ok(mut entries) => {
+                entries.sort();
+                self.pending.push((entries.into_iter(), depth));
+            }
+            Err(e) => {
+                self.failed = Some(ShellError::labeled_error(
+                    format!("Could not list {} ({})", dir.display(), e),
+                    "skipped this directory",
+                    &self.tag,
+                ));
+            }
+        }
+    }
+}
+
+impl Iterator for Descendants {
+    type Item = Result<PathBuf, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.failed.take() {
+            return Some(Err(err));
+        }
+
+        loop {
+            let (entries, depth) = self.pending.last_mut()?;
+            let depth = *depth;
+
+            let path = match entries.next() {
+                Some(path) => path,
+                None => {
+                    self.pending.pop();
+                    continue;
+                }
+            };
+
+            if !self.all && is_hidden_dir(&path) {
+                continue;
+            }
+
+            if depth < self.max_depth && is_real_dir(&path) {
+                self.enter(&path, depth + 1);
+            }
+
+            return Some(Ok(path));
+        }
+    }
+}
+
 fn path_contains_hidden_folder(path: &PathBuf, folders: &[PathBuf]) -> bool {
     let path_str = path.to_str().expect("failed to read path");
     if folders
@@ -75,9 +75,172 @@ where
     Ok(dunce::simplified(&path).to_path_buf())
 }
 
+/// The directories a path abbreviated to the start of each of its steps could be, like
+/// `src/parser/registry` for `s/p/r`. A step naming a directory exactly isn't taken as the start
+/// of others. More than one directory being returned makes the abbreviation ambiguous.
+pub fn expand_abbreviation<P, Q>(relative_to: P, path: Q) -> Vec<PathBuf>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let mut candidates = if path.as_ref().has_root() {
+        vec![PathBuf::new()]
+    } else {
+        vec![relative_to.as_ref().to_path_buf()]
+    };
+
+    for component in path.as_ref().components() {
+        candidates = match component {
+            Component::Prefix(_) | Component::RootDir | Component::ParentDir => candidates
+                .into_iter()
+                .map(|candidate| candidate.join(component))
+                .collect(),
+            Component::CurDir => candidates,
+            Component::Normal(step) => {
+                let step = step.to_string_lossy();
+                let mut expanded = vec![];
+
+                for candidate in candidates {
+                    let exact = candidate.join(step.as_ref());
+                    if exact.is_dir() {
+                        expanded.push(exact);
+                        continue;
+                    }
+
+                    let mut starting = match candidate.read_dir() {
+                        Ok(entries) => entries
+                            .filter_map(Result::ok)
+                            .filter(|entry| {
+                                entry
+                                    .file_name()
+                                    .to_string_lossy()
+                                    .starts_with(step.as_ref())
+                            })
+                            .map(|entry| entry.path())
+                            .filter(|path| path.is_dir())
+                            .collect(),
+                        Err(_) => vec![],
+                    };
+                    starting.sort();
+                    expanded.append(&mut starting);
+                }
+
+                expanded
+            }
+        };
+    }
+
+    candidates
+}
+
+/// Splits a path relative to the current directory of a drive, like `D:` or `D:src`, into its
+/// drive letter and the rest. Only Windows reads paths this way, as each of its drives has a
+/// current directory of its own.
+pub fn drive_relative(path: &str) -> Option<(char, &str)> {
+    let mut chars = path.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    if chars.next() != Some(':') {
+        return None;
+    }
+
+    let rest = &path[2..];
+    if rest.starts_with('\\') || rest.starts_with('/') {
+        None
+    } else {
+        Some((drive.to_ascii_uppercase(), rest))
+    }
+}
+
+/// The drive an absolute Windows path is on, like `C` for `C:\Users`, also when written as a
+/// verbatim path like `\\?\C:\Users`. UNC paths, like `\\server\share`, are on no drive, so that
+/// `D:` never leads to a share that happened to be visited.
+pub fn drive_of(path: &str) -> Option<char> {
+    let path = path.strip_prefix(r"\\?\").unwrap_or(path);
+    let mut chars = path.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+
+    if chars.next() == Some(':') {
+        Some(drive.to_ascii_uppercase())
+    } else {
+        None
+    }
+}
+
+/// Whether the filesystem the directory is on ignores the case of names, found by looking an entry
+/// of the directory with letters in its name up with its case swapped. The entry has to be inside
+/// the directory, as the directory's own name belongs to its parent, which can be on another
+/// filesystem when the directory is a mount point. Where that can't be told, like for a directory
+/// without such entries, it goes by the platform, as Windows and macOS ignore case by default.
+pub fn is_case_insensitive(dir: &Path) -> bool {
+    let platform_default = cfg!(windows) || cfg!(target_os = "macos");
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return platform_default,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        let swapped: String = name
+            .chars()
+            .flat_map(|c| {
+                if c.is_lowercase() {
+                    c.to_uppercase().collect::<Vec<_>>()
+                } else {
+                    c.to_lowercase().collect::<Vec<_>>()
+                }
+            })
+            .collect();
+
+        if swapped != name {
+            return is_same_entry(&entry.path(), &dir.join(swapped));
+        }
+    }
+
+    platform_default
+}
+
+#[cfg(unix)]
+fn is_same_entry(path: &Path, other: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (std::fs::metadata(path), std::fs::metadata(other)) {
+        (Ok(path), Ok(other)) => path.dev() == other.dev() && path.ino() == other.ino(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_same_entry(_path: &Path, other: &Path) -> bool {
+    // Names differing only in case can't both be there where case is ignored
+    other.exists()
+}
+
+/// The part of a glob pattern before its first wildcard, the directory its matches are in.
+pub fn glob_root(pattern: &Path) -> PathBuf {
+    let root: PathBuf = pattern
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(|c: char| c == '*' || c == '?' || c == '[')
+        })
+        .collect();
+
+    if root.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        root
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nu_test_support::playground::Playground;
     use std::io;
 
     #[test]
@@ -111,4 +274,71 @@ mod tests {
 
         assert!(canonicalize(relative_to, path).is_err());
     }
+
+    #[test]
+    fn drive_relative_paths() {
+        assert_eq!(drive_relative("d:"), Some(('D', "")));
+        assert_eq!(drive_relative("D:src\\nu"), Some(('D', "src\\nu")));
+        assert_eq!(drive_relative("D:\\src"), None);
+        assert_eq!(drive_relative("D:/src"), None);
+        assert_eq!(drive_relative("\\\\server\\share"), None);
+        assert_eq!(drive_relative(r"\\?\UNC\server\share"), None);
+        assert_eq!(drive_relative("src"), None);
+    }
+
+    #[test]
+    fn drives_of_absolute_paths() {
+        assert_eq!(drive_of("c:\\Users"), Some('C'));
+        assert_eq!(drive_of("\\\\server\\share"), None);
+        assert_eq!(drive_of(r"\\?\D:\src\nu"), Some('D'));
+        assert_eq!(drive_of(r"\\?\UNC\server\share"), None);
+        assert_eq!(drive_of("/home"), None);
+    }
+
+    #[test]
+    fn expands_abbreviated_paths() {
+        Playground::setup("expand_abbreviation_test", |dirs, sandbox| {
+            sandbox
+                .mkdir("src/parser/registry")
+                .mkdir("src/plugins")
+                .mkdir("scripts");
+
+            assert_eq!(
+                expand_abbreviation(dirs.test(), "sr/pa/r"),
+                vec![dirs.test().join("src/parser/registry")]
+            );
+            assert_eq!(
+                expand_abbreviation(dirs.test(), "src/p"),
+                vec![
+                    dirs.test().join("src/parser"),
+                    dirs.test().join("src/plugins")
+                ]
+            );
+            assert_eq!(
+                expand_abbreviation(dirs.test().join("src"), "../sc"),
+                vec![dirs.test().join("src/../scripts")]
+            );
+            assert!(expand_abbreviation(dirs.test(), "x").is_empty());
+        })
+    }
+
+    #[test]
+    fn tells_case_by_an_entry_inside_the_directory() {
+        Playground::setup("is_case_insensitive_test", |dirs, sandbox| {
+            sandbox.mkdir("Probe");
+
+            assert_eq!(
+                is_case_insensitive(dirs.test()),
+                dirs.test().join("pROBE").exists()
+            );
+        })
+    }
+
+    #[test]
+    fn glob_roots() {
+        assert_eq!(glob_root(Path::new("./*")), PathBuf::from("."));
+        assert_eq!(glob_root(Path::new("src/**/*.rs")), PathBuf::from("src"));
+        assert_eq!(glob_root(Path::new("src/nu")), PathBuf::from("src/nu"));
+        assert_eq!(glob_root(Path::new("[ab]/c")), PathBuf::from("."));
+    }
 }
@@ -1,8 +1,21 @@
 use nu_data::config::Conf;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
 
 const DEFAULT_LOCATION: &str = "history.txt";
 
+/// When a command from the history was run and how long it took.
+#[derive(Serialize, Deserialize)]
+pub struct HistoryTiming {
+    pub command: String,
+    /// Seconds since the Unix epoch.
+    pub start: i64,
+    /// Nanoseconds.
+    pub duration: u64,
+}
+
 pub fn history_path(config: &dyn Conf) -> PathBuf {
     let default_path = nu_data::config::user_data()
         .map(|mut p| {
@@ -20,3 +33,74 @@ pub fn history_path(config: &dyn Conf) -> PathBuf {
             }
         })
 }
+
+/// The timings are kept apart from the history itself, which belongs to the line editor.
+pub fn history_timing_path(config: &dyn Conf) -> PathBuf {
+    let mut path = history_path(config).into_os_string();
+    path.push(".timing");
+    PathBuf::from(path)
+}
+
+/// Appends the timings in one write, so that an import doesn't reopen the file for each command.
+pub fn record_history_timings(config: &dyn Conf, timings: &[HistoryTiming]) -> std::io::Result<()> {
+    let mut lines = String::new();
+    for timing in timings {
+        lines.push_str(&serde_json::to_string(timing)?);
+        lines.push('\n');
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_timing_path(config))?;
+
+    file.write_all(lines.as_bytes())
+}
+
+/// Keeps the timings of only as many commands as the history has. This reads both files, so the
+/// shell does it when it starts and exits, while each command's timing is only appended.
+pub fn trim_history_timings(config: &dyn Conf) -> std::io::Result<()> {
+    let entries = match std::fs::File::open(history_path(config)) {
+        Ok(file) => BufReader::new(file).lines().count(),
+        Err(_) => 0,
+    };
+
+    let path = history_timing_path(config);
+    let timings = match std::fs::read_to_string(&path) {
+        Ok(timings) => timings,
+        Err(_) => return Ok(()),
+    };
+
+    if let Some(kept) = trimmed(&timings, entries) {
+        std::fs::write(&path, kept)?;
+    }
+
+    Ok(())
+}
+
+/// The last `entries` lines of the timings, if there are more.
+fn trimmed(timings: &str, entries: usize) -> Option<String> {
+    let lines: Vec<&str> = timings.lines().collect();
+    if lines.len() <= entries {
+        return None;
+    }
+
+    let mut kept = lines[lines.len() - entries..].join("\n");
+    if !kept.is_empty() {
+        kept.push('\n');
+    }
+
+    Some(kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::trimmed;
+
+    #[test]
+    fn trims_the_timings_to_as_many_as_the_entries() {
+        assert_eq!(trimmed("a\nb\n", 2), None);
+        assert_eq!(trimmed("a\nb\nc\nd\ne\n", 2), Some("d\ne\n".to_string()));
+        assert_eq!(trimmed("a\n", 0), Some("".to_string()));
+    }
+}
@@ -2,6 +2,7 @@ pub mod basic_evaluation_context;
 pub mod basic_shell_manager;
 mod call_info;
 mod command_args;
+mod debugger;
 pub mod deserializer;
 pub mod documentation;
 mod env;
@@ -12,6 +13,7 @@ pub mod filesystem;
 mod history_path;
 mod maybe_text_codec;
 pub mod plugin;
+mod range_iterator;
 pub mod shell;
 mod whole_stream_command;
 
@@ -21,20 +23,24 @@ pub use crate::call_info::UnevaluatedCallInfo;
 pub use crate::command_args::{
     CommandArgs, EvaluatedCommandArgs, EvaluatedWholeStreamCommandArgs, RawCommandArgs,
 };
+pub use crate::debugger::{break_into_debugger, enable_debugger, is_debugger_enabled};
 pub use crate::documentation::{generate_docs, get_documentation, get_help};
 pub use crate::env::environment::Env;
 pub use crate::env::host::FakeHost;
 pub use crate::env::host::Host;
 pub use crate::evaluate::block::run_block;
 pub use crate::evaluate::evaluator::evaluate_baseline_expr;
-pub use crate::evaluate::scope::Scope;
+pub use crate::evaluate::scope::{PipelineBuffer, Scope};
 pub use crate::evaluation_context::EvaluationContext;
 pub use crate::example::Example;
 pub use crate::filesystem::dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use crate::filesystem::filesystem_shell::FilesystemShell;
 pub use crate::filesystem::path;
-pub use crate::history_path::history_path;
+pub use crate::history_path::{
+    history_path, history_timing_path, record_history_timings, trim_history_timings, HistoryTiming,
+};
 pub use crate::maybe_text_codec::{MaybeTextCodec, StringOrBinary};
+pub use crate::range_iterator::RangeIterator;
 pub use crate::shell::help_shell::{command_dict, HelpShell};
 pub use crate::shell::painter::Painter;
 pub use crate::shell::palette::{DefaultPalette, Palette};
@@ -1,16 +1,79 @@
+use crate::plugin::cache::{CachedPlugin, PluginCache};
+use crate::plugin::dynamic_plugin::PluginLibrary;
 use crate::plugin::run_plugin::PluginCommandBuilder;
 use log::trace;
 use nu_errors::ShellError;
 use nu_plugin::jsonrpc::JsonRpc;
-use nu_protocol::{Signature, Value};
+use nu_protocol::{Signature, UntaggedValue, Value};
+use nu_source::Tag;
+use std::collections::HashSet;
+use std::env::consts::{DLL_EXTENSION, DLL_PREFIX};
 use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 
 use rayon::prelude::*;
 
+/// The directories searched for plugins, in order.
+pub fn search_paths() -> Vec<std::path::PathBuf> {
+    use std::env;
+
+    let mut search_paths = plugin_dirs();
+
+    // Plugins installed like any other program are found on the PATH, after the ones above
+    if let Some(paths) = env::var_os("PATH") {
+        for path in env::split_paths(&paths) {
+            if !path.as_os_str().is_empty() && !search_paths.contains(&path) {
+                search_paths.push(path);
+            }
+        }
+    }
+
+    search_paths
+}
+
+/// The directories of nu and of the `plugin_dirs` setting, the only ones plugin libraries are
+/// loaded from, since loading one runs its code inside nu.
+pub fn plugin_dirs() -> Vec<std::path::PathBuf> {
+    use std::env;
+
+    let mut search_paths = Vec::new();
+
+    // Automatically add path `nu` is in as a search path
+    if let Ok(exe_path) = env::current_exe() {
+        if let Some(exe_dir) = exe_path.parent() {
+            search_paths.push(exe_dir.to_path_buf());
+        }
+    }
+
+    if let Ok(config) = nu_data::config::config(Tag::unknown()) {
+        if let Some(plugin_dirs) = config.get("plugin_dirs") {
+            if let Value {
+                value: UntaggedValue::Table(pipelines),
+                ..
+            } = plugin_dirs
+            {
+                for pipeline in pipelines {
+                    if let Ok(plugin_dir) = pipeline.as_string() {
+                        search_paths.push(PathBuf::from(plugin_dir));
+                    }
+                }
+            }
+        }
+    }
+
+    search_paths
+}
+
 pub fn build_plugin_command(
     path: &std::path::Path,
 ) -> Result<Option<PluginCommandBuilder>, ShellError> {
+    Ok(query_plugin(path)?
+        .map(|(fname, params)| PluginCommandBuilder::new(params.name.clone(), fname, params)))
+}
+
+/// Starts the plugin to ask for its signature, returning it with the path to run the plugin from.
+fn query_plugin(path: &std::path::Path) -> Result<Option<(String, Signature)>, ShellError> {
     let ext = path.extension();
     let ps1_file = match ext {
         Some(ext) => ext == "ps1",
@@ -65,11 +128,7 @@ pub fn build_plugin_command(
 
                         trace!(target: "nu::load", "plugin infrastructure -> processing {:?}", params);
 
-                        let name = params.name.clone();
-
-                        let fname = fname.to_string();
-
-                        Ok(Some(PluginCommandBuilder::new(&name, &fname, params)))
+                        Ok(Some((fname.to_string(), params)))
                     }
                     Err(e) => Err(e),
                 },
@@ -93,10 +152,20 @@ pub fn build_plugin_command(
     result
 }
 
+/// The plugins in the paths. Their signatures come from the cache unless their binary changed since,
+/// or `refresh` asks for every plugin to be started again. Plugin libraries are only loaded from
+/// the paths also in `library_dirs`.
 pub fn scan(
     paths: Vec<std::path::PathBuf>,
+    library_dirs: &[std::path::PathBuf],
+    refresh: bool,
 ) -> Result<Vec<crate::whole_stream_command::Command>, ShellError> {
-    let mut plugins = vec![];
+    let cache = PluginCache::load();
+
+    let mut plugins: Vec<CachedPlugin> = vec![];
+    let mut libraries = vec![];
+    // A plugin found in more than one of the paths is loaded from the first, like PATH lookup
+    let mut seen = HashSet::new();
 
     let opts = glob::MatchOptions {
         case_sensitive: false,
@@ -104,13 +173,49 @@ pub fn scan(
         require_literal_leading_dot: false,
     };
 
-    for path in paths {
+    for path in &paths {
+        // Plugins built as libraries are loaded into nu instead of being started
+        let mut library_pattern = path.to_path_buf();
+        library_pattern.push(format!("{}nu_plugin_*.{}", DLL_PREFIX, DLL_EXTENSION));
+
+        let library_matches = if library_dirs.contains(path) {
+            glob::glob_with(&library_pattern.to_string_lossy(), opts).ok()
+        } else {
+            None
+        };
+
+        if let Some(matches) = library_matches {
+            for library in matches.filter_map(|x| x.ok()) {
+                let is_new = match library.file_name() {
+                    Some(name) => seen.insert(name.to_os_string()),
+                    None => false,
+                };
+
+                if is_new {
+                    match PluginLibrary::load(&library) {
+                        Ok(commands) => libraries.extend(commands),
+                        Err(e) => trace!(target: "nu::load", "plugin infrastructure -> {:?}", e),
+                    }
+                }
+            }
+        }
+
         let mut pattern = path.to_path_buf();
 
         pattern.push(std::path::Path::new("nu_plugin_[a-z0-9][a-z0-9]*"));
 
-        let plugs: Vec<_> = glob::glob_with(&pattern.to_string_lossy(), opts)?
+        // A directory of the PATH can't keep the others from being searched
+        let matches = match glob::glob_with(&pattern.to_string_lossy(), opts) {
+            Ok(matches) => matches,
+            Err(_) => continue,
+        };
+
+        let plugs: Vec<_> = matches
             .filter_map(|x| x.ok())
+            .filter(|path| match path.file_name() {
+                Some(name) => seen.insert(name.to_os_string()),
+                None => false,
+            })
             .collect();
 
         let plugs: Vec<_> = plugs
@@ -157,16 +262,47 @@ pub fn scan(
                 };
 
                 if is_valid_name && is_executable {
+                    let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+                    if !refresh {
+                        if let Some(cached) = cache.find(&path, modified) {
+                            return Some(cached.clone());
+                        }
+                    }
+
                     trace!(target: "nu::load", "plugin infrastructure -> Trying {:?}", path.display());
-                    build_plugin_command(&path).unwrap_or(None)
+                    query_plugin(&path)
+                        .unwrap_or(None)
+                        .map(|(command_path, signature)| CachedPlugin {
+                            path: path.clone(),
+                            modified,
+                            command_path,
+                            signature,
+                        })
                 } else {
                     None
                 }
-            }).map(|p| p.build())
-            .filter_map(Result::ok)
-            .collect::<Vec<crate::whole_stream_command::Command>>();
+            })
+            .collect::<Vec<CachedPlugin>>();
         plugins.extend(plugs);
     }
 
-    Ok(plugins)
+    let mut commands: Vec<_> = plugins
+        .iter()
+        .cloned()
+        .map(|p| {
+            PluginCommandBuilder::new(p.signature.name.clone(), p.command_path, p.signature).build()
+        })
+        .filter_map(Result::ok)
+        .collect();
+
+    commands.extend(libraries);
+
+    // Not being able to keep the signatures only means asking for them again next time
+    let updated = cache.update(&paths, plugins);
+    if refresh || updated.differs_from(&cache) {
+        let _ = updated.save();
+    }
+
+    Ok(commands)
 }
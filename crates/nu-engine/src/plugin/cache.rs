@@ -0,0 +1,157 @@
+use nu_protocol::Signature;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const DEFAULT_LOCATION: &str = "plugins.json";
+
+/// What a plugin binary answered when asked for its signature, and when the binary had last been
+/// changed then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedPlugin {
+    /// Where the plugin was found
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+    /// What is run when the plugin is called
+    pub command_path: String,
+    pub signature: Signature,
+}
+
+/// The signatures of the plugins found the last time they were searched for, so that only new or
+/// changed binaries are started to ask for theirs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PluginCache {
+    pub plugins: Vec<CachedPlugin>,
+}
+
+impl PluginCache {
+    /// The cache, or an empty one if it can't be read, like when nu changed what it keeps.
+    pub fn load() -> PluginCache {
+        std::fs::read_to_string(cache_path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let contents = serde_json::to_string(self)?;
+        std::fs::write(cache_path(), contents)
+    }
+
+    /// The plugin found at the path, if the binary hasn't changed since it was cached.
+    pub fn find(&self, path: &Path, modified: Option<SystemTime>) -> Option<&CachedPlugin> {
+        modified?;
+
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.path == path && plugin.modified == modified)
+    }
+
+    /// The cache once the plugins of the directories were searched for: those found in them, and
+    /// those of other directories whose binary is still there. Plugins whose binary has no time it
+    /// was changed at are left out, as they're never found in the cache.
+    pub fn update(&self, dirs: &[PathBuf], found: Vec<CachedPlugin>) -> PluginCache {
+        let mut plugins: Vec<CachedPlugin> = self
+            .plugins
+            .iter()
+            .filter(|cached| match cached.path.parent() {
+                Some(dir) => !dirs.iter().any(|searched| searched == dir) && cached.path.exists(),
+                None => false,
+            })
+            .cloned()
+            .collect();
+        plugins.extend(found.into_iter().filter(|plugin| plugin.modified.is_some()));
+
+        PluginCache { plugins }
+    }
+
+    /// Whether the caches differ in the binaries they keep the signatures of.
+    pub fn differs_from(&self, other: &PluginCache) -> bool {
+        self.plugins.len() != other.plugins.len()
+            || self
+                .plugins
+                .iter()
+                .any(|plugin| other.find(&plugin.path, plugin.modified).is_none())
+    }
+}
+
+pub fn cache_path() -> PathBuf {
+    nu_data::config::user_cache_path_for(DEFAULT_LOCATION, DEFAULT_LOCATION)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_LOCATION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachedPlugin, PluginCache};
+    use nu_protocol::Signature;
+    use nu_test_support::playground::Playground;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
+
+    fn cached(path: PathBuf, modified: SystemTime) -> CachedPlugin {
+        CachedPlugin {
+            command_path: path.display().to_string(),
+            path,
+            modified: Some(modified),
+            signature: Signature::build("inc"),
+        }
+    }
+
+    #[test]
+    fn finds_plugins_whose_binary_is_unchanged() {
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_609_459_200);
+        let cache = PluginCache {
+            plugins: vec![CachedPlugin {
+                path: PathBuf::from("/bin/nu_plugin_inc"),
+                modified: Some(modified),
+                command_path: "/bin/nu_plugin_inc".to_string(),
+                signature: Signature::build("inc"),
+            }],
+        };
+        let path = Path::new("/bin/nu_plugin_inc");
+
+        assert!(cache.find(path, Some(modified)).is_some());
+        assert!(cache
+            .find(path, Some(modified + Duration::from_secs(1)))
+            .is_none());
+        assert!(cache.find(path, None).is_none());
+        assert!(cache
+            .find(Path::new("/usr/bin/nu_plugin_inc"), Some(modified))
+            .is_none());
+    }
+
+    #[test]
+    fn drops_the_plugins_gone_and_tells_when_it_changed() {
+        Playground::setup("plugin_cache_test_1", |dirs, sandbox| {
+            sandbox.mkdir("searched").mkdir("other");
+            std::fs::write(dirs.test().join("other/nu_plugin_kept"), "").expect("a plugin");
+            let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_609_459_200);
+
+            let cache = PluginCache {
+                plugins: vec![
+                    cached(dirs.test().join("searched/nu_plugin_inc"), modified),
+                    cached(dirs.test().join("other/nu_plugin_kept"), modified),
+                    cached(dirs.test().join("other/nu_plugin_removed"), modified),
+                ],
+            };
+            let searched = vec![dirs.test().join("searched")];
+
+            let unchanged = PluginCache {
+                plugins: vec![
+                    cached(dirs.test().join("searched/nu_plugin_inc"), modified),
+                    cached(dirs.test().join("other/nu_plugin_kept"), modified),
+                ],
+            };
+            let updated = unchanged.update(
+                &searched,
+                vec![cached(dirs.test().join("searched/nu_plugin_inc"), modified)],
+            );
+            assert!(!updated.differs_from(&unchanged));
+
+            let updated = cache.update(&searched, vec![]);
+            let paths: Vec<_> = updated.plugins.iter().map(|plugin| &plugin.path).collect();
+            assert_eq!(paths, vec![&dirs.test().join("other/nu_plugin_kept")]);
+            assert!(updated.differs_from(&cache));
+        })
+    }
+}
@@ -0,0 +1,331 @@
+use crate::command_args::CommandArgs;
+use crate::whole_stream_command::{whole_stream_command, Command, WholeStreamCommand};
+use async_trait::async_trait;
+use futures::StreamExt;
+use libloading::Library;
+use log::trace;
+use nu_errors::ShellError;
+use nu_plugin::dynamic::ABI_VERSION;
+use nu_plugin::jsonrpc::NuCommand;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+use nu_stream::{OutputStream, ToOutputStream};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+use std::sync::Arc;
+
+type SignaturesFn = unsafe extern "C" fn() -> *mut c_char;
+type NewFn = unsafe extern "C" fn(*const c_char) -> *mut c_void;
+type CallFn = unsafe extern "C" fn(*mut c_void, *const c_char) -> *mut c_char;
+type DropFn = unsafe extern "C" fn(*mut c_void);
+type FreeStringFn = unsafe extern "C" fn(*mut c_char);
+
+/// Values are sent to a library in batches of the values already in the stream, even to plugins
+/// without a batch size, since each call has its own JSON request and response.
+const BATCH_SIZE: usize = 1024;
+
+/// A plugin library loaded into nu, kept loaded as long as any of its commands are.
+pub struct PluginLibrary {
+    new: NewFn,
+    call: CallFn,
+    drop: DropFn,
+    free_string: FreeStringFn,
+    _library: Option<Library>,
+}
+
+impl PluginLibrary {
+    /// Loads the library, returning a command for each of its plugins.
+    pub fn load(path: &Path) -> Result<Vec<Command>, ShellError> {
+        let error = |message: String| {
+            ShellError::untagged_runtime_error(format!(
+                "Could not load the plugin library {}: {}",
+                path.display(),
+                message
+            ))
+        };
+
+        unsafe {
+            let library = Library::new(path).map_err(|e| error(e.to_string()))?;
+
+            let version = *library
+                .get::<unsafe extern "C" fn() -> u32>(b"nu_plugin_abi_version\0")
+                .map_err(|e| error(e.to_string()))?;
+            if version() != ABI_VERSION {
+                return Err(error(format!(
+                    "it was built for version {} of the plugin interface, not {}",
+                    version(),
+                    ABI_VERSION
+                )));
+            }
+
+            let signatures = *library
+                .get::<SignaturesFn>(b"nu_plugin_signatures\0")
+                .map_err(|e| error(e.to_string()))?;
+
+            let plugins = PluginLibrary {
+                new: *library
+                    .get::<NewFn>(b"nu_plugin_new\0")
+                    .map_err(|e| error(e.to_string()))?,
+                call: *library
+                    .get::<CallFn>(b"nu_plugin_call\0")
+                    .map_err(|e| error(e.to_string()))?,
+                drop: *library
+                    .get::<DropFn>(b"nu_plugin_drop\0")
+                    .map_err(|e| error(e.to_string()))?,
+                free_string: *library
+                    .get::<FreeStringFn>(b"nu_plugin_free_string\0")
+                    .map_err(|e| error(e.to_string()))?,
+                _library: Some(library),
+            };
+
+            let commands = plugins.commands(signatures).map_err(error)?;
+            trace!(target: "nu::load", "plugin infrastructure -> library {:?} has {} plugins", path, commands.len());
+
+            Ok(commands)
+        }
+    }
+
+    /// A command for each of the plugins listed by `signatures`.
+    unsafe fn commands(self, signatures: SignaturesFn) -> Result<Vec<Command>, String> {
+        let listed = signatures();
+        if listed.is_null() {
+            return Err("it panicked listing its plugins".to_string());
+        }
+
+        let signatures: Vec<Signature> =
+            serde_json::from_str(&self.read(listed)).map_err(|e| e.to_string())?;
+
+        let library = Arc::new(self);
+        Ok(signatures
+            .into_iter()
+            .map(|signature| {
+                whole_stream_command(LibraryPlugin {
+                    signature,
+                    library: library.clone(),
+                })
+            })
+            .collect())
+    }
+
+    fn instance(library: &Arc<PluginLibrary>, name: &str) -> Result<Instance, ShellError> {
+        let name = CString::new(name)
+            .map_err(|_| ShellError::untagged_runtime_error("Invalid plugin name"))?;
+        let pointer = unsafe { (library.new)(name.as_ptr()) };
+
+        if pointer.is_null() {
+            Err(ShellError::untagged_runtime_error(
+                "The plugin is no longer in its library, or could not be created",
+            ))
+        } else {
+            Ok(Instance {
+                library: library.clone(),
+                pointer,
+            })
+        }
+    }
+
+    /// Takes a string returned by the library, which the library frees.
+    unsafe fn read(&self, string: *mut c_char) -> String {
+        if string.is_null() {
+            return String::new();
+        }
+
+        let read = CStr::from_ptr(string).to_string_lossy().to_string();
+        (self.free_string)(string);
+        read
+    }
+}
+
+/// A plugin created for a single call, dropped by its library when the call is done.
+struct Instance {
+    library: Arc<PluginLibrary>,
+    pointer: *mut c_void,
+}
+
+// Libraries are built with `Send` plugins, see `nu_plugin::dynamic::Constructor`
+unsafe impl Send for Instance {}
+
+impl Instance {
+    fn call(&mut self, command: &NuCommand) -> Vec<ReturnValue> {
+        let response = serde_json::to_string(command)
+            .ok()
+            .and_then(|request| CString::new(request).ok())
+            .map(|request| unsafe {
+                self.library
+                    .read((self.library.call)(self.pointer, request.as_ptr()))
+            })
+            .unwrap_or_default();
+
+        match serde_json::from_str::<Result<Vec<ReturnValue>, ShellError>>(&response) {
+            Ok(Ok(values)) => values,
+            Ok(Err(error)) => vec![Err(error)],
+            Err(_) => vec![Err(ShellError::untagged_runtime_error(
+                "The plugin gave an invalid response",
+            ))],
+        }
+    }
+}
+
+impl Drop for Instance {
+    fn drop(&mut self) {
+        unsafe { (self.library.drop)(self.pointer) }
+    }
+}
+
+pub struct LibraryPlugin {
+    signature: Signature,
+    library: Arc<PluginLibrary>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for LibraryPlugin {
+    fn name(&self) -> &str {
+        &self.signature.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.signature.clone()
+    }
+
+    fn usage(&self) -> &str {
+        &self.signature.usage
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        let args = args.evaluate_once().await?;
+        let call_info = args.call_info.clone();
+        let mut instance = PluginLibrary::instance(&self.library, &self.signature.name)?;
+
+        if !self.signature.is_filter {
+            let input: Vec<Value> = args.input.collect().await;
+            let output = instance.call(&NuCommand::sink {
+                params: (call_info, input),
+            });
+
+            return Ok(futures::stream::iter(output).to_output_stream());
+        }
+
+        let begin = instance.call(&NuCommand::begin_filter { params: call_info });
+
+        let requests = args
+            .input
+            .ready_chunks(self.signature.batch_size.unwrap_or(BATCH_SIZE))
+            .map(|params| NuCommand::filter_batch { params });
+
+        let output = requests
+            .chain(futures::stream::iter(vec![NuCommand::end_filter]))
+            .map(move |request| instance.call(&request))
+            .flat_map(futures::stream::iter);
+
+        Ok(futures::stream::iter(begin)
+            .chain(output)
+            .to_output_stream())
+    }
+
+    fn argument_completions(&self, flag: Option<&str>, partial: &str) -> Vec<String> {
+        let mut instance = match PluginLibrary::instance(&self.library, &self.signature.name) {
+            Ok(instance) => instance,
+            Err(_) => return vec![],
+        };
+
+        instance
+            .call(&NuCommand::complete {
+                params: (flag.map(String::from), partial.to_string()),
+            })
+            .into_iter()
+            .filter_map(|completion| match completion {
+                Ok(ReturnSuccess::Value(value)) => value.as_string().ok(),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PluginLibrary, SignaturesFn};
+    use nu_errors::ShellError;
+    use nu_plugin::jsonrpc::NuCommand;
+    use nu_plugin::Plugin;
+    use nu_protocol::{ReturnSuccess, ReturnValue, Signature, UntaggedValue, Value};
+    use std::sync::Arc;
+
+    struct Double;
+
+    impl Plugin for Double {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("double").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            let doubled = input.as_i64()? * 2;
+            Ok(vec![ReturnSuccess::value(UntaggedValue::int(doubled))])
+        }
+
+        fn complete(&mut self, flag: Option<String>, partial: String) -> Vec<String> {
+            match flag {
+                None => vec![format!("{}0", partial), format!("{}00", partial)],
+                Some(_) => vec![],
+            }
+        }
+    }
+
+    // The functions a plugin library exports, so that they are called the way nu calls a library
+    nu_plugin::export_plugins!(Double);
+
+    fn library() -> PluginLibrary {
+        PluginLibrary {
+            new: nu_plugin_new,
+            call: nu_plugin_call,
+            drop: nu_plugin_drop,
+            free_string: nu_plugin_free_string,
+            _library: None,
+        }
+    }
+
+    #[test]
+    fn has_a_command_for_each_plugin() {
+        let signatures: SignaturesFn = nu_plugin_signatures;
+        let commands = unsafe { library().commands(signatures) }.expect("commands");
+
+        let names: Vec<&str> = commands.iter().map(|command| command.name()).collect();
+        assert_eq!(names, vec!["double"]);
+    }
+
+    #[test]
+    fn filters_values_through_the_library() {
+        let library = Arc::new(library());
+        assert!(PluginLibrary::instance(&library, "triple").is_err());
+
+        let mut instance = PluginLibrary::instance(&library, "double").expect("an instance");
+        let doubled: Vec<i64> = instance
+            .call(&NuCommand::filter_batch {
+                params: vec![
+                    UntaggedValue::int(1).into_untagged_value(),
+                    UntaggedValue::int(2).into_untagged_value(),
+                ],
+            })
+            .into_iter()
+            .filter_map(|value| match value {
+                Ok(ReturnSuccess::Value(value)) => value.as_i64().ok(),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(doubled, vec![2, 4]);
+    }
+
+    #[test]
+    fn completes_arguments_through_the_library() {
+        let signatures: SignaturesFn = nu_plugin_signatures;
+        let commands = unsafe { library().commands(signatures) }.expect("commands");
+
+        assert_eq!(
+            commands[0].argument_completions(None, "1"),
+            vec!["10".to_string(), "100".to_string()]
+        );
+        assert!(commands[0]
+            .argument_completions(Some("times"), "1")
+            .is_empty());
+    }
+}
@@ -1,2 +1,4 @@
 pub mod build_plugin;
+pub mod cache;
+pub(crate) mod dynamic_plugin;
 pub(crate) mod run_plugin;
@@ -6,7 +6,7 @@ use futures::StreamExt;
 use log::trace;
 use nu_errors::ShellError;
 use nu_plugin::jsonrpc::JsonRpc;
-use nu_protocol::{Primitive, ReturnValue, Signature, UntaggedValue, Value};
+use nu_protocol::{ReturnValue, Signature, Value};
 use nu_stream::{OutputStream, ToOutputStream};
 use serde::{self, Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -109,22 +109,48 @@ impl WholeStreamCommand for PluginFilter {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        run_filter(self.path.clone(), (args)).await
+        run_filter(self.path.clone(), self.config.batch_size, args).await
     }
+
+    fn argument_completions(&self, flag: Option<&str>, partial: &str) -> Vec<String> {
+        plugin_completions(&self.path, flag, partial)
+    }
+}
+
+/// What is sent to a filter plugin, in order.
+enum FilterRequest {
+    Begin,
+    Next(Result<String, serde_json::Error>),
+    End,
 }
 
-async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
+async fn run_filter(
+    path: String,
+    batch_size: Option<usize>,
+    args: CommandArgs,
+) -> Result<OutputStream, ShellError> {
     trace!("filter_plugin :: {}", path);
 
-    let bos = futures::stream::iter(vec![
-        UntaggedValue::Primitive(Primitive::BeginningOfStream).into_untagged_value()
-    ]);
-    let eos = futures::stream::iter(vec![
-        UntaggedValue::Primitive(Primitive::EndOfStream).into_untagged_value()
-    ]);
+    let bos = futures::stream::iter(vec![FilterRequest::Begin]);
+    let eos = futures::stream::iter(vec![FilterRequest::End]);
 
     let args = args.evaluate_once().await?;
 
+    // Values are sent as they come, so that plugins can filter streams that never end. Batches
+    // only take the values that are already waiting.
+    let requests = match batch_size {
+        Some(size) => args
+            .input
+            .ready_chunks(size)
+            .map(|values| serde_json::to_string(&JsonRpc::new("filter_batch", values)))
+            .boxed(),
+        None => args
+            .input
+            .map(|value| serde_json::to_string(&JsonRpc::new("filter", value)))
+            .boxed(),
+    }
+    .map(FilterRequest::Next);
+
     let real_path = Path::new(&path);
     let ext = real_path.extension();
     let ps1_file = match ext {
@@ -159,14 +185,11 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
     trace!("filtering :: {:?}", call_info);
 
     Ok(bos
-        .chain(args.input)
+        .chain(requests)
         .chain(eos)
         .map(move |item| {
             match item {
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::BeginningOfStream),
-                    ..
-                } => {
+                FilterRequest::Begin => {
                     // Beginning of the stream
                     let stdin = child.stdin.as_mut().expect("Failed to open stdin");
                     let stdout = child.stdout.as_mut().expect("Failed to open stdout");
@@ -223,10 +246,7 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
                         ))),
                     }
                 }
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::EndOfStream),
-                    ..
-                } => {
+                FilterRequest::End => {
                     // post stream contents
                     let stdin = child.stdin.as_mut().expect("Failed to open stdin");
                     let stdout = child.stdout.as_mut().expect("Failed to open stdout");
@@ -308,15 +328,13 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
                     stream
                 }
 
-                v => {
+                FilterRequest::Next(request_raw) => {
                     // Stream contents
                     let stdin = child.stdin.as_mut().expect("Failed to open stdin");
                     let stdout = child.stdout.as_mut().expect("Failed to open stdout");
 
                     let mut reader = BufReader::new(stdout);
 
-                    let request = JsonRpc::new("filter", v);
-                    let request_raw = serde_json::to_string(&request);
                     trace!("filter:request {:?}", &request_raw);
 
                     match request_raw {
@@ -386,6 +404,10 @@ impl WholeStreamCommand for PluginSink {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         run_sink(self.path.clone(), args).await
     }
+
+    fn argument_completions(&self, flag: Option<&str>, partial: &str) -> Vec<String> {
+        plugin_completions(&self.path, flag, partial)
+    }
 }
 
 async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -449,3 +471,62 @@ async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, Shell
         ))
     }
 }
+
+/// Asks the plugin for the values completing one of its arguments. Plugins failing to answer,
+/// like those built before plugins could complete their arguments, have none to suggest.
+fn plugin_completions(path: &str, flag: Option<&str>, partial: &str) -> Vec<String> {
+    let real_path = Path::new(path);
+    let ps1_file = match real_path.extension() {
+        Some(ext) => ext == "ps1",
+        None => false,
+    };
+
+    let mut command = if ps1_file {
+        let mut command = Command::new("pwsh");
+        command.args(&[
+            "-NoLogo",
+            "-NoProfile",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-File",
+            &real_path.to_string_lossy(),
+        ]);
+        command
+    } else {
+        Command::new(path)
+    };
+
+    // Anything the plugin prints besides its answer would end up in the middle of the prompt
+    let mut child = match command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return vec![],
+    };
+
+    let request = JsonRpc::new("complete", (flag, partial));
+    let mut input = String::new();
+    let sent = match (child.stdin.as_mut(), serde_json::to_string(&request)) {
+        (Some(stdin), Ok(request_raw)) => stdin
+            .write_all(format!("{}\n", request_raw).as_bytes())
+            .is_ok(),
+        _ => false,
+    };
+    if sent {
+        if let Some(stdout) = child.stdout.as_mut() {
+            let _ = BufReader::new(stdout).read_line(&mut input);
+        }
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    trace!("complete:response {:?}", &input);
+
+    serde_json::from_str::<JsonRpc<Vec<String>>>(&input)
+        .map(|response| response.params)
+        .unwrap_or_default()
+}
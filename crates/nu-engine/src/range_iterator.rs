@@ -0,0 +1,154 @@
+use nu_errors::ShellError;
+use nu_protocol::hir::Operator;
+use nu_protocol::{Primitive, Range, RangeInclusion, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tag};
+use std::cmp::Ordering;
+
+/// Counts through a range one number at a time, so that large or open ranges are only generated
+/// as far as they're read.
+pub struct RangeIterator {
+    curr: Primitive,
+    end: Primitive,
+    tag: Tag,
+    is_end_inclusive: bool,
+    moves_up: bool,
+}
+
+impl RangeIterator {
+    pub fn new(range: Range, tag: Tag) -> RangeIterator {
+        let start = match range.from.0.item {
+            Primitive::Nothing => Primitive::Int(0.into()),
+            x => x,
+        };
+
+        let end = match range.to.0.item {
+            Primitive::Nothing => Primitive::Int(u64::MAX.into()),
+            x => x,
+        };
+
+        RangeIterator {
+            moves_up: start <= end,
+            curr: start,
+            end,
+            tag,
+            is_end_inclusive: matches!(range.to.1, RangeInclusion::Inclusive),
+        }
+    }
+
+    fn step(&mut self, by: i64) -> Result<(), ShellError> {
+        let next_value = nu_data::value::compute_values(
+            Operator::Plus,
+            &UntaggedValue::Primitive(self.curr.clone()),
+            &UntaggedValue::int(by),
+        );
+
+        self.curr = match next_value {
+            Ok(UntaggedValue::Primitive(p)) => p,
+            Ok(_) => {
+                return Err(ShellError::unimplemented(
+                    "Internal error: expected a primitive result from increment",
+                ));
+            }
+            Err((left_type, right_type)) => {
+                return Err(ShellError::coerce_error(
+                    left_type.spanned(self.tag.span),
+                    right_type.spanned(self.tag.span),
+                ));
+            }
+        };
+
+        Ok(())
+    }
+}
+
+impl Iterator for RangeIterator {
+    type Item = Result<Value, ShellError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = if self.end == Primitive::Nothing {
+            Ordering::Less
+        } else {
+            match nu_data::base::coerce_compare_primitive(&self.curr, &self.end) {
+                Ok(result) => result.compare(),
+                Err(_) => {
+                    return Some(Err(ShellError::labeled_error(
+                        "Cannot create range",
+                        "unsupported range",
+                        self.tag.span,
+                    )));
+                }
+            }
+        };
+
+        let in_range = if self.moves_up {
+            ordering == Ordering::Less || self.is_end_inclusive && ordering == Ordering::Equal
+        } else {
+            ordering == Ordering::Greater || self.is_end_inclusive && ordering == Ordering::Equal
+        };
+
+        if !in_range {
+            return None;
+        }
+
+        let output = UntaggedValue::Primitive(self.curr.clone()).into_value(self.tag.clone());
+
+        if let Err(err) = self.step(if self.moves_up { 1 } else { -1 }) {
+            return Some(Err(err));
+        }
+
+        Some(Ok(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeIterator;
+    use nu_errors::ShellError;
+    use nu_protocol::{Primitive, Range, RangeInclusion, Value};
+    use nu_source::{Span, SpannedItem, Tag};
+
+    fn range(from: i64, to: i64, to_inclusion: RangeInclusion) -> Range {
+        Range {
+            from: (
+                Primitive::Int(from.into()).spanned(Span::unknown()),
+                RangeInclusion::Inclusive,
+            ),
+            to: (
+                Primitive::Int(to.into()).spanned(Span::unknown()),
+                to_inclusion,
+            ),
+        }
+    }
+
+    fn numbers(range: Range) -> Result<Vec<i64>, ShellError> {
+        RangeIterator::new(range, Tag::unknown())
+            .map(|value| value.and_then(|value: Value| value.as_i64()))
+            .collect()
+    }
+
+    #[test]
+    fn counts_up_and_down() -> Result<(), ShellError> {
+        assert_eq!(
+            numbers(range(1, 3, RangeInclusion::Inclusive))?,
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            numbers(range(3, 1, RangeInclusion::Inclusive))?,
+            vec![3, 2, 1]
+        );
+        assert_eq!(numbers(range(1, 3, RangeInclusion::Exclusive))?, vec![1, 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generates_only_what_is_read() {
+        let first = RangeIterator::new(
+            range(1, i64::MAX, RangeInclusion::Inclusive),
+            Tag::unknown(),
+        )
+        .take(3)
+        .count();
+
+        assert_eq!(first, 3);
+    }
+}
@@ -239,6 +239,7 @@ impl Shell for HelpShell {
         _path: &PathBuf,
         _contents: &[u8],
         _name: Span,
+        _append: bool,
     ) -> Result<OutputStream, ShellError> {
         Err(ShellError::unimplemented(
             "save on help shell is not supported",
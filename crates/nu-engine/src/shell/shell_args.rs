@@ -23,6 +23,10 @@ pub struct LsArgs {
     pub short_names: bool,
     #[serde(rename = "du")]
     pub du: bool,
+    #[serde(rename = "case-insensitive")]
+    pub case_insensitive: bool,
+    pub recursive: bool,
+    pub depth: Option<Tagged<usize>>,
 }
 
 #[derive(Deserialize)]
@@ -17,6 +17,8 @@ use std::sync::Arc;
 pub struct ShellManager {
     pub current_shell: Arc<AtomicUsize>,
     pub shells: Arc<Mutex<Vec<Box<dyn Shell + Send>>>>,
+    /// The paths left with `pushd`, the last one on top, for `popd` to go back to.
+    pub directory_stack: Arc<Mutex<Vec<String>>>,
 }
 
 impl ShellManager {
@@ -84,8 +86,9 @@ impl ShellManager {
         full_path: &PathBuf,
         save_data: &[u8],
         name: Span,
+        append: bool,
     ) -> Result<OutputStream, ShellError> {
-        self.shells.lock()[self.current_shell()].save(full_path, save_data, name)
+        self.shells.lock()[self.current_shell()].save(full_path, save_data, name, append)
     }
 
     pub fn next(&self) {
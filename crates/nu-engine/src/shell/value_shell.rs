@@ -5,7 +5,6 @@ use crate::shell::Shell;
 use encoding_rs::Encoding;
 use futures::stream::BoxStream;
 use nu_errors::ShellError;
-use nu_protocol::ValueStructure;
 use nu_protocol::{ReturnSuccess, ShellTypeName, UntaggedValue, Value};
 use nu_source::SpannedItem;
 use nu_source::{Span, Tag, Tagged};
@@ -13,7 +12,7 @@ use nu_stream::OutputStream;
 use nu_value_ext::ValueExt;
 use std::collections::VecDeque;
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
@@ -74,6 +73,48 @@ impl ValueShell {
         shell_entries
     }
 
+    /// The value at a path, walking down from the top of the value.
+    fn value_at(&self, path: &Path) -> Option<Value> {
+        let mut viewed = self.value.clone();
+
+        for step in path.components() {
+            if let Component::Normal(key) = step {
+                viewed =
+                    viewed.get_data_by_key(key.to_string_lossy().as_ref().spanned_unknown())?;
+            }
+        }
+
+        Some(viewed)
+    }
+
+    /// Where a path leads from the current one. Its steps may be written with dots, like
+    /// `package.dependencies`, unless a key has the dot in its name.
+    fn resolve(&self, target: &Path) -> PathBuf {
+        let mut path = PathBuf::from(&self.path);
+
+        for step in target.components() {
+            match step {
+                Component::RootDir | Component::Prefix(_) => path = PathBuf::from("/"),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    path.pop();
+                }
+                Component::Normal(step) => {
+                    let step = step.to_string_lossy();
+                    let literal = path.join(step.as_ref());
+
+                    if step.contains('.') && self.value_at(&literal).is_none() {
+                        path.extend(step.split('.').filter(|key| !key.is_empty()));
+                    } else {
+                        path = literal;
+                    }
+                }
+            }
+        }
+
+        path
+    }
+
     // TODO make use of this in the new completion engine
     #[allow(dead_code)]
     fn members(&self) -> VecDeque<Value> {
@@ -101,16 +142,12 @@ impl Shell for ValueShell {
         name_tag: Tag,
         _ctrl_c: Arc<AtomicBool>,
     ) -> Result<OutputStream, ShellError> {
-        let mut full_path = PathBuf::from(self.path());
-
-        if let Some(value) = &path {
-            full_path.push(value.as_ref());
-        }
-
-        let mut value_system = ValueStructure::new();
-        value_system.walk_decorate(&self.value)?;
+        let full_path = match &path {
+            Some(value) => self.resolve(&value.item),
+            None => PathBuf::from(self.path()),
+        };
 
-        if !value_system.exists(&full_path) {
+        if self.value_at(&full_path).is_none() {
             if let Some(target) = &path {
                 return Err(ShellError::labeled_error(
                     "Can not list entries inside",
@@ -141,29 +178,16 @@ impl Shell for ValueShell {
             None => "/".to_string(),
             Some(ref v) => {
                 let Tagged { item: target, .. } = v;
-                let mut cwd = PathBuf::from(&self.path);
 
-                if target == &PathBuf::from("..") {
-                    cwd.pop();
-                } else if target == &PathBuf::from("-") {
-                    cwd = PathBuf::from(&self.last_path);
+                if target == &PathBuf::from("-") {
+                    self.last_path.clone()
                 } else {
-                    match target.to_str() {
-                        Some(target) => match target.chars().next() {
-                            Some(x) if x == '/' => cwd = PathBuf::from(target),
-                            _ => cwd.push(target),
-                        },
-                        None => cwd.push(target),
-                    }
+                    self.resolve(target).to_string_lossy().to_string()
                 }
-                cwd.to_string_lossy().to_string()
             }
         };
 
-        let mut value_system = ValueStructure::new();
-        value_system.walk_decorate(&self.value)?;
-
-        if !value_system.exists(&PathBuf::from(&path)) {
+        if self.value_at(&PathBuf::from(&path)).is_none() {
             if let Some(destination) = destination {
                 return Err(ShellError::labeled_error(
                     "Can not change to path inside",
@@ -249,6 +273,7 @@ impl Shell for ValueShell {
         _path: &PathBuf,
         _contents: &[u8],
         _name: Span,
+        _append: bool,
     ) -> Result<OutputStream, ShellError> {
         Err(ShellError::unimplemented(
             "save on help shell is not supported",
@@ -3,7 +3,9 @@ use crate::documentation::get_help;
 use crate::evaluate::block::run_block;
 use crate::evaluation_context::EvaluationContext;
 use crate::example::Example;
+use crate::shell::shell_manager::ShellManager;
 use async_trait::async_trait;
+use futures::StreamExt;
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
 use nu_protocol::hir::Block;
@@ -36,6 +38,27 @@ pub trait WholeStreamCommand: Send + Sync {
     fn examples(&self) -> Vec<Example> {
         Vec::new()
     }
+
+    /// Values to suggest when completing one of the command's arguments, on top of file paths.
+    /// `flag` is the flag the argument is for, or `None` for positional arguments.
+    fn argument_completions(&self, _flag: Option<&str>, _partial: &str) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Moves the shell back to the directory a custom command started in, once its output is done with,
+/// as `cd` in a command only changes the caller's directory with `def --env`.
+struct RestorePath {
+    shell_manager: ShellManager,
+    path: String,
+}
+
+impl Drop for RestorePath {
+    fn drop(&mut self) {
+        if self.shell_manager.path() != self.path {
+            self.shell_manager.set_path(self.path.clone());
+        }
+    }
 }
 
 // Custom commands are blocks, so we can use the information in the block to also
@@ -63,6 +86,7 @@ impl WholeStreamCommand for Block {
 
         let ctx = EvaluationContext::from_args(&args);
         let evaluated = call_info.evaluate(&ctx).await?;
+        let cwd = ctx.shell_manager.path();
 
         let input = args.input;
         ctx.scope.enter_scope();
@@ -150,8 +174,24 @@ impl WholeStreamCommand for Block {
             }
         }
         let result = run_block(&block, &ctx, input).await;
+
+        if block.exports_env {
+            ctx.scope.exit_scope_with_env();
+            return result.map(|x| x.to_output_stream());
+        }
+
         ctx.scope.exit_scope();
-        result.map(|x| x.to_output_stream())
+        let restore = RestorePath {
+            shell_manager: ctx.shell_manager.clone(),
+            path: cwd,
+        };
+        result.map(|x| {
+            x.map(move |value| {
+                let _ = &restore;
+                value
+            })
+            .to_output_stream()
+        })
     }
 
     fn is_binary(&self) -> bool {
@@ -206,6 +246,10 @@ impl Command {
         self.0.examples()
     }
 
+    pub fn argument_completions(&self, flag: Option<&str>, partial: &str) -> Vec<String> {
+        self.0.argument_completions(flag, partial)
+    }
+
     pub async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         if args.call_info.switch_present("help") {
             let cl = self.0.clone();
@@ -462,9 +462,9 @@ impl ShellError {
                     )
                 )
                 .with_labels(
-                    vec![Label::primary(0, argument.span).with_message(
-                        format!("unexpected argument (try {} -h)", &command.item))]
-                ),
+                    vec![Label::primary(0, argument.span).with_message("unexpected argument")]
+                )
+                .with_notes(vec![arguments_hint(&command.item)]),
                 ArgumentError::UnexpectedFlag(flag) => Diagnostic::error().with_message(
                     format!(
                         "{} unexpected {}",
@@ -473,9 +473,9 @@ impl ShellError {
                     ),
                 )
                 .with_labels(vec![
-                    Label::primary(0, flag.span).with_message(
-                    format!("unexpected flag (try {} -h)", &command.item))
-                    ]),
+                    Label::primary(0, flag.span).with_message("unexpected flag")
+                    ])
+                .with_notes(vec![arguments_hint(&command.item)]),
                 ArgumentError::MissingMandatoryFlag(name) => Diagnostic::error().with_message(                    format!(
                         "{} requires {}{}",
                         Color::Cyan.paint(&command.item),
@@ -483,7 +483,8 @@ impl ShellError {
                         Color::Green.bold().paint(name)
                     ),
                 )
-                .with_labels(vec![Label::primary(0, command.span)]),
+                .with_labels(vec![Label::primary(0, command.span)])
+                .with_notes(vec![arguments_hint(&command.item)]),
                 ArgumentError::MissingMandatoryPositional(name) => Diagnostic::error().with_message(
                     format!(
                         "{} requires {} parameter",
@@ -493,7 +494,8 @@ impl ShellError {
                 )
                 .with_labels(
                     vec![Label::primary(0, command.span).with_message(format!("requires {} parameter", name))],
-                ),
+                )
+                .with_notes(vec![arguments_hint(&command.item)]),
                 ArgumentError::MissingValueForName(name) => Diagnostic::error().with_message(
                     format!(
                         "{} is missing value for flag {}{}",
@@ -637,6 +639,45 @@ impl ShellError {
         )
     }
 
+    /// Adds a secondary label to the error, pointing at another part of the source that explains it.
+    pub fn with_label(self, label: impl Into<String>, span: impl Into<Span>) -> ShellError {
+        let label = Label::secondary(0, span.into()).with_message(label.into());
+
+        self.map_diagnostic(|diagnostic| diagnostic.with_labels(vec![label]))
+    }
+
+    /// Adds a hint on how to fix the error, shown under the source.
+    pub fn with_hint(self, hint: impl Into<String>) -> ShellError {
+        let hint = format!("help: {}", hint.into());
+
+        self.map_diagnostic(|diagnostic| diagnostic.with_notes(vec![hint]))
+    }
+
+    /// Sets the code identifying the kind of error, shown next to its severity.
+    pub fn with_code(self, code: impl Into<String>) -> ShellError {
+        let code = code.into();
+
+        self.map_diagnostic(|diagnostic| diagnostic.with_code(code))
+    }
+
+    fn map_diagnostic(
+        self,
+        map: impl FnOnce(Diagnostic<usize>) -> Diagnostic<usize>,
+    ) -> ShellError {
+        let original = self.clone();
+        let cause = self.cause.clone();
+
+        match self.into_diagnostic() {
+            Some(diagnostic) => ShellError {
+                error: ProximateShellError::Diagnostic(ShellDiagnostic {
+                    diagnostic: map(diagnostic),
+                }),
+                cause,
+            },
+            None => original,
+        }
+    }
+
     pub fn unimplemented(title: impl Into<String>) -> ShellError {
         ShellError::untagged_runtime_error(&format!("Unimplemented: {}", title.into()))
     }
@@ -786,6 +827,13 @@ impl HasFallibleSpan for ProximateShellError {
     }
 }
 
+fn arguments_hint(command: &str) -> String {
+    format!(
+        "help: run `{} --help` to see the arguments it takes",
+        command
+    )
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShellDiagnostic {
     pub diagnostic: Diagnostic<usize>,
@@ -805,6 +853,8 @@ impl std::hash::Hash for ShellDiagnostic {
                 codespan_reporting::diagnostic::LabelStyle::Secondary => 1.hash(state),
             }
         }
+
+        self.diagnostic.notes.hash(state);
     }
 }
 
@@ -977,3 +1027,65 @@ macro_rules! ranged_decimal {
 
 ranged_decimal!(f32 -> to_f32 -> F32);
 ranged_decimal!(f64 -> to_f64 -> F64);
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use codespan_reporting::diagnostic::LabelStyle;
+    use nu_source::Span;
+
+    #[test]
+    fn adds_secondary_labels_after_the_primary_one() {
+        let diagnostic =
+            ShellError::labeled_error("Type Error", "expected a number", Span::new(8, 11))
+                .with_label("because of this", Span::new(0, 3))
+                .with_label("and this", Span::new(4, 7))
+                .into_diagnostic()
+                .expect("a diagnostic");
+
+        let labels: Vec<_> = diagnostic
+            .labels
+            .iter()
+            .map(|label| (label.style, label.range.clone(), label.message.as_str()))
+            .collect();
+
+        assert_eq!(
+            labels,
+            vec![
+                (LabelStyle::Primary, 8..11, "expected a number"),
+                (LabelStyle::Secondary, 0..3, "because of this"),
+                (LabelStyle::Secondary, 4..7, "and this"),
+            ]
+        );
+    }
+
+    #[test]
+    fn adds_hints_as_help_notes() {
+        let diagnostic = ShellError::labeled_error("Missing argument", "here", Span::new(0, 4))
+            .with_hint("give it a path")
+            .into_diagnostic()
+            .expect("a diagnostic");
+
+        assert_eq!(diagnostic.notes, vec!["help: give it a path".to_string()]);
+    }
+
+    #[test]
+    fn sets_the_code() {
+        let diagnostic = ShellError::labeled_error("Missing argument", "here", Span::new(0, 4))
+            .with_code("E0001")
+            .into_diagnostic()
+            .expect("a diagnostic");
+
+        assert_eq!(diagnostic.code, Some("E0001".to_string()));
+    }
+
+    #[test]
+    fn leaves_errors_without_a_diagnostic_alone() {
+        let error = ShellError::external_non_zero()
+            .with_label("here", Span::new(0, 4))
+            .with_hint("nowhere to show this")
+            .with_code("E0001");
+
+        assert_eq!(error, ShellError::external_non_zero());
+    }
+}
@@ -305,6 +305,7 @@ impl BlockParser {
         let command = LiteCommand {
             parts: command.into(),
             comments: self.prev_comments.take().map(|c| c.into()),
+            source: None,
         };
 
         self.prev_comment_indent = 0;
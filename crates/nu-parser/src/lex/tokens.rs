@@ -152,6 +152,8 @@ pub struct LiteCommand {
     pub parts: Vec<Spanned<String>>,
     /// Preceding comments.
     pub comments: Option<Vec<LiteComment>>,
+    /// The text of the command as written, kept for definitions.
+    pub source: Option<String>,
 }
 
 impl HasSpan for LiteCommand {
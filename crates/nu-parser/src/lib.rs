@@ -11,8 +11,8 @@ mod scope;
 mod shapes;
 mod signature;
 
-pub use lex::lexer::{block, lex};
-pub use lex::tokens::{LiteBlock, LiteCommand, LiteGroup, LitePipeline};
+pub use lex::lexer::{block, lex, Token};
+pub use lex::tokens::{LiteBlock, LiteCommand, LiteGroup, LitePipeline, TokenContents};
 pub use parse::{classify_block, garbage, parse, parse_full_column_path, parse_math_expression};
 pub use path::expand_ndots;
 pub use scope::ParserScope;
@@ -19,7 +19,7 @@ use crate::scope::ParserScope;
 use bigdecimal::BigDecimal;
 
 use self::{
-    def::{parse_definition, parse_definition_prototype},
+    def::{keep_definition_sources, parse_definition, parse_definition_prototype},
     util::trim_quotes,
     util::verify_and_strip,
 };
@@ -750,6 +750,23 @@ fn parse_arg(
         return parse_dollar_expr(&lite_arg, scope);
     }
 
+    // Parentheses hold a math expression wherever a value is expected, like `echo (2 + 3)`
+    if lite_arg.item.starts_with('(') && lite_arg.item.ends_with(')') {
+        match expected_type {
+            SyntaxShape::Number | SyntaxShape::Int | SyntaxShape::Unit => {
+                return parse_parenthesized_expression(lite_arg, scope, false);
+            }
+            SyntaxShape::Any => {
+                // Anything else in parentheses is left as the text it was
+                let (expr, err) = parse_parenthesized_expression(lite_arg, scope, false);
+                if err.is_none() && matches!(expr.expr, Expression::Binary(_)) {
+                    return (expr, err);
+                }
+            }
+            _ => {}
+        }
+    }
+
     match expected_type {
         SyntaxShape::Number => {
             if let Ok(x) = lite_arg.item.parse::<BigInt>() {
@@ -902,22 +919,45 @@ fn parse_arg(
                 (Some('{'), Some('}')) => {
                     // We have a literal block
                     let string: String = chars.collect();
+                    let (params, body_start) = block_params(&string).unwrap_or((vec![], 0));
 
                     // We haven't done much with the inner string, so let's go ahead and work with it
-                    let (tokens, err) = lex(&string, lite_arg.span.start() + 1);
+                    let (tokens, err) = lex(
+                        &string[body_start..],
+                        lite_arg.span.start() + 1 + body_start,
+                    );
                     if err.is_some() {
                         return (garbage(lite_arg.span), err);
                     }
 
-                    let (lite_block, err) = block(tokens);
+                    let (mut lite_block, err) = block(tokens);
                     if err.is_some() {
                         return (garbage(lite_arg.span), err);
                     }
+                    keep_definition_sources(
+                        &mut lite_block,
+                        &string[body_start..],
+                        lite_arg.span.start() + 1 + body_start,
+                    );
 
                     scope.enter_scope();
-                    let (classified_block, err) = classify_block(&lite_block, scope);
+                    let (mut classified_block, err) = classify_block(&lite_block, scope);
                     scope.exit_scope();
 
+                    classified_block.source = Some(lite_arg.item.clone());
+                    if !params.is_empty() {
+                        classified_block.span = lite_arg.span;
+                        classified_block.params.positional = params
+                            .into_iter()
+                            .map(|name| {
+                                (
+                                    PositionalType::Mandatory(name.clone(), SyntaxShape::Any),
+                                    name,
+                                )
+                            })
+                            .collect();
+                    }
+
                     (
                         SpannedExpression::new(Expression::Block(classified_block), lite_arg.span),
                         err,
@@ -936,6 +976,32 @@ fn parse_arg(
     }
 }
 
+/// The parameters of a block like `{|row, old| ...}`, named with the `$` they're used with, and
+/// where the body of the block starts after them.
+fn block_params(inner: &str) -> Option<(Vec<String>, usize)> {
+    let params = inner.trim_start().strip_prefix('|')?;
+    let end = params.find('|')?;
+
+    let names = params[..end]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .map(|name| name.trim_start_matches('$'))
+        .map(|name| {
+            if !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c: char| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                Some(format!("${}", name))
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((names, inner.len() - params.len() + end + 1))
+}
+
 /*
 #[cfg(test)]
 mod test {
@@ -1670,6 +1736,32 @@ fn parse_value_call(
     )
 }
 
+/// Whether the command is a math expression without the `=` in front, like `$it.size / 2` or
+/// `(1 + 2) * 3`, as it starts with a value followed by an operator.
+fn starts_math_expression(lite_cmd: &LiteCommand) -> bool {
+    let head = &lite_cmd.parts[0].item;
+    let is_value = head.starts_with('$') || (head.starts_with('(') && head.ends_with(')'));
+
+    match lite_cmd.parts.get(1) {
+        Some(operator) if is_value => parse_operator(operator).1.is_none(),
+        None => head.starts_with('(') && head.ends_with(')'),
+        _ => false,
+    }
+}
+
+/// A range on its own, like `1..10 | each { ... }`, which is a value rather than a command to run.
+/// Leading dots are left alone, as `..` and `...` move up directories.
+fn parse_range_call(lite_cmd: &LiteCommand, scope: &dyn ParserScope) -> Option<SpannedExpression> {
+    if lite_cmd.parts.len() != 1 || lite_cmd.parts[0].item.starts_with('.') {
+        return None;
+    }
+
+    match parse_range(&lite_cmd.parts[0], scope) {
+        (range, None) => Some(range),
+        _ => None,
+    }
+}
+
 fn expand_aliases_in_call(call: &mut LiteCommand, scope: &dyn ParserScope) {
     if let Some(name) = call.parts.get(0) {
         if let Some(mut expansion) = scope.get_alias(name) {
@@ -1740,6 +1832,12 @@ fn parse_call(
             })),
             error,
         );
+    } else if starts_math_expression(&lite_cmd) {
+        let (_, expr, err) = parse_math_expression(0, &lite_cmd.parts[..], scope, false);
+        error = error.or(err);
+        return (Some(ClassifiedCommand::Expr(Box::new(expr))), error);
+    } else if let Some(range) = parse_range_call(&lite_cmd, scope) {
+        return (Some(ClassifiedCommand::Expr(Box::new(range))), None);
     } else if lite_cmd.parts[0].item.starts_with('$') || lite_cmd.parts[0].item.starts_with('{') {
         return parse_value_call(lite_cmd, scope);
     } else if lite_cmd.parts[0].item == "=" {
@@ -1757,7 +1855,7 @@ fn parse_call(
             garbage(lite_cmd.span())
         };
         return (Some(ClassifiedCommand::Expr(Box::new(expr))), error);
-    } else if lite_cmd.parts[0].item == "alias" {
+    } else if lite_cmd.parts[0].item == "alias" && !is_alias_import(&lite_cmd) {
         let error = parse_alias(&lite_cmd, scope);
         if error.is_none() {
             return (None, None);
@@ -1843,6 +1941,105 @@ fn parse_call(
 /// Convert a lite-ly parsed pipeline into a fully classified pipeline, ready to be evaluated.
 /// This conversion does error-recovery, so the result is allowed to be lossy. A lossy unit is designated as garbage.
 /// Errors are returned as part of a side-car error rather than a Result to allow both error and lossy result simultaneously.
+/// Whether `parse_call` will classify the given command as a call to an external.
+fn is_external_call(lite_cmd: &LiteCommand, scope: &dyn ParserScope) -> bool {
+    let name = match lite_cmd.parts.get(0) {
+        Some(name) => name,
+        None => return false,
+    };
+
+    let name = scope
+        .get_alias(&name.item)
+        .and_then(|expansion| expansion.into_iter().next())
+        .map(|head| head.item)
+        .unwrap_or_else(|| name.item.clone());
+
+    if name.starts_with('^') {
+        return true;
+    } else if name.starts_with('$')
+        || name.starts_with('{')
+        || name == "="
+        || name == "alias"
+        || name == "source"
+    {
+        return false;
+    }
+
+    if let Some(sub) = lite_cmd.parts.get(1) {
+        if scope.has_signature(&format!("{} {}", name, sub.item)) {
+            return false;
+        }
+    }
+
+    !scope.has_signature(&name)
+}
+
+/// Removes the first redirection using one of the given operators from a command and returns the
+/// operator together with its target.
+fn take_redirection(
+    lite_cmd: &mut LiteCommand,
+    operators: &[&str],
+) -> Result<Option<(Spanned<String>, Spanned<String>)>, ParseError> {
+    let position = match lite_cmd
+        .parts
+        .iter()
+        .skip(1)
+        .position(|part| operators.contains(&part.item.as_str()))
+    {
+        Some(position) => position + 1,
+        None => return Ok(None),
+    };
+
+    if position + 1 >= lite_cmd.parts.len() {
+        return Err(ParseError::argument_error(
+            lite_cmd.parts[position].clone(),
+            ArgumentError::MissingMandatoryPositional("a target for the redirection".into()),
+        ));
+    }
+
+    let target = lite_cmd.parts.remove(position + 1);
+    let operator = lite_cmd.parts.remove(position);
+
+    Ok(Some((operator, target)))
+}
+
+/// The command producing the stdin of an input redirection: `open --raw file` for `<` and
+/// `echo expression` for `in<`.
+fn input_redirection_source(operator: &Spanned<String>, source: Spanned<String>) -> LiteCommand {
+    let span = operator.span;
+    let parts = if operator.item == "<" {
+        vec![
+            "open".to_string().spanned(span),
+            "--raw".to_string().spanned(span),
+            source,
+        ]
+    } else {
+        vec!["echo".to_string().spanned(span), source]
+    };
+
+    LiteCommand {
+        parts,
+        comments: None,
+        source: None,
+    }
+}
+
+/// The command appending the output of an `out>>` redirection to its target.
+fn append_redirection_sink(operator: &Spanned<String>, target: Spanned<String>) -> LiteCommand {
+    let span = operator.span;
+
+    LiteCommand {
+        parts: vec![
+            "save".to_string().spanned(span),
+            "--append".to_string().spanned(span),
+            "--raw".to_string().spanned(span),
+            target,
+        ],
+        comments: None,
+        source: None,
+    }
+}
+
 fn parse_pipeline(
     lite_pipeline: LitePipeline,
     scope: &dyn ParserScope,
@@ -1851,14 +2048,80 @@ fn parse_pipeline(
     let mut error = None;
 
     let mut iter = lite_pipeline.commands.into_iter().peekable();
-    while let Some(lite_cmd) = iter.next() {
-        let (call, err) = parse_call(lite_cmd, iter.peek().is_none(), scope);
-        if error.is_none() {
-            error = err;
+    let mut first = true;
+    while let Some(mut lite_cmd) = iter.next() {
+        let end_of_pipeline = iter.peek().is_none();
+        let is_external = is_external_call(&lite_cmd, scope);
+
+        if is_external {
+            match take_redirection(&mut lite_cmd, &["<", "in<"]) {
+                Ok(Some((operator, source))) if first => {
+                    let source = input_redirection_source(&operator, source);
+                    let (call, err) = parse_call(source, false, scope);
+                    error = error.or(err);
+                    if let Some(call) = call {
+                        commands.push(call);
+                    }
+                }
+                Ok(Some((operator, _))) => {
+                    error = error.or_else(|| {
+                        Some(ParseError::mismatch(
+                            "input redirection only on the first command of a pipeline",
+                            operator,
+                        ))
+                    });
+                }
+                Ok(None) => {}
+                Err(err) => error = error.or(Some(err)),
+            }
         }
-        if let Some(call) = call {
+        first = false;
+
+        // External commands append their stderr to the target, and internal ones their errors
+        let stderr_target = match take_redirection(&mut lite_cmd, &["err>>"]) {
+            Ok(Some((operator, target))) => {
+                let (target, err) = parse_arg(SyntaxShape::FilePath, scope, &target);
+                error = error.or(err);
+                Some((operator, target))
+            }
+            Ok(None) => None,
+            Err(err) => {
+                error = error.or(Some(err));
+                None
+            }
+        };
+
+        let stdout_target = match take_redirection(&mut lite_cmd, &["out>>"]) {
+            Ok(target) => target,
+            Err(err) => {
+                error = error.or(Some(err));
+                None
+            }
+        };
+
+        let (call, err) = parse_call(lite_cmd, end_of_pipeline && stdout_target.is_none(), scope);
+        error = error.or(err);
+        if let Some(mut call) = call {
+            if let (Some((operator, target)), ClassifiedCommand::Internal(internal)) =
+                (stderr_target, &mut call)
+            {
+                internal
+                    .args
+                    .named
+                    .get_or_insert_with(NamedArguments::new)
+                    .insert_mandatory("append-stderr", operator.span, target);
+            }
             commands.push(call);
         }
+
+        if let Some((operator, target)) = stdout_target {
+            let sink = append_redirection_sink(&operator, target);
+            let (call, err) = parse_call(sink, end_of_pipeline, scope);
+            error = error.or(err);
+            if let Some(call) = call {
+                commands.push(call);
+            }
+        }
     }
 
     (commands, error)
@@ -1994,6 +2257,12 @@ fn expand_shorthand_forms(
 //     ClassifiedBlock::new(block, error)
 // }
 
+/// `alias import` is a command of its own, unless it's defining an alias named `import`.
+fn is_alias_import(call: &LiteCommand) -> bool {
+    matches!(call.parts.get(1), Some(part) if part.item == "import")
+        && !matches!(call.parts.get(2), Some(part) if part.item == "=")
+}
+
 fn parse_alias(call: &LiteCommand, scope: &dyn ParserScope) -> Option<ParseError> {
     if call.parts.len() < 4 {
         return Some(ParseError::mismatch("alias", call.parts[0].clone()));
@@ -2129,10 +2398,11 @@ pub fn parse(
     if error.is_some() {
         return (Block::basic(), error);
     }
-    let (lite_block, error) = block(output);
+    let (mut lite_block, error) = block(output);
     if error.is_some() {
         return (Block::basic(), error);
     }
+    keep_definition_sources(&mut lite_block, input, span_offset);
 
     classify_block(&lite_block, scope)
 }
@@ -2240,3 +2510,16 @@ fn unit_parse_byte_units() -> Result<(), ParseError> {
     }
     Ok(())
 }
+
+#[test]
+fn parse_block_params() {
+    assert_eq!(
+        block_params("|row, old| echo $old"),
+        Some((vec!["$row".to_string(), "$old".to_string()], 10))
+    );
+    assert_eq!(
+        block_params(" |$x| = $x + 1"),
+        Some((vec!["$x".to_string()], 5))
+    );
+    assert_eq!(block_params(" echo $it | count"), None);
+}
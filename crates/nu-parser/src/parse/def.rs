@@ -1,5 +1,5 @@
 use crate::{
-    lex::tokens::LiteCommand,
+    lex::tokens::{LiteBlock, LiteCommand},
     parse::{classify_block, util::trim_quotes},
 };
 
@@ -20,10 +20,45 @@ mod primitives;
 mod signature;
 mod tests;
 
+/// Takes the `--env` switch out of a definition, returning whether it was there.
+fn without_env_flag(call: &LiteCommand) -> (LiteCommand, bool) {
+    let mut call = call.clone();
+    let len = call.parts.len();
+    call.parts.retain(|part| part.item != "--env");
+
+    let exports_env = call.parts.len() != len;
+    (call, exports_env)
+}
+
+/// Keeps the text of each definition of the block as it was written. The source is the text the
+/// block was lexed from, starting at the span offset.
+pub(crate) fn keep_definition_sources(
+    lite_block: &mut LiteBlock,
+    source: &str,
+    span_offset: usize,
+) {
+    for group in lite_block.block.iter_mut() {
+        for pipeline in group.pipelines.iter_mut() {
+            for call in pipeline.commands.iter_mut() {
+                if call.parts.first().map(|part| part.item.as_str()) != Some("def") {
+                    continue;
+                }
+
+                let span = call.span();
+                call.source = source
+                    .get(span.start() - span_offset..span.end() - span_offset)
+                    .map(|text| text.to_string());
+            }
+        }
+    }
+}
+
 pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> Option<ParseError> {
     // A this point, we've already handled the prototype and put it into scope;
     // So our main goal here is to parse the block now that the names and
     // prototypes of adjacent commands are also available
+    let (call, exports_env) = without_env_flag(call);
+    let call = &call;
 
     if call.parts.len() == 4 {
         if call.parts.len() != 4 {
@@ -56,16 +91,20 @@ pub(crate) fn parse_definition(call: &LiteCommand, scope: &dyn ParserScope) -> O
                 if err.is_some() {
                     return err;
                 };
-                let (lite_block, err) = block(tokens);
+                let (mut lite_block, err) = block(tokens);
                 if err.is_some() {
                     return err;
                 };
+                keep_definition_sources(&mut lite_block, &string, call.parts[3].span.start() + 1);
 
                 let (mut block, err) = classify_block(&lite_block, scope);
                 scope.exit_scope();
 
                 block.params = signature;
                 block.params.name = name;
+                block.exports_env = exports_env;
+                block.source = call.source.clone();
+                block.span = call.span();
 
                 scope.add_definition(block);
 
@@ -85,6 +124,7 @@ pub(crate) fn parse_definition_prototype(
     scope: &dyn ParserScope,
 ) -> Option<ParseError> {
     let mut err = None;
+    let (call, exports_env) = without_env_flag(call);
 
     if call.parts.len() != 4 {
         return Some(ParseError::mismatch("definition", call.parts[0].clone()));
@@ -100,7 +140,9 @@ pub(crate) fn parse_definition_prototype(
         err = error;
     }
 
-    scope.add_definition(Block::new(signature, vec![], IndexMap::new(), call.span()));
+    let mut prototype = Block::new(signature, vec![], IndexMap::new(), call.span());
+    prototype.exports_env = exports_env;
+    scope.add_definition(prototype);
 
     err
 }
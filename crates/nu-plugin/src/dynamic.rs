@@ -0,0 +1,316 @@
+//! Plugins loaded into nu as shared libraries, instead of being started as processes. For plugins
+//! called on many values, this saves sending each of them to another process and back.
+//!
+//! A `cdylib` crate exports its plugins with [`export_plugins!`](crate::export_plugins), and is
+//! loaded when named `nu_plugin_*` (`libnu_plugin_*.so` on Linux, for instance) and kept in nu's
+//! directory or one of the `plugin_dirs`. Unlike plugin programs, libraries on the PATH aren't
+//! loaded, since they run inside nu. Rust types have no stable layout between compilers, so calls
+//! cross the library boundary through C functions, with the same JSON messages as plugins running
+//! as processes. Values are sent in batches, so the messages are few even for long streams.
+
+use crate::jsonrpc::NuCommand;
+use crate::Plugin;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, UntaggedValue};
+use serde::Serialize;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null_mut;
+
+/// Changes whenever the functions exported by [`export_plugins!`](crate::export_plugins) do, so nu
+/// doesn't load libraries built for another version.
+pub const ABI_VERSION: u32 = 1;
+
+/// Creates a plugin. A library is called from any of nu's threads, so its plugins must be `Send`.
+pub type Constructor = fn() -> Box<dyn Plugin + Send>;
+
+/// The JSON list of the signatures of the plugins, or null if creating one of them panicked.
+pub fn signatures(constructors: &[Constructor]) -> *mut c_char {
+    // A panic can't unwind into nu, which may not even be built with the same compiler
+    catch_unwind(|| {
+        let signatures: Vec<Signature> = constructors
+            .iter()
+            .filter_map(|new| new().config().ok())
+            .collect();
+
+        to_c_string(&signatures)
+    })
+    .unwrap_or_else(|_| null_mut())
+}
+
+/// A new instance of the plugin with the name, or null if the library has no such plugin or
+/// creating it panicked.
+///
+/// # Safety
+///
+/// `name` must be a valid C string.
+pub unsafe fn new_instance(constructors: &[Constructor], name: *const c_char) -> *mut c_void {
+    let name = CStr::from_ptr(name).to_string_lossy();
+
+    catch_unwind(|| {
+        for new in constructors {
+            let mut plugin = new();
+            if matches!(plugin.config(), Ok(signature) if signature.name == name) {
+                return Box::into_raw(Box::new(plugin)) as *mut c_void;
+            }
+        }
+
+        null_mut()
+    })
+    .unwrap_or_else(|_| null_mut())
+}
+
+/// Runs a JSON request on a plugin, returning the JSON of what it returned.
+///
+/// # Safety
+///
+/// `instance` must come from [`new_instance`] and not be dropped yet, and `request` must be a valid
+/// C string.
+pub unsafe fn call(instance: *mut c_void, request: *const c_char) -> *mut c_char {
+    let plugin = &mut *(instance as *mut Box<dyn Plugin + Send>);
+    let request = CStr::from_ptr(request).to_string_lossy();
+
+    let response = catch_unwind(AssertUnwindSafe(|| run(plugin.as_mut(), &request)))
+        .unwrap_or_else(|_| Err(ShellError::untagged_runtime_error("The plugin panicked")));
+
+    to_c_string(&response)
+}
+
+fn run(plugin: &mut dyn Plugin, request: &str) -> Result<Vec<ReturnValue>, ShellError> {
+    match serde_json::from_str::<NuCommand>(request) {
+        Ok(NuCommand::begin_filter { params }) => plugin.begin_filter(params),
+        Ok(NuCommand::filter { params }) => plugin.filter(params),
+        Ok(NuCommand::filter_batch { params }) => plugin.filter_batch(params),
+        Ok(NuCommand::end_filter) => plugin.end_filter(),
+        Ok(NuCommand::sink { params }) => {
+            plugin.sink(params.0, params.1);
+            Ok(vec![])
+        }
+        Ok(NuCommand::quit) => {
+            plugin.quit();
+            Ok(vec![])
+        }
+        Ok(NuCommand::complete { params }) => Ok(plugin
+            .complete(params.0, params.1)
+            .into_iter()
+            .map(|completion| ReturnSuccess::value(UntaggedValue::string(completion)))
+            .collect()),
+        Ok(NuCommand::config) => Err(ShellError::untagged_runtime_error(
+            "Signatures are read once for the whole library",
+        )),
+        Err(err) => Err(ShellError::untagged_runtime_error(format!(
+            "Invalid request to the plugin: {}",
+            err
+        ))),
+    }
+}
+
+/// A plugin panicking as it's dropped is leaked rather than unwinding into nu.
+///
+/// # Safety
+///
+/// `instance` must come from [`new_instance`], and not be used after.
+pub unsafe fn drop_instance(instance: *mut c_void) {
+    if !instance.is_null() {
+        let plugin = Box::from_raw(instance as *mut Box<dyn Plugin + Send>);
+        let _ = catch_unwind(AssertUnwindSafe(|| drop(plugin)));
+    }
+}
+
+/// Strings returned by the library are freed by it, since nu may use another allocator.
+///
+/// # Safety
+///
+/// `string` must have been returned by the library, and not be used after.
+pub unsafe fn free_string(string: *mut c_char) {
+    if !string.is_null() {
+        let string = CString::from_raw(string);
+        let _ = catch_unwind(AssertUnwindSafe(|| drop(string)));
+    }
+}
+
+fn to_c_string<T: Serialize>(value: &T) -> *mut c_char {
+    // JSON escapes nul characters, so the string can't contain any
+    serde_json::to_string(value)
+        .ok()
+        .and_then(|json| CString::new(json).ok())
+        .map(CString::into_raw)
+        .unwrap_or_else(null_mut)
+}
+
+/// Exports plugins from a `cdylib` crate, given an expression creating each of them.
+///
+/// ```ignore
+/// nu_plugin::export_plugins!(Len::new(), Sum::new());
+/// ```
+#[macro_export]
+macro_rules! export_plugins {
+    ($($constructor:expr),+ $(,)?) => {
+        fn __nu_plugin_constructors() -> ::std::vec::Vec<$crate::dynamic::Constructor> {
+            let mut constructors: ::std::vec::Vec<$crate::dynamic::Constructor> =
+                ::std::vec::Vec::new();
+            $(
+                constructors.push(|| -> ::std::boxed::Box<dyn $crate::Plugin + Send> {
+                    ::std::boxed::Box::new($constructor)
+                });
+            )+
+            constructors
+        }
+
+        #[no_mangle]
+        pub extern "C" fn nu_plugin_abi_version() -> u32 {
+            $crate::dynamic::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn nu_plugin_signatures() -> *mut ::std::os::raw::c_char {
+            $crate::dynamic::signatures(&__nu_plugin_constructors())
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn nu_plugin_new(
+            name: *const ::std::os::raw::c_char,
+        ) -> *mut ::std::os::raw::c_void {
+            $crate::dynamic::new_instance(&__nu_plugin_constructors(), name)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn nu_plugin_call(
+            instance: *mut ::std::os::raw::c_void,
+            request: *const ::std::os::raw::c_char,
+        ) -> *mut ::std::os::raw::c_char {
+            $crate::dynamic::call(instance, request)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn nu_plugin_drop(instance: *mut ::std::os::raw::c_void) {
+            $crate::dynamic::drop_instance(instance)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn nu_plugin_free_string(string: *mut ::std::os::raw::c_char) {
+            $crate::dynamic::free_string(string)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{call, drop_instance, free_string, new_instance, signatures, Constructor};
+    use crate::jsonrpc::NuCommand;
+    use crate::Plugin;
+    use nu_errors::ShellError;
+    use nu_protocol::{ReturnSuccess, ReturnValue, Signature, UntaggedValue, Value};
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    struct Double;
+
+    impl Plugin for Double {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("double").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            let doubled = input.as_i64()? * 2;
+            Ok(vec![ReturnSuccess::value(UntaggedValue::int(doubled))])
+        }
+    }
+
+    struct Broken;
+
+    impl Plugin for Broken {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            panic!("no signature")
+        }
+    }
+
+    fn constructors() -> Vec<Constructor> {
+        let double: Constructor = || Box::new(Double);
+        vec![double]
+    }
+
+    unsafe fn read(string: *mut c_char) -> String {
+        let read = CStr::from_ptr(string).to_string_lossy().to_string();
+        free_string(string);
+        read
+    }
+
+    #[test]
+    fn lists_the_signatures() {
+        let json = unsafe { read(signatures(&constructors())) };
+        let signatures: Vec<Signature> = serde_json::from_str(&json).expect("signatures");
+
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(signatures[0].name, "double");
+    }
+
+    #[test]
+    fn keeps_panics_in_the_library() {
+        let broken: Constructor = || Box::new(Broken);
+        let name = CString::new("broken").expect("a name");
+
+        assert!(signatures(&[broken]).is_null());
+        assert!(unsafe { new_instance(&[broken], name.as_ptr()) }.is_null());
+    }
+
+    #[test]
+    fn calls_the_plugin_by_name() {
+        unsafe {
+            let unknown = CString::new("triple").expect("a name");
+            assert!(new_instance(&constructors(), unknown.as_ptr()).is_null());
+
+            let name = CString::new("double").expect("a name");
+            let instance = new_instance(&constructors(), name.as_ptr());
+            assert!(!instance.is_null());
+
+            let request = serde_json::to_string(&NuCommand::filter {
+                params: UntaggedValue::int(21).into_untagged_value(),
+            })
+            .expect("a request");
+            let request = CString::new(request).expect("a request");
+            let response = read(call(instance, request.as_ptr()));
+            drop_instance(instance);
+
+            let response: Result<Vec<ReturnValue>, ShellError> =
+                serde_json::from_str(&response).expect("a response");
+            let values = response.expect("values");
+            match &values[0] {
+                Ok(ReturnSuccess::Value(value)) => assert_eq!(value.as_i64().ok(), Some(42)),
+                _ => panic!("expected a value"),
+            }
+        }
+    }
+
+    #[test]
+    fn filters_batches_one_value_at_a_time_by_default() {
+        unsafe {
+            let name = CString::new("double").expect("a name");
+            let instance = new_instance(&constructors(), name.as_ptr());
+
+            let request = serde_json::to_string(&NuCommand::filter_batch {
+                params: vec![
+                    UntaggedValue::int(1).into_untagged_value(),
+                    UntaggedValue::int(2).into_untagged_value(),
+                ],
+            })
+            .expect("a request");
+            let request = CString::new(request).expect("a request");
+            let response = read(call(instance, request.as_ptr()));
+            drop_instance(instance);
+
+            let response: Result<Vec<ReturnValue>, ShellError> =
+                serde_json::from_str(&response).expect("a response");
+            let doubled: Vec<i64> = response
+                .expect("values")
+                .into_iter()
+                .filter_map(|value| match value {
+                    Ok(ReturnSuccess::Value(value)) => value.as_i64().ok(),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(doubled, vec![2, 4]);
+        }
+    }
+}
@@ -42,7 +42,9 @@ pub enum NuCommand {
     config,
     begin_filter { params: CallInfo },
     filter { params: Value },
+    filter_batch { params: Vec<Value> },
     end_filter,
     sink { params: (CallInfo, Vec<Value>) },
+    complete { params: (Option<String>, String) },
     quit,
 }
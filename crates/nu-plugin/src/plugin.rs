@@ -24,6 +24,16 @@ pub trait Plugin {
         Ok(vec![])
     }
 
+    /// `filter_batch` is called with the next values in the stream when the plugin `Signature` is configured
+    /// with `filter_batch`. By default, it calls `filter` for each of them in order.
+    fn filter_batch(&mut self, input: Vec<Value>) -> Result<Vec<ReturnValue>, ShellError> {
+        let mut output = vec![];
+        for value in input {
+            output.extend(self.filter(value)?);
+        }
+        Ok(output)
+    }
+
     /// `end_filter` is the last method to be called by the plugin after all `Value`s are processed by the plugin.
     /// This method requires the plugin `Signature` to be configured as filterable.
     fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
@@ -34,6 +44,12 @@ pub trait Plugin {
     /// This method requires the plugin `Signature` to be configured without filtering.
     fn sink(&mut self, _call_info: CallInfo, _input: Vec<Value>) {}
 
+    /// `complete` gives the values to suggest when completing an argument of the plugin, on top of file paths.
+    /// `flag` is the flag the argument is for, or `None` for positional arguments, and `partial` is what was typed so far.
+    fn complete(&mut self, _flag: Option<String>, _partial: String) -> Vec<String> {
+        vec![]
+    }
+
     fn quit(&mut self) {}
 }
 
@@ -63,6 +79,9 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
                 Ok(NuCommand::filter { params }) => {
                     send_response(plugin.filter(params));
                 }
+                Ok(NuCommand::filter_batch { params }) => {
+                    send_response(plugin.filter_batch(params));
+                }
                 Ok(NuCommand::end_filter) => {
                     send_response(plugin.end_filter());
                     return;
@@ -72,6 +91,10 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
                     plugin.sink(params.0, params.1);
                     return;
                 }
+                Ok(NuCommand::complete { params }) => {
+                    send_response(plugin.complete(params.0, params.1));
+                    return;
+                }
                 Ok(NuCommand::quit) => {
                     plugin.quit();
                     return;
@@ -102,6 +125,9 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
                         Ok(NuCommand::filter { params }) => {
                             send_response(plugin.filter(params));
                         }
+                        Ok(NuCommand::filter_batch { params }) => {
+                            send_response(plugin.filter_batch(params));
+                        }
                         Ok(NuCommand::end_filter) => {
                             send_response(plugin.end_filter());
                             break;
@@ -110,6 +136,10 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
                             plugin.sink(params.0, params.1);
                             break;
                         }
+                        Ok(NuCommand::complete { params }) => {
+                            send_response(plugin.complete(params.0, params.1));
+                            break;
+                        }
                         Ok(NuCommand::quit) => {
                             plugin.quit();
                             break;
@@ -45,13 +45,18 @@ impl EvaluatedArgs {
 
     /// Get the nth positional argument, error if not possible
     pub fn expect_nth(&self, pos: usize) -> Result<&Value, ShellError> {
-        match &self.positional {
-            None => Err(ShellError::unimplemented("Better error: expect_nth")),
-            Some(array) => match array.get(pos) {
-                None => Err(ShellError::unimplemented("Better error: expect_nth")),
-                Some(item) => Ok(item),
-            },
-        }
+        self.nth(pos).ok_or_else(|| {
+            // Without the name of the command, the closest place to point at is the last argument
+            match pos.checked_sub(1).and_then(|last| self.nth(last)) {
+                Some(last) => ShellError::labeled_error(
+                    "Missing argument",
+                    format!("expected argument {} after this one", pos + 1),
+                    last.tag.span,
+                ),
+                None => ShellError::untagged_runtime_error(format!("Missing argument {}", pos + 1)),
+            }
+            .with_hint("run the command with --help to see the arguments it takes")
+        })
     }
 
     /// Get the number of positional arguments available
@@ -175,6 +175,16 @@ pub struct Block {
     pub block: Vec<Group>,
     pub definitions: IndexMap<String, Block>,
     pub span: Span,
+    /// The text of a literal block or `def`, as it was written
+    #[serde(default)]
+    pub source: Option<String>,
+    /// The script the block was sourced from, if any
+    #[serde(default)]
+    pub source_file: Option<String>,
+    /// Whether a custom command keeps its changes to the environment and the current directory
+    /// for its caller, as with `def --env`
+    #[serde(default)]
+    pub exports_env: bool,
 }
 
 impl Block {
@@ -189,6 +199,9 @@ impl Block {
             block,
             definitions,
             span,
+            source: None,
+            source_file: None,
+            exports_env: false,
         }
     }
 
@@ -198,6 +211,9 @@ impl Block {
             block: vec![],
             definitions: IndexMap::new(),
             span: Span::unknown(),
+            source: None,
+            source_file: None,
+            exports_env: false,
         }
     }
 
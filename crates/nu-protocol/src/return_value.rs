@@ -8,8 +8,8 @@ use serde::{Deserialize, Serialize};
 pub enum CommandAction {
     /// Change to a new directory or path (in non-filesystem situations)
     ChangePath(String),
-    /// Exit out of Nu
-    Exit,
+    /// Exit out of Nu with the given exit code
+    Exit(i32),
     /// Display an error
     Error(ShellError),
     /// Enter a new shell at the given path
@@ -22,12 +22,14 @@ pub enum CommandAction {
     EnterHelpShell(Value),
     /// Add plugins from path given
     AddPlugins(String),
+    /// Search for plugins again, asking every plugin for its signature instead of using the cached ones
+    RefreshPlugins,
     /// Go to the previous shell in the shell ring buffer
     PreviousShell,
     /// Go to the next shell in the shell ring buffer
     NextShell,
-    /// Leave the current shell. If it's the last shell, exit out of Nu
-    LeaveShell,
+    /// Leave the current shell. If it's the last shell, exit out of Nu with the given exit code
+    LeaveShell(i32),
 }
 
 impl PrettyDebug for CommandAction {
@@ -37,7 +39,7 @@ impl PrettyDebug for CommandAction {
             CommandAction::ChangePath(path) => {
                 DbgDocBldr::typed("change path", DbgDocBldr::description(path))
             }
-            CommandAction::Exit => DbgDocBldr::description("exit"),
+            CommandAction::Exit(_) => DbgDocBldr::description("exit"),
             CommandAction::Error(_) => DbgDocBldr::error("error"),
             CommandAction::AutoConvert(_, extension) => {
                 DbgDocBldr::typed("auto convert", DbgDocBldr::description(extension))
@@ -48,9 +50,10 @@ impl PrettyDebug for CommandAction {
             CommandAction::EnterValueShell(v) => DbgDocBldr::typed("enter value shell", v.pretty()),
             CommandAction::EnterHelpShell(v) => DbgDocBldr::typed("enter help shell", v.pretty()),
             CommandAction::AddPlugins(..) => DbgDocBldr::description("add plugins"),
+            CommandAction::RefreshPlugins => DbgDocBldr::description("refresh plugins"),
             CommandAction::PreviousShell => DbgDocBldr::description("previous shell"),
             CommandAction::NextShell => DbgDocBldr::description("next shell"),
-            CommandAction::LeaveShell => DbgDocBldr::description("leave shell"),
+            CommandAction::LeaveShell(_) => DbgDocBldr::description("leave shell"),
         }
     }
 }
@@ -121,6 +121,9 @@ pub struct Signature {
     pub input: Option<Type>,
     /// If the command is expected to filter data, or to consume it (as a sink)
     pub is_filter: bool,
+    /// The most values a plugin filter is sent at once, if it takes them in batches
+    #[serde(default)]
+    pub batch_size: Option<usize>,
 }
 
 impl PartialEq for Signature {
@@ -196,6 +199,7 @@ impl Signature {
             rest_positional: None,
             named: indexmap::indexmap! {"help".into() => (NamedType::Switch(Some('h')), "Display this help message".into())},
             is_filter: false,
+            batch_size: None,
             yields: None,
             input: None,
         }
@@ -309,6 +313,14 @@ impl Signature {
         self
     }
 
+    /// Set the filter flag for the signature, with values sent to the plugin in batches of up to
+    /// `size`. Only the values already in the stream are batched, so a slow stream isn't waited on.
+    pub fn filter_batch(mut self, size: usize) -> Signature {
+        self.is_filter = true;
+        self.batch_size = Some(size.max(1));
+        self
+    }
+
     /// Set the type for the "rest" of the positional arguments
     /// Note: Not naming the field in your struct holding the rest values "rest", can
     /// cause errors when deserializing
@@ -208,7 +208,15 @@ impl UntaggedValue {
             UntaggedValue::from("NaN")
         } else {
             match dec {
-                Some(dec) => UntaggedValue::Primitive(Primitive::Decimal(dec)),
+                // Floats come with as many digits as they can hold, most often trailing zeros
+                Some(dec) => {
+                    let dec = dec.normalized();
+                    if dec.as_bigint_and_exponent().1 < 1 {
+                        UntaggedValue::Primitive(Primitive::Decimal(dec.with_scale(1)))
+                    } else {
+                        UntaggedValue::Primitive(Primitive::Decimal(dec))
+                    }
+                }
                 None => UntaggedValue::Error(ShellError::labeled_error(
                     "Can not convert f64 to big decimal",
                     "can not create decimal",
@@ -22,15 +22,67 @@ pub fn did_you_mean(obj_source: &Value, field_tried: String) -> Option<Vec<Strin
     }
 }
 
-/// Borrowed from https://crates.io/crates/natural
-fn levenshtein_distance(str1: &str, str2: &str) -> usize {
-    let n = str1.len();
-    let m = str2.len();
+/// The names close enough to the one tried to be a typo of it, closest first
+pub fn closest_names(tried: &str, names: impl IntoIterator<Item = String>) -> Vec<String> {
+    // Allow one typo for short names, and one more for every three characters after that
+    let allowed = cmp::max(1, tried.chars().count() / 3);
 
-    let mut current: Vec<usize> = (0..n + 1).collect();
+    let mut close: Vec<_> = names
+        .into_iter()
+        .map(|name| (typo_distance(tried, &name), name))
+        .filter(|(distance, _)| *distance <= allowed)
+        .collect();
+
+    close.sort();
+    close.dedup();
+    close.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Like `levenshtein_distance`, but swapping two neighbouring characters counts as a single edit,
+/// since it's such a common typo (`sl` for `ls`).
+fn typo_distance(str1: &str, str2: &str) -> usize {
+    let a: Vec<char> = str1.chars().collect();
+    let b: Vec<char> = str2.chars().collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in distances[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let change = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            distances[i][j] = min3(
+                distances[i - 1][j] + 1,
+                distances[i][j - 1] + 1,
+                distances[i - 1][j - 1] + change,
+            );
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distances[i][j] = cmp::min(distances[i][j], distances[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// The number of single character edits needed to turn one string into the other
+///
+/// Borrowed from https://crates.io/crates/natural
+pub fn levenshtein_distance(str1: &str, str2: &str) -> usize {
     let a_vec: Vec<char> = str1.chars().collect();
     let b_vec: Vec<char> = str2.chars().collect();
 
+    let n = a_vec.len();
+    let m = b_vec.len();
+
+    let mut current: Vec<usize> = (0..n + 1).collect();
+
     for i in 1..m + 1 {
         let previous = current;
         current = vec![0; n + 1];
@@ -82,6 +134,25 @@ mod test {
         )
     }
 
+    #[test]
+    fn levenshtein_distance_counts_characters_not_bytes() {
+        assert_eq!(levenshtein_distance("andrés", "andres"), 1);
+        assert_eq!(levenshtein_distance("", "nu"), 2);
+    }
+
+    #[test]
+    fn closest_names_suggests_only_likely_typos() {
+        let names = || {
+            vec!["cd", "du", "ls", "echo", "select"]
+                .into_iter()
+                .map(String::from)
+        };
+
+        assert_eq!(closest_names("sl", names()), vec!["ls".to_string()]);
+        assert_eq!(closest_names("slect", names()), vec!["select".to_string()]);
+        assert!(closest_names("xyz", names()).is_empty());
+    }
+
     #[test]
     fn did_you_mean_returns_no_matches_when_empty() {
         let empty_source = Value {
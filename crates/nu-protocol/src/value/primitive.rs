@@ -322,7 +322,7 @@ pub fn format_primitive(primitive: &Primitive, field_name: Option<&String>) -> S
             (false, Some(_)) => "No",
         }
         .to_owned(),
-        Primitive::Binary(_) => "<binary>".to_owned(),
+        Primitive::Binary(b) => format!("<binary: {} bytes>", b.len()),
         Primitive::Date(d) => format_date(d),
     }
 }
@@ -508,3 +508,20 @@ pub fn format_date(d: &DateTime<FixedOffset>) -> String {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{format_primitive, Primitive};
+
+    #[test]
+    fn formats_binaries_with_their_length() {
+        assert_eq!(
+            format_primitive(&Primitive::Binary(vec![0, 1, 2]), None),
+            "<binary: 3 bytes>"
+        );
+        assert_eq!(
+            format_primitive(&Primitive::Binary(vec![]), None),
+            "<binary: 0 bytes>"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Whether output is colored, following https://no-color.org and the `CLICOLOR_FORCE` convention.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref ANSI_STYLE: Regex =
+        Regex::new(r"\x1b\[[0-9;]*m").expect("error with ansi escape regex");
+}
+
+/// False when `NO_COLOR` is set to anything, unless `CLICOLOR_FORCE` asks for colors anyway.
+/// Running `nu --no-color` sets `NO_COLOR`, so plugins and external commands see it too.
+pub fn colors_enabled() -> bool {
+    let set = |name: &str| match std::env::var_os(name) {
+        Some(value) => !value.is_empty() && value != "0",
+        None => false,
+    };
+
+    set("CLICOLOR_FORCE") || std::env::var_os("NO_COLOR").map_or(true, |value| value.is_empty())
+}
+
+/// Removes the escape sequences which color and style text.
+pub fn strip_ansi(text: &str) -> String {
+    ANSI_STYLE.replace_all(text, "").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_ansi;
+    use ansi_term::Color;
+
+    #[test]
+    fn strips_colors_and_styles() {
+        let text = format!(
+            "{} {}",
+            Color::Green.bold().paint("name"),
+            Color::Red.paint("size")
+        );
+
+        assert_eq!(strip_ansi(&text), "name size");
+    }
+}
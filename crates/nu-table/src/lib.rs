@@ -1,5 +1,7 @@
+mod color;
 mod table;
 mod wrap;
 
-pub use table::{draw_table, StyledString, Table, TextStyle, Theme};
-pub use wrap::Alignment;
+pub use color::{colors_enabled, strip_ansi};
+pub use table::{draw_table, render_table, StyledString, Table, TextStyle, Theme};
+pub use wrap::{truncate, Alignment};
@@ -1,3 +1,4 @@
+use crate::color::{colors_enabled, strip_ansi};
 use crate::wrap::{column_width, split_sublines, wrap, Alignment, Subline, WrappedCell};
 use ansi_term::{Color, Style};
 use std::collections::HashMap;
@@ -627,10 +628,11 @@ pub struct WrappedTable {
 }
 
 impl WrappedTable {
-    fn print_separator(
+    fn render_separator(
         &self,
         separator_position: SeparatorPosition,
         color_hm: &HashMap<String, Style>,
+        rendered: &mut String,
     ) {
         let column_count = self.column_widths.len();
         let mut output = String::new();
@@ -774,10 +776,16 @@ impl WrappedTable {
             }
         }
 
-        println!("{}", output);
+        rendered.push_str(&output);
+        rendered.push('\n');
     }
 
-    fn print_cell_contents(&self, cells: &[WrappedCell], color_hm: &HashMap<String, Style>) {
+    fn render_cell_contents(
+        &self,
+        cells: &[WrappedCell],
+        color_hm: &HashMap<String, Style>,
+        rendered: &mut String,
+    ) {
         let sep_color = color_hm
             .get("separator_color")
             .unwrap_or(&Style::default())
@@ -859,30 +867,28 @@ impl WrappedTable {
             if lines_printed == 0 {
                 break;
             } else {
-                println!("{}", output);
+                rendered.push_str(&output);
+                rendered.push('\n');
             }
         }
     }
 
-    fn print_table(&self, color_hm: &HashMap<String, Style>) {
-        #[cfg(windows)]
-        {
-            let _ = ansi_term::enable_ansi_support();
-        }
+    fn render_table(&self, color_hm: &HashMap<String, Style>) -> String {
+        let mut rendered = String::new();
 
         if self.data.is_empty() {
-            return;
+            return rendered;
         }
 
         if self.theme.print_top_border {
-            self.print_separator(SeparatorPosition::Top, &color_hm);
+            self.render_separator(SeparatorPosition::Top, &color_hm, &mut rendered);
         }
 
         let skip_headers = (self.headers.len() == 2 && self.headers[1].max_width == 0)
             || (self.headers.len() == 1 && self.headers[0].max_width == 0);
 
         if !self.headers.is_empty() && !skip_headers {
-            self.print_cell_contents(&self.headers, &color_hm);
+            self.render_cell_contents(&self.headers, &color_hm, &mut rendered);
         }
 
         let mut first_row = true;
@@ -890,22 +896,24 @@ impl WrappedTable {
         for row in &self.data {
             if !first_row {
                 if self.theme.separate_rows {
-                    self.print_separator(SeparatorPosition::Middle, &color_hm);
+                    self.render_separator(SeparatorPosition::Middle, &color_hm, &mut rendered);
                 }
             } else {
                 first_row = false;
 
                 if self.theme.separate_header && !self.headers.is_empty() && !skip_headers {
-                    self.print_separator(SeparatorPosition::Middle, &color_hm);
+                    self.render_separator(SeparatorPosition::Middle, &color_hm, &mut rendered);
                 }
             }
 
-            self.print_cell_contents(row, &color_hm);
+            self.render_cell_contents(row, &color_hm, &mut rendered);
         }
 
         if self.theme.print_bottom_border {
-            self.print_separator(SeparatorPosition::Bottom, &color_hm);
+            self.render_separator(SeparatorPosition::Bottom, &color_hm, &mut rendered);
         }
+
+        rendered
     }
 }
 
@@ -964,6 +972,16 @@ fn get_max_column_widths(processed_table: &ProcessedTable) -> Vec<usize> {
 }
 
 pub fn draw_table(table: &Table, termwidth: usize, color_hm: &HashMap<String, Style>) {
+    #[cfg(windows)]
+    {
+        let _ = ansi_term::enable_ansi_support();
+    }
+
+    print!("{}", render_table(table, termwidth, color_hm));
+}
+
+/// Renders the table into lines of text, each ending with a newline, instead of printing it.
+pub fn render_table(table: &Table, termwidth: usize, color_hm: &HashMap<String, Style>) -> String {
     // Remove the edges, if used
     let termwidth = if table.theme.print_left_border && table.theme.print_right_border {
         termwidth - 2
@@ -985,7 +1003,7 @@ pub fn draw_table(table: &Table, termwidth: usize, color_hm: &HashMap<String, St
         if !table.data.is_empty() && !table.data[0].is_empty() {
             table.data[0].len()
         } else {
-            return;
+            return String::new();
         }
     } else {
         headers_len
@@ -1022,7 +1040,12 @@ pub fn draw_table(table: &Table, termwidth: usize, color_hm: &HashMap<String, St
         &re_trailing,
     );
 
-    wrapped_table.print_table(&color_hm);
+    let rendered = wrapped_table.render_table(&color_hm);
+    if colors_enabled() {
+        rendered
+    } else {
+        strip_ansi(&rendered)
+    }
 }
 
 fn wrap_cells(
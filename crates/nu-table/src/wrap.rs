@@ -100,6 +100,36 @@ pub fn column_width(input: &[Vec<Subline>]) -> usize {
     max
 }
 
+/// Shortens each line of the input to at most `max_width` columns, marking the cut with an ellipsis.
+pub fn truncate(input: &str, max_width: usize) -> String {
+    use unicode_width::UnicodeWidthChar;
+
+    input
+        .lines()
+        .map(|line| {
+            if UnicodeWidthStr::width(line) <= max_width {
+                return line.to_string();
+            }
+
+            let mut output = String::new();
+            let mut current_width = 0;
+
+            for c in line.chars() {
+                let width = c.width().unwrap_or(0);
+                if current_width + width + 1 > max_width {
+                    break;
+                }
+                current_width += width;
+                output.push(c);
+            }
+
+            output.push('…');
+            output
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn split_word(cell_width: usize, word: &str) -> Vec<Subline> {
     use unicode_width::UnicodeWidthChar;
 
@@ -269,3 +299,28 @@ pub fn wrap<'a>(
 
     (output, current_max)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::truncate;
+
+    #[test]
+    fn truncates_wide_text_with_an_ellipsis() {
+        assert_eq!(truncate("nushell rocks", 6), "nushe…");
+    }
+
+    #[test]
+    fn leaves_text_that_fits_alone() {
+        assert_eq!(truncate("nushell", 7), "nushell");
+    }
+
+    #[test]
+    fn truncates_each_line() {
+        assert_eq!(truncate("a\nlonger", 4), "a\nlon…");
+    }
+
+    #[test]
+    fn truncates_to_the_width_of_wide_characters() {
+        assert_eq!(truncate("日本語テキスト", 5), "日本…");
+    }
+}
@@ -578,6 +578,18 @@ pub fn insert_data_at_column_path(
         let mut current: &mut Value = &mut original;
 
         for member in front {
+            // Rows missing an intermediate column get an empty row to insert into
+            if let (UntaggedValue::Row(dict), UnspannedPathMember::String(key)) =
+                (&mut current.value, &member.unspanned)
+            {
+                if !dict.contains_key(key) {
+                    dict.insert_data_at_key(
+                        key,
+                        UntaggedValue::Row(Default::default()).into_value(member.span),
+                    );
+                }
+            }
+
             let type_name = current.spanned_type_name();
 
             current = get_mut_data_by_member(current, &member).ok_or_else(|| {
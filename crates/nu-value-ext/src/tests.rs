@@ -99,6 +99,32 @@ fn forgiving_insertion_test_3() {
     );
 }
 
+#[test]
+fn insertion_creates_missing_intermediate_rows() {
+    let field_path = column_path("package.metadata.license")
+        .as_column_path()
+        .unwrap();
+
+    let value = row(indexmap! {
+        "package".into() =>
+            row(indexmap! {
+                "name".into() => string("nu")
+            })
+    });
+
+    assert_eq!(
+        *value
+            .insert_data_at_column_path(&field_path.item, string("MIT"))
+            .unwrap()
+            .get_data_by_column_path(
+                &field_path.item,
+                Box::new(error_callback("package.metadata.license"))
+            )
+            .unwrap(),
+        *string("MIT")
+    );
+}
+
 #[test]
 fn get_row_data_by_key() {
     let row = row(indexmap! {
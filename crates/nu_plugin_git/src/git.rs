@@ -0,0 +1,226 @@
+use chrono::{FixedOffset, TimeZone};
+use git2::{BlameOptions, Repository, Sort, Status, StatusOptions};
+use nu_errors::ShellError;
+use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+use std::path::Path;
+
+/// The repository the current directory is in.
+pub fn open_repository(tag: &Tag) -> Result<Repository, ShellError> {
+    Repository::discover(".").map_err(|_| {
+        ShellError::labeled_error(
+            "Not in a git repository",
+            "no git repository in the current directory or above it",
+            tag,
+        )
+    })
+}
+
+fn git_error(tag: &Tag) -> impl Fn(git2::Error) -> ShellError + '_ {
+    move |error| ShellError::labeled_error(error.message().to_string(), "git failed", tag)
+}
+
+/// The date of a signature, failing for an offset or a time chrono can't represent rather than
+/// panicking on a corrupt commit.
+fn date(time: git2::Time, tag: &Tag) -> Result<UntaggedValue, ShellError> {
+    FixedOffset::east_opt(time.offset_minutes() * 60)
+        .and_then(|offset| offset.timestamp_opt(time.seconds(), 0).single())
+        .map(UntaggedValue::date)
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                "The repository has a date that is out of range",
+                "date out of range",
+                tag,
+            )
+        })
+}
+
+/// The commits reachable from HEAD, newest first.
+pub fn log(repo: &Repository, limit: Option<usize>, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+    let mut revwalk = repo.revwalk().map_err(git_error(tag))?;
+    revwalk.push_head().map_err(git_error(tag))?;
+    revwalk.set_sorting(Sort::TIME).map_err(git_error(tag))?;
+
+    let mut rows = vec![];
+    for oid in revwalk.take(limit.unwrap_or(usize::MAX)) {
+        let commit = repo
+            .find_commit(oid.map_err(git_error(tag))?)
+            .map_err(git_error(tag))?;
+        let author = commit.author();
+
+        let mut row = TaggedDictBuilder::new(tag);
+        row.insert_untagged("commit", UntaggedValue::string(commit.id().to_string()));
+        row.insert_untagged(
+            "author",
+            UntaggedValue::string(author.name().unwrap_or_default()),
+        );
+        row.insert_untagged(
+            "email",
+            UntaggedValue::string(author.email().unwrap_or_default()),
+        );
+        row.insert_untagged("date", date(author.when(), tag)?);
+        row.insert_untagged(
+            "subject",
+            UntaggedValue::string(commit.summary().unwrap_or_default()),
+        );
+        row.insert_untagged(
+            "message",
+            UntaggedValue::string(commit.message().unwrap_or_default().trim_end()),
+        );
+        row.insert_untagged("parents", UntaggedValue::int(commit.parent_count()));
+        rows.push(row.into_value());
+    }
+
+    Ok(rows)
+}
+
+/// The files that differ from HEAD, with what changed in the index and in the working tree.
+pub fn status(repo: &Repository, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .renames_head_to_index(true);
+
+    let statuses = repo.statuses(Some(&mut options)).map_err(git_error(tag))?;
+
+    let mut rows = vec![];
+    for entry in statuses.iter() {
+        let status = entry.status();
+
+        let mut row = TaggedDictBuilder::new(tag);
+        row.insert_untagged(
+            "path",
+            UntaggedValue::string(String::from_utf8_lossy(entry.path_bytes())),
+        );
+        row.insert_untagged("staged", change(staged_change(status)));
+        row.insert_untagged("unstaged", change(unstaged_change(status)));
+        rows.push(row.into_value());
+    }
+
+    Ok(rows)
+}
+
+fn change(description: Option<&str>) -> UntaggedValue {
+    match description {
+        Some(description) => UntaggedValue::string(description),
+        None => UntaggedValue::nothing(),
+    }
+}
+
+fn staged_change(status: Status) -> Option<&'static str> {
+    if status.is_conflicted() {
+        Some("conflicted")
+    } else if status.is_index_new() {
+        Some("added")
+    } else if status.is_index_modified() {
+        Some("modified")
+    } else if status.is_index_deleted() {
+        Some("deleted")
+    } else if status.is_index_renamed() {
+        Some("renamed")
+    } else if status.is_index_typechange() {
+        Some("typechange")
+    } else {
+        None
+    }
+}
+
+fn unstaged_change(status: Status) -> Option<&'static str> {
+    if status.is_conflicted() {
+        Some("conflicted")
+    } else if status.is_wt_new() {
+        Some("untracked")
+    } else if status.is_wt_modified() {
+        Some("modified")
+    } else if status.is_wt_deleted() {
+        Some("deleted")
+    } else if status.is_wt_renamed() {
+        Some("renamed")
+    } else if status.is_wt_typechange() {
+        Some("typechange")
+    } else {
+        None
+    }
+}
+
+/// Each line of a file as of the last commit, with the commit that last changed it.
+pub fn blame(repo: &Repository, file: &Path, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+    let workdir = repo.workdir().ok_or_else(|| {
+        ShellError::labeled_error(
+            "Can't blame in a bare repository",
+            "the repository has no working tree",
+            tag,
+        )
+    })?;
+    let workdir = workdir.canonicalize()?;
+    let file = file.canonicalize()?;
+    let relative = file.strip_prefix(&workdir).map_err(|_| {
+        ShellError::labeled_error(
+            "The file is outside of the repository",
+            format!("not in {}", workdir.display()),
+            tag,
+        )
+    })?;
+
+    let blob = repo
+        .head()
+        .and_then(|head| head.peel_to_tree())
+        .and_then(|tree| tree.get_path(relative))
+        .and_then(|entry| entry.to_object(repo))
+        .and_then(|object| object.peel_to_blob())
+        .map_err(|_| {
+            ShellError::labeled_error(
+                "The file isn't committed",
+                "there is no history for this file",
+                tag,
+            )
+        })?;
+    let contents = String::from_utf8_lossy(blob.content());
+    let lines: Vec<&str> = contents.lines().collect();
+
+    let blame = repo
+        .blame_file(relative, Some(&mut BlameOptions::new()))
+        .map_err(git_error(tag))?;
+
+    let mut rows = vec![];
+    for hunk in blame.iter() {
+        let commit = hunk.final_commit_id().to_string();
+        let signature = hunk.final_signature();
+        let start = hunk.final_start_line();
+
+        for line in start..start + hunk.lines_in_hunk() {
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("line", UntaggedValue::int(line));
+            row.insert_untagged("commit", UntaggedValue::string(&commit));
+            row.insert_untagged(
+                "author",
+                UntaggedValue::string(signature.name().unwrap_or_default()),
+            );
+            row.insert_untagged("date", date(signature.when(), tag)?);
+            row.insert_untagged(
+                "content",
+                UntaggedValue::string(lines.get(line - 1).copied().unwrap_or_default()),
+            );
+            rows.push(row.into_value());
+        }
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{staged_change, unstaged_change};
+    use git2::Status;
+
+    #[test]
+    fn describes_staged_and_unstaged_changes() {
+        let status = Status::INDEX_NEW | Status::WT_MODIFIED;
+
+        assert_eq!(staged_change(status), Some("added"));
+        assert_eq!(unstaged_change(status), Some("modified"));
+        assert_eq!(staged_change(Status::WT_NEW), None);
+        assert_eq!(unstaged_change(Status::WT_NEW), Some("untracked"));
+    }
+}
@@ -0,0 +1,4 @@
+mod git;
+mod nu;
+
+pub use nu::{GitBlame, GitLog, GitStatus};
@@ -0,0 +1,7 @@
+mod blame;
+mod log;
+mod status;
+
+pub use blame::SubCommand as GitBlame;
+pub use log::SubCommand as GitLog;
+pub use status::SubCommand as GitStatus;
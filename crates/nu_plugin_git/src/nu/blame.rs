@@ -0,0 +1,45 @@
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+use crate::git;
+
+#[derive(Default)]
+pub struct SubCommand;
+
+impl SubCommand {
+    pub fn new() -> SubCommand {
+        SubCommand
+    }
+}
+
+impl Plugin for SubCommand {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("git-blame")
+            .desc("The commit that last changed each line of a file in the current git repository")
+            .required("path", SyntaxShape::FilePath, "the file to blame")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let path = match call_info.args.nth(0) {
+            Some(path) => path.as_path()?,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Missing argument",
+                    "expected the file to blame",
+                    &call_info.name_tag,
+                ))
+            }
+        };
+
+        let repo = git::open_repository(&call_info.name_tag)?;
+        let lines = git::blame(&repo, &path, &call_info.name_tag)?;
+
+        Ok(lines.into_iter().map(ReturnSuccess::value).collect())
+    }
+
+    fn filter(&mut self, _input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![])
+    }
+}
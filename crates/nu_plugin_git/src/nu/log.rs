@@ -0,0 +1,44 @@
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+use crate::git;
+
+#[derive(Default)]
+pub struct SubCommand;
+
+impl SubCommand {
+    pub fn new() -> SubCommand {
+        SubCommand
+    }
+}
+
+impl Plugin for SubCommand {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("git-log")
+            .desc("The commits of the current git repository, newest first")
+            .named(
+                "limit",
+                SyntaxShape::Int,
+                "the number of commits to show",
+                Some('n'),
+            )
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let limit = match call_info.args.get("limit") {
+            Some(limit) => Some(limit.as_u64()? as usize),
+            None => None,
+        };
+
+        let repo = git::open_repository(&call_info.name_tag)?;
+        let commits = git::log(&repo, limit, &call_info.name_tag)?;
+
+        Ok(commits.into_iter().map(ReturnSuccess::value).collect())
+    }
+
+    fn filter(&mut self, _input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![])
+    }
+}
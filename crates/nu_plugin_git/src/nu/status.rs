@@ -0,0 +1,33 @@
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, Value};
+
+use crate::git;
+
+#[derive(Default)]
+pub struct SubCommand;
+
+impl SubCommand {
+    pub fn new() -> SubCommand {
+        SubCommand
+    }
+}
+
+impl Plugin for SubCommand {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("git-status")
+            .desc("The changed files of the current git repository, staged and unstaged")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let repo = git::open_repository(&call_info.name_tag)?;
+        let files = git::status(&repo, &call_info.name_tag)?;
+
+        Ok(files.into_iter().map(ReturnSuccess::value).collect())
+    }
+
+    fn filter(&mut self, _input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![])
+    }
+}
@@ -1,7 +1,11 @@
 use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
+use std::time::Duration;
 use sysinfo::{ComponentExt, DiskExt, NetworkExt, ProcessorExt, System, SystemExt, UserExt};
 
+/// How long the load of the cores is measured for.
+const CPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+
 #[derive(Default)]
 pub struct Sys;
 
@@ -67,6 +71,9 @@ pub fn net(sys: &mut System, tag: Tag) -> Option<UntaggedValue> {
 }
 
 pub fn cpu(sys: &mut System, tag: Tag) -> Option<UntaggedValue> {
+    // The load of a core is measured between two refreshes
+    sys.refresh_cpu();
+    std::thread::sleep(CPU_SAMPLE_INTERVAL);
     sys.refresh_cpu();
 
     let mut output = vec![];
@@ -81,6 +88,10 @@ pub fn cpu(sys: &mut System, tag: Tag) -> Option<UntaggedValue> {
             UntaggedValue::string(trim_cstyle_null(cpu.get_brand().to_string())),
         );
         dict.insert_untagged("freq", UntaggedValue::int(cpu.get_frequency()));
+        dict.insert_untagged(
+            "usage",
+            UntaggedValue::decimal_from_float(cpu.get_cpu_usage() as f64, tag.span),
+        );
 
         output.push(dict.into_value());
     }
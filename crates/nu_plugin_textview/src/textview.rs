@@ -87,6 +87,11 @@ pub fn view_text_value(value: &Value) {
         }
     }
 
+    // nu passes `--no-color` on as NO_COLOR
+    if !nu_data::primitive::colors_enabled() {
+        colored_output = false;
+    }
+
     let value_anchor = value.anchor();
     if let UntaggedValue::Primitive(Primitive::String(ref s)) = &value.value {
         if let Some(source) = value_anchor {
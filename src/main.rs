@@ -1,14 +1,15 @@
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg, Shell};
 use log::LevelFilter;
 use nu_cli::create_default_context;
 use nu_command::utils::test_bins as binaries;
 use std::error::Error;
-use std::fs::File;
 use std::io::prelude::*;
+use std::path::Path;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let matches = App::new("nushell")
+fn app() -> App<'static, 'static> {
+    App::new("nushell")
         .version(clap::crate_version!())
+        .setting(AppSettings::TrailingVarArg)
         .arg(
             Arg::with_name("loglevel")
                 .short("l")
@@ -29,7 +30,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .hidden(true)
                 .long("testbin")
                 .value_name("TESTBIN")
-                .possible_values(&["cococo", "iecho", "fail", "nonu", "chop"])
+                .possible_values(&["cococo", "iecho", "eecho", "fail", "nonu", "chop"])
                 .takes_value(true),
         )
         .arg(
@@ -51,29 +52,89 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .multiple(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("debugger")
+                .long("debugger")
+                .help("stop the script at each `debug break` and wait for debugger commands")
+                .multiple(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("no-color")
+                .long("no-color")
+                .help("print without colors, as when NO_COLOR is set")
+                .multiple(false)
+                .takes_value(false),
+        )
         .arg(
             Arg::with_name("stdin")
                 .long("stdin")
                 .multiple(false)
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("sh-compat")
+                .long("sh-compat")
+                .help("run -c commands as a simple POSIX sh snippet, for tools using $SHELL -c")
+                .multiple(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("login")
+                .long("login")
+                .help("start as a login shell, sourcing the system-wide and user login.nu scripts")
+                .multiple(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("test")
+                .long("test")
+                .value_name("PATH")
+                .help("run the test_* commands in the nu scripts at PATH and report the results")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("completions")
+                .long("completions")
+                .value_name("SHELL")
+                .help("print a completion script for the given shell and exit")
+                .possible_values(&Shell::variants())
+                .case_insensitive(true)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("script")
-                .help("the nu script to run")
+                .help("the nu script to run, or - to read it from stdin")
                 .index(1),
         )
         .arg(
             Arg::with_name("args")
-                .help("positional args (used by --testbin)")
+                .help("arguments for the script's main (also used by --testbin)")
                 .index(2)
-                .multiple(true),
+                .multiple(true)
+                .allow_hyphen_values(true),
         )
-        .get_matches();
+}
+
+/// The text of the script at `path`, or of stdin if the path is `-`.
+fn read_script(path: &str) -> std::io::Result<String> {
+    if path == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        Ok(buffer)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let matches = app().get_matches();
 
     if let Some(bin) = matches.value_of("testbin") {
         match bin {
             "cococo" => binaries::cococo(),
             "iecho" => binaries::iecho(),
+            "eecho" => binaries::eecho(),
             "fail" => binaries::fail(),
             "nonu" => binaries::nonu(),
             "chop" => binaries::chop(),
@@ -83,6 +144,19 @@ fn main() -> Result<(), Box<dyn Error>> {
         return Ok(());
     }
 
+    if let Some(shell) = matches.value_of("completions") {
+        let shell = shell.parse::<Shell>()?;
+        app().gen_completions_to("nu", shell, &mut std::io::stdout());
+
+        return Ok(());
+    }
+
+    // Set for the whole process, so that plugins and external commands leave out colors too
+    if matches.is_present("no-color") {
+        std::env::set_var("NO_COLOR", "1");
+        std::env::remove_var("CLICOLOR_FORCE");
+    }
+
     let loglevel = match matches.value_of("loglevel") {
         None => LevelFilter::Warn,
         Some("error") => LevelFilter::Error,
@@ -121,6 +195,17 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     builder.try_init()?;
 
+    let login = nu_cli::is_login_shell(matches.is_present("login"));
+    if login {
+        nu_cli::setup_login_environment();
+    }
+
+    if let Some(path) = matches.value_of("test") {
+        let passed = futures::executor::block_on(nu_cli::run_tests(Path::new(path)))?;
+        nu_command::remove_temp_files();
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
     match matches.values_of("commands") {
         None => {}
         Some(values) => {
@@ -128,24 +213,56 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .map(|x| x.to_string())
                 .collect::<Vec<String>>()
                 .join("\n");
-            futures::executor::block_on(nu_cli::run_script_file(
-                script_text,
-                matches.is_present("stdin"),
-            ))?;
+            if matches.is_present("sh-compat") {
+                let status = futures::executor::block_on(nu_cli::run_sh_compat(
+                    script_text,
+                    matches.is_present("stdin"),
+                    login,
+                ))?;
+                nu_command::remove_temp_files();
+                if status != 0 {
+                    std::process::exit(status);
+                }
+                return Ok(());
+            } else {
+                futures::executor::block_on(nu_cli::run_script_file(
+                    script_text,
+                    matches.is_present("stdin"),
+                    vec![],
+                    login,
+                ))?;
+            }
+            nu_command::remove_temp_files();
             return Ok(());
         }
     }
 
     match matches.value_of("script") {
         Some(script) => {
-            let mut file = File::open(script)?;
-            let mut buffer = String::new();
-            file.read_to_string(&mut buffer)?;
+            let buffer = match read_script(script) {
+                Ok(buffer) => buffer,
+                Err(err) => {
+                    eprintln!("nu: {}: {}", script, err);
+                    std::process::exit(1);
+                }
+            };
+
+            let script_args = matches
+                .values_of("args")
+                .map(|args| args.map(String::from).collect())
+                .unwrap_or_default();
+
+            if matches.is_present("debugger") {
+                nu_engine::enable_debugger(&buffer);
+            }
 
             futures::executor::block_on(nu_cli::run_script_file(
                 buffer,
                 matches.is_present("stdin"),
+                script_args,
+                login,
             ))?;
+            nu_command::remove_temp_files();
             return Ok(());
         }
 
@@ -158,7 +275,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
             #[cfg(feature = "rustyline-support")]
             {
-                futures::executor::block_on(nu_cli::cli(context))?;
+                futures::executor::block_on(nu_cli::cli(context, login))?;
             }
 
             #[cfg(not(feature = "rustyline-support"))]
@@ -168,5 +285,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    nu_command::remove_temp_files();
+
     Ok(())
 }
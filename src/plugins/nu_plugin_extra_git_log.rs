@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_git::GitLog;
+
+fn main() {
+    serve_plugin(&mut GitLog::new());
+}
@@ -1,4 +1,7 @@
+use nu_test_support::fs::executable_path;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
+use std::process::Command;
 
 mod pipeline;
 
@@ -34,3 +37,24 @@ fn plugins_are_declared_with_wix() {
 
     assert_eq!(actual.out, "0");
 }
+
+#[test]
+fn reports_the_data_and_cache_dirs_without_creating_them() {
+    Playground::setup("nu_dirs_test_1", |dirs, _| {
+        let output = Command::new(executable_path())
+            .current_dir(dirs.test())
+            .env("NU_DATA_DIR", dirs.test().join("data"))
+            .env("NU_CACHE_DIR", dirs.test().join("cache"))
+            .arg("--skip-plugins")
+            .arg("-c")
+            .arg("echo $nu.data-dir $nu.cache-dir | path basename | str collect ' '")
+            .output()
+            .expect("couldn't run nu");
+
+        let actual = String::from_utf8_lossy(&output.stdout);
+
+        assert_eq!(actual.trim(), "data cache");
+        assert!(!dirs.test().join("data").exists());
+        assert!(!dirs.test().join("cache").exists());
+    })
+}
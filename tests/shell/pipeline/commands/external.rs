@@ -22,6 +22,17 @@ fn shows_error_for_command_not_found_in_pipeline() {
     assert!(actual.err.contains("Command not found"));
 }
 
+#[cfg(feature = "which")]
+#[test]
+fn suggests_a_command_for_a_typo() {
+    let actual = nu!(
+        cwd: ".",
+        "slect"
+    );
+
+    assert!(actual.err.contains("did you mean `select`?"));
+}
+
 #[cfg(feature = "which")]
 #[test]
 fn automatically_change_directory() {
@@ -146,7 +157,8 @@ mod it_evaluation {
 
 mod stdin_evaluation {
     use super::nu;
-    use nu_test_support::pipeline;
+    use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+    use nu_test_support::{pipeline, playground::Playground};
 
     #[test]
     fn does_not_panic_with_no_newline_in_stream() {
@@ -177,6 +189,117 @@ mod stdin_evaluation {
 
         assert_eq!(stdout, "y");
     }
+
+    #[test]
+    fn takes_stdin_from_a_file_redirection() {
+        Playground::setup("stdin_redirection_test_1", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContentToBeTrimmed(
+                "nu_candies.txt",
+                r#"
+                    AndrásWithKitKatzz
+                    AndrásWithKitKatz
+                "#,
+            )]);
+
+            let actual = nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    nu --testbin chop < nu_candies.txt
+                    | lines
+                    | first 1
+                "#
+            ));
+
+            assert_eq!(actual.out, "AndrásWithKitKatz");
+        })
+    }
+
+    #[test]
+    fn takes_stdin_from_an_expression_redirection() {
+        let actual = nu!(
+            cwd: ".",
+            pipeline(r#"
+                nu --testbin chop in< $(echo nushell)
+                | lines
+                | first 1
+            "#
+        ));
+
+        assert_eq!(actual.out, "nushel");
+    }
+
+    #[test]
+    fn errors_when_redirecting_stdin_in_the_middle_of_a_pipeline() {
+        let actual = nu!(
+            cwd: ".",
+            pipeline(r#"
+                echo nushell | nu --testbin chop in< nu
+            "#
+        ));
+
+        assert!(actual.err.contains("first command of a pipeline"));
+    }
+}
+
+mod output_redirection {
+    use super::nu;
+    use nu_test_support::fs::file_contents;
+    use nu_test_support::playground::Playground;
+
+    #[test]
+    fn appends_stdout_of_internal_commands() {
+        Playground::setup("output_redirection_test_1", |dirs, _| {
+            nu!(
+                cwd: dirs.test(),
+                "echo nu out>> log.txt; echo shell out>> log.txt"
+            );
+
+            assert_eq!(file_contents(dirs.test().join("log.txt")), "nushell");
+        })
+    }
+
+    #[test]
+    fn appends_stdout_of_external_commands() {
+        Playground::setup("output_redirection_test_2", |dirs, _| {
+            let actual = nu!(
+                cwd: dirs.test(),
+                "nu --testbin cococo nushell out>> log.txt"
+            );
+
+            assert_eq!(actual.out, "");
+            assert!(file_contents(dirs.test().join("log.txt")).contains("nushell"));
+        })
+    }
+
+    #[test]
+    fn appends_stderr_of_external_commands() {
+        Playground::setup("output_redirection_test_3", |dirs, _| {
+            let actual = nu!(
+                cwd: dirs.test(),
+                "nu --testbin eecho oops err>> errors.txt; nu --testbin eecho again err>> errors.txt"
+            );
+
+            assert_eq!(actual.err, "");
+            assert_eq!(
+                file_contents(dirs.test().join("errors.txt")),
+                "oops\nagain\n"
+            );
+        })
+    }
+
+    #[test]
+    fn appends_errors_of_internal_commands() {
+        Playground::setup("output_redirection_test_4", |dirs, _| {
+            let actual = nu!(
+                cwd: dirs.test(),
+                "open missing.txt err>> errors.txt; echo done"
+            );
+
+            assert_eq!(actual.err, "");
+            assert_eq!(actual.out, "done");
+            assert!(file_contents(dirs.test().join("errors.txt")).contains("Cannot find file"));
+        })
+    }
 }
 
 mod external_words {
@@ -203,6 +326,7 @@ mod external_words {
 
 mod nu_commands {
     use super::nu;
+    use nu_test_support::pipeline;
 
     #[test]
     fn echo_internally_externally() {
@@ -212,10 +336,92 @@ mod nu_commands {
 
         assert_eq!(actual.out, "foo");
     }
+
+    #[test]
+    fn runs_sh_snippets_in_compatibility_mode() {
+        let actual = nu!(cwd: ".", r#"
+        nu --sh-compat -c "nu --testbin fail && nu --testbin cococo skipped || nu --testbin cococo 'ran fallback'"
+        "#);
+
+        assert_eq!(actual.out, "ran fallback");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exits_with_the_status_of_the_sh_snippet() {
+        use nu_test_support::fs::executable_path;
+        use std::process::Command;
+
+        let status = |script: &str| {
+            Command::new(executable_path())
+                .args(&["--skip-plugins", "--sh-compat", "-c", script])
+                .status()
+                .expect("couldn't run nu")
+                .code()
+        };
+
+        assert_eq!(status("sh -c 'exit 7'"), Some(7));
+        assert_eq!(status("sh -c 'exit 7'; exit"), Some(7));
+        assert_eq!(status("sh -c 'exit 7' || true"), Some(0));
+    }
+
+    #[test]
+    fn accepts_login_flag() {
+        let actual = nu!(cwd: ".", r#"
+        nu --login -c "echo 'foo'"
+        "#);
+
+        assert_eq!(actual.out, "foo");
+    }
+
+    #[test]
+    fn sources_the_login_scripts_for_commands_given_with_login() {
+        use nu_test_support::fs::{executable_path, Stub::FileWithContent};
+        use nu_test_support::playground::Playground;
+        use std::process::Command;
+
+        Playground::setup("login_test_1", |dirs, sandbox| {
+            sandbox.mkdir("config").with_files(vec![FileWithContent(
+                "config/login.nu",
+                "echo yes | save logged_in.txt",
+            )]);
+
+            let run = |args: &[&str]| {
+                Command::new(executable_path())
+                    .current_dir(dirs.test())
+                    .env("NU_CONFIG_DIR", dirs.test().join("config"))
+                    .arg("--skip-plugins")
+                    .args(args)
+                    .output()
+                    .expect("couldn't run nu")
+            };
+
+            run(&["-c", "echo 'foo'"]);
+            assert!(!dirs.test().join("logged_in.txt").exists());
+
+            run(&["--login", "-c", "echo 'foo'"]);
+            assert!(dirs.test().join("logged_in.txt").exists());
+        })
+    }
+
+    #[test]
+    fn prints_completions_for_other_shells() {
+        let actual = nu!(cwd: ".", pipeline(r#"
+        nu --completions fish
+        | lines
+        | where $it =~ "loglevel"
+        | count
+        "#));
+
+        assert_eq!(actual.out, "1");
+    }
 }
 
 mod nu_script {
     use super::nu;
+    use nu_test_support::fs::file_contents;
+    use nu_test_support::fs::Stub::FileWithContent;
+    use nu_test_support::playground::Playground;
 
     #[test]
     fn run_nu_script() {
@@ -234,6 +440,136 @@ mod nu_script {
 
         assert_eq!(actual.out, "23");
     }
+
+    #[test]
+    fn reports_scripts_that_cannot_be_read() {
+        let actual = nu!(cwd: ".", r#"
+        nu not_a_script.nu
+        "#);
+
+        assert!(actual.err.contains("nu: not_a_script.nu:"));
+    }
+
+    #[test]
+    fn runs_script_from_stdin() {
+        let actual = nu!(cwd: ".", r#"
+        echo "= 1 + 2" | nu -
+        "#);
+
+        assert_eq!(actual.out, "3");
+    }
+
+    #[test]
+    fn passes_arguments_to_main() {
+        Playground::setup("run_nu_script_main_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "greet.nu",
+                r#"
+                    def main [name: string, --shout] {
+                        if $shout { echo $name | str upcase } { echo $name }
+                    }
+                "#,
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            nu greet.nu nushell --shout
+            "#);
+
+            assert_eq!(actual.out, "NUSHELL");
+        })
+    }
+
+    #[test]
+    fn breakpoints_pass_values_through_without_the_debugger() {
+        Playground::setup("run_nu_script_break_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "break.nu",
+                "echo [1 2] | debug break | count",
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            nu break.nu
+            "#);
+
+            assert_eq!(actual.out, "2");
+        })
+    }
+
+    #[test]
+    fn debugger_stops_at_breakpoints() {
+        Playground::setup("run_nu_script_debugger_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "break.nu",
+                "echo [1 2] | debug break | count",
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            echo continue | nu --debugger break.nu
+            "#);
+
+            assert!(actual.err.contains("stopped at line 1"));
+            assert_eq!(actual.out, "2");
+        })
+    }
+
+    #[test]
+    fn debugger_carries_on_when_stdin_ends() {
+        Playground::setup("run_nu_script_debugger_eof_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "break.nu",
+                "echo [1 2] | debug break | count",
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            echo [] | nu --debugger break.nu
+            "#);
+
+            assert_eq!(actual.out, "2");
+        })
+    }
+
+    #[test]
+    fn quitting_the_debugger_runs_the_exit_hook() {
+        Playground::setup("run_nu_script_debugger_quit_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "break.nu",
+                r#"
+                    on-exit { echo bye | save exited.txt }
+                    echo [1 2] | debug break | count
+                "#,
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            echo quit | nu --debugger break.nu
+            "#);
+
+            assert!(actual.err.contains("Quit in the debugger"));
+            assert_eq!(actual.out, "");
+            assert_eq!(file_contents(dirs.test().join("exited.txt")), "bye");
+        })
+    }
+
+    #[test]
+    fn runs_tests_in_scripts() {
+        Playground::setup("run_nu_script_tests_test", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "math.nu",
+                r#"
+                    def test_addition [] { assert equal $(= 1 + 1) 2 }
+                    def test_subtraction [] { assert -m "subtraction is off" 3 - 1 == 1 }
+                "#,
+            )]);
+
+            let actual = nu!(cwd: dirs.test(), r#"
+            nu --test math.nu
+            "#);
+
+            assert!(actual.out.contains("test_addition"));
+            assert!(actual.out.contains("passed"));
+            assert!(actual.out.contains("failed"));
+            assert!(actual.err.contains("subtraction is off"));
+        })
+    }
 }
 
 mod tilde_expansion {
@@ -220,6 +220,27 @@ fn autoenv() {
     })
 }
 
+#[test]
+fn nu_variable_has_paths_and_startup_details() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $nu.current-exe | path exists
+        "#
+    );
+
+    assert_eq!(actual.out, "true");
+
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $nu.started | describe
+        "#
+    );
+
+    assert_eq!(actual.out, "date");
+}
+
 #[test]
 fn invocation_properly_redirects() {
     let actual = nu!(
@@ -244,6 +265,30 @@ fn argument_invocation() {
     assert_eq!(actual.out, "foo");
 }
 
+#[test]
+fn invocation_result_is_an_argument() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $(echo [a b c] | count)
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn invocation_table_is_spliced_into_arguments() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            nu --testbin cococo $(echo [a b c]) | str collect
+        "#
+    );
+
+    assert_eq!(actual.out, "a b c");
+}
+
 #[test]
 fn invocation_handles_dot() {
     Playground::setup("invocation_handles_dot", |dirs, sandbox| {
@@ -464,6 +509,62 @@ fn run_custom_command_with_rest_and_flag() {
     assert_eq!(actual.out, r#"["world","hello","yay"]"#);
 }
 
+#[test]
+fn custom_command_keeps_its_environment_to_itself() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def set-it [] { let-env xyz = "my message" }; set-it; echo $nu.env.xyz
+        "#
+    );
+
+    assert!(actual.err.contains("did you mean"));
+}
+
+#[test]
+fn custom_command_with_env_flag_exports_its_environment() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def --env set-it [] { let-env xyz = "my message" }; set-it; echo $nu.env.xyz
+        "#
+    );
+
+    assert_eq!(actual.out, "my message");
+}
+
+#[test]
+fn custom_command_changes_directory_only_for_itself() {
+    Playground::setup("custom_command_cd_test_1", |dirs, sandbox| {
+        sandbox.mkdir("inner");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                def go-in [] { cd inner; pwd | path basename }; go-in; pwd | path basename
+            "#
+        );
+
+        assert_eq!(actual.out, "innercustom_command_cd_test_1");
+    })
+}
+
+#[test]
+fn custom_command_with_env_flag_changes_the_callers_directory() {
+    Playground::setup("custom_command_cd_test_2", |dirs, sandbox| {
+        sandbox.mkdir("inner");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                def --env go-in [] { cd inner }; go-in; pwd | path basename
+            "#
+        );
+
+        assert_eq!(actual.out, "inner");
+    })
+}
+
 #[test]
 fn set_variable() {
     let actual = nu!(
@@ -490,6 +591,19 @@ fn set_doesnt_leak() {
     assert!(actual.err.contains("unknown variable"));
 }
 
+#[test]
+fn set_underscore_discards_the_value() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+        let _ = $(echo [1 2 3] | each { echo $it }); echo $_
+        "#
+    );
+
+    assert!(actual.out.is_empty());
+    assert!(actual.err.contains("unknown variable"));
+}
+
 #[test]
 fn set_env_variable() {
     let actual = nu!(